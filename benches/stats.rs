@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use dsp4rust::signal::Signal;
+
+const LEN: usize = 10_000_000;
+
+// 大直流偏置上的小正弦，与统计量的典型输入一致
+fn test_signal() -> Signal {
+    Signal::from_len_fn(LEN, |i| 1e3 + (i as f64 * 1e-3).sin())
+}
+
+fn energy(c: &mut Criterion) {
+    let signal = test_signal();
+    let mut group = c.benchmark_group("energy_1e7");
+    group.sample_size(20);
+    // 基线：逐元素 powf(2.) 的朴素求和
+    group.bench_function("naive_powf", |b| {
+        b.iter(|| black_box(&signal).iter().map(|x| x.powf(2.)).sum::<f64>())
+    });
+    group.bench_function("naive_mul", |b| {
+        b.iter(|| black_box(&signal).iter().map(|x| x * x).sum::<f64>())
+    });
+    group.bench_function("energy", |b| b.iter(|| black_box(&signal).energy()));
+    // 分别求和与能量需要两遍，moments 只需一遍
+    group.bench_function("sum_then_energy", |b| {
+        b.iter(|| {
+            let signal = black_box(&signal);
+            (signal.sum(), signal.energy())
+        })
+    });
+    group.bench_function("moments", |b| b.iter(|| black_box(&signal).moments()));
+    group.finish();
+}
+
+fn variance(c: &mut Criterion) {
+    let signal = test_signal();
+    let mut group = c.benchmark_group("variance_1e7");
+    group.sample_size(20);
+    group.bench_function("var_pop", |b| b.iter(|| black_box(&signal).var_pop()));
+    group.bench_function("ndarray_welford", |b| {
+        b.iter(|| black_box(&signal).as_array().var(0.0))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, energy, variance);
+criterion_main!(benches);
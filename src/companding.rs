@@ -0,0 +1,156 @@
+use crate::signal::Signal;
+
+// μ 律与 A 律压扩
+impl Signal {
+    /// Compresses samples in `[-1, 1]` with the μ-law curve
+    /// `y = sgn(x)·ln(1 + μ·|x|) / ln(1 + μ)`.
+    ///
+    /// Samples outside `[-1, 1]` are clipped first. `mu = 255` is the North American and Japanese
+    /// G.711 standard. NaN stays NaN.
+    ///
+    /// # Panics
+    /// Panics if `mu` is not positive and finite.
+    ///
+    /// 使用 μ 律曲线 `y = sgn(x)·ln(1 + μ·|x|) / ln(1 + μ)` 压缩 `[-1, 1]` 内的样本。
+    ///
+    /// 超出 `[-1, 1]` 的样本先被削波。`mu = 255` 为北美与日本的 G.711 标准。NaN 保持为 NaN。
+    ///
+    /// # 恐慌
+    /// `mu` 不是有限正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-1.0, 0.0, 0.5]);
+    /// let compressed = signal.mu_law_compress(255.0);
+    /// assert_eq!(compressed[0], -1.0);
+    /// assert!((compressed[2] - 0.8757).abs() < 1e-4);
+    /// ```
+    pub fn mu_law_compress(&self, mu: f64) -> Signal {
+        check_mu(mu);
+        let norm = mu.ln_1p();
+        self.map_clipped(|x| (mu * x).ln_1p() / norm)
+    }
+
+    /// Expands μ-law compressed samples, the inverse of `mu_law_compress`:
+    /// `x = sgn(y)·((1 + μ)^|y| - 1) / μ`.
+    ///
+    /// Samples outside `[-1, 1]` are clipped first. NaN stays NaN.
+    ///
+    /// # Panics
+    /// Panics if `mu` is not positive and finite.
+    ///
+    /// 展开 μ 律压缩后的样本，是 `mu_law_compress` 的逆运算：`x = sgn(y)·((1 + μ)^|y| - 1) / μ`。
+    ///
+    /// 超出 `[-1, 1]` 的样本先被削波。NaN 保持为 NaN。
+    ///
+    /// # 恐慌
+    /// `mu` 不是有限正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-0.3, 0.0, 0.8]);
+    /// let restored = signal.mu_law_compress(255.0).mu_law_expand(255.0);
+    /// assert!(restored.iter().zip([-0.3, 0.0, 0.8]).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn mu_law_expand(&self, mu: f64) -> Signal {
+        check_mu(mu);
+        let norm = mu.ln_1p();
+        self.map_clipped(|y| (y * norm).exp_m1() / mu)
+    }
+
+    /// Compresses samples in `[-1, 1]` with the A-law curve: `y = A·|x| / (1 + ln A)` for
+    /// `|x| < 1/A` and `y = (1 + ln(A·|x|)) / (1 + ln A)` above, with the sign of `x`.
+    ///
+    /// Samples outside `[-1, 1]` are clipped first. `a = 87.6` is the European G.711 standard,
+    /// and `a = 1` is the identity. NaN stays NaN.
+    ///
+    /// # Panics
+    /// Panics if `a` is not finite or is less than 1.
+    ///
+    /// 使用 A 律曲线压缩 `[-1, 1]` 内的样本：`|x| < 1/A` 时 `y = A·|x| / (1 + ln A)`，
+    /// 否则 `y = (1 + ln(A·|x|)) / (1 + ln A)`，符号与 `x` 相同。
+    ///
+    /// 超出 `[-1, 1]` 的样本先被削波。`a = 87.6` 为欧洲 G.711 标准，`a = 1` 为恒等映射。NaN 保持为 NaN。
+    ///
+    /// # 恐慌
+    /// `a` 不是有限数或小于 1 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.01, 0.5]);
+    /// let compressed = signal.a_law_compress(87.6);
+    /// assert!((compressed[0] - 0.1601).abs() < 1e-4);
+    /// assert!((compressed[1] - 0.8733).abs() < 1e-4);
+    /// ```
+    pub fn a_law_compress(&self, a: f64) -> Signal {
+        check_a(a);
+        let norm = 1.0 + a.ln();
+        self.map_clipped(|x| {
+            if x < 1.0 / a {
+                a * x / norm
+            } else {
+                (1.0 + (a * x).ln()) / norm
+            }
+        })
+    }
+
+    /// Expands A-law compressed samples, the inverse of `a_law_compress`.
+    ///
+    /// Samples outside `[-1, 1]` are clipped first. NaN stays NaN.
+    ///
+    /// # Panics
+    /// Panics if `a` is not finite or is less than 1.
+    ///
+    /// 展开 A 律压缩后的样本，是 `a_law_compress` 的逆运算。
+    ///
+    /// 超出 `[-1, 1]` 的样本先被削波。NaN 保持为 NaN。
+    ///
+    /// # 恐慌
+    /// `a` 不是有限数或小于 1 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-0.004, 0.25]);
+    /// let restored = signal.a_law_compress(87.6).a_law_expand(87.6);
+    /// assert!(restored.iter().zip([-0.004, 0.25]).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn a_law_expand(&self, a: f64) -> Signal {
+        check_a(a);
+        let norm = 1.0 + a.ln();
+        self.map_clipped(|y| {
+            if y < 1.0 / norm {
+                y * norm / a
+            } else {
+                (y * norm - 1.0).exp() / a
+            }
+        })
+    }
+
+    // 削波到 [-1, 1] 后对绝对值应用 curve，再恢复符号
+    fn map_clipped<F>(&self, curve: F) -> Signal
+    where
+        F: Fn(f64) -> f64,
+    {
+        self.iter()
+            .map(|&x| {
+                let x = x.clamp(-1.0, 1.0);
+                curve(x.abs()).copysign(x)
+            })
+            .collect()
+    }
+}
+
+fn check_mu(mu: f64) {
+    assert!(
+        mu.is_finite() && mu > 0.0,
+        "mu must be positive and finite, got {mu}"
+    );
+}
+
+fn check_a(a: f64) {
+    assert!(a.is_finite() && a >= 1.0, "a must be at least 1, got {a}");
+}
@@ -0,0 +1,367 @@
+use crate::errors::ShapeError;
+use crate::inner::fft;
+use crate::signal::Signal;
+use ndarray::{Array1, ArrayView1};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub use num_complex::Complex64;
+
+/// A signal of complex samples, for spectra, analytic signals and IQ data.
+///
+/// The arithmetic mirrors `Signal`: `+`, `-`, `*` and `/` work elementwise between complex
+/// signals (by reference or by value, reusing an owned buffer), with a real `Signal` on the
+/// right, and with `f64` or `Complex64` scalars on either side. As with `Signal`, combining
+/// signals of different lengths panics; the checked constructors return
+/// `ShapeError::LengthMismatch` instead.
+///
+/// 复数样本构成的信号，用于频谱、解析信号与 IQ 数据。
+///
+/// 运算与 `Signal` 一致：复数信号之间（按引用或按值传入，按值传入时复用其缓冲区）、右侧为实信号 `Signal`，
+/// 以及任一侧为 `f64` 或 `Complex64` 标量时，`+`、`-`、`*`、`/` 均逐元素进行。与 `Signal` 相同，
+/// 组合长度不同的信号会发生恐慌；带检查的构造函数则返回 `ShapeError::LengthMismatch`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::complex_signal::{Complex64, ComplexSignal};
+/// use dsp4rust::signal::Signal;
+/// let z = ComplexSignal::from_re_im(
+///     &Signal::from_vec(vec![3.0, 0.0]),
+///     &Signal::from_vec(vec![4.0, -2.0]),
+/// )
+/// .unwrap();
+/// assert_eq!(z.abs().to_vec(), vec![5.0, 2.0]);
+/// assert_eq!(z.conj()[0], Complex64::new(3.0, -4.0));
+/// // 与共轭相乘得到 |z|²
+/// assert_eq!((&z * &z.conj()).re().to_vec(), vec![25.0, 4.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ComplexSignal {
+    data: Array1<Complex64>,
+}
+
+// 构造与转换
+impl ComplexSignal {
+    /// Creates a complex signal from a vector of samples.
+    ///
+    /// 由样本向量创建复数信号。
+    pub fn from_vec(vec: Vec<Complex64>) -> Self {
+        ComplexSignal {
+            data: Array1::from_vec(vec),
+        }
+    }
+
+    /// Creates a complex signal from an ndarray array.
+    ///
+    /// 由 ndarray 数组创建复数信号。
+    pub fn from_array1(data: Array1<Complex64>) -> Self {
+        ComplexSignal { data }
+    }
+
+    /// Creates a complex signal of `len` zeros.
+    ///
+    /// 创建 `len` 个零组成的复数信号。
+    pub fn zeros(len: usize) -> Self {
+        ComplexSignal {
+            data: Array1::zeros(len),
+        }
+    }
+
+    /// Creates a complex signal with the samples of `signal` as real parts and zero imaginary
+    /// parts.
+    ///
+    /// 以 `signal` 的样本为实部、零为虚部创建复数信号。
+    pub fn from_real(signal: &Signal) -> Self {
+        ComplexSignal {
+            data: signal.as_array().mapv(|re| Complex64::new(re, 0.0)),
+        }
+    }
+
+    /// Creates a complex signal from its real and imaginary parts.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if `re` and `im` differ in length.
+    ///
+    /// 由实部与虚部创建复数信号。
+    ///
+    /// # 错误
+    /// `re` 与 `im` 长度不同时返回 `ShapeError::LengthMismatch`。
+    pub fn from_re_im(re: &Signal, im: &Signal) -> Result<Self, ShapeError> {
+        Self::zip_with(re, im, Complex64::new)
+    }
+
+    /// Creates a complex signal from magnitudes and phases in radians, `r·e^(iθ)`.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if `magnitude` and `phase` differ in length.
+    ///
+    /// 由模与以弧度为单位的相位创建复数信号 `r·e^(iθ)`。
+    ///
+    /// # 错误
+    /// `magnitude` 与 `phase` 长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::complex_signal::ComplexSignal;
+    /// use dsp4rust::signal::Signal;
+    /// use std::f64::consts::FRAC_PI_2;
+    /// let z = ComplexSignal::from_polar(&Signal::from_vec(vec![2.0]), &Signal::from_vec(vec![FRAC_PI_2])).unwrap();
+    /// assert!(z[0].re.abs() < 1e-15);
+    /// assert_eq!(z[0].im, 2.0);
+    /// ```
+    pub fn from_polar(magnitude: &Signal, phase: &Signal) -> Result<Self, ShapeError> {
+        Self::zip_with(magnitude, phase, Complex64::from_polar)
+    }
+
+    fn zip_with(a: &Signal, b: &Signal, f: fn(f64, f64) -> Complex64) -> Result<Self, ShapeError> {
+        if a.len() != b.len() {
+            return Err(ShapeError::LengthMismatch {
+                left: a.len(),
+                right: b.len(),
+            });
+        }
+        Ok(a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect())
+    }
+
+    /// Returns the number of samples.
+    ///
+    /// 返回样本数。
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the signal has no samples.
+    ///
+    /// 信号没有样本时返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator over the samples.
+    ///
+    /// 返回样本的迭代器。
+    pub fn iter(&self) -> ndarray::iter::Iter<'_, Complex64, ndarray::Ix1> {
+        self.data.iter()
+    }
+
+    /// Returns the underlying ndarray view.
+    ///
+    /// 返回底层的 ndarray 视图。
+    pub fn as_array(&self) -> ArrayView1<'_, Complex64> {
+        self.data.view()
+    }
+
+    /// Copies the samples into a vector.
+    ///
+    /// 将样本复制到向量中。
+    pub fn to_vec(&self) -> Vec<Complex64> {
+        self.data.to_vec()
+    }
+
+    /// Returns the real parts.
+    ///
+    /// 返回实部。
+    pub fn re(&self) -> Signal {
+        self.map_real(|z| z.re)
+    }
+
+    /// Returns the imaginary parts.
+    ///
+    /// 返回虚部。
+    pub fn im(&self) -> Signal {
+        self.map_real(|z| z.im)
+    }
+
+    /// Returns the magnitudes `|z|`.
+    ///
+    /// 返回模 `|z|`。
+    pub fn abs(&self) -> Signal {
+        self.map_real(|z| z.norm())
+    }
+
+    /// Returns the squared magnitudes `|z|²`, without the square root of `abs`.
+    ///
+    /// 返回模的平方 `|z|²`，省去 `abs` 中的开方。
+    pub fn norm_sqr(&self) -> Signal {
+        self.map_real(|z| z.norm_sqr())
+    }
+
+    /// Returns the phases in radians, in `(-π, π]`.
+    ///
+    /// 返回以弧度为单位的相位，取值在 `(-π, π]` 内。
+    pub fn arg(&self) -> Signal {
+        self.map_real(|z| z.arg())
+    }
+
+    /// Returns the complex conjugate of every sample.
+    ///
+    /// 返回每个样本的共轭复数。
+    pub fn conj(&self) -> ComplexSignal {
+        ComplexSignal {
+            data: self.data.mapv(|z| z.conj()),
+        }
+    }
+
+    fn map_real(&self, f: impl Fn(&Complex64) -> f64) -> Signal {
+        self.data.iter().map(f).collect()
+    }
+}
+
+// 离散傅里叶变换
+impl ComplexSignal {
+    /// Returns the discrete Fourier transform `X[k] = Σ x[n]·e^(-2πikn/N)`, without
+    /// normalization. Any length is supported.
+    ///
+    /// 返回离散傅里叶变换 `X[k] = Σ x[n]·e^(-2πikn/N)`，不做归一化。支持任意长度。
+    pub fn fft(&self) -> ComplexSignal {
+        let mut data = self.data.to_vec();
+        fft::fft_in_place(&mut data, false);
+        ComplexSignal::from_vec(data)
+    }
+
+    /// Returns the inverse discrete Fourier transform, normalized by `1/N` so that
+    /// `x.fft().ifft()` gives back `x`.
+    ///
+    /// 返回逆离散傅里叶变换，按 `1/N` 归一化，因此 `x.fft().ifft()` 还原 `x`。
+    pub fn ifft(&self) -> ComplexSignal {
+        ComplexSignal::from_vec(fft::ifft(&self.data.to_vec()))
+    }
+}
+
+impl Signal {
+    /// Returns the discrete Fourier transform of the real signal, as `ComplexSignal::fft`. The
+    /// spectrum is conjugate-symmetric, `X[N - k] = conj(X[k])`.
+    ///
+    /// 返回实信号的离散傅里叶变换，同 `ComplexSignal::fft`。频谱共轭对称，`X[N - k] = conj(X[k])`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let spectrum = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]).fft();
+    /// assert_eq!(spectrum[0].re, 10.0);
+    /// let back = spectrum.ifft().re();
+    /// assert!(back.allclose(&Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]), 0.0, 1e-12));
+    /// ```
+    pub fn fft(&self) -> ComplexSignal {
+        ComplexSignal::from_vec(fft::fft_real(&self.to_vec()))
+    }
+
+    /// Returns the analytic signal `x + i·H{x}`, whose imaginary part is the Hilbert transform.
+    /// Its magnitude is `envelope(EnvelopeMethod::Hilbert)` and its phase is
+    /// `instantaneous_phase()`.
+    ///
+    /// 返回解析信号 `x + i·H{x}`，其虚部为希尔伯特变换。其模即 `envelope(EnvelopeMethod::Hilbert)`，
+    /// 相位即 `instantaneous_phase()`。
+    pub fn analytic_signal(&self) -> ComplexSignal {
+        self.analytic()
+    }
+}
+
+impl FromIterator<Complex64> for ComplexSignal {
+    fn from_iter<I: IntoIterator<Item = Complex64>>(iter: I) -> Self {
+        ComplexSignal::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl Index<usize> for ComplexSignal {
+    type Output = Complex64;
+
+    fn index(&self, index: usize) -> &Complex64 {
+        &self.data[index]
+    }
+}
+
+impl Neg for &ComplexSignal {
+    type Output = ComplexSignal;
+    fn neg(self) -> ComplexSignal {
+        -self.clone()
+    }
+}
+
+impl Neg for ComplexSignal {
+    type Output = ComplexSignal;
+    fn neg(mut self) -> ComplexSignal {
+        self.data.mapv_inplace(|z| -z);
+        self
+    }
+}
+
+// 复数信号之间、与实信号以及与标量的运算，按值传入的左操作数复用其缓冲区
+macro_rules! impl_complex_ops {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<&ComplexSignal> for &ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, other: &ComplexSignal) -> ComplexSignal {
+                ComplexSignal::from_array1((&self.data).$op(&other.data))
+            }
+        }
+
+        impl $Op<&ComplexSignal> for ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(mut self, other: &ComplexSignal) -> ComplexSignal {
+                self.$op_assign(other);
+                self
+            }
+        }
+
+        impl $Op<ComplexSignal> for ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, other: ComplexSignal) -> ComplexSignal {
+                self.$op(&other)
+            }
+        }
+
+        impl $Op<ComplexSignal> for &ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, other: ComplexSignal) -> ComplexSignal {
+                self.$op(&other)
+            }
+        }
+
+        impl $OpAssign<&ComplexSignal> for ComplexSignal {
+            fn $op_assign(&mut self, other: &ComplexSignal) {
+                self.data.$op_assign(&other.data);
+            }
+        }
+
+        impl $Op<&Signal> for &ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, other: &Signal) -> ComplexSignal {
+                let other = other.as_array().mapv(|re| Complex64::new(re, 0.0));
+                ComplexSignal::from_array1((&self.data).$op(&other))
+            }
+        }
+
+        impl $Op<Complex64> for &ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, scalar: Complex64) -> ComplexSignal {
+                ComplexSignal::from_array1(self.data.mapv(|z| z.$op(scalar)))
+            }
+        }
+
+        impl $Op<f64> for &ComplexSignal {
+            type Output = ComplexSignal;
+            fn $op(self, scalar: f64) -> ComplexSignal {
+                ComplexSignal::from_array1(self.data.mapv(|z| z.$op(scalar)))
+            }
+        }
+
+        impl $Op<&ComplexSignal> for Complex64 {
+            type Output = ComplexSignal;
+            fn $op(self, signal: &ComplexSignal) -> ComplexSignal {
+                ComplexSignal::from_array1(signal.data.mapv(|z| self.$op(z)))
+            }
+        }
+
+        impl $Op<&ComplexSignal> for f64 {
+            type Output = ComplexSignal;
+            fn $op(self, signal: &ComplexSignal) -> ComplexSignal {
+                ComplexSignal::from_array1(signal.data.mapv(|z| self.$op(z)))
+            }
+        }
+    };
+}
+
+impl_complex_ops!(Add, add, AddAssign, add_assign);
+impl_complex_ops!(Sub, sub, SubAssign, sub_assign);
+impl_complex_ops!(Mul, mul, MulAssign, mul_assign);
+impl_complex_ops!(Div, div, DivAssign, div_assign);
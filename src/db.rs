@@ -0,0 +1,207 @@
+use crate::signal::Signal;
+
+/// Converts an amplitude to decibels, `20·log10(|amplitude| / reference)`, clamped below at
+/// `floor_db`.
+///
+/// The floor keeps exact zeros from producing `-∞`; pass `f64::NEG_INFINITY` to disable it. Use
+/// `reference = 1.0` for dB re 1.0, or the full-scale amplitude for dBFS. NaN stays NaN.
+///
+/// # Panics
+/// Panics if `reference` is not positive.
+///
+/// 将幅度转换为分贝，即 `20·log10(|amplitude| / reference)`，并以 `floor_db` 为下限。
+///
+/// 下限避免精确的零产生 `-∞`；传入 `f64::NEG_INFINITY` 可取消下限。`reference = 1.0` 得到相对 1.0 的分贝值，
+/// 传入满量程幅度则得到 dBFS。NaN 保持为 NaN。
+///
+/// # 恐慌
+/// `reference` 不为正数时发生恐慌。
+///
+/// # Example
+/// ```
+/// use dsp4rust::db::amplitude_to_db;
+/// assert!((amplitude_to_db(10.0, 1.0, -120.0) - 20.0).abs() < 1e-12);
+/// assert_eq!(amplitude_to_db(0.0, 1.0, -120.0), -120.0);
+/// ```
+pub fn amplitude_to_db(amplitude: f64, reference: f64, floor_db: f64) -> f64 {
+    ratio_to_db(amplitude.abs(), reference, 20.0, floor_db)
+}
+
+/// Converts a power to decibels, `10·log10(power / reference)`, clamped below at `floor_db`.
+///
+/// The floor keeps zero power from producing `-∞`; pass `f64::NEG_INFINITY` to disable it.
+/// Negative powers have no logarithm and give NaN, as does NaN.
+///
+/// # Panics
+/// Panics if `reference` is not positive.
+///
+/// 将功率转换为分贝，即 `10·log10(power / reference)`，并以 `floor_db` 为下限。
+///
+/// 下限避免零功率产生 `-∞`；传入 `f64::NEG_INFINITY` 可取消下限。负功率没有对数，结果为 NaN，
+/// NaN 也保持为 NaN。
+///
+/// # 恐慌
+/// `reference` 不为正数时发生恐慌。
+///
+/// # Example
+/// ```
+/// use dsp4rust::db::power_to_db;
+/// assert!((power_to_db(100.0, 1.0, -120.0) - 20.0).abs() < 1e-12);
+/// assert_eq!(power_to_db(0.0, 1.0, -120.0), -120.0);
+/// ```
+pub fn power_to_db(power: f64, reference: f64, floor_db: f64) -> f64 {
+    ratio_to_db(power, reference, 10.0, floor_db)
+}
+
+/// Converts decibels back to an amplitude, `reference·10^(db / 20)`.
+///
+/// # Panics
+/// Panics if `reference` is not positive.
+///
+/// 将分贝转换回幅度，即 `reference·10^(db / 20)`。
+///
+/// # 恐慌
+/// `reference` 不为正数时发生恐慌。
+///
+/// # Example
+/// ```
+/// use dsp4rust::db::db_to_amplitude;
+/// assert!((db_to_amplitude(-6.0, 1.0) - 0.501187).abs() < 1e-6);
+/// ```
+pub fn db_to_amplitude(db: f64, reference: f64) -> f64 {
+    check_reference(reference);
+    reference * 10f64.powf(db / 20.0)
+}
+
+/// Converts decibels back to a power, `reference·10^(db / 10)`.
+///
+/// # Panics
+/// Panics if `reference` is not positive.
+///
+/// 将分贝转换回功率，即 `reference·10^(db / 10)`。
+///
+/// # 恐慌
+/// `reference` 不为正数时发生恐慌。
+///
+/// # Example
+/// ```
+/// use dsp4rust::db::db_to_power;
+/// assert!((db_to_power(30.0, 1.0) - 1000.0).abs() < 1e-9);
+/// ```
+pub fn db_to_power(db: f64, reference: f64) -> f64 {
+    check_reference(reference);
+    reference * 10f64.powf(db / 10.0)
+}
+
+fn check_reference(reference: f64) {
+    assert!(
+        reference > 0.0,
+        "reference must be positive, got {reference}"
+    );
+}
+
+fn ratio_to_db(value: f64, reference: f64, factor: f64, floor_db: f64) -> f64 {
+    check_reference(reference);
+    let db = factor * (value / reference).log10();
+    // f64::max 会忽略 NaN，因此先单独处理
+    if db.is_nan() {
+        db
+    } else {
+        db.max(floor_db)
+    }
+}
+
+// 分贝转换
+impl Signal {
+    /// Converts every sample from amplitude to decibels with `amplitude_to_db`.
+    ///
+    /// # Panics
+    /// Panics if `reference` is not positive.
+    ///
+    /// 使用 `amplitude_to_db` 将每个样本从幅度转换为分贝。
+    ///
+    /// # 恐慌
+    /// `reference` 不为正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -0.1, 0.0]);
+    /// let db = signal.amplitude_to_db(1.0, -100.0);
+    /// assert_eq!(db.to_vec(), vec![0.0, -20.0, -100.0]);
+    /// ```
+    pub fn amplitude_to_db(&self, reference: f64, floor_db: f64) -> Signal {
+        check_reference(reference);
+        self.iter()
+            .map(|&x| amplitude_to_db(x, reference, floor_db))
+            .collect()
+    }
+
+    /// Converts every sample from power to decibels with `power_to_db`.
+    ///
+    /// # Panics
+    /// Panics if `reference` is not positive.
+    ///
+    /// 使用 `power_to_db` 将每个样本从功率转换为分贝。
+    ///
+    /// # 恐慌
+    /// `reference` 不为正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 0.01, 0.0]);
+    /// let db = signal.power_to_db(1.0, -100.0);
+    /// assert_eq!(db.to_vec(), vec![0.0, -20.0, -100.0]);
+    /// ```
+    pub fn power_to_db(&self, reference: f64, floor_db: f64) -> Signal {
+        check_reference(reference);
+        self.iter()
+            .map(|&x| power_to_db(x, reference, floor_db))
+            .collect()
+    }
+
+    /// Converts every sample from decibels to amplitude with `db_to_amplitude`.
+    ///
+    /// # Panics
+    /// Panics if `reference` is not positive.
+    ///
+    /// 使用 `db_to_amplitude` 将每个样本从分贝转换为幅度。
+    ///
+    /// # 恐慌
+    /// `reference` 不为正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let db = Signal::from_vec(vec![0.0, 20.0]);
+    /// assert_eq!(db.db_to_amplitude(2.0).to_vec(), vec![2.0, 20.0]);
+    /// ```
+    pub fn db_to_amplitude(&self, reference: f64) -> Signal {
+        check_reference(reference);
+        self.iter()
+            .map(|&db| db_to_amplitude(db, reference))
+            .collect()
+    }
+
+    /// Converts every sample from decibels to power with `db_to_power`.
+    ///
+    /// # Panics
+    /// Panics if `reference` is not positive.
+    ///
+    /// 使用 `db_to_power` 将每个样本从分贝转换为功率。
+    ///
+    /// # 恐慌
+    /// `reference` 不为正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let db = Signal::from_vec(vec![0.0, 20.0]);
+    /// assert_eq!(db.db_to_power(1.0).to_vec(), vec![1.0, 100.0]);
+    /// ```
+    pub fn db_to_power(&self, reference: f64) -> Signal {
+        check_reference(reference);
+        self.iter().map(|&db| db_to_power(db, reference)).collect()
+    }
+}
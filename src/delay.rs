@@ -0,0 +1,131 @@
+use crate::signal::Signal;
+
+/// Fixed-capacity ring buffer of the most recent samples, for streaming delays, echoes and
+/// filters.
+///
+/// Pushing a sample overwrites the oldest one in O(1), instead of shifting the whole buffer.
+/// A delay of `d` samples refers to the sample pushed `d` pushes before the most recent one, so
+/// `tap(0)` is the sample just pushed and delays run from 0 to `capacity - 1`. The line starts
+/// filled with zeros, as if silence had been pushed before the first sample.
+///
+/// 保存最近样本的固定容量环形缓冲区，用于流式延迟、回声与滤波。
+///
+/// 加入样本时以 O(1) 覆盖最旧的样本，而不是移动整个缓冲区。延迟 `d` 个样本指最近加入的样本之前第 `d` 次
+/// 加入的样本，因此 `tap(0)` 是刚加入的样本，延迟的取值范围为 0 到 `capacity - 1`。初始时填满零，
+/// 如同在第一个样本之前加入了静音。
+///
+/// # Example
+/// ```
+/// use dsp4rust::delay::DelayLine;
+/// let mut line = DelayLine::new(3);
+/// for x in [1.0, 2.0, 3.0] {
+///     line.push(x);
+/// }
+/// assert_eq!(line.tap(0), 3.0);
+/// assert_eq!(line.tap(2), 1.0);
+/// // 容量已满，加入新样本时最旧的样本被挤出
+/// assert_eq!(line.push(4.0), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelayLine {
+    buffer: Vec<f64>,
+    // 下一个样本写入的位置，也是最旧样本所在的位置
+    write: usize,
+}
+
+impl DelayLine {
+    /// Creates a delay line holding the last `capacity` samples, initially all zero.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// 创建保存最近 `capacity` 个样本的延迟线，初始全为零。
+    ///
+    /// # 恐慌
+    /// `capacity` 为零时发生恐慌。
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be positive");
+        DelayLine {
+            buffer: vec![0.0; capacity],
+            write: 0,
+        }
+    }
+
+    /// Returns the number of samples the line holds.
+    ///
+    /// 返回延迟线保存的样本数。
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Pushes a sample and returns the one falling out, i.e. the sample pushed `capacity` pushes
+    /// ago (zero until the line has filled up).
+    ///
+    /// 加入一个样本并返回被挤出的样本，即 `capacity` 次之前加入的样本（延迟线填满之前为零）。
+    pub fn push(&mut self, x: f64) -> f64 {
+        let out = std::mem::replace(&mut self.buffer[self.write], x);
+        self.write = (self.write + 1) % self.buffer.len();
+        out
+    }
+
+    /// Returns the sample delayed by `delay_samples` relative to the most recent one.
+    ///
+    /// # Panics
+    /// Panics if `delay_samples >= capacity`.
+    ///
+    /// 返回相对最近样本延迟 `delay_samples` 个样本的值。
+    ///
+    /// # 恐慌
+    /// `delay_samples >= capacity` 时发生恐慌。
+    pub fn tap(&self, delay_samples: usize) -> f64 {
+        let capacity = self.buffer.len();
+        assert!(
+            delay_samples < capacity,
+            "delay {delay_samples} is out of range for a delay line of capacity {capacity}"
+        );
+        self.buffer[(self.write + capacity - 1 - delay_samples) % capacity]
+    }
+
+    /// Pushes every sample of `block`, in order.
+    ///
+    /// 按顺序加入 `block` 的每个样本。
+    pub fn write_block(&mut self, block: &Signal) {
+        for &x in block.iter() {
+            self.push(x);
+        }
+    }
+
+    /// Returns `len` consecutive samples in chronological order, the newest of which is delayed by
+    /// `delay` relative to the most recent sample.
+    ///
+    /// After `write_block(&block)`, `read_block(k, block.len())` is `block` delayed by `k`
+    /// samples, which requires `k + block.len() <= capacity`.
+    ///
+    /// # Panics
+    /// Panics if `delay + len > capacity`.
+    ///
+    /// 按时间顺序返回 `len` 个连续样本，其中最新的一个相对最近样本延迟 `delay` 个样本。
+    ///
+    /// 调用 `write_block(&block)` 之后，`read_block(k, block.len())` 即为延迟 `k` 个样本的 `block`，
+    /// 这要求 `k + block.len() <= capacity`。
+    ///
+    /// # 恐慌
+    /// `delay + len > capacity` 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::delay::DelayLine;
+    /// use dsp4rust::signal::Signal;
+    /// let mut line = DelayLine::new(4);
+    /// line.write_block(&Signal::from_vec(vec![1.0, 2.0]));
+    /// assert_eq!(line.read_block(1, 2).to_vec(), vec![0.0, 1.0]);
+    /// ```
+    pub fn read_block(&self, delay: usize, len: usize) -> Signal {
+        let capacity = self.buffer.len();
+        assert!(
+            delay.checked_add(len).is_some_and(|end| end <= capacity),
+            "a block of {len} samples at delay {delay} does not fit in a delay line of capacity {capacity}"
+        );
+        (0..len).map(|j| self.tap(delay + len - 1 - j)).collect()
+    }
+}
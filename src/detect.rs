@@ -0,0 +1,502 @@
+use crate::signal::Signal;
+
+/// How `Signal::zero_crossings` treats samples exactly equal to zero.
+///
+/// `Signal::zero_crossings` 处理恰好等于零的样本的方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZeroPolicy {
+    /// Zero counts as positive, so `[-1, 0, -1]` crosses twice and `[1, 0, 1]` never does.
+    ///
+    /// 零视为正数，因此 `[-1, 0, -1]` 过零两次，`[1, 0, 1]` 不过零。
+    #[default]
+    Positive,
+    /// Zeros take the sign of the last non-zero sample, so touching zero is not a crossing and
+    /// passing through a run of zeros is a single crossing, reported just before the first
+    /// sample of the new sign.
+    ///
+    /// 零沿用上一个非零样本的符号，因此触及零不算过零，穿过一段零只算一次过零，
+    /// 记录在新符号的第一个样本之前。
+    Hold,
+}
+
+// 过零检测
+impl Signal {
+    /// Returns every index `i` where the sign changes between samples `i` and `i + 1`.
+    ///
+    /// `policy` decides how samples exactly equal to zero are handled. NaN samples are skipped
+    /// like zeros under `ZeroPolicy::Hold`.
+    ///
+    /// 返回样本 `i` 与 `i + 1` 之间符号发生变化的所有下标 `i`。
+    ///
+    /// `policy` 决定如何处理恰好等于零的样本。NaN 样本按 `ZeroPolicy::Hold` 中零的方式跳过。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::ZeroPolicy;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -1.0, 0.0, 2.0, 0.0, 3.0]);
+    /// assert_eq!(signal.zero_crossings(ZeroPolicy::Positive), vec![0, 1]);
+    /// assert_eq!(signal.zero_crossings(ZeroPolicy::Hold), vec![0, 2]);
+    /// ```
+    pub fn zero_crossings(&self, policy: ZeroPolicy) -> Vec<usize> {
+        let mut crossings = Vec::new();
+        // 上一个有符号样本的符号，true 表示非负
+        let mut previous: Option<bool> = None;
+        for (i, &x) in self.iter().enumerate() {
+            let sign = match policy {
+                _ if x.is_nan() => None,
+                ZeroPolicy::Positive => Some(x >= 0.0),
+                ZeroPolicy::Hold if x == 0.0 => None,
+                ZeroPolicy::Hold => Some(x > 0.0),
+            };
+            if let Some(sign) = sign {
+                if previous.is_some_and(|p| p != sign) {
+                    crossings.push(i - 1);
+                }
+                previous = Some(sign);
+            }
+        }
+        crossings
+    }
+
+    /// Returns the number of zero crossings, i.e. `zero_crossings(policy).len()`.
+    ///
+    /// 返回过零次数，即 `zero_crossings(policy).len()`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::ZeroPolicy;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -1.0, 1.0]);
+    /// assert_eq!(signal.zero_crossing_count(ZeroPolicy::Positive), 2);
+    /// ```
+    pub fn zero_crossing_count(&self, policy: ZeroPolicy) -> usize {
+        self.zero_crossings(policy).len()
+    }
+
+    /// Returns the zero-crossing rate of each non-overlapping frame of `frame_len` samples.
+    ///
+    /// A frame's rate is the number of crossings between two samples inside the frame divided
+    /// by `frame_len`. Crossings are located on the whole signal first, so `ZeroPolicy::Hold`
+    /// carries the sign across frame boundaries. A partial frame at the end is skipped.
+    ///
+    /// # Panics
+    /// Panics if `frame_len` is zero.
+    ///
+    /// 返回每个长度为 `frame_len` 的不重叠帧的过零率。
+    ///
+    /// 一帧的过零率为帧内两个样本之间的过零次数除以 `frame_len`。过零点先在整个信号上确定，
+    /// 因此 `ZeroPolicy::Hold` 会跨帧边界沿用符号。末尾不完整的帧被跳过。
+    ///
+    /// # 恐慌
+    /// `frame_len` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::ZeroPolicy;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -1.0, 1.0, -1.0, 1.0, 1.0, 1.0, 1.0]);
+    /// let zcr = signal.zero_crossing_rate(4, ZeroPolicy::Positive);
+    /// assert_eq!(zcr.to_vec(), vec![0.75, 0.0]);
+    /// ```
+    pub fn zero_crossing_rate(&self, frame_len: usize, policy: ZeroPolicy) -> Signal {
+        assert!(frame_len > 0, "frame_len must be positive");
+        let n_frames = self.len() / frame_len;
+        let mut counts = vec![0usize; n_frames];
+        for i in self.zero_crossings(policy) {
+            let frame = i / frame_len;
+            // 跨越帧边界的过零不属于任何一帧
+            if frame < n_frames && (i + 1) / frame_len == frame {
+                counts[frame] += 1;
+            }
+        }
+        counts
+            .into_iter()
+            .map(|count| count as f64 / frame_len as f64)
+            .collect()
+    }
+}
+
+/// Constraints applied by `Signal::find_peaks`. Every constraint is optional and unset by
+/// default, in which case every local maximum is reported.
+///
+/// `Signal::find_peaks` 使用的约束条件。所有约束都是可选的，默认均不设置，此时报告所有局部极大值。
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PeakOptions {
+    /// Minimum peak value.
+    ///
+    /// 峰值的最小值。
+    pub min_height: Option<f64>,
+    /// Minimum distance in samples between neighbouring peaks. Among peaks closer than this,
+    /// higher peaks are kept first.
+    ///
+    /// 相邻峰之间的最小样本距离。对于距离过近的峰，优先保留较高的峰。
+    pub min_distance: Option<usize>,
+    /// Minimum prominence.
+    ///
+    /// 最小突出度。
+    pub min_prominence: Option<f64>,
+}
+
+/// A peak found by `Signal::find_peaks`.
+///
+/// `Signal::find_peaks` 找到的峰。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Peak {
+    /// Index of the peak sample.
+    ///
+    /// 峰所在样本的下标。
+    pub index: usize,
+    /// Value of the peak sample.
+    ///
+    /// 峰所在样本的值。
+    pub value: f64,
+    /// How far the peak stands out above the higher of its two bases: the lowest points
+    /// between it and the nearest higher sample (or the signal end) on each side.
+    ///
+    /// 峰相对其两侧基点中较高者的突出高度；每侧的基点是峰与该侧最近的更高样本（或信号端点）之间的最低点。
+    pub prominence: f64,
+}
+
+// 峰值检测
+impl Signal {
+    /// Finds local maxima that satisfy `options`, following the semantics of
+    /// `scipy.signal.find_peaks`.
+    ///
+    /// A peak is a sample strictly greater than its left neighbour and greater than its right
+    /// neighbour. For a flat plateau the middle sample is reported, rounding down to the left
+    /// one for an even plateau width. The first and last samples are never peaks. The
+    /// constraints are applied in the order height, distance, prominence. The distance
+    /// constraint visits peaks from highest to lowest, keeping each peak and removing all lower
+    /// ones within `min_distance` of it; among equally high peaks the rightmost goes first.
+    /// Peaks are returned in ascending index order.
+    ///
+    /// 按 `scipy.signal.find_peaks` 的语义查找满足 `options` 的局部极大值。
+    ///
+    /// 峰是严格大于左侧相邻样本、且大于右侧相邻样本的样本。对于平顶，报告中间的样本，
+    /// 平顶宽度为偶数时取偏左的一个。首、尾样本永远不是峰。约束按高度、距离、突出度的顺序应用。
+    /// 距离约束从高到低依次访问各峰，保留当前峰并移除其 `min_distance` 范围内的较低峰；
+    /// 高度相同时最右侧的峰优先。结果按下标升序返回。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::PeakOptions;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 2.0, 1.0, 3.0, 0.0]);
+    /// let peaks = signal.find_peaks(PeakOptions::default());
+    /// assert_eq!(peaks.iter().map(|p| p.index).collect::<Vec<_>>(), vec![1, 3]);
+    /// assert_eq!(peaks[0].prominence, 1.0);
+    ///
+    /// let prominent = signal.find_peaks(PeakOptions {
+    ///     min_prominence: Some(2.0),
+    ///     ..PeakOptions::default()
+    /// });
+    /// assert_eq!(prominent.len(), 1);
+    /// assert_eq!(prominent[0].value, 3.0);
+    /// ```
+    pub fn find_peaks(&self, options: PeakOptions) -> Vec<Peak> {
+        let values = self.to_vec();
+        find_peaks_in(&values, options)
+            .into_iter()
+            .map(|(index, prominence)| Peak {
+                index,
+                value: values[index],
+                prominence,
+            })
+            .collect()
+    }
+}
+
+// 返回满足约束的峰的 (下标, 突出度)
+fn find_peaks_in(values: &[f64], options: PeakOptions) -> Vec<(usize, f64)> {
+    let mut peaks = local_maxima(values);
+    if let Some(min_height) = options.min_height {
+        peaks.retain(|&i| values[i] >= min_height);
+    }
+    if let Some(min_distance) = options.min_distance {
+        peaks = select_by_distance(values, &peaks, min_distance);
+    }
+    peaks
+        .into_iter()
+        .map(|i| (i, prominence(values, i)))
+        .filter(|&(_, p)| options.min_prominence.is_none_or(|min| p >= min))
+        .collect()
+}
+
+// 局部极大值，平顶取中点（偶数宽度偏左），不含首尾样本
+pub(crate) fn local_maxima(values: &[f64]) -> Vec<usize> {
+    let mut peaks = Vec::new();
+    let mut i = 1;
+    while i + 1 < values.len() {
+        if values[i - 1] < values[i] {
+            let mut ahead = i + 1;
+            while ahead + 1 < values.len() && values[ahead] == values[i] {
+                ahead += 1;
+            }
+            if values[ahead] < values[i] {
+                peaks.push((i + ahead - 1) / 2);
+                i = ahead;
+            }
+        }
+        i += 1;
+    }
+    peaks
+}
+
+// 从高到低保留峰，移除与已保留峰距离小于 min_distance 的较低峰
+fn select_by_distance(values: &[f64], peaks: &[usize], min_distance: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..peaks.len()).collect();
+    // 稳定排序后倒序访问，高度相同时靠右的峰优先
+    order.sort_by(|&a, &b| values[peaks[a]].total_cmp(&values[peaks[b]]));
+    let mut keep = vec![true; peaks.len()];
+    for &k in order.iter().rev() {
+        if !keep[k] {
+            continue;
+        }
+        for j in (0..k).rev() {
+            if peaks[k] - peaks[j] >= min_distance {
+                break;
+            }
+            keep[j] = false;
+        }
+        for j in k + 1..peaks.len() {
+            if peaks[j] - peaks[k] >= min_distance {
+                break;
+            }
+            keep[j] = false;
+        }
+    }
+    peaks
+        .iter()
+        .zip(keep)
+        .filter_map(|(&peak, kept)| kept.then_some(peak))
+        .collect()
+}
+
+// 向两侧搜索直到遇到更高的样本或端点，取两侧最低点中较高者作为基点
+fn prominence(values: &[f64], peak: usize) -> f64 {
+    let height = values[peak];
+    let left = lowest_before_higher(values[..=peak].iter().rev().copied(), height);
+    let right = lowest_before_higher(values[peak..].iter().copied(), height);
+    height - left.max(right)
+}
+
+fn lowest_before_higher(side: impl Iterator<Item = f64>, height: f64) -> f64 {
+    side.take_while(|&x| x <= height).fold(height, f64::min)
+}
+
+// 谷值与相对极值检测
+impl Signal {
+    /// Finds local minima that satisfy `options` by running `find_peaks` on the negated signal.
+    ///
+    /// The options therefore apply to `-x`: `min_height: Some(h)` keeps valleys with
+    /// `x <= -h`, and the reported prominence is the (positive) depth of the valley below the
+    /// lower of its two bases. `value` is the sample of the original signal.
+    ///
+    /// 通过在取负后的信号上运行 `find_peaks` 查找满足 `options` 的局部极小值。
+    ///
+    /// 因此各选项作用于 `-x`：`min_height: Some(h)` 保留满足 `x <= -h` 的谷，
+    /// 报告的突出度为谷相对两侧基点中较低者的（正的）深度。`value` 为原信号中的样本值。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::PeakOptions;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, -2.0, -1.0, -3.0, 0.0]);
+    /// let valleys = signal.find_valleys(PeakOptions::default());
+    /// assert_eq!(valleys.iter().map(|v| v.index).collect::<Vec<_>>(), vec![1, 3]);
+    /// assert_eq!(valleys[1].value, -3.0);
+    /// assert_eq!(valleys[1].prominence, 3.0);
+    /// ```
+    pub fn find_valleys(&self, options: PeakOptions) -> Vec<Peak> {
+        let negated: Vec<f64> = self.iter().map(|&x| -x).collect();
+        find_peaks_in(&negated, options)
+            .into_iter()
+            .map(|(index, prominence)| Peak {
+                index,
+                value: -negated[index],
+                prominence,
+            })
+            .collect()
+    }
+
+    /// Returns the indices of samples strictly greater than every other sample within
+    /// `±order` positions, like `scipy.signal.argrelmax`.
+    ///
+    /// Near the ends only the neighbours that exist are compared, but the first and last
+    /// samples themselves are never reported. Plateaus are not maxima because the comparison
+    /// is strict.
+    ///
+    /// # Panics
+    /// Panics if `order` is zero.
+    ///
+    /// 返回严格大于 `±order` 范围内所有其他样本的样本下标，与 `scipy.signal.argrelmax` 相同。
+    ///
+    /// 靠近端点时只与实际存在的相邻样本比较，但首、尾样本本身永远不会被报告。由于比较是严格的，平顶不是极大值。
+    ///
+    /// # 恐慌
+    /// `order` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 2.0, 1.0, 3.0, 1.0, 0.0]);
+    /// assert_eq!(signal.argrelmax(1), vec![1, 3]);
+    /// assert_eq!(signal.argrelmax(2), vec![3]);
+    /// ```
+    pub fn argrelmax(&self, order: usize) -> Vec<usize> {
+        relative_extrema(&self.to_vec(), order, |x, neighbour| x > neighbour)
+    }
+
+    /// Returns the indices of samples strictly less than every other sample within `±order`
+    /// positions, like `scipy.signal.argrelmin`. Boundary handling matches `argrelmax`.
+    ///
+    /// # Panics
+    /// Panics if `order` is zero.
+    ///
+    /// 返回严格小于 `±order` 范围内所有其他样本的样本下标，与 `scipy.signal.argrelmin` 相同。
+    /// 端点处理与 `argrelmax` 一致。
+    ///
+    /// # 恐慌
+    /// `order` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![3.0, 1.0, 2.0, 0.0, 2.0]);
+    /// assert_eq!(signal.argrelmin(1), vec![1, 3]);
+    /// ```
+    pub fn argrelmin(&self, order: usize) -> Vec<usize> {
+        relative_extrema(&self.to_vec(), order, |x, neighbour| x < neighbour)
+    }
+}
+
+// 与 ±order 范围内实际存在的相邻样本比较，首尾样本除外
+fn relative_extrema(values: &[f64], order: usize, beats: fn(f64, f64) -> bool) -> Vec<usize> {
+    assert!(order > 0, "order must be positive");
+    let len = values.len();
+    (1..len.saturating_sub(1))
+        .filter(|&i| {
+            let lo = i.saturating_sub(order);
+            let hi = (i + order).min(len - 1);
+            (lo..=hi).all(|j| j == i || beats(values[i], values[j]))
+        })
+        .collect()
+}
+
+/// Direction of a threshold crossing.
+///
+/// 越过阈值的方向。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From below the threshold to at or above it.
+    ///
+    /// 从阈值以下到达或超过阈值。
+    Rising,
+    /// From at or above the threshold to below it.
+    ///
+    /// 从阈值及以上降到阈值以下。
+    Falling,
+    /// Either direction.
+    ///
+    /// 任一方向。
+    Both,
+}
+
+// 阈值穿越检测
+impl Signal {
+    /// Returns the indices where the signal crosses `threshold` in the given direction.
+    ///
+    /// Each reported index is the first sample on the new side: a rising crossing at `i` means
+    /// `x[i - 1] < threshold <= x[i]`, and a falling one means `x[i - 1] >= threshold > x[i]`.
+    /// A signal that starts above the threshold has no rising crossing at the start. Pairs
+    /// involving a NaN sample never cross.
+    ///
+    /// 返回信号沿给定方向越过 `threshold` 的下标。
+    ///
+    /// 每个报告的下标都是到达新一侧的第一个样本：在 `i` 处上升穿越表示 `x[i - 1] < threshold <= x[i]`，
+    /// 下降穿越表示 `x[i - 1] >= threshold > x[i]`。起始就在阈值之上的信号在开头没有上升穿越。
+    /// 涉及 NaN 样本的相邻样本对不算穿越。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::Direction;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 2.0, 3.0, 0.0, 2.0]);
+    /// assert_eq!(signal.crossings(1.0, Direction::Rising), vec![1, 4]);
+    /// assert_eq!(signal.crossings(1.0, Direction::Falling), vec![3]);
+    /// assert_eq!(signal.crossings(1.0, Direction::Both), vec![1, 3, 4]);
+    /// ```
+    pub fn crossings(&self, threshold: f64, direction: Direction) -> Vec<usize> {
+        let values = self.to_vec();
+        (1..values.len())
+            .filter(|&i| {
+                let (before, after) = (values[i - 1], values[i]);
+                let rising = before < threshold && after >= threshold;
+                let falling = before >= threshold && after < threshold;
+                match direction {
+                    Direction::Rising => rising,
+                    Direction::Falling => falling,
+                    Direction::Both => rising || falling,
+                }
+            })
+            .collect()
+    }
+
+    /// Detects crossings with hysteresis, like a Schmitt trigger, and returns each event's
+    /// index and direction (`Rising` or `Falling`).
+    ///
+    /// A rising event is registered at the first sample `>= upper` after the signal has been
+    /// below `lower`, and a falling event at the first sample `< lower` after it has been at or
+    /// above `upper`. Noise that stays between the two levels therefore cannot cause chatter.
+    /// Until the signal first leaves the band `[lower, upper)` its state is unknown, so the
+    /// first entry into either side produces no event. With `upper == lower` this matches
+    /// `crossings(upper, Direction::Both)`.
+    ///
+    /// # Panics
+    /// Panics if `upper < lower` or either level is NaN.
+    ///
+    /// 以迟滞方式（类似施密特触发器）检测穿越，返回每个事件的下标与方向（`Rising` 或 `Falling`）。
+    ///
+    /// 信号曾低于 `lower` 之后，第一个 `>= upper` 的样本处记录上升事件；信号曾达到或超过 `upper` 之后，
+    /// 第一个 `< lower` 的样本处记录下降事件。因此停留在两个电平之间的噪声不会引起抖动。
+    /// 在信号首次离开区间 `[lower, upper)` 之前状态未知，首次进入任一侧不产生事件。
+    /// `upper == lower` 时结果与 `crossings(upper, Direction::Both)` 相同。
+    ///
+    /// # 恐慌
+    /// `upper < lower` 或任一电平为 NaN 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::detect::Direction;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-0.2, 0.6, 0.4, 0.6, 1.2, 0.4, 0.6, -0.2]);
+    /// assert_eq!(
+    ///     signal.crossings_hysteresis(1.0, 0.0),
+    ///     vec![(4, Direction::Rising), (7, Direction::Falling)]
+    /// );
+    /// ```
+    pub fn crossings_hysteresis(&self, upper: f64, lower: f64) -> Vec<(usize, Direction)> {
+        assert!(
+            lower <= upper,
+            "hysteresis levels must satisfy lower <= upper and not be NaN, got {lower} and {upper}"
+        );
+        let mut events = Vec::new();
+        // 当前状态，true 表示高电平，None 表示尚未离开迟滞区间
+        let mut high: Option<bool> = None;
+        for (i, &x) in self.iter().enumerate() {
+            if x >= upper {
+                if high == Some(false) {
+                    events.push((i, Direction::Rising));
+                }
+                high = Some(true);
+            } else if x < lower {
+                if high == Some(true) {
+                    events.push((i, Direction::Falling));
+                }
+                high = Some(false);
+            }
+        }
+        events
+    }
+}
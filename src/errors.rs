@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum DiffError {
     ShortLength,
 }
@@ -26,3 +26,413 @@ pub enum PadError {
     EmptyInput,
     UnknownError(String),
 }
+
+/// Errors that can occur when constructing a window function.
+///
+/// 构造窗函数时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum WindowError {
+    /// A shape parameter lies outside its valid range.
+    ///
+    /// 形状参数超出其有效范围。
+    #[error("Window parameter `{name}` is out of range: {value}")]
+    ParameterOutOfRange { name: &'static str, value: f64 },
+
+    /// The window length differs from the signal length.
+    ///
+    /// 窗的长度与信号长度不一致。
+    #[error("Window length {window} does not match signal length {signal}")]
+    LengthMismatch { signal: usize, window: usize },
+
+    /// The requested gain correction would divide by zero.
+    ///
+    /// 所请求的增益补偿会导致除以零。
+    #[error("Window gain is zero, cannot compensate")]
+    ZeroGain,
+}
+
+/// Errors that can occur during resampling and interpolation.
+///
+/// 重采样与插值过程中可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ResampleError {
+    /// The resampling factor is zero.
+    ///
+    /// 重采样因子为零。
+    #[error("Resampling factor must be at least 1")]
+    ZeroFactor,
+
+    /// The filter specification is invalid.
+    ///
+    /// 滤波器参数无效。
+    #[error("Invalid filter specification: {0}")]
+    InvalidFilter(String),
+
+    /// An interpolation position lies outside the sampled range `[0, len - 1]`.
+    ///
+    /// 插值位置超出采样范围 `[0, len - 1]`。
+    #[error("Interpolation position {0} is outside the signal")]
+    PositionOutOfRange(f64),
+
+    /// The interpolation positions are not in non-decreasing order; `index` is the first
+    /// position smaller than the one before it.
+    ///
+    /// 插值位置不是非递减的；`index` 为第一个小于前一个位置的位置的索引。
+    #[error("Interpolation position {index} is smaller than the one before it")]
+    NonMonotonicPositions { index: usize },
+
+    /// The input signal is empty.
+    ///
+    /// 输入信号为空。
+    #[error("Cannot interpolate an empty signal")]
+    EmptyInput,
+}
+
+/// Errors that can occur when configuring a `Generator` or generating a waveform.
+///
+/// 配置 `Generator` 或生成波形时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum GeneratorError {
+    /// A waveform parameter is outside its valid range.
+    ///
+    /// 波形参数超出其有效范围。
+    #[error("Generator parameter `{name}` is invalid: {value}")]
+    InvalidParameter { name: &'static str, value: f64 },
+}
+
+/// Errors that can occur when normalizing or standardizing a signal.
+///
+/// 对信号进行归一化或标准化时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum NormError {
+    /// The input signal is empty.
+    ///
+    /// 输入信号为空。
+    #[error("Cannot normalize an empty signal")]
+    EmptyInput,
+
+    /// The scale the signal is divided by is zero, e.g. a constant or all-zero signal.
+    ///
+    /// 信号所除的尺度为零，例如常数信号或全零信号。
+    #[error("Signal has zero {0}, cannot rescale")]
+    ZeroScale(&'static str),
+
+    /// A target value is not finite or is outside its valid range.
+    ///
+    /// 目标值不是有限数或超出其有效范围。
+    #[error("Normalization target `{name}` is invalid: {value}")]
+    InvalidTarget { name: &'static str, value: f64 },
+}
+
+/// Errors that can occur when splitting or slicing a signal.
+///
+/// 拆分或切片信号时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum SliceError {
+    /// An index lies outside the valid range for a signal of length `len`.
+    ///
+    /// 索引超出长度为 `len` 的信号的有效范围。
+    #[error("Index {index} is out of bounds for a signal of length {len}")]
+    IndexOutOfBounds { index: isize, len: usize },
+
+    /// The start of a range lies after its end once negative indices are resolved.
+    ///
+    /// 换算负索引后，区间的起点位于终点之后。
+    #[error("Range start {from} lies after its end {to}")]
+    InvertedRange { from: isize, to: isize },
+}
+
+/// Errors that can occur when combining signals whose shapes must agree.
+///
+/// 组合形状必须一致的信号时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum ShapeError {
+    /// The two signals have different lengths.
+    ///
+    /// 两个信号的长度不同。
+    #[error("Signal lengths differ: {left} and {right}")]
+    LengthMismatch { left: usize, right: usize },
+}
+
+/// Errors that can occur when computing order statistics of a signal.
+///
+/// 计算信号的顺序统计量时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum StatError {
+    /// The signal has no non-NaN samples.
+    ///
+    /// 信号中没有非 NaN 样本。
+    #[error("Signal has no samples to compute a statistic from")]
+    EmptyInput,
+
+    /// A quantile lies outside `[0, 1]` or is NaN.
+    ///
+    /// 分位数超出 `[0, 1]` 或为 NaN。
+    #[error("Quantile {0} is out of range")]
+    InvalidQuantile(f64),
+
+    /// A percentile lies outside `[0, 100]` or is NaN.
+    ///
+    /// 百分位数超出 `[0, 100]` 或为 NaN。
+    #[error("Percentile {0} is out of range")]
+    InvalidPercentile(f64),
+
+    /// The statistic is a ratio whose denominator is zero, e.g. the crest factor of an all-zero
+    /// signal.
+    ///
+    /// 统计量是一个比值且分母为零，例如全零信号的峰值因数。
+    #[error("Signal has zero {0}, the ratio is undefined")]
+    ZeroDenominator(&'static str),
+}
+
+/// Errors that can occur when measuring the similarity of two signals.
+///
+/// 度量两个信号的相似度时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum SimilarityError {
+    /// The signals have incompatible shapes.
+    ///
+    /// 信号形状不兼容。
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+
+    /// The signals are empty.
+    ///
+    /// 信号为空。
+    #[error("Cannot compare empty signals")]
+    EmptyInput,
+
+    /// One of the signals has zero variance or zero norm, so the measure is undefined.
+    ///
+    /// 其中一个信号的方差或范数为零，度量无定义。
+    #[error("Signal has zero {0}, similarity is undefined")]
+    ZeroScale(&'static str),
+}
+
+/// Errors that can occur when fitting a polynomial to a signal.
+///
+/// 对信号进行多项式拟合时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum FitError {
+    /// The signal has too few samples to determine a polynomial of the requested order.
+    ///
+    /// 信号样本数过少，无法确定所需阶数的多项式。
+    #[error("A polynomial of order {order} needs at least {} samples, got {len}", order + 1)]
+    TooFewSamples { order: usize, len: usize },
+}
+
+/// Errors that can occur when computing linear prediction coefficients.
+///
+/// 计算线性预测系数时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum LpcError {
+    /// The prediction order is not below the signal length.
+    ///
+    /// 预测阶数不小于信号长度。
+    #[error("Prediction order {order} must be less than the signal length {len}")]
+    OrderTooHigh { order: usize, len: usize },
+
+    /// The signal has zero energy, so its autocorrelation determines no predictor.
+    ///
+    /// 信号能量为零，其自相关无法确定预测器。
+    #[error("Signal has zero energy, linear prediction is undefined")]
+    ZeroEnergy,
+}
+
+/// Errors that can occur when measuring signal quality figures such as THD or SINAD.
+///
+/// 测量 THD、SINAD 等信号质量指标时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum MeasureError {
+    /// The input signal is empty.
+    ///
+    /// 输入信号为空。
+    #[error("Cannot measure an empty signal")]
+    EmptyInput,
+
+    /// A parameter is outside its valid range, e.g. a fundamental too close to DC or Nyquist to
+    /// be resolved by the signal's length.
+    ///
+    /// 参数超出其有效范围，例如基波频率离直流或奈奎斯特频率过近，以当前信号长度无法分辨。
+    #[error("Measurement parameter `{name}` is invalid: {value}")]
+    InvalidParameter { name: &'static str, value: f64 },
+
+    /// The fundamental has no power, so ratios relative to it are undefined.
+    ///
+    /// 基波功率为零，相对基波的比值无定义。
+    #[error("Fundamental has zero power")]
+    ZeroFundamental,
+}
+
+/// Errors that can occur when designing or constructing a filter.
+///
+/// 设计或构造滤波器时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum FilterError {
+    /// The numerator or denominator has no coefficients.
+    ///
+    /// 分子或分母没有系数。
+    #[error("Filter coefficients must not be empty")]
+    EmptyCoefficients,
+
+    /// The leading denominator coefficient `a[0]` is zero, so the filter cannot be normalized.
+    ///
+    /// 分母首项系数 `a[0]` 为零，无法归一化滤波器。
+    #[error("Leading denominator coefficient must be nonzero")]
+    ZeroLeadingDenominator,
+
+    /// A design parameter is outside its valid range.
+    ///
+    /// 设计参数超出其有效范围。
+    #[error("Filter parameter `{name}` is invalid: {value}")]
+    InvalidParameter { name: &'static str, value: f64 },
+}
+
+/// Errors that can occur when working with signals that carry a sample rate.
+///
+/// 处理带采样率的信号时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum SampledError {
+    /// The sample rate is not a positive finite number.
+    ///
+    /// 采样率不是有限正数。
+    #[error("Sample rate must be positive and finite, got {0}")]
+    InvalidSampleRate(f64),
+
+    /// The two signals were sampled at different rates.
+    ///
+    /// 两个信号的采样率不同。
+    #[error("Sample rates differ: {left} Hz and {right} Hz")]
+    RateMismatch { left: f64, right: f64 },
+
+    /// The signals have incompatible shapes.
+    ///
+    /// 信号形状不兼容。
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+
+    /// The start of a time range lies after its end, or either end is NaN.
+    ///
+    /// 时间区间的起点位于终点之后，或任一端为 NaN。
+    #[error("Invalid time range from {start} s to {end} s")]
+    InvalidTimeRange { start: f64, end: f64 },
+}
+
+/// Errors that can occur when building or combining multi-channel frames.
+///
+/// 构造或组合多通道帧时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum FrameError {
+    /// A frame needs at least one channel.
+    ///
+    /// 帧至少需要一个通道。
+    #[error("A frame needs at least one channel")]
+    NoChannels,
+
+    /// A channel's length differs from that of the first channel.
+    ///
+    /// 某个通道的长度与第一个通道不同。
+    #[error("Channel {channel} has {found} samples, expected {expected}")]
+    ChannelLength {
+        channel: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    /// The two frames differ in channel count or channel length, given as `(channels, len)`.
+    ///
+    /// 两个帧的通道数或通道长度不同，以 `(通道数, 长度)` 给出。
+    #[error("Frame shapes differ: {left:?} and {right:?}")]
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+}
+
+/// Errors that can occur when shifting a signal between passband and baseband.
+///
+/// 在通带与基带之间搬移信号时可能发生的错误。
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum IqError {
+    /// A frequency or the sample rate is outside its valid range.
+    ///
+    /// 频率或采样率超出其有效范围。
+    #[error("IQ parameter `{name}` is invalid: {value}")]
+    InvalidParameter { name: &'static str, value: f64 },
+}
+
+/// Errors that can occur when reading or writing signal files.
+///
+/// 读写信号文件时可能发生的错误。
+#[derive(thiserror::Error, Debug)]
+pub enum IoError {
+    /// The file could not be opened, read or written.
+    ///
+    /// 无法打开、读取或写入文件。
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A line of a text file could not be parsed. Lines are numbered from 1.
+    ///
+    /// 文本文件的某一行无法解析。行号从 1 开始。
+    #[error("Parse error on line {line}: {message}")]
+    Parse { line: usize, message: String },
+
+    /// The file is not a valid NPY file, or holds an array that cannot be read as a signal.
+    ///
+    /// 文件不是有效的 NPY 文件，或其中的数组无法读取为信号。
+    #[error("Invalid NPY file: {0}")]
+    Npy(String),
+
+    /// A raw binary file ends partway through a sample.
+    ///
+    /// 原始二进制文件在某个样本中间结束。
+    #[error(
+        "File ends with {trailing_bytes} trailing bytes, less than one {sample_size}-byte sample"
+    )]
+    TruncatedSample {
+        trailing_bytes: usize,
+        sample_size: usize,
+    },
+
+    /// The file could not be opened, read or parsed as a WAV file.
+    ///
+    /// 无法打开、读取文件或将其解析为 WAV 文件。
+    #[cfg(feature = "hound")]
+    #[error("WAV error: {0}")]
+    Wav(#[from] hound::Error),
+
+    /// The WAV file uses a sample format that is not supported.
+    ///
+    /// WAV 文件使用了不支持的采样格式。
+    #[cfg(feature = "hound")]
+    #[error("Unsupported WAV sample format: {bits_per_sample}-bit {kind}")]
+    UnsupportedFormat {
+        kind: &'static str,
+        bits_per_sample: u16,
+    },
+
+    /// The channels to write differ in length.
+    ///
+    /// 要写入的各通道长度不同。
+    #[error(transparent)]
+    Shape(#[from] ShapeError),
+
+    /// There are no channels to write, or more than a WAV file can hold.
+    ///
+    /// 没有要写入的通道，或通道数超过 WAV 文件的上限。
+    #[cfg(feature = "hound")]
+    #[error("Cannot write {0} channels to a WAV file")]
+    ChannelCount(usize),
+
+    /// A sample to write lies outside `[-1, 1]` or is NaN, and clipping was not requested.
+    ///
+    /// 要写入的样本超出 `[-1, 1]` 或为 NaN，且未要求削波。
+    #[cfg(feature = "hound")]
+    #[error("Sample {index} of channel {channel} is out of range: {value}")]
+    SampleOutOfRange {
+        channel: usize,
+        index: usize,
+        value: f64,
+    },
+}
@@ -0,0 +1,244 @@
+use crate::errors::FilterError;
+use crate::inner::filter::{self, TransposedForm};
+use crate::process::Processor;
+use crate::signal::Signal;
+use std::f64::consts::PI;
+
+// 校验系数后构造直接 II 型转置结构
+fn transposed_form(b: &[f64], a: &[f64]) -> Result<TransposedForm, FilterError> {
+    if b.is_empty() || a.is_empty() {
+        return Err(FilterError::EmptyCoefficients);
+    }
+    if a[0] == 0.0 {
+        return Err(FilterError::ZeroLeadingDenominator);
+    }
+    Ok(TransposedForm::new(b, a))
+}
+
+// 截止频率为相对奈奎斯特频率的值
+fn check_cutoff(cutoff: f64, allow_nyquist: bool) -> Result<(), FilterError> {
+    let valid = cutoff > 0.0 && (cutoff < 1.0 || allow_nyquist && cutoff == 1.0);
+    if !valid {
+        return Err(FilterError::InvalidParameter {
+            name: "cutoff",
+            value: cutoff,
+        });
+    }
+    Ok(())
+}
+
+/// Causal finite impulse response filter, `y[n] = Σ h[k]·x[n - k]`, that keeps its history
+/// between blocks.
+///
+/// FIR 因果滤波器 `y[n] = Σ h[k]·x[n - k]`，在块与块之间保留历史样本。
+///
+/// # Example
+/// ```
+/// use dsp4rust::filter::Fir;
+/// use dsp4rust::process::Processor;
+/// use dsp4rust::signal::Signal;
+/// // 两点滑动平均
+/// let mut average = Fir::new(&[0.5, 0.5]).unwrap();
+/// let output = average.process(&Signal::from_vec(vec![2.0, 4.0, 6.0]));
+/// assert_eq!(output.to_vec(), vec![1.0, 3.0, 5.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fir {
+    form: TransposedForm,
+}
+
+impl Fir {
+    /// Creates a filter with the impulse response `taps`.
+    ///
+    /// # Errors
+    /// Returns `FilterError::EmptyCoefficients` if `taps` is empty.
+    ///
+    /// 创建冲激响应为 `taps` 的滤波器。
+    ///
+    /// # 错误
+    /// `taps` 为空时返回 `FilterError::EmptyCoefficients`。
+    pub fn new(taps: &[f64]) -> Result<Self, FilterError> {
+        Ok(Fir {
+            form: transposed_form(taps, &[1.0])?,
+        })
+    }
+
+    /// Designs a Hamming-windowed lowpass with `num_taps` taps and unit gain at DC.
+    ///
+    /// `cutoff` is relative to the Nyquist frequency, in `(0, 1]`.
+    ///
+    /// # Errors
+    /// Returns `FilterError::InvalidParameter` if `num_taps` is zero or `cutoff` is outside
+    /// `(0, 1]`.
+    ///
+    /// 设计抽头数为 `num_taps`、直流增益为 1 的 Hamming 窗低通滤波器。
+    ///
+    /// `cutoff` 为相对奈奎斯特频率的截止频率，取值 `(0, 1]`。
+    ///
+    /// # 错误
+    /// `num_taps` 为零或 `cutoff` 超出 `(0, 1]` 时返回 `FilterError::InvalidParameter`。
+    pub fn lowpass(num_taps: usize, cutoff: f64) -> Result<Self, FilterError> {
+        if num_taps == 0 {
+            return Err(FilterError::InvalidParameter {
+                name: "num_taps",
+                value: 0.0,
+            });
+        }
+        check_cutoff(cutoff, true)?;
+        Fir::new(&filter::firwin_lowpass(&filter::hamming(num_taps), cutoff))
+    }
+
+    /// Returns the impulse response.
+    ///
+    /// 返回冲激响应。
+    pub fn taps(&self) -> &[f64] {
+        &self.form.b
+    }
+}
+
+impl Processor for Fir {
+    fn process(&mut self, input: &Signal) -> Signal {
+        input.iter().map(|&x| self.form.step(x)).collect()
+    }
+
+    fn reset(&mut self) {
+        self.form.reset()
+    }
+}
+
+/// Infinite impulse response filter with transfer function `B(z) / A(z)`, run in transposed
+/// direct form II and keeping its state between blocks.
+///
+/// 传递函数为 `B(z) / A(z)` 的 IIR 滤波器，以直接 II 型转置结构运行，在块与块之间保留状态。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iir {
+    form: TransposedForm,
+}
+
+impl Iir {
+    /// Creates a filter from its numerator `b` and denominator `a`, both in ascending powers
+    /// of `z⁻¹`. The coefficients are normalized so that `a[0] = 1`.
+    ///
+    /// # Errors
+    /// - `FilterError::EmptyCoefficients` if `b` or `a` is empty.
+    /// - `FilterError::ZeroLeadingDenominator` if `a[0]` is zero.
+    ///
+    /// 由分子 `b` 与分母 `a`（均按 `z⁻¹` 的升幂排列）创建滤波器，系数按 `a[0] = 1` 归一化。
+    ///
+    /// # 错误
+    /// - `b` 或 `a` 为空时返回 `FilterError::EmptyCoefficients`。
+    /// - `a[0]` 为零时返回 `FilterError::ZeroLeadingDenominator`。
+    pub fn new(b: &[f64], a: &[f64]) -> Result<Self, FilterError> {
+        Ok(Iir {
+            form: transposed_form(b, a)?,
+        })
+    }
+
+    /// Creates the DC blocker `y[n] = x[n] - x[n - 1] + pole·y[n - 1]`, a highpass whose corner
+    /// moves towards DC as `pole` approaches 1 (typically 0.99 to 0.999).
+    ///
+    /// # Errors
+    /// Returns `FilterError::InvalidParameter` if `pole` is outside `[0, 1)`.
+    ///
+    /// 创建隔直滤波器 `y[n] = x[n] - x[n - 1] + pole·y[n - 1]`，这是一个高通滤波器，`pole` 越接近 1，
+    /// 转折频率越接近直流（通常取 0.99 到 0.999）。
+    ///
+    /// # 错误
+    /// `pole` 超出 `[0, 1)` 时返回 `FilterError::InvalidParameter`。
+    pub fn dc_blocker(pole: f64) -> Result<Self, FilterError> {
+        if !(0.0..1.0).contains(&pole) {
+            return Err(FilterError::InvalidParameter {
+                name: "pole",
+                value: pole,
+            });
+        }
+        Iir::new(&[1.0, -1.0], &[1.0, -pole])
+    }
+}
+
+impl Processor for Iir {
+    fn process(&mut self, input: &Signal) -> Signal {
+        input.iter().map(|&x| self.form.step(x)).collect()
+    }
+
+    fn reset(&mut self) {
+        self.form.reset()
+    }
+}
+
+/// Second-order IIR section `(b0 + b1·z⁻¹ + b2·z⁻²) / (1 + a1·z⁻¹ + a2·z⁻²)`.
+///
+/// 二阶 IIR 节 `(b0 + b1·z⁻¹ + b2·z⁻²) / (1 + a1·z⁻¹ + a2·z⁻²)`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Biquad {
+    form: TransposedForm,
+}
+
+impl Biquad {
+    /// Creates a section from its coefficients, with `a0 = 1`.
+    ///
+    /// 由系数创建二阶节，`a0 = 1`。
+    pub fn new(b0: f64, b1: f64, b2: f64, a1: f64, a2: f64) -> Self {
+        Biquad {
+            form: transposed_form(&[b0, b1, b2], &[1.0, a1, a2])
+                .expect("a0 = 1 is a valid leading coefficient"),
+        }
+    }
+
+    /// Designs a second-order lowpass with unit gain at DC (the RBJ audio cookbook design).
+    ///
+    /// `cutoff` is relative to the Nyquist frequency, in `(0, 1)`; `q` sets the resonance,
+    /// with `1/√2` giving a Butterworth response.
+    ///
+    /// # Errors
+    /// Returns `FilterError::InvalidParameter` if `cutoff` is outside `(0, 1)` or `q` is not
+    /// positive.
+    ///
+    /// 设计直流增益为 1 的二阶低通滤波器（RBJ 音频 cookbook 设计）。
+    ///
+    /// `cutoff` 为相对奈奎斯特频率的截止频率，取值 `(0, 1)`；`q` 决定谐振程度，`1/√2` 对应 Butterworth 响应。
+    ///
+    /// # 错误
+    /// `cutoff` 超出 `(0, 1)` 或 `q` 不是正数时返回 `FilterError::InvalidParameter`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::filter::Biquad;
+    /// use dsp4rust::process::Processor;
+    /// use dsp4rust::signal::Signal;
+    /// let mut lowpass = Biquad::lowpass(0.1, std::f64::consts::FRAC_1_SQRT_2).unwrap();
+    /// let settled = lowpass.process(&Signal::ones(200));
+    /// assert!((settled[-1] - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn lowpass(cutoff: f64, q: f64) -> Result<Self, FilterError> {
+        check_cutoff(cutoff, false)?;
+        if q.is_nan() || q <= 0.0 {
+            return Err(FilterError::InvalidParameter {
+                name: "q",
+                value: q,
+            });
+        }
+        let w0 = PI * cutoff;
+        let alpha = w0.sin() / (2.0 * q);
+        let cos = w0.cos();
+        let a0 = 1.0 + alpha;
+        let b0 = (1.0 - cos) / 2.0 / a0;
+        Ok(Biquad::new(
+            b0,
+            2.0 * b0,
+            b0,
+            -2.0 * cos / a0,
+            (1.0 - alpha) / a0,
+        ))
+    }
+}
+
+impl Processor for Biquad {
+    fn process(&mut self, input: &Signal) -> Signal {
+        input.iter().map(|&x| self.form.step(x)).collect()
+    }
+
+    fn reset(&mut self) {
+        self.form.reset()
+    }
+}
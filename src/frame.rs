@@ -0,0 +1,284 @@
+use crate::errors::FrameError;
+use crate::signal::Signal;
+use ndarray::ArrayViewMut1;
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A multi-channel signal: one or more channels of equal length, such as stereo audio or the
+/// inputs of a data acquisition card.
+///
+/// Each channel is an ordinary `Signal`, so any `Signal` method can be applied to it through
+/// `channel`. The channels are kept at equal length: `channel_mut` hands out the samples rather
+/// than the `Signal`, and `map_channels` checks the lengths it gets back. Interleaved data stores
+/// sample `n` of channel `c` at index `n * channels + c`, as in WAV files and most audio APIs.
+///
+/// 多通道信号：一个或多个等长的通道，例如立体声音频或数据采集卡的各路输入。
+///
+/// 每个通道都是普通的 `Signal`，可以通过 `channel` 对其调用任何 `Signal` 方法。各通道始终保持等长：
+/// `channel_mut` 提供的是样本而不是 `Signal`，`map_channels` 会检查返回的长度。交错数据中通道 `c` 的
+/// 第 `n` 个样本位于索引 `n * channels + c`，与 WAV 文件及大多数音频 API 相同。
+///
+/// # Example
+/// ```
+/// use dsp4rust::frame::SignalFrame;
+/// use dsp4rust::signal::Signal;
+/// let interleaved = Signal::from_vec(vec![1.0, -1.0, 2.0, -2.0, 3.0, -3.0]);
+/// let stereo = SignalFrame::from_interleaved(&interleaved, 2).unwrap();
+/// assert_eq!(stereo.channels(), 2);
+/// assert_eq!(stereo.channel(1).to_vec(), vec![-1.0, -2.0, -3.0]);
+/// assert_eq!(stereo.channel_means(), vec![2.0, -2.0]);
+/// assert_eq!(stereo.to_interleaved(), interleaved);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignalFrame {
+    channels: Vec<Signal>,
+}
+
+impl SignalFrame {
+    /// Creates a frame from its channels.
+    ///
+    /// # Errors
+    /// - `FrameError::NoChannels` if `channels` is empty.
+    /// - `FrameError::ChannelLength` if a channel's length differs from the first channel's.
+    ///
+    /// 由各通道创建帧。
+    ///
+    /// # 错误
+    /// - `channels` 为空时返回 `FrameError::NoChannels`。
+    /// - 某个通道的长度与第一个通道不同时返回 `FrameError::ChannelLength`。
+    pub fn new(channels: Vec<Signal>) -> Result<Self, FrameError> {
+        let expected = channels.first().ok_or(FrameError::NoChannels)?.len();
+        if let Some((channel, signal)) = channels
+            .iter()
+            .enumerate()
+            .find(|(_, signal)| signal.len() != expected)
+        {
+            return Err(FrameError::ChannelLength {
+                channel,
+                expected,
+                found: signal.len(),
+            });
+        }
+        Ok(SignalFrame { channels })
+    }
+
+    /// Splits an interleaved signal into `channels` channels, as `Signal::deinterleave` does.
+    ///
+    /// If the length is not a multiple of `channels`, the incomplete last frame is dropped.
+    ///
+    /// # Errors
+    /// Returns `FrameError::NoChannels` if `channels` is zero.
+    ///
+    /// 与 `Signal::deinterleave` 一样，将交错信号拆分为 `channels` 个通道。
+    ///
+    /// 若长度不是 `channels` 的整数倍，则丢弃不完整的最后一帧。
+    ///
+    /// # 错误
+    /// `channels` 为零时返回 `FrameError::NoChannels`。
+    pub fn from_interleaved(interleaved: &Signal, channels: usize) -> Result<Self, FrameError> {
+        if channels == 0 {
+            return Err(FrameError::NoChannels);
+        }
+        Ok(SignalFrame {
+            channels: interleaved.deinterleave(channels),
+        })
+    }
+
+    /// Merges the channels into one interleaved signal, the inverse of `from_interleaved`.
+    ///
+    /// 将各通道合并为一个交错信号，是 `from_interleaved` 的逆操作。
+    pub fn to_interleaved(&self) -> Signal {
+        (0..self.len())
+            .flat_map(|n| {
+                self.channels
+                    .iter()
+                    .map(move |channel| channel.as_array()[n])
+            })
+            .collect()
+    }
+
+    /// Returns the number of channels.
+    ///
+    /// 返回通道数。
+    pub fn channels(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Returns the number of samples in each channel.
+    ///
+    /// 返回每个通道的样本数。
+    pub fn len(&self) -> usize {
+        self.channels[0].len()
+    }
+
+    /// Returns `true` if the channels have no samples.
+    ///
+    /// 通道没有样本时返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns channel `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= channels()`.
+    ///
+    /// 返回第 `index` 个通道。
+    ///
+    /// # 恐慌
+    /// `index >= channels()` 时发生恐慌。
+    pub fn channel(&self, index: usize) -> &Signal {
+        &self.channels[index]
+    }
+
+    /// Returns a mutable view of the samples of channel `index`.
+    ///
+    /// The view can change the samples but not the length, so the channels stay equal in length.
+    ///
+    /// # Panics
+    /// Panics if `index >= channels()`.
+    ///
+    /// 返回第 `index` 个通道样本的可变视图。
+    ///
+    /// 该视图可以修改样本但不能改变长度，因此各通道始终等长。
+    ///
+    /// # 恐慌
+    /// `index >= channels()` 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::frame::SignalFrame;
+    /// use dsp4rust::signal::Signal;
+    /// let mut frame = SignalFrame::new(vec![Signal::ones(2), Signal::ones(2)]).unwrap();
+    /// frame.channel_mut(1).fill(0.5);
+    /// assert_eq!(frame.channel(1).to_vec(), vec![0.5, 0.5]);
+    /// ```
+    pub fn channel_mut(&mut self, index: usize) -> ArrayViewMut1<'_, f64> {
+        self.channels[index].as_array_mut()
+    }
+
+    /// Returns an iterator over the channels.
+    ///
+    /// 返回各通道的迭代器。
+    pub fn iter(&self) -> std::slice::Iter<'_, Signal> {
+        self.channels.iter()
+    }
+
+    /// Consumes the frame and returns its channels.
+    ///
+    /// 消耗帧，返回其各通道。
+    pub fn into_channels(self) -> Vec<Signal> {
+        self.channels
+    }
+
+    /// Applies `f` to every channel and collects the results into a new frame.
+    ///
+    /// `f` may change the length, e.g. to resample, as long as it does so equally for every
+    /// channel. It is called on the channels in order, so it may carry state across them.
+    ///
+    /// # Errors
+    /// Returns `FrameError::ChannelLength` if the results differ in length.
+    ///
+    /// 对每个通道应用 `f`，并将结果收集为新的帧。
+    ///
+    /// `f` 可以改变长度（例如重采样），只要对每个通道的改变相同即可。`f` 按通道顺序调用，因此可以在通道之间
+    /// 携带状态。
+    ///
+    /// # 错误
+    /// 结果的长度不一致时返回 `FrameError::ChannelLength`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::frame::SignalFrame;
+    /// use dsp4rust::signal::Signal;
+    /// let frame = SignalFrame::new(vec![Signal::from_vec(vec![3.0, -4.0]); 2]).unwrap();
+    /// let rectified = frame.map_channels(|channel| channel.abs()).unwrap();
+    /// assert_eq!(rectified.channel(0).to_vec(), vec![3.0, 4.0]);
+    /// ```
+    pub fn map_channels<F>(&self, f: F) -> Result<SignalFrame, FrameError>
+    where
+        F: FnMut(&Signal) -> Signal,
+    {
+        SignalFrame::new(self.channels.iter().map(f).collect())
+    }
+
+    /// Computes `stat` on every channel, in channel order.
+    ///
+    /// 按通道顺序对每个通道计算 `stat`。
+    pub fn channel_stat<F>(&self, stat: F) -> Vec<f64>
+    where
+        F: FnMut(&Signal) -> f64,
+    {
+        self.channels.iter().map(stat).collect()
+    }
+
+    /// Returns the mean of every channel, NaN for empty channels.
+    ///
+    /// 返回每个通道的均值，通道为空时为 NaN。
+    pub fn channel_means(&self) -> Vec<f64> {
+        self.channel_stat(|channel| channel.mean().unwrap_or(f64::NAN))
+    }
+
+    /// Returns the root mean square of every channel, NaN for empty channels.
+    ///
+    /// 返回每个通道的均方根，通道为空时为 NaN。
+    pub fn channel_rms(&self) -> Vec<f64> {
+        self.channel_stat(|channel| channel.rms().unwrap_or(f64::NAN))
+    }
+
+    /// Returns the population standard deviation of every channel.
+    ///
+    /// 返回每个通道的总体标准差。
+    pub fn channel_std_pop(&self) -> Vec<f64> {
+        self.channel_stat(|channel| channel.std_pop())
+    }
+
+    /// Returns the energy `Σ x[i]²` of every channel.
+    ///
+    /// 返回每个通道的能量 `Σ x[i]²`。
+    pub fn channel_energy(&self) -> Vec<f64> {
+        self.channel_stat(|channel| channel.energy())
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        (self.channels(), self.len())
+    }
+}
+
+// 两帧间的逐元素运算须形状一致，与标量的运算作用于每个通道
+macro_rules! impl_frame_ops {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&SignalFrame> for &SignalFrame {
+            type Output = Result<SignalFrame, FrameError>;
+            fn $op(self, other: &SignalFrame) -> Self::Output {
+                if self.shape() != other.shape() {
+                    return Err(FrameError::ShapeMismatch {
+                        left: self.shape(),
+                        right: other.shape(),
+                    });
+                }
+                Ok(SignalFrame {
+                    channels: self
+                        .channels
+                        .iter()
+                        .zip(&other.channels)
+                        .map(|(x, y)| x.$op(y))
+                        .collect(),
+                })
+            }
+        }
+
+        impl $Op<f64> for &SignalFrame {
+            type Output = SignalFrame;
+            fn $op(self, scalar: f64) -> SignalFrame {
+                SignalFrame {
+                    channels: self.channels.iter().map(|x| x.$op(scalar)).collect(),
+                }
+            }
+        }
+    };
+}
+
+impl_frame_ops!(Add, add);
+impl_frame_ops!(Sub, sub);
+impl_frame_ops!(Mul, mul);
+impl_frame_ops!(Div, div);
@@ -1,7 +1,14 @@
+use crate::errors::GeneratorError;
+use crate::inner::base::sum_of_squares;
+use crate::inner::fft;
 use crate::signal::Signal;
-use ndarray_rand::rand;
-use ndarray_rand::rand_distr::{Distribution, Normal};
-use std::f64::consts::TAU;
+use crate::window::WindowKind;
+use ndarray::Array1;
+use ndarray_rand::rand::rngs::StdRng;
+use ndarray_rand::rand::SeedableRng;
+use ndarray_rand::rand_distr::{Distribution, Normal, Uniform};
+use num_complex::Complex64;
+use std::f64::consts::{PI, TAU};
 
 /// Signal generator for various waveforms.
 ///
@@ -17,13 +24,16 @@ use std::f64::consts::TAU;
 /// * `sample_rate` - The number of samples per second (Hz) / 每秒采样数（赫兹）
 /// * `start_time` - The start time of the signal (seconds) / 信号的起始时间（秒）
 /// * `stop_time` - The stop time of the signal (seconds) / 信号的结束时间（秒）
+/// * `samples` - The number of generated samples / 生成的样本数
+/// * `seed` - Optional seed making the noise methods reproducible / 可选的随机种子，使噪声方法可复现
 ///
 /// # Construction
 ///
-/// Use the `new()` method to create a default instance, then chain the builder methods
-/// to set the desired attributes:
+/// Use the `new()` method to create a `GeneratorBuilder`, chain the builder methods to set the
+/// desired attributes, then call `build()`, which validates them:
 ///
-/// 使用 `new()` 方法创建默认实例，然后链式调用构建器方法设置所需的属性：
+/// 使用 `new()` 方法创建 `GeneratorBuilder`，链式调用构建器方法设置所需的属性，
+/// 最后调用 `build()` 对其进行校验：
 ///
 /// ```
 /// use dsp4rust::generator::Generator;
@@ -32,7 +42,8 @@ use std::f64::consts::TAU;
 ///     .sample_rate(44100.0)
 ///     .start_time(0.0)
 ///     .stop_time(1.0)
-///     .build();
+///     .build()
+///     .unwrap();
 /// ```
 ///
 /// # Modifying Attributes
@@ -55,34 +66,47 @@ use std::f64::consts::TAU;
 ///     .sample_rate(44100.0)
 ///     .start_time(0.0)
 ///     .stop_time(1.0)
-///     .build();
+///     .build()
+///     .unwrap();
 ///
 /// let sine_wave = generator.sin_unit(440.0, 0.0);
 /// ```
-#[derive(Default)]
 pub struct Generator {
     sample_rate: f64,
     start_time: f64,
     stop_time: f64,
+    samples: usize,
+    seed: Option<u64>,
 }
 
-impl Generator {
-    /// Creates a new Generator instance with default values.
-    ///
-    /// 创建一个具有默认值的新 Generator 实例。
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use dsp4rust::generator::Generator;
-    ///
-    /// let generator = Generator::new();
-    /// ```
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Default::default()
-    }
+/// Builder for `Generator`.
+///
+/// The end of the signal can be given as a `stop_time`, a `duration` or a number of samples.
+/// These three setters are alternatives: whichever is called last wins.
+///
+/// `Generator` 的构建器。
+///
+/// 信号的结束可以通过 `stop_time`、`duration` 或样本数给出。这三个设置方法互为替代，
+/// 以最后调用的为准。
+#[derive(Default)]
+pub struct GeneratorBuilder {
+    sample_rate: f64,
+    start_time: f64,
+    end: End,
+    seed: Option<u64>,
+}
+
+// 信号结束位置的三种指定方式
+#[derive(Default)]
+enum End {
+    #[default]
+    Unset,
+    StopTime(f64),
+    Duration(f64),
+    Samples(usize),
+}
 
+impl GeneratorBuilder {
     /// Sets the sample rate.
     ///
     /// 设置采样率。
@@ -92,7 +116,7 @@ impl Generator {
     /// ```
     /// use dsp4rust::generator::Generator;
     ///
-    /// let generator = Generator::new().sample_rate(44100.0);
+    /// let builder = Generator::new().sample_rate(44100.0);
     /// ```
     #[must_use]
     pub fn sample_rate(mut self, sample_rate: f64) -> Self {
@@ -109,7 +133,7 @@ impl Generator {
     /// ```
     /// use dsp4rust::generator::Generator;
     ///
-    /// let generator = Generator::new().start_time(0.0);
+    /// let builder = Generator::new().start_time(0.0);
     /// ```
     #[must_use]
     pub fn start_time(mut self, start_time: f64) -> Self {
@@ -117,27 +141,109 @@ impl Generator {
         self
     }
 
-    /// Sets the stop time.
+    /// Sets the stop time, replacing any earlier `duration` or `num_samples`.
     ///
-    /// 设置结束时间。
+    /// 设置结束时间，覆盖之前设置的 `duration` 或 `num_samples`。
     ///
     /// # Examples
     ///
     /// ```
     /// use dsp4rust::generator::Generator;
     ///
-    /// let generator = Generator::new().stop_time(1.0);
+    /// let builder = Generator::new().stop_time(1.0);
     /// ```
     #[must_use]
     pub fn stop_time(mut self, stop_time: f64) -> Self {
-        self.stop_time = stop_time;
+        self.end = End::StopTime(stop_time);
+        self
+    }
+
+    /// Sets the duration in seconds, replacing any earlier `stop_time` or `num_samples`.
+    ///
+    /// The stop time becomes `start_time + duration`.
+    ///
+    /// 设置时长（秒），覆盖之前设置的 `stop_time` 或 `num_samples`。
+    ///
+    /// 结束时间为 `start_time + duration`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    ///
+    /// let generator = Generator::new().sample_rate(100.0).start_time(2.0).duration(0.5).build().unwrap();
+    /// assert_eq!(generator.num_samples(), 50);
+    /// ```
+    #[must_use]
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.end = End::Duration(duration);
+        self
+    }
+
+    /// Sets the exact number of samples, replacing any earlier `stop_time` or `duration`.
+    ///
+    /// The stop time becomes `start_time + num_samples / sample_rate`.
+    ///
+    /// 设置精确的样本数，覆盖之前设置的 `stop_time` 或 `duration`。
+    ///
+    /// 结束时间为 `start_time + num_samples / sample_rate`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    ///
+    /// let generator = Generator::new().sample_rate(44100.0).num_samples(1024).build().unwrap();
+    /// assert_eq!(generator.sin_unit(440.0, 0.0).len(), 1024);
+    /// ```
+    #[must_use]
+    pub fn num_samples(mut self, num_samples: usize) -> Self {
+        self.end = End::Samples(num_samples);
+        self
+    }
+
+    /// Sets the seed of the random number generator used by the noise methods.
+    ///
+    /// With a seed, every noise method is deterministic: the same seed always produces the same
+    /// `Signal`, including across repeated calls on the same generator. Without a seed, noise is
+    /// drawn from fresh entropy on every call.
+    ///
+    /// 设置噪声方法所用随机数生成器的种子。
+    ///
+    /// 设置种子后，所有噪声方法都是确定性的：相同的种子总是产生相同的 `Signal`，
+    /// 在同一个生成器上重复调用也是如此。未设置种子时，每次调用都使用新的熵源生成噪声。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    ///
+    /// let generator = Generator::new().sample_rate(1000.0).stop_time(1.0).seed(42).build().unwrap();
+    /// let a = generator.gaussian_white_noise(0.0, 1.0);
+    /// let b = generator.gaussian_white_noise(0.0, 1.0);
+    /// assert_eq!(a.to_vec(), b.to_vec());
+    /// ```
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
         self
     }
 
     /// Builds the Generator.
     ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if the sample rate is not positive and finite,
+    /// the start time is not finite, or the resulting duration is not positive and finite
+    /// (including when no stop time, duration or sample count was given).
+    ///
     /// 构建 Generator。
     ///
+    /// # 错误
+    ///
+    /// 采样率不是有限正数、起始时间不是有限值，或得到的时长不是有限正数
+    /// （包括未设置结束时间、时长或样本数）时，返回 `GeneratorError::InvalidParameter`。
+    ///
     /// # Examples
     ///
     /// ```
@@ -147,38 +253,135 @@ impl Generator {
     ///     .sample_rate(44100.0)
     ///     .start_time(0.0)
     ///     .stop_time(1.0)
-    ///     .build();
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(Generator::new().sample_rate(0.0).stop_time(1.0).build().is_err());
     /// ```
-    pub fn build(self) -> Self {
-        self
+    pub fn build(self) -> Result<Generator, GeneratorError> {
+        let invalid = |name, value| Err(GeneratorError::InvalidParameter { name, value });
+        if !self.sample_rate.is_finite() || self.sample_rate <= 0.0 {
+            return invalid("sample_rate", self.sample_rate);
+        }
+        if !self.start_time.is_finite() {
+            return invalid("start_time", self.start_time);
+        }
+        // 出错时报告用户实际设置的参数
+        let (name, value, duration) = match self.end {
+            End::Unset => ("duration", 0.0, 0.0),
+            End::StopTime(stop_time) => ("stop_time", stop_time, stop_time - self.start_time),
+            End::Duration(duration) => ("duration", duration, duration),
+            End::Samples(samples) => (
+                "num_samples",
+                samples as f64,
+                samples as f64 / self.sample_rate,
+            ),
+        };
+        if !duration.is_finite() || duration <= 0.0 {
+            return invalid(name, value);
+        }
+        let samples = match self.end {
+            End::Samples(samples) => samples,
+            _ => (duration * self.sample_rate) as usize,
+        };
+
+        Ok(Generator {
+            sample_rate: self.sample_rate,
+            start_time: self.start_time,
+            stop_time: self.start_time + duration,
+            samples,
+            seed: self.seed,
+        })
     }
+}
 
-    /// Generates a unit sine wave signal.
+impl Generator {
+    /// Creates a `GeneratorBuilder` with default values.
     ///
-    /// 生成单位正弦波信号。
+    /// 创建一个具有默认值的 `GeneratorBuilder`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    ///
+    /// let builder = Generator::new();
+    /// ```
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> GeneratorBuilder {
+        GeneratorBuilder::default()
+    }
+
+    /// Returns the number of samples the generator methods produce.
+    ///
+    /// This is the count given to the `num_samples` builder method, or otherwise
+    /// `(stop_time - start_time) * sample_rate` truncated towards zero.
+    ///
+    /// 返回各生成方法产生的样本数。
+    ///
+    /// 其值为构建器方法 `num_samples` 给定的数目，否则为 `(stop_time - start_time) * sample_rate`
+    /// 向零截断的结果。
     ///
     /// # Examples
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
-    /// let sine = generator.sin_unit(440.0, 0.0);
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(0.5).build().unwrap();
+    /// assert_eq!(generator.num_samples(), 22050);
     /// ```
-    pub fn sin_unit(&self, freq: f64, phase: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
-        let mut data = Vec::with_capacity(samples);
+    pub fn num_samples(&self) -> usize {
+        self.samples
+    }
 
-        let mut t = self.start_time;
-        let sample_gap = 1.0 / self.sample_rate;
+    /// Returns the sample rate in Hz.
+    ///
+    /// 返回采样率（Hz）。
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
 
-        for _ in 0..samples {
-            data.push((TAU * freq * t + phase).sin());
-            t += sample_gap;
+    /// Returns the sample time axis used by the generator methods.
+    ///
+    /// Sample `k` is at `start_time + k / sample_rate` for `k` in `0..num_samples()`, so
+    /// `stop_time` itself is excluded.
+    ///
+    /// 返回各生成方法所使用的采样时间轴。
+    ///
+    /// 第 `k` 个样本位于 `start_time + k / sample_rate`，`k` 取 `0..num_samples()`，
+    /// 因此不包含 `stop_time` 本身。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(4.0).start_time(1.0).stop_time(2.0).build().unwrap();
+    /// assert_eq!(generator.time_vector().to_vec(), vec![1.0, 1.25, 1.5, 1.75]);
+    /// ```
+    pub fn time_vector(&self) -> Signal {
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            data.push(self.start_time + i as f64 / self.sample_rate);
         }
 
         Signal::from_vec(data)
     }
 
+    /// Generates a unit sine wave signal.
+    ///
+    /// 生成单位正弦波信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let sine = generator.sin_unit(440.0, 0.0);
+    /// ```
+    pub fn sin_unit(&self, freq: f64, phase: f64) -> Signal {
+        self.sin(freq, phase, 1.0, 0.0)
+    }
+
     /// Generates a unit pulse wave signal.
     ///
     /// 生成单位脉冲波信号。
@@ -192,11 +395,111 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let pulse = generator.pulse_unit(440.0, 0.0, 0.5);
     /// ```
     pub fn pulse_unit(&self, freq: f64, phase: f64, duty_cycle: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        self.pulse(freq, phase, duty_cycle, 1.0, 0.0)
+    }
+
+    /// Generates a unit square wave signal.
+    ///
+    /// 生成单位方波信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let square = generator.square_unit(440.0, 0.0);
+    /// ```
+    pub fn square_unit(&self, freq: f64, phase: f64) -> Signal {
+        self.square(freq, phase, 1.0, 0.0)
+    }
+
+    /// Generates a unit triangle wave signal.
+    ///
+    /// 生成单位三角波信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let triangle = generator.triangle_unit(440.0, 0.0);
+    /// ```
+    pub fn triangle_unit(&self, freq: f64, phase: f64) -> Signal {
+        self.triangle(freq, phase, 1.0, 0.0)
+    }
+
+    /// Generates a unit sawtooth wave signal.
+    ///
+    /// 生成单位锯齿波信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let sawtooth = generator.sawtooth_unit(440.0, 0.0);
+    /// ```
+    pub fn sawtooth_unit(&self, freq: f64, phase: f64) -> Signal {
+        self.sawtooth(freq, phase, 1.0, 0.0)
+    }
+
+    /// Generates a sine wave with the given amplitude and DC offset in a single pass.
+    ///
+    /// Each sample equals `amplitude * unit + offset`, where `unit` is the corresponding sample of
+    /// `sin_unit`.
+    ///
+    /// 一次性生成具有给定幅度和直流偏置的正弦波。
+    ///
+    /// 每个样本等于 `amplitude * unit + offset`，其中 `unit` 为 `sin_unit` 的对应样本。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let wave = generator.sin(440.0, 0.0, 2.0, 1.0);
+    /// ```
+    pub fn sin(&self, freq: f64, phase: f64, amplitude: f64, offset: f64) -> Signal {
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            data.push(amplitude * (TAU * freq * t + phase).sin() + offset);
+        }
+
+        Signal::from_vec(data)
+    }
+
+    /// Generates a pulse wave with the given amplitude and DC offset in a single pass.
+    ///
+    /// Each sample equals `amplitude * unit + offset`, where `unit` is the corresponding sample of
+    /// `pulse_unit`.
+    ///
+    /// 一次性生成具有给定幅度和直流偏置的脉冲波。
+    ///
+    /// 每个样本等于 `amplitude * unit + offset`，其中 `unit` 为 `pulse_unit` 的对应样本。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let wave = generator.pulse(440.0, 0.0, 0.25, 1.0, 0.0);
+    /// ```
+    pub fn pulse(
+        &self,
+        freq: f64,
+        phase: f64,
+        duty_cycle: f64,
+        amplitude: f64,
+        offset: f64,
+    ) -> Signal {
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
         let period_samples = (self.sample_rate / freq) as usize;
@@ -205,44 +508,55 @@ impl Generator {
 
         for i in 0..samples {
             let t_in_period = (i + phase_offset) % period_samples;
-            if t_in_period < high_samples {
-                data.push(1.0)
+            let value = if t_in_period < high_samples {
+                1.0
             } else {
-                data.push(-1.0)
-            }
+                -1.0
+            };
+            data.push(amplitude * value + offset);
         }
 
         Signal::from_vec(data)
     }
 
-    /// Generates a unit square wave signal.
+    /// Generates a square wave with the given amplitude and DC offset in a single pass.
     ///
-    /// 生成单位方波信号。
+    /// Each sample equals `amplitude * unit + offset`, where `unit` is the corresponding sample of
+    /// `square_unit`.
+    ///
+    /// 一次性生成具有给定幅度和直流偏置的方波。
+    ///
+    /// 每个样本等于 `amplitude * unit + offset`，其中 `unit` 为 `square_unit` 的对应样本。
     ///
     /// # Examples
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
-    /// let square = generator.square_unit(440.0, 0.0);
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let wave = generator.square(440.0, 0.0, 0.5, 0.5);
     /// ```
-    pub fn square_unit(&self, freq: f64, phase: f64) -> Signal {
-        self.pulse_unit(freq, phase, 0.5)
+    pub fn square(&self, freq: f64, phase: f64, amplitude: f64, offset: f64) -> Signal {
+        self.pulse(freq, phase, 0.5, amplitude, offset)
     }
 
-    /// Generates a unit triangle wave signal.
+    /// Generates a triangle wave with the given amplitude and DC offset in a single pass.
     ///
-    /// 生成单位三角波信号。
+    /// Each sample equals `amplitude * unit + offset`, where `unit` is the corresponding sample of
+    /// `triangle_unit`.
+    ///
+    /// 一次性生成具有给定幅度和直流偏置的三角波。
+    ///
+    /// 每个样本等于 `amplitude * unit + offset`，其中 `unit` 为 `triangle_unit` 的对应样本。
     ///
     /// # Examples
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
-    /// let triangle = generator.triangle_unit(440.0, 0.0);
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let wave = generator.triangle(440.0, 0.0, 0.8, -0.1);
     /// ```
-    pub fn triangle_unit(&self, freq: f64, phase: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+    pub fn triangle(&self, freq: f64, phase: f64, amplitude: f64, offset: f64) -> Signal {
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
         let period_samples = (self.sample_rate / freq) as usize;
@@ -258,25 +572,30 @@ impl Generator {
                 1.0 - 4.0 * (normalized_t - 0.5)
             };
 
-            data.push(value);
+            data.push(amplitude * value + offset);
         }
 
         Signal::from_vec(data)
     }
 
-    /// Generates a unit sawtooth wave signal.
+    /// Generates a sawtooth wave with the given amplitude and DC offset in a single pass.
     ///
-    /// 生成单位锯齿波信号。
+    /// Each sample equals `amplitude * unit + offset`, where `unit` is the corresponding sample of
+    /// `sawtooth_unit`.
+    ///
+    /// 一次性生成具有给定幅度和直流偏置的锯齿波。
+    ///
+    /// 每个样本等于 `amplitude * unit + offset`，其中 `unit` 为 `sawtooth_unit` 的对应样本。
     ///
     /// # Examples
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
-    /// let sawtooth = generator.sawtooth_unit(440.0, 0.0);
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let wave = generator.sawtooth(440.0, 0.0, 3.0, 0.0);
     /// ```
-    pub fn sawtooth_unit(&self, freq: f64, phase: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+    pub fn sawtooth(&self, freq: f64, phase: f64, amplitude: f64, offset: f64) -> Signal {
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
         let period_samples = (self.sample_rate / freq) as usize;
         let phase_offset = ((phase / TAU) * period_samples as f64).round() as usize;
@@ -288,7 +607,7 @@ impl Generator {
 
             let value = 2.0 * (normalized_t - 0.5);
 
-            data.push(value);
+            data.push(amplitude * value + offset);
         }
 
         Signal::from_vec(data)
@@ -302,11 +621,11 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let step = generator.step_unit(0.5);
     /// ```
     pub fn step_unit(&self, step_time: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
         for i in 0..samples {
@@ -330,20 +649,16 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let gaussian_pulse = generator.gaussian_pulse(0.5, 0.1);
     /// ```
     pub fn gaussian_pulse(&self, center_time: f64, sigma: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
-        let mut t = self.start_time;
-        let sample_gap = 1.0 / self.sample_rate;
-
-        for _ in 0..samples {
-            let value = (-((t - center_time).powi(2)) / (2.0 * sigma.powi(2))).exp();
-            data.push(value);
-            t += sample_gap;
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            data.push(gaussian(t, center_time, sigma));
         }
 
         Signal::from_vec(data)
@@ -357,7 +672,7 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let gaussian_unit = generator.gaussian_unit();
     /// ```
     pub fn gaussian_unit(&self) -> Signal {
@@ -372,24 +687,66 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let noise = generator.gaussian_white_noise(0.0, 1.0);
     /// ```
     pub fn gaussian_white_noise(&self, mean: f64, std_dev: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        let samples = self.num_samples();
 
         // 创建一个服从正态分布的随机数组
-        let noise_vec: Vec<f64> = (0..samples)
-            .map(|_| {
-                Normal::new(mean, std_dev)
-                    .unwrap()
-                    .sample(&mut rand::thread_rng())
-            })
-            .collect();
+        let normal = Normal::new(mean, std_dev).unwrap();
+        let mut rng = self.rng();
+        let noise_vec: Vec<f64> = (0..samples).map(|_| normal.sample(&mut rng)).collect();
 
         Signal::from_vec(noise_vec)
     }
 
+    /// Generates uniform white noise.
+    ///
+    /// Samples are drawn independently from the uniform distribution on `[low, high)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` unless `low < high` and both are finite.
+    ///
+    /// 生成均匀分布白噪声。
+    ///
+    /// 每个样本独立地服从 `[low, high)` 上的均匀分布。
+    ///
+    /// # 错误
+    ///
+    /// 除非 `low < high` 且两者均为有限值，否则返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let noise = generator.uniform_white_noise(-1.0, 1.0).unwrap();
+    /// assert!(noise.iter().all(|&x| (-1.0..1.0).contains(&x)));
+    /// ```
+    pub fn uniform_white_noise(&self, low: f64, high: f64) -> Result<Signal, GeneratorError> {
+        if !low.is_finite() {
+            return Err(GeneratorError::InvalidParameter {
+                name: "low",
+                value: low,
+            });
+        }
+        if !high.is_finite() || high <= low {
+            return Err(GeneratorError::InvalidParameter {
+                name: "high",
+                value: high,
+            });
+        }
+        let samples = self.num_samples();
+        let uniform = Uniform::new(low, high);
+        let mut rng = self.rng();
+
+        let noise_vec: Vec<f64> = (0..samples).map(|_| uniform.sample(&mut rng)).collect();
+
+        Ok(Signal::from_vec(noise_vec))
+    }
+
     /// Generates an exponential signal.
     ///
     /// 生成指数信号。
@@ -398,19 +755,16 @@ impl Generator {
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let exp_signal = generator.exponential_signal(2.0);
     /// ```
     pub fn exponential_signal(&self, alpha: f64) -> Signal {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
-        let mut t = self.start_time;
-        let sample_gap = 1.0 / self.sample_rate;
-
-        for _ in 0..samples {
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
             data.push((alpha * t).exp());
-            t += sample_gap;
         }
 
         Signal::from_vec(data)
@@ -418,28 +772,883 @@ impl Generator {
 
     /// Generates a custom waveform based on the provided function.
     ///
+    /// `f` is evaluated at each instant of `time_vector`, i.e. starting at `start_time`.
+    ///
     /// 根据提供的函数生成自定义波形。
     ///
+    /// `f` 在 `time_vector` 的每个时刻上求值，即从 `start_time` 开始。
+    ///
     /// # Examples
     ///
     /// ```
     /// # use dsp4rust::generator::Generator;
-    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build();
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
     /// let custom_wave = generator.fn_wave(|t| t.sin() + 0.5 * (2.0 * t).sin());
     /// ```
     pub fn fn_wave<T>(&self, f: T) -> Signal
     where
         T: Fn(f64) -> f64,
     {
-        let samples = ((self.stop_time - self.start_time) * self.sample_rate) as usize;
+        let samples = self.num_samples();
         let mut data = Vec::with_capacity(samples);
 
-        let mut t = 0.0;
-        let sample_gap = 1.0 / self.sample_rate;
-        for _ in 0..samples {
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
             data.push(f(t));
-            t += sample_gap;
         }
+
         Signal::from_vec(data)
     }
+
+    /// Generates a linear chirp.
+    ///
+    /// The instantaneous frequency sweeps linearly from `f0` at `start_time` to `f1` at
+    /// `stop_time`. The phase is the analytic integral of the frequency,
+    /// `φ(τ) = 2π(f0·τ + (f1 - f0)·τ² / (2T)) + phase`, where `τ` is the time since `start_time`
+    /// and `T` the total duration.
+    ///
+    /// 生成线性调频（chirp）信号。
+    ///
+    /// 瞬时频率从 `start_time` 时的 `f0` 线性扫到 `stop_time` 时的 `f1`。相位为频率的解析积分
+    /// `φ(τ) = 2π(f0·τ + (f1 - f0)·τ² / (2T)) + phase`，其中 `τ` 为相对 `start_time` 的时间，
+    /// `T` 为总时长。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let chirp = generator.chirp_linear(100.0, 1000.0, 0.0);
+    /// ```
+    pub fn chirp_linear(&self, f0: f64, f1: f64, phase: f64) -> Signal {
+        let samples = self.num_samples();
+        let duration = self.stop_time - self.start_time;
+        let sweep_rate = (f1 - f0) / duration;
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let tau = i as f64 / self.sample_rate;
+            data.push((TAU * (f0 * tau + 0.5 * sweep_rate * tau * tau) + phase).sin());
+        }
+
+        Signal::from_vec(data)
+    }
+
+    /// Generates a logarithmic (exponential) chirp.
+    ///
+    /// The instantaneous frequency sweeps exponentially from `f0` at `start_time` to `f1` at
+    /// `stop_time`, spending equal time per octave: `f(τ) = f0·(f1/f0)^(τ/T)`. The phase is the
+    /// analytic integral `φ(τ) = 2π·f0·T·((f1/f0)^(τ/T) - 1) / ln(f1/f0) + phase`, where `τ` is
+    /// the time since `start_time` and `T` the total duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `f0` or `f1` is not positive.
+    ///
+    /// 生成对数（指数）调频信号。
+    ///
+    /// 瞬时频率从 `start_time` 时的 `f0` 按指数规律扫到 `stop_time` 时的 `f1`，每个倍频程耗时相同：
+    /// `f(τ) = f0·(f1/f0)^(τ/T)`。相位为解析积分 `φ(τ) = 2π·f0·T·((f1/f0)^(τ/T) - 1) / ln(f1/f0) + phase`，
+    /// 其中 `τ` 为相对 `start_time` 的时间，`T` 为总时长。
+    ///
+    /// # 错误
+    ///
+    /// `f0` 或 `f1` 不为正数时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let sweep = generator.chirp_log(20.0, 20000.0, 0.0).unwrap();
+    /// assert!(generator.chirp_log(0.0, 20000.0, 0.0).is_err());
+    /// ```
+    pub fn chirp_log(&self, f0: f64, f1: f64, phase: f64) -> Result<Signal, GeneratorError> {
+        for (name, value) in [("f0", f0), ("f1", f1)] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(GeneratorError::InvalidParameter { name, value });
+            }
+        }
+        let samples = self.num_samples();
+        let duration = self.stop_time - self.start_time;
+        let ratio_ln = (f1 / f0).ln();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let tau = i as f64 / self.sample_rate;
+            let cycles = if ratio_ln == 0.0 {
+                f0 * tau
+            } else {
+                f0 * duration * ((ratio_ln * tau / duration).exp() - 1.0) / ratio_ln
+            };
+            data.push((TAU * cycles + phase).sin());
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates colored Gaussian noise whose power spectral density follows `f^exponent`.
+    ///
+    /// White Gaussian noise is shaped in the frequency domain by `f^(exponent / 2)` and the DC
+    /// component is removed, so the result has zero mean and no drift. The output is scaled so
+    /// that its RMS equals `amplitude`. Common exponents are `-2` (brown), `-1` (pink), `0`
+    /// (white), `1` (blue) and `2` (violet).
+    ///
+    /// 生成功率谱密度按 `f^exponent` 变化的有色高斯噪声。
+    ///
+    /// 在频域中以 `f^(exponent / 2)` 对高斯白噪声整形并去除直流分量，因此结果均值为零且无漂移。
+    /// 输出经过缩放，使其均方根值等于 `amplitude`。常用指数为 `-2`（布朗）、`-1`（粉红）、
+    /// `0`（白）、`1`（蓝）和 `2`（紫）。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let pink = generator.colored_noise(-1.0, 0.5);
+    /// assert!((pink.avg_power().sqrt() - 0.5).abs() < 1e-9);
+    /// ```
+    pub fn colored_noise(&self, exponent: f64, amplitude: f64) -> Signal {
+        let samples = self.num_samples();
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let mut rng = self.rng();
+        let white: Vec<f64> = (0..samples).map(|_| normal.sample(&mut rng)).collect();
+
+        let mut spectrum = fft::fft_real(&white);
+        if let Some(dc) = spectrum.first_mut() {
+            *dc = Complex64::new(0.0, 0.0);
+        }
+        for k in 1..=samples / 2 {
+            let gain = (k as f64).powf(exponent / 2.0);
+            spectrum[k] *= gain;
+            if samples - k != k {
+                spectrum[samples - k] *= gain;
+            }
+        }
+        let shaped: Array1<f64> = fft::ifft(&spectrum).iter().map(|c| c.re).collect();
+
+        let rms = (sum_of_squares(shaped.view()) / samples as f64).sqrt();
+        let scale = if rms > 0.0 { amplitude / rms } else { 0.0 };
+        Signal::from_iter(shaped.iter().map(|x| x * scale))
+    }
+
+    /// Generates Brownian (red) noise with RMS `amplitude`.
+    ///
+    /// Equivalent to integrated white noise with the drift removed; its power spectral density
+    /// falls as `1/f²`.
+    ///
+    /// 生成均方根值为 `amplitude` 的布朗（红）噪声。
+    ///
+    /// 等价于去除漂移后的积分白噪声，其功率谱密度按 `1/f²` 下降。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let brown = generator.brown_noise(1.0);
+    /// ```
+    pub fn brown_noise(&self, amplitude: f64) -> Signal {
+        self.colored_noise(-2.0, amplitude)
+    }
+
+    /// Generates blue noise with RMS `amplitude`, whose power spectral density rises as `f`.
+    ///
+    /// 生成均方根值为 `amplitude` 的蓝噪声，其功率谱密度按 `f` 上升。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let blue = generator.blue_noise(1.0);
+    /// ```
+    pub fn blue_noise(&self, amplitude: f64) -> Signal {
+        self.colored_noise(1.0, amplitude)
+    }
+
+    /// Generates violet noise with RMS `amplitude`, whose power spectral density rises as `f²`.
+    ///
+    /// Equivalent to differentiated white noise.
+    ///
+    /// 生成均方根值为 `amplitude` 的紫噪声，其功率谱密度按 `f²` 上升。
+    ///
+    /// 等价于微分后的白噪声。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let violet = generator.violet_noise(1.0);
+    /// ```
+    pub fn violet_noise(&self, amplitude: f64) -> Signal {
+        self.colored_noise(2.0, amplitude)
+    }
+
+    /// Generates a unit impulse at the sample nearest to `at_time`.
+    ///
+    /// The sample index is `round((at_time - start_time) * sample_rate)`, with ties rounded away
+    /// from zero. If that index falls outside the generated range (for example `at_time` well
+    /// before `start_time` or at or after `stop_time`), the result is all zeros.
+    ///
+    /// 在距离 `at_time` 最近的样本处生成单位冲激。
+    ///
+    /// 样本索引为 `round((at_time - start_time) * sample_rate)`，恰在中间时远离零取整。
+    /// 若该索引超出生成范围（例如 `at_time` 明显早于 `start_time`，或不早于 `stop_time`），结果为全零。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(10.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let impulse = generator.impulse_unit(0.32);
+    /// assert_eq!(impulse[3], 1.0);
+    /// assert_eq!(impulse.iter().sum::<f64>(), 1.0);
+    /// ```
+    pub fn impulse_unit(&self, at_time: f64) -> Signal {
+        let samples = self.num_samples();
+        let index = ((at_time - self.start_time) * self.sample_rate).round();
+        if index >= 0.0 && index < samples as f64 {
+            Signal::impulse(samples, index as usize)
+        } else {
+            Signal::zeros(samples)
+        }
+    }
+
+    /// Generates the sum of several sinusoids in a single pass.
+    ///
+    /// Each component is `(freq, amplitude, phase)` and contributes
+    /// `amplitude · sin(2π·freq·t + phase)`, with the same time base as `sin_unit`. An empty
+    /// component list yields an all-zero signal.
+    ///
+    /// 一次性生成多个正弦分量之和。
+    ///
+    /// 每个分量为 `(freq, amplitude, phase)`，贡献 `amplitude · sin(2π·freq·t + phase)`，
+    /// 时间基准与 `sin_unit` 相同。分量列表为空时生成全零信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let chord = generator.multi_tone(&[(440.0, 1.0, 0.0), (554.37, 0.8, 0.0), (659.25, 0.6, 0.0)]);
+    /// assert_eq!(generator.multi_tone(&[]).to_vec(), vec![0.0; 44100]);
+    /// ```
+    pub fn multi_tone(&self, components: &[(f64, f64, f64)]) -> Signal {
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            let value = components
+                .iter()
+                .map(|&(freq, amplitude, phase)| amplitude * (TAU * freq * t + phase).sin())
+                .sum();
+            data.push(value);
+        }
+
+        Signal::from_vec(data)
+    }
+
+    /// Generates an amplitude-modulated (AM) signal.
+    ///
+    /// The output is `(1 + m·cos(2π·fm·t))·cos(2π·fc·t)`, with the carrier at `carrier_freq`, the
+    /// message at `mod_freq` and `m = modulation_index`. Each sideband at `fc ± fm` has amplitude
+    /// `m / 2` relative to the carrier. Over-modulation (`m > 1`) is allowed and simply lets the
+    /// envelope cross zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `modulation_index` is negative or NaN.
+    ///
+    /// 生成调幅（AM）信号。
+    ///
+    /// 输出为 `(1 + m·cos(2π·fm·t))·cos(2π·fc·t)`，其中载波频率为 `carrier_freq`，调制信号频率为
+    /// `mod_freq`，`m = modulation_index`。位于 `fc ± fm` 的两个边带幅度均为载波的 `m / 2`。
+    /// 允许过调制（`m > 1`），此时包络会穿过零点。
+    ///
+    /// # 错误
+    ///
+    /// `modulation_index` 为负数或 NaN 时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let am = generator.am(10000.0, 440.0, 0.5).unwrap();
+    /// assert!(generator.am(10000.0, 440.0, -0.5).is_err());
+    /// ```
+    pub fn am(
+        &self,
+        carrier_freq: f64,
+        mod_freq: f64,
+        modulation_index: f64,
+    ) -> Result<Signal, GeneratorError> {
+        if modulation_index.is_nan() || modulation_index < 0.0 {
+            return Err(GeneratorError::InvalidParameter {
+                name: "modulation_index",
+                value: modulation_index,
+            });
+        }
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            let envelope = 1.0 + modulation_index * (TAU * mod_freq * t).cos();
+            data.push(envelope * (TAU * carrier_freq * t).cos());
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates a frequency-modulated (FM) signal.
+    ///
+    /// Uses the integrated-phase form `cos(2π·fc·t + (Δf/fm)·sin(2π·fm·t))`, so the
+    /// instantaneous frequency swings sinusoidally between `carrier_freq - freq_deviation` and
+    /// `carrier_freq + freq_deviation` at the rate `mod_freq`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `mod_freq` is zero or NaN.
+    ///
+    /// 生成调频（FM）信号。
+    ///
+    /// 采用相位积分形式 `cos(2π·fc·t + (Δf/fm)·sin(2π·fm·t))`，瞬时频率以 `mod_freq` 的速率
+    /// 在 `carrier_freq - freq_deviation` 与 `carrier_freq + freq_deviation` 之间正弦摆动。
+    ///
+    /// # 错误
+    ///
+    /// `mod_freq` 为零或 NaN 时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let fm = generator.fm(10000.0, 5.0, 200.0).unwrap();
+    /// assert!(generator.fm(10000.0, 0.0, 200.0).is_err());
+    /// ```
+    pub fn fm(
+        &self,
+        carrier_freq: f64,
+        mod_freq: f64,
+        freq_deviation: f64,
+    ) -> Result<Signal, GeneratorError> {
+        if mod_freq.is_nan() || mod_freq == 0.0 {
+            return Err(GeneratorError::InvalidParameter {
+                name: "mod_freq",
+                value: mod_freq,
+            });
+        }
+        Ok(self.angle_modulated(carrier_freq, mod_freq, freq_deviation / mod_freq))
+    }
+
+    /// Generates a phase-modulated (PM) signal.
+    ///
+    /// The output is `cos(2π·fc·t + Δφ·sin(2π·fm·t))` with `Δφ = phase_deviation` in radians.
+    /// This is the same waveform as `fm` with `freq_deviation = phase_deviation · mod_freq`.
+    ///
+    /// 生成调相（PM）信号。
+    ///
+    /// 输出为 `cos(2π·fc·t + Δφ·sin(2π·fm·t))`，其中 `Δφ = phase_deviation`（弧度）。
+    /// 它与 `freq_deviation = phase_deviation · mod_freq` 时的 `fm` 波形相同。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let pm = generator.pm(10000.0, 5.0, 40.0);
+    /// assert_eq!(pm.to_vec(), generator.fm(10000.0, 5.0, 200.0).unwrap().to_vec());
+    /// ```
+    pub fn pm(&self, carrier_freq: f64, mod_freq: f64, phase_deviation: f64) -> Signal {
+        self.angle_modulated(carrier_freq, mod_freq, phase_deviation)
+    }
+
+    /// Generates a Gaussian-modulated sinusoid, following the classic `gausspulse`.
+    ///
+    /// The output is `exp(-a·τ²)·cos(2π·fc·τ)` with `τ = t - center_time`. The envelope width is
+    /// chosen so that the spectrum falls to -6 dB of its peak at `fc·(1 ± fractional_bandwidth / 2)`,
+    /// i.e. `a = -(π·fc·B)² / (4·ln(10^(-6/20)))`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `center_freq` or `fractional_bandwidth` is not
+    /// positive.
+    ///
+    /// 生成高斯调制正弦信号，与经典的 `gausspulse` 一致。
+    ///
+    /// 输出为 `exp(-a·τ²)·cos(2π·fc·τ)`，其中 `τ = t - center_time`。包络宽度的选取使频谱在
+    /// `fc·(1 ± fractional_bandwidth / 2)` 处降到峰值的 -6 dB，即 `a = -(π·fc·B)² / (4·ln(10^(-6/20)))`。
+    ///
+    /// # 错误
+    ///
+    /// `center_freq` 或 `fractional_bandwidth` 不为正数时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(1e6).start_time(0.0).stop_time(1e-3).build().unwrap();
+    /// let pulse = generator.gauss_pulse(50e3, 0.5, 5e-4).unwrap();
+    /// assert_eq!(pulse[500], 1.0);
+    /// ```
+    pub fn gauss_pulse(
+        &self,
+        center_freq: f64,
+        fractional_bandwidth: f64,
+        center_time: f64,
+    ) -> Result<Signal, GeneratorError> {
+        for (name, value) in [
+            ("center_freq", center_freq),
+            ("fractional_bandwidth", fractional_bandwidth),
+        ] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(GeneratorError::InvalidParameter { name, value });
+            }
+        }
+        let samples = self.num_samples();
+        let reference = 10f64.powf(-6.0 / 20.0);
+        let a = -(PI * center_freq * fractional_bandwidth).powi(2) / (4.0 * reference.ln());
+        let sigma = (2.0 * a).sqrt().recip();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            let carrier = (TAU * center_freq * (t - center_time)).cos();
+            data.push(gaussian(t, center_time, sigma) * carrier);
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates a ±1 pseudo-random binary sequence (maximum-length sequence).
+    ///
+    /// The sequence comes from a Galois LFSR with a standard primitive polynomial of degree
+    /// `order`, so it has period `2^order - 1` and an impulse-like circular autocorrelation. The
+    /// low `order` bits of `seed` form the initial register state. A set output bit maps to `1.0`
+    /// and a cleared bit to `-1.0`. The sequence is repeated or truncated to fill the configured
+    /// duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `order` is outside 3..=20, or if the low
+    /// `order` bits of `seed` are all zero (the LFSR would lock up).
+    ///
+    /// 生成 ±1 伪随机二进制序列（最大长度序列）。
+    ///
+    /// 序列由使用 `order` 次标准本原多项式的 Galois 线性反馈移位寄存器产生，周期为 `2^order - 1`，
+    /// 循环自相关近似为冲激。`seed` 的低 `order` 位作为寄存器初始状态。输出位为 1 时映射为 `1.0`，
+    /// 为 0 时映射为 `-1.0`。序列按配置的时长重复或截断。
+    ///
+    /// # 错误
+    ///
+    /// `order` 不在 3..=20 范围内，或 `seed` 的低 `order` 位全为零（寄存器会锁死）时，
+    /// 返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(1000.0).start_time(0.0).stop_time(2.0).build().unwrap();
+    /// let mls = generator.prbs(10, 1).unwrap();
+    /// assert_eq!(mls[0], mls[1023]);
+    /// assert!(generator.prbs(2, 1).is_err());
+    /// ```
+    pub fn prbs(&self, order: u8, seed: u32) -> Result<Signal, GeneratorError> {
+        if !(3..=20).contains(&order) {
+            return Err(GeneratorError::InvalidParameter {
+                name: "order",
+                value: order as f64,
+            });
+        }
+        let mask = PRBS_TAPS[order as usize - 3];
+        let mut state = seed & ((1 << order) - 1);
+        if state == 0 {
+            return Err(GeneratorError::InvalidParameter {
+                name: "seed",
+                value: seed as f64,
+            });
+        }
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for _ in 0..samples {
+            let bit = state & 1;
+            state >>= 1;
+            if bit == 1 {
+                state ^= mask;
+            }
+            data.push(if bit == 1 { 1.0 } else { -1.0 });
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates a straight line from `start_value` to `end_value`.
+    ///
+    /// The first sample is exactly `start_value` and the last sample exactly `end_value`; the
+    /// samples in between are evenly spaced. A single-sample signal holds `start_value`.
+    ///
+    /// 生成从 `start_value` 到 `end_value` 的直线。
+    ///
+    /// 第一个样本恰为 `start_value`，最后一个样本恰为 `end_value`，中间样本等间距分布。
+    /// 只有一个样本时其值为 `start_value`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(5.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let ramp = generator.ramp(1.0, 0.0);
+    /// assert_eq!(ramp.to_vec(), vec![1.0, 0.75, 0.5, 0.25, 0.0]);
+    /// ```
+    pub fn ramp(&self, start_value: f64, end_value: f64) -> Signal {
+        let samples = self.num_samples();
+        let last = samples.saturating_sub(1).max(1) as f64;
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let fraction = i as f64 / last;
+            data.push(start_value * (1.0 - fraction) + end_value * fraction);
+        }
+
+        Signal::from_vec(data)
+    }
+
+    /// Generates a rise-then-fall envelope that peaks at `peak_time`.
+    ///
+    /// The envelope rises linearly from `0.0` at the first sample to exactly `1.0` at the sample
+    /// nearest `peak_time`, then falls linearly to `0.0` at the last sample. If the peak sample is
+    /// the first (or last) sample, there is no rising (or falling) part.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `peak_time` lies outside
+    /// `[start_time, stop_time]`.
+    ///
+    /// 生成在 `peak_time` 处达到峰值的先升后降包络。
+    ///
+    /// 包络从第一个样本处的 `0.0` 线性上升到最接近 `peak_time` 的样本处的 `1.0`，
+    /// 再线性下降到最后一个样本处的 `0.0`。若峰值样本是第一个（或最后一个）样本，则没有上升（或下降）段。
+    ///
+    /// # 错误
+    ///
+    /// `peak_time` 不在 `[start_time, stop_time]` 内时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(5.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let envelope = generator.triangle_envelope(0.2).unwrap();
+    /// assert_eq!(envelope.to_vec(), vec![0.0, 1.0, 2.0 / 3.0, 1.0 / 3.0, 0.0]);
+    /// assert!(generator.triangle_envelope(1.5).is_err());
+    /// ```
+    pub fn triangle_envelope(&self, peak_time: f64) -> Result<Signal, GeneratorError> {
+        if !(self.start_time..=self.stop_time).contains(&peak_time) {
+            return Err(GeneratorError::InvalidParameter {
+                name: "peak_time",
+                value: peak_time,
+            });
+        }
+        let samples = self.num_samples();
+        let last = samples.saturating_sub(1);
+        let peak = (((peak_time - self.start_time) * self.sample_rate).round() as usize).min(last);
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            if i < peak {
+                data.push(i as f64 / peak as f64);
+            } else if i == peak {
+                data.push(1.0);
+            } else {
+                data.push((last - i) as f64 / (last - peak) as f64);
+            }
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates a band-limited square wave by additive synthesis.
+    ///
+    /// The output is the Fourier series of `square_unit` truncated below the Nyquist frequency,
+    /// `(4/π)·Σ sin(2π·k·f·τ + k·phase) / k` over odd `k` with `k·f < sample_rate / 2`, where `τ`
+    /// is the time since `start_time`. It therefore contains no aliased components, unlike the
+    /// naive `square_unit`, at the cost of Gibbs ringing near the edges. A `freq` at or above the
+    /// Nyquist frequency yields an all-zero signal.
+    ///
+    /// 通过加性合成生成带限方波。
+    ///
+    /// 输出为 `square_unit` 在奈奎斯特频率以下截断的傅里叶级数 `(4/π)·Σ sin(2π·k·f·τ + k·phase) / k`，
+    /// 求和遍历满足 `k·f < sample_rate / 2` 的奇数 `k`，`τ` 为相对 `start_time` 的时间。
+    /// 因此与朴素的 `square_unit` 不同，它不含任何混叠分量，代价是跳变附近的吉布斯振荡。
+    /// `freq` 不低于奈奎斯特频率时生成全零信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let square = generator.square_bl(1000.0, 0.0);
+    /// ```
+    pub fn square_bl(&self, freq: f64, phase: f64) -> Signal {
+        self.additive(freq, phase, |k| match k % 2 {
+            1 => (4.0 / (PI * k as f64), 0.0),
+            _ => (0.0, 0.0),
+        })
+    }
+
+    /// Generates a band-limited sawtooth wave by additive synthesis.
+    ///
+    /// The output is the Fourier series of `sawtooth_unit` (rising from -1 to 1) truncated below
+    /// the Nyquist frequency, `-(2/π)·Σ sin(2π·k·f·τ + k·phase) / k` over `k·f < sample_rate / 2`.
+    /// A `freq` at or above the Nyquist frequency yields an all-zero signal.
+    ///
+    /// 通过加性合成生成带限锯齿波。
+    ///
+    /// 输出为 `sawtooth_unit`（从 -1 上升到 1）在奈奎斯特频率以下截断的傅里叶级数
+    /// `-(2/π)·Σ sin(2π·k·f·τ + k·phase) / k`，求和遍历满足 `k·f < sample_rate / 2` 的 `k`。
+    /// `freq` 不低于奈奎斯特频率时生成全零信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let sawtooth = generator.sawtooth_bl(1000.0, 0.0);
+    /// ```
+    pub fn sawtooth_bl(&self, freq: f64, phase: f64) -> Signal {
+        self.additive(freq, phase, |k| (-2.0 / (PI * k as f64), 0.0))
+    }
+
+    /// Generates a band-limited triangle wave by additive synthesis.
+    ///
+    /// The output is the Fourier series of `triangle_unit` truncated below the Nyquist frequency,
+    /// `-(8/π²)·Σ cos(2π·k·f·τ + k·phase) / k²` over odd `k` with `k·f < sample_rate / 2`.
+    /// A `freq` at or above the Nyquist frequency yields an all-zero signal.
+    ///
+    /// 通过加性合成生成带限三角波。
+    ///
+    /// 输出为 `triangle_unit` 在奈奎斯特频率以下截断的傅里叶级数 `-(8/π²)·Σ cos(2π·k·f·τ + k·phase) / k²`，
+    /// 求和遍历满足 `k·f < sample_rate / 2` 的奇数 `k`。`freq` 不低于奈奎斯特频率时生成全零信号。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(44100.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let triangle = generator.triangle_bl(1000.0, 0.0);
+    /// ```
+    pub fn triangle_bl(&self, freq: f64, phase: f64) -> Signal {
+        self.additive(freq, phase, |k| match k % 2 {
+            1 => (0.0, -8.0 / (PI * k as f64).powi(2)),
+            _ => (0.0, 0.0),
+        })
+    }
+
+    /// Generates a tone burst: exactly `n_cycles` cycles of a sine starting at `start_at`.
+    ///
+    /// Samples with `start_at <= t < start_at + n_cycles / freq` hold
+    /// `w(τ / T)·sin(2π·freq·τ)`, where `τ = t - start_at`, `T = n_cycles / freq` is the burst
+    /// length and `w` is the chosen window evaluated over the burst. All other samples are exactly
+    /// zero, including when the burst lies partly or wholly outside the generated range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `freq` is not positive or `start_at` is not
+    /// finite.
+    ///
+    /// 生成猝发音：从 `start_at` 开始、恰好 `n_cycles` 个周期的正弦信号。
+    ///
+    /// 满足 `start_at <= t < start_at + n_cycles / freq` 的样本取值为 `w(τ / T)·sin(2π·freq·τ)`，
+    /// 其中 `τ = t - start_at`，`T = n_cycles / freq` 为猝发长度，`w` 为在猝发区间上求值的所选窗函数。
+    /// 其余样本恰好为零，猝发部分或全部落在生成范围之外时同样如此。
+    ///
+    /// # 错误
+    ///
+    /// `freq` 不为正数或 `start_at` 不是有限值时返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// use dsp4rust::window::WindowKind;
+    /// let generator = Generator::new().sample_rate(1e6).start_time(0.0).stop_time(1e-3).build().unwrap();
+    /// let burst = generator.tone_burst(40e3, 5, 1e-4, WindowKind::Hann).unwrap();
+    /// assert_eq!(burst[50], 0.0);
+    /// ```
+    pub fn tone_burst(
+        &self,
+        freq: f64,
+        n_cycles: usize,
+        start_at: f64,
+        window: WindowKind,
+    ) -> Result<Signal, GeneratorError> {
+        if freq.is_nan() || freq <= 0.0 {
+            return Err(GeneratorError::InvalidParameter {
+                name: "freq",
+                value: freq,
+            });
+        }
+        if !start_at.is_finite() {
+            return Err(GeneratorError::InvalidParameter {
+                name: "start_at",
+                value: start_at,
+            });
+        }
+        let samples = self.num_samples();
+        let burst_length = n_cycles as f64 / freq;
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let tau = self.start_time + i as f64 / self.sample_rate - start_at;
+            if (0.0..burst_length).contains(&tau) {
+                data.push(window.value_at(tau / burst_length) * (TAU * freq * tau).sin());
+            } else {
+                data.push(0.0);
+            }
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+
+    /// Generates a unipolar pulse train with an absolute pulse width.
+    ///
+    /// Pulse `k` starts at `start_time + k / rep_rate_hz` and lasts `pulse_width_s` seconds; the
+    /// signal is `amplitude` inside a pulse and `0.0` elsewhere. Both edges are rounded to the
+    /// nearest sample, so every complete pulse spans exactly `round(pulse_width_s·sample_rate)`
+    /// samples. A pulse cut off by the end of the signal is truncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::InvalidParameter` if `rep_rate_hz` or `pulse_width_s` is not
+    /// positive, or if `pulse_width_s` is not shorter than the period `1 / rep_rate_hz`.
+    ///
+    /// 生成具有绝对脉宽的单极性脉冲串。
+    ///
+    /// 第 `k` 个脉冲从 `start_time + k / rep_rate_hz` 开始，持续 `pulse_width_s` 秒；脉冲内取值为
+    /// `amplitude`，其余为 `0.0`。两个边沿都取整到最近的样本，因此每个完整脉冲恰好占
+    /// `round(pulse_width_s·sample_rate)` 个样本。被信号末尾截断的脉冲只保留其前一部分。
+    ///
+    /// # 错误
+    ///
+    /// `rep_rate_hz` 或 `pulse_width_s` 不为正数，或 `pulse_width_s` 不短于周期 `1 / rep_rate_hz` 时，
+    /// 返回 `GeneratorError::InvalidParameter`。
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(1000.0).start_time(0.0).stop_time(1.0).build().unwrap();
+    /// let train = generator.pulse_train(10.0, 0.002, 5.0).unwrap();
+    /// assert_eq!(train.iter().filter(|&&x| x == 5.0).count(), 20);
+    /// assert!(generator.pulse_train(10.0, 0.2, 5.0).is_err());
+    /// ```
+    pub fn pulse_train(
+        &self,
+        rep_rate_hz: f64,
+        pulse_width_s: f64,
+        amplitude: f64,
+    ) -> Result<Signal, GeneratorError> {
+        for (name, value) in [
+            ("rep_rate_hz", rep_rate_hz),
+            ("pulse_width_s", pulse_width_s),
+        ] {
+            if value.is_nan() || value <= 0.0 {
+                return Err(GeneratorError::InvalidParameter { name, value });
+            }
+        }
+        if pulse_width_s >= rep_rate_hz.recip() {
+            return Err(GeneratorError::InvalidParameter {
+                name: "pulse_width_s",
+                value: pulse_width_s,
+            });
+        }
+        let samples = self.num_samples();
+        let width = (pulse_width_s * self.sample_rate).round() as usize;
+        let mut data = vec![0.0; samples];
+
+        // 逐个脉冲填充，最后一个不完整的周期按信号末尾截断；起点取整后可能多出一个脉冲，循环内再检查
+        let count = (samples as f64 * rep_rate_hz / self.sample_rate).ceil() as usize + 1;
+        for k in 0..count {
+            let start = (k as f64 * self.sample_rate / rep_rate_hz).round() as usize;
+            if start >= samples {
+                break;
+            }
+            let end = (start + width).min(samples);
+            data[start..end].fill(amplitude);
+        }
+
+        Ok(Signal::from_vec(data))
+    }
+}
+
+// 私有辅助方法
+impl Generator {
+    // 噪声方法使用的随机数生成器：有种子时可复现，否则使用系统熵源
+    fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    // 调角信号 cos(2π·fc·t + β·sin(2π·fm·t))，供调频与调相共用
+    fn angle_modulated(&self, carrier_freq: f64, mod_freq: f64, beta: f64) -> Signal {
+        let samples = self.num_samples();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let t = self.start_time + i as f64 / self.sample_rate;
+            let phase = TAU * carrier_freq * t + beta * (TAU * mod_freq * t).sin();
+            data.push(phase.cos());
+        }
+
+        Signal::from_vec(data)
+    }
+
+    // 加性合成：对所有低于奈奎斯特频率的谐波 k 求和 a_k·sin(kθ) + b_k·cos(kθ)，
+    // 其中 (a_k, b_k) = coeffs(k)，kθ 的正余弦由角度加法递推得到
+    fn additive<F>(&self, freq: f64, phase: f64, coeffs: F) -> Signal
+    where
+        F: Fn(usize) -> (f64, f64),
+    {
+        let samples = self.num_samples();
+        let nyquist = self.sample_rate / 2.0;
+        let coeffs: Vec<(f64, f64)> = (1..)
+            .take_while(|&k| freq > 0.0 && k as f64 * freq < nyquist)
+            .map(coeffs)
+            .collect();
+        let mut data = Vec::with_capacity(samples);
+
+        for i in 0..samples {
+            let cycles = (freq * i as f64 / self.sample_rate).rem_euclid(1.0);
+            let theta = TAU * cycles + phase;
+            let (sin1, cos1) = theta.sin_cos();
+            let (mut sin_k, mut cos_k) = (sin1, cos1);
+            let mut value = 0.0;
+            for &(a, b) in &coeffs {
+                value += a * sin_k + b * cos_k;
+                (sin_k, cos_k) = (sin_k * cos1 + cos_k * sin1, cos_k * cos1 - sin_k * sin1);
+            }
+            data.push(value);
+        }
+
+        Signal::from_vec(data)
+    }
+}
+
+// 3 至 20 阶本原多项式对应的 Galois LFSR 反馈掩码（第 k 个抽头对应第 k - 1 位）
+const PRBS_TAPS: [u32; 18] = [
+    0x6, 0xc, 0x14, 0x30, 0x60, 0xb8, 0x110, 0x240, 0x500, 0xe08, 0x1c80, 0x3802, 0x6000, 0xd008,
+    0x12000, 0x20400, 0x72000, 0x90000,
+];
+
+// 高斯函数 exp(-(x - center)² / (2σ²))，供高斯脉冲与高斯窗共用
+pub(crate) fn gaussian(x: f64, center: f64, sigma: f64) -> f64 {
+    (-((x - center).powi(2)) / (2.0 * sigma.powi(2))).exp()
 }
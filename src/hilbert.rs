@@ -0,0 +1,213 @@
+use crate::complex_signal::ComplexSignal;
+use crate::detect::local_maxima;
+use crate::errors::DiffError;
+use crate::inner::{fft, interp};
+use crate::resample::InterpKind;
+use crate::signal::Signal;
+use std::f64::consts::{PI, TAU};
+
+/// How `Signal::envelope` estimates the amplitude envelope.
+///
+/// `Signal::envelope` 估计幅度包络的方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnvelopeMethod {
+    /// Magnitude of the analytic signal. Exact for narrowband signals over whole periods, but it
+    /// ripples near the edges of short bursts.
+    ///
+    /// 解析信号的模。对整周期的窄带信号是精确的，但在短脉冲串边缘附近会有波纹。
+    #[default]
+    Hilbert,
+    /// Interpolation between the local maxima of `|x|`, found as `find_peaks` finds them, so a
+    /// flat top counts once at its middle sample. Beyond the outermost maxima the envelope holds
+    /// the nearest maximum.
+    ///
+    /// 在 `|x|` 的局部极大值之间插值。极大值的判定与 `find_peaks` 相同，因此平顶只在其中间样本处计一次。
+    /// 最外侧极大值以外的包络保持为最近的极大值。
+    Peaks { interp: InterpKind },
+}
+
+// 包络
+impl Signal {
+    /// Returns the amplitude envelope of the signal.
+    ///
+    /// The output has the same length as the input. With `EnvelopeMethod::Peaks`, a signal whose
+    /// `|x|` has no local maximum (e.g. a monotonic one) returns `|x|` itself. An empty signal
+    /// gives an empty result.
+    ///
+    /// 返回信号的幅度包络。
+    ///
+    /// 输出与输入等长。使用 `EnvelopeMethod::Peaks` 时，若 `|x|` 没有局部极大值（例如单调信号），
+    /// 则直接返回 `|x|`。空信号得到空结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::hilbert::EnvelopeMethod;
+    /// use dsp4rust::signal::Signal;
+    /// use std::f64::consts::TAU;
+    /// let signal: Signal = (0..64).map(|i| 2.0 * (TAU * 8.0 * i as f64 / 64.0).cos()).collect();
+    /// let envelope = signal.envelope(EnvelopeMethod::Hilbert);
+    /// assert!(envelope.iter().all(|a| (a - 2.0).abs() < 1e-9));
+    /// ```
+    pub fn envelope(&self, method: EnvelopeMethod) -> Signal {
+        match method {
+            EnvelopeMethod::Hilbert => self.analytic().abs(),
+            EnvelopeMethod::Peaks { interp } => {
+                let magnitude: Vec<f64> = self.iter().map(|x| x.abs()).collect();
+                let knots = local_maxima(&magnitude);
+                if knots.is_empty() {
+                    return Signal::from_vec(magnitude);
+                }
+                interp_knots(&knots, &magnitude, interp)
+            }
+        }
+    }
+
+    // 通过 FFT 构造解析信号：保留直流与奈奎斯特分量，正频率加倍，负频率置零
+    pub(crate) fn analytic(&self) -> ComplexSignal {
+        let len = self.len();
+        let mut spectrum = fft::fft_real(&self.to_vec());
+        for (k, bin) in spectrum.iter_mut().enumerate() {
+            if k == 0 || 2 * k == len {
+                continue;
+            }
+            *bin *= if 2 * k < len { 2.0 } else { 0.0 };
+        }
+        ComplexSignal::from_vec(fft::ifft(&spectrum))
+    }
+}
+
+// 以 knots 处的 values 为节点，在每个样本位置插值，节点范围以外保持端点值
+fn interp_knots(knots: &[usize], values: &[f64], interp: InterpKind) -> Signal {
+    let y: Vec<f64> = knots.iter().map(|&k| values[k]).collect();
+    let last = knots.len() - 1;
+    // 非均匀节点上的有限差分切线（每个样本的斜率）
+    let slope = |a: usize, b: usize| (y[b] - y[a]) / (knots[b] - knots[a]) as f64;
+    let tangent = |j: usize| match j {
+        _ if last == 0 => 0.0,
+        0 => slope(0, 1),
+        j if j == last => slope(last - 1, last),
+        j => slope(j - 1, j + 1),
+    };
+
+    let mut segment = 0;
+    (0..values.len())
+        .map(|i| {
+            if i <= knots[0] {
+                return y[0];
+            }
+            if i >= knots[last] {
+                return y[last];
+            }
+            while knots[segment + 1] < i {
+                segment += 1;
+            }
+            let (k0, k1) = (knots[segment], knots[segment + 1]);
+            let h = (k1 - k0) as f64;
+            let t = (i - k0) as f64 / h;
+            match interp {
+                InterpKind::Linear => y[segment] + t * (y[segment + 1] - y[segment]),
+                InterpKind::CubicHermite => interp::cubic_hermite(
+                    y[segment],
+                    y[segment + 1],
+                    tangent(segment) * h,
+                    tangent(segment + 1) * h,
+                    t,
+                ),
+            }
+        })
+        .collect()
+}
+
+// 瞬时相位与瞬时频率
+impl Signal {
+    /// Returns the instantaneous phase, the angle of the analytic signal, in `(-π, π]`.
+    ///
+    /// Use `unwrap_phase` to remove the 2π jumps.
+    ///
+    /// 返回瞬时相位，即解析信号的辐角，取值在 `(-π, π]` 内。
+    ///
+    /// 可使用 `unwrap_phase` 去除 2π 跳变。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use std::f64::consts::{FRAC_PI_2, TAU};
+    /// let signal: Signal = (0..16).map(|i| (TAU * 4.0 * i as f64 / 16.0).cos()).collect();
+    /// assert!((signal.instantaneous_phase()[1] - FRAC_PI_2).abs() < 1e-9);
+    /// ```
+    pub fn instantaneous_phase(&self) -> Signal {
+        self.analytic().arg()
+    }
+
+    /// Removes the jumps from a wrapped phase by adding multiples of 2π.
+    ///
+    /// Whenever two consecutive samples differ by more than `discont`, the later samples are
+    /// shifted by the multiple of 2π that brings the step back into `[-π, π]`. A `discont` below
+    /// π has the same effect as π. The first sample is unchanged. This works on any phase-like
+    /// signal, e.g. the phase of a frequency response, not only on `instantaneous_phase`.
+    ///
+    /// 通过加上 2π 的整数倍去除卷绕相位中的跳变。
+    ///
+    /// 每当相邻两个样本之差超过 `discont` 时，将其后的样本平移 2π 的整数倍，使该步长回到 `[-π, π]` 内。
+    /// `discont` 小于 π 时与 π 效果相同。第一个样本保持不变。本方法适用于任何相位类信号，
+    /// 例如频率响应的相位，而不仅是 `instantaneous_phase` 的结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use std::f64::consts::PI;
+    /// let wrapped = Signal::from_vec(vec![2.5, 3.0, -3.0, -2.5]);
+    /// let unwrapped = wrapped.unwrap_phase(PI);
+    /// assert!((unwrapped[2] - (2.0 * PI - 3.0)).abs() < 1e-12);
+    /// ```
+    pub fn unwrap_phase(&self, discont: f64) -> Signal {
+        let discont = discont.max(PI);
+        let mut correction = 0.0;
+        let mut previous = None;
+        self.iter()
+            .map(|&phase| {
+                if let Some(p) = previous {
+                    let step: f64 = phase - p;
+                    let mut wrapped = (step + PI).rem_euclid(TAU) - PI;
+                    // 恰好相差 π 时保留原方向
+                    if wrapped == -PI && step > 0.0 {
+                        wrapped = PI;
+                    }
+                    if step.abs() >= discont {
+                        correction += wrapped - step;
+                    }
+                }
+                previous = Some(phase);
+                phase + correction
+            })
+            .collect()
+    }
+
+    /// Returns the instantaneous frequency in Hz, the derivative of the unwrapped instantaneous
+    /// phase scaled by `sample_rate / 2π`.
+    ///
+    /// The derivative uses `gradient`, so the output has the same length as the input.
+    ///
+    /// # Errors
+    /// Returns `DiffError::ShortLength` if the signal has fewer than 2 samples.
+    ///
+    /// 返回以 Hz 为单位的瞬时频率，即解卷绕瞬时相位的导数乘以 `sample_rate / 2π`。
+    ///
+    /// 导数由 `gradient` 计算，因此输出与输入等长。
+    ///
+    /// # 错误
+    /// 信号少于 2 个样本时返回 `DiffError::ShortLength`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use std::f64::consts::TAU;
+    /// let signal: Signal = (0..100).map(|i| (TAU * 10.0 * i as f64 / 100.0).sin()).collect();
+    /// let frequency = signal.instantaneous_frequency(100.0).unwrap();
+    /// assert!((frequency[50] - 10.0).abs() < 1e-6);
+    /// ```
+    pub fn instantaneous_frequency(&self, sample_rate: f64) -> Result<Signal, DiffError> {
+        let phase = self.instantaneous_phase().unwrap_phase(PI);
+        Ok(&phase.gradient(1.0)? * (sample_rate / TAU))
+    }
+}
@@ -1,16 +1,19 @@
 use ndarray::iter::{Iter, IterMut};
-use ndarray::{concatenate, Axis};
-use ndarray::{Array1, Ix, Ix1};
+use ndarray::{Array1, ArrayView1, ArrayViewMut1, Axis, Ix, Ix1};
 use ndarray_stats::errors::MinMaxError;
 use ndarray_stats::QuantileExt;
 use num_traits::AsPrimitive;
 use std::fmt::{Display, Formatter};
-use std::iter::repeat;
-use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Range, RangeFrom, RangeFull,
+    RangeInclusive, RangeTo, RangeToInclusive, Sub, SubAssign,
+};
 
 use super::{BaseOperationError, PadError};
+use crate::errors::{DiffError, SliceError, StatError};
 
-#[derive(Debug, Clone)]
+// PartialEq 为逐元素精确比较，长度不同或含 NaN 的信号不相等；信号之间没有有意义的全序，因此不实现 PartialOrd
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct SignalBase {
     base: Array1<f64>,
 }
@@ -32,11 +35,19 @@ where
         }
     }
 }
-// 实现 Iterator, 也会自动实现 IntoIterator
-impl Iterator for SignalBase {
+// 按值迭代消耗信号，按引用迭代借用样本
+impl IntoIterator for SignalBase {
     type Item = f64;
-    fn next(&mut self) -> Option<Self::Item> {
-        self.base.iter().next().copied()
+    type IntoIter = ndarray::iter::IntoIter<f64, Ix1>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.base.into_iter()
+    }
+}
+impl<'a> IntoIterator for &'a SignalBase {
+    type Item = &'a f64;
+    type IntoIter = Iter<'a, f64, Ix1>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.base.iter()
     }
 }
 // 实现滑动窗口
@@ -47,39 +58,221 @@ impl SignalBase {
             .into_iter()
             .map(|window| SignalBase::from_array1(window.to_owned()))
     }
+
+    // 借用视图版本，不为每个窗口分配内存
+    pub fn windows_view(&self, window_size: usize) -> impl Iterator<Item = ArrayView1<'_, f64>> {
+        self.base.windows(window_size).into_iter()
+    }
+
+    // 窗口起点为 0, hop, 2·hop, …，不足一个窗口的尾部被跳过
+    pub(crate) fn windows_view_with_hop(
+        &self,
+        window_size: usize,
+        hop: usize,
+    ) -> impl Iterator<Item = ArrayView1<'_, f64>> {
+        assert!(window_size > 0, "window_size must be positive");
+        assert!(hop > 0, "hop must be positive");
+        self.base.windows_with_stride(window_size, hop).into_iter()
+    }
+
+    pub fn windows_with_hop(
+        &self,
+        window_size: usize,
+        hop: usize,
+    ) -> impl Iterator<Item = SignalBase> + '_ {
+        self.windows_view_with_hop(window_size, hop)
+            .map(|window| SignalBase::from_array1(window.to_owned()))
+    }
+}
+
+/// How `chunks` treats a final chunk shorter than the chunk length.
+///
+/// `chunks` 处理长度不足的最后一块的方式。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChunkTail {
+    /// Yield the short chunk as is.
+    ///
+    /// 原样产出较短的最后一块。
+    Keep,
+    /// Drop the short chunk.
+    ///
+    /// 丢弃较短的最后一块。
+    Drop,
+    /// Pad the short chunk to full length with the given value.
+    ///
+    /// 用给定值将较短的最后一块填充到完整长度。
+    Pad(f64),
+}
+
+// 拆分与分块
+impl SignalBase {
+    // 支持负索引，idx 的有效范围为 [-len, len]
+    pub fn split_at(&self, idx: isize) -> Result<(Self, Self), SliceError> {
+        let pos = self.boundary_pos(idx)?;
+        let (left, right) = self.base.view().split_at(Axis(0), pos);
+        Ok((
+            SignalBase::from_array1(left.to_owned()),
+            SignalBase::from_array1(right.to_owned()),
+        ))
+    }
+
+    // 不重叠分块，末尾不足 chunk_len 的部分按 tail 处理
+    pub fn chunks(
+        &self,
+        chunk_len: usize,
+        tail: ChunkTail,
+    ) -> impl Iterator<Item = SignalBase> + '_ {
+        assert!(chunk_len > 0, "chunk_len must be positive");
+        self.base
+            .axis_chunks_iter(Axis(0), chunk_len)
+            .filter_map(move |chunk| {
+                let missing = chunk_len - chunk.len();
+                match tail {
+                    _ if missing == 0 => Some(SignalBase::from_array1(chunk.to_owned())),
+                    ChunkTail::Keep => Some(SignalBase::from_array1(chunk.to_owned())),
+                    ChunkTail::Drop => None,
+                    ChunkTail::Pad(value) => Some(SignalBase::from_iter(
+                        chunk
+                            .iter()
+                            .copied()
+                            .chain(std::iter::repeat_n(value, missing)),
+                    )),
+                }
+            })
+    }
+}
+/// Integer types that can index a single sample of a signal.
+///
+/// Signed indices count from the end when negative, so `-1` is the last sample; `usize` indices
+/// are always from the start. Implemented for `isize`, `i32` (the type of unsuffixed integer
+/// literals) and `usize`. A single generic `Index` impl over this trait keeps the output type
+/// `f64` known while a literal's type is still being inferred, so `signal[-1] - x` compiles.
+///
+/// 可以索引信号单个样本的整数类型。
+///
+/// 有符号索引为负时从末尾计数，`-1` 即最后一个样本；`usize` 索引总是从开头计数。为 `isize`、`i32`
+/// （无后缀整数字面量的类型）与 `usize` 实现。基于该 trait 的单个泛型 `Index` 实现使得在推断字面量类型时
+/// 输出类型 `f64` 已经确定，因此 `signal[-1] - x` 可以通过编译。
+pub trait SignalIndex: Copy + Display {
+    /// Returns the position from the start for a signal of length `len`, or `None` if the
+    /// index is out of bounds.
+    ///
+    /// 返回在长度为 `len` 的信号中从开头计数的位置，索引越界时返回 `None`。
+    fn position(self, len: usize) -> Option<usize>;
+}
+// 将负索引换算为从开头计数的位置，结果仍可能越界
+fn offset_from_start(index: isize, len: usize) -> isize {
+    if index < 0 {
+        len as isize + index
+    } else {
+        index
+    }
+}
+impl SignalIndex for isize {
+    fn position(self, len: usize) -> Option<usize> {
+        let idx = offset_from_start(self, len);
+        (0..len as isize).contains(&idx).then_some(idx as usize)
+    }
+}
+impl SignalIndex for i32 {
+    fn position(self, len: usize) -> Option<usize> {
+        (self as isize).position(len)
+    }
+}
+impl SignalIndex for usize {
+    fn position(self, len: usize) -> Option<usize> {
+        Some(self).filter(|&idx| idx < len)
+    }
 }
 // 实现方括号索引
-impl Index<isize> for SignalBase {
+impl SignalBase {
+    fn checked_position<I: SignalIndex>(&self, index: I) -> usize {
+        index.position(self.len()).unwrap_or_else(|| {
+            panic!(
+                "index {index} is out of bounds for a signal of length {}",
+                self.len()
+            )
+        })
+    }
+
+    // from_array1 保证数据总是以连续的标准布局存储
+    fn contiguous(&self) -> &[f64] {
+        self.as_slice().expect("signal data is stored contiguously")
+    }
+}
+impl<I: SignalIndex> Index<I> for SignalBase {
     type Output = f64;
-    fn index(&self, index: isize) -> &Self::Output {
-        if !self.is_idx_valid(index) {
-            panic!("Index out of bound");
-        }
-        let idx = if index < 0 {
-            (self.len() as isize + index) as usize
-        } else {
-            index as usize
-        };
-        &self.base[idx]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.base[self.checked_position(index)]
     }
 }
 // 可变索引操作
-impl IndexMut<isize> for SignalBase {
-    fn index_mut(&mut self, index: isize) -> &mut Self::Output {
-        if !self.is_idx_valid(index) {
-            panic!("Index out of bound");
-        }
-        let idx = if index < 0 {
-            (self.len() as isize + index) as usize
-        } else {
-            index as usize
-        };
+impl<I: SignalIndex> IndexMut<I> for SignalBase {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let idx = self.checked_position(index);
         &mut self.base[idx]
     }
 }
+// 不会恐慌的元素访问，越界时返回 None
+impl SignalBase {
+    pub fn get<I: SignalIndex>(&self, index: I) -> Option<f64> {
+        index.position(self.len()).map(|idx| self.base[idx])
+    }
+
+    pub fn get_mut<I: SignalIndex>(&mut self, index: I) -> Option<&mut f64> {
+        index.position(self.len()).map(|idx| &mut self.base[idx])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_empty()
+    }
+}
+// 区间索引返回切片，越界时沿用切片的恐慌信息
+impl Index<Range<usize>> for SignalBase {
+    type Output = [f64];
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        &self.contiguous()[range]
+    }
+}
+impl Index<RangeTo<usize>> for SignalBase {
+    type Output = [f64];
+    fn index(&self, range: RangeTo<usize>) -> &Self::Output {
+        &self.contiguous()[range]
+    }
+}
+impl Index<RangeFrom<usize>> for SignalBase {
+    type Output = [f64];
+    fn index(&self, range: RangeFrom<usize>) -> &Self::Output {
+        &self.contiguous()[range]
+    }
+}
+impl Index<RangeFull> for SignalBase {
+    type Output = [f64];
+    fn index(&self, _: RangeFull) -> &Self::Output {
+        self.contiguous()
+    }
+}
+impl Index<RangeInclusive<usize>> for SignalBase {
+    type Output = [f64];
+    fn index(&self, range: RangeInclusive<usize>) -> &Self::Output {
+        &self.contiguous()[range]
+    }
+}
+impl Index<RangeToInclusive<usize>> for SignalBase {
+    type Output = [f64];
+    fn index(&self, range: RangeToInclusive<usize>) -> &Self::Output {
+        &self.contiguous()[range]
+    }
+}
 // 构造函数
 impl SignalBase {
-    fn from_array1(base: Array1<f64>) -> Self {
+    pub fn from_array1(base: Array1<f64>) -> Self {
+        // 非标准布局（如反向切片）复制为连续存储，其余情况直接接管
+        let base = if base.is_standard_layout() {
+            base
+        } else {
+            base.as_standard_layout().into_owned()
+        };
         SignalBase { base }
     }
     pub fn from_vec(signal: Vec<f64>) -> Self {
@@ -147,6 +340,205 @@ impl SignalBase {
         }
     }
 }
+
+// 逐元素运算
+impl SignalBase {
+    // 限幅，None 表示该侧不限；NaN 样本原样保留
+    pub fn clip_inplace(&mut self, min: Option<f64>, max: Option<f64>) {
+        let lo = min.unwrap_or(f64::NEG_INFINITY);
+        let hi = max.unwrap_or(f64::INFINITY);
+        assert!(
+            lo <= hi,
+            "clip bounds must satisfy min <= max and not be NaN, got {lo} and {hi}"
+        );
+        self.base.map_inplace(|x| {
+            if *x < lo {
+                *x = lo;
+            } else if *x > hi {
+                *x = hi;
+            }
+        });
+    }
+
+    pub fn clip(&self, min: Option<f64>, max: Option<f64>) -> Self {
+        let mut clipped = self.clone();
+        clipped.clip_inplace(min, max);
+        clipped
+    }
+
+    pub fn abs(&self) -> Self {
+        self.map(|x| x.abs())
+    }
+
+    pub fn abs_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        self.map(|x| x.signum())
+    }
+
+    pub fn signum_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.signum())
+    }
+
+    pub fn floor(&self) -> Self {
+        self.map(|x| x.floor())
+    }
+
+    pub fn floor_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.floor())
+    }
+
+    pub fn ceil(&self) -> Self {
+        self.map(|x| x.ceil())
+    }
+
+    pub fn ceil_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.ceil())
+    }
+
+    pub fn round(&self) -> Self {
+        self.map(|x| x.round())
+    }
+
+    pub fn round_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.round())
+    }
+
+    pub fn recip(&self) -> Self {
+        self.map(|x| x.recip())
+    }
+
+    pub fn recip_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.recip())
+    }
+
+    pub fn neg_inplace(&mut self) {
+        self.map_inplace(|x| *x = -*x)
+    }
+
+    pub fn powf(&self, exponent: f64) -> Self {
+        self.map(|x| x.powf(exponent))
+    }
+
+    pub fn powi(&self, exponent: i32) -> Self {
+        self.map(|x| x.powi(exponent))
+    }
+
+    pub fn rem_scalar(&self, divisor: f64) -> Self {
+        self.map(|x| x % divisor)
+    }
+
+    pub fn sin(&self) -> Self {
+        self.map(|x| x.sin())
+    }
+
+    pub fn sin_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.sin())
+    }
+
+    pub fn cos(&self) -> Self {
+        self.map(|x| x.cos())
+    }
+
+    pub fn cos_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.cos())
+    }
+
+    pub fn tan(&self) -> Self {
+        self.map(|x| x.tan())
+    }
+
+    pub fn tan_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.tan())
+    }
+
+    pub fn exp(&self) -> Self {
+        self.map(|x| x.exp())
+    }
+
+    pub fn exp_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.exp())
+    }
+
+    pub fn ln(&self) -> Self {
+        self.map(|x| x.ln())
+    }
+
+    pub fn ln_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.ln())
+    }
+
+    pub fn log10(&self) -> Self {
+        self.map(|x| x.log10())
+    }
+
+    pub fn log10_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.log10())
+    }
+
+    pub fn sqrt(&self) -> Self {
+        self.map(|x| x.sqrt())
+    }
+
+    pub fn sqrt_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.sqrt())
+    }
+
+    pub fn tanh(&self) -> Self {
+        self.map(|x| x.tanh())
+    }
+
+    pub fn tanh_inplace(&mut self) {
+        self.map_inplace(|x| *x = x.tanh())
+    }
+
+    // 条件成立处为 1.0，否则为 0.0
+    pub fn mask<F>(&self, predicate: F) -> Self
+    where
+        F: Fn(f64) -> bool,
+    {
+        self.map(|&x| if predicate(x) { 1.0 } else { 0.0 })
+    }
+
+    // 逐元素组合两个信号，调用方保证长度一致
+    pub fn zip_map<F>(&self, other: &SignalBase, f: F) -> Self
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        SignalBase {
+            base: self
+                .base
+                .iter()
+                .zip(other.base.iter())
+                .map(|(&x, &y)| f(x, y))
+                .collect(),
+        }
+    }
+
+    pub fn enumerate_map<F>(&self, f: F) -> Self
+    where
+        F: Fn(usize, f64) -> f64,
+    {
+        SignalBase {
+            base: self
+                .base
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| f(i, x))
+                .collect(),
+        }
+    }
+
+    // 原地逐元素组合，调用方保证长度一致
+    pub fn zip_map_inplace<F>(&mut self, other: &SignalBase, f: F)
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        self.base.zip_mut_with(&other.base, |x, &y| *x = f(*x, y));
+    }
+}
 // 转换成数组切片
 impl SignalBase {
     pub fn as_slice(&self) -> Option<&[f64]> {
@@ -164,7 +556,7 @@ impl SignalBase {
     }
 
     pub fn sum(&self) -> f64 {
-        self.base.iter().sum()
+        compensated_sum(self.base.iter().copied())
     }
     pub fn min(&self) -> Result<f64, MinMaxError> {
         Ok(*self.base.min()?)
@@ -183,23 +575,23 @@ impl SignalBase {
     }
 
     pub fn mean(&self) -> Option<f64> {
-        self.base.mean()
+        mean_of(self.base.view())
     }
 
     pub fn std_pop(&self) -> f64 {
-        self.base.std(0.)
+        self.var_pop().sqrt()
     }
 
     pub fn std_sample(&self) -> f64 {
-        self.base.std(1.)
+        self.var_sample().sqrt()
     }
 
     pub fn var_pop(&self) -> f64 {
-        self.base.var(0.)
+        variance_of(self.base.view(), 0)
     }
 
     pub fn var_sample(&self) -> f64 {
-        self.base.var(1.)
+        variance_of(self.base.view(), 1)
     }
 
     pub fn range(&self) -> Result<(f64, f64), MinMaxError> {
@@ -212,68 +604,229 @@ impl SignalBase {
         Ok(self.max()? - self.min()?)
     }
 
+    pub fn sum_of_squares(&self) -> f64 {
+        sum_of_squares(self.base.view())
+    }
+
     pub fn energy(&self) -> f64 {
-        self.base.iter().map(|&x| x.powf(2.)).sum::<f64>()
+        self.sum_of_squares()
     }
 
     pub fn avg_power(&self) -> f64 {
         self.energy() / self.len() as f64
     }
-}
-// 信号截取生成新的信号
-impl SignalBase {
-    fn is_idx_valid(&self, idx: isize) -> bool {
-        let len = self.len();
-        idx >= -(len as isize) && idx < len as isize
+
+    // 空信号返回 None，而不是 0/0 得到的 NaN
+    pub fn rms(&self) -> Option<f64> {
+        match self.len() {
+            0 => None,
+            len => Some((self.sum_of_squares() / len as f64).sqrt()),
+        }
     }
-    pub fn cut_from(&self, from: isize) -> SignalBase {
-        if !self.is_idx_valid(from) {
-            panic!("Index out of bounds");
+
+    // 忽略 NaN 后排序的副本，不修改原信号
+    fn sorted_valid(&self) -> Result<Vec<f64>, StatError> {
+        let sorted = sorted_non_nan(self.base.iter());
+        if sorted.is_empty() {
+            return Err(StatError::EmptyInput);
         }
-        let n_skip = self.pos_idx(from);
-        let iter = self.iter().skip(n_skip).cloned();
-        SignalBase {
-            base: Array1::from_iter(iter),
+        Ok(sorted)
+    }
+
+    pub fn median(&self) -> Result<f64, StatError> {
+        self.quantile(0.5)
+    }
+
+    pub fn quantile(&self, q: f64) -> Result<f64, StatError> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(StatError::InvalidQuantile(q));
         }
+        Ok(quantile_of_sorted(&self.sorted_valid()?, q))
     }
 
-    pub fn cut_to(&self, to: isize) -> SignalBase {
-        if !self.is_idx_valid(to) {
-            panic!("Index out of bounds");
+    pub fn percentiles(&self, percents: &[f64]) -> Result<Vec<f64>, StatError> {
+        if let Some(&p) = percents.iter().find(|p| !(0.0..=100.0).contains(*p)) {
+            return Err(StatError::InvalidPercentile(p));
         }
-        let n_skip = self.len() - self.pos_idx(to) - 1;
-        let iter = self.iter().rev().skip(n_skip).rev().cloned();
-        SignalBase {
-            base: Array1::from_iter(iter),
+        let sorted = self.sorted_valid()?;
+        Ok(percents
+            .iter()
+            .map(|p| quantile_of_sorted(&sorted, p / 100.0))
+            .collect())
+    }
+}
+
+// 以下统计量由信号与视图共用，保证两者的结果逐位一致
+
+// Neumaier 补偿累加器：误差与长度无关，大直流偏置上的小信号也不会丢失有效数字
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct CompensatedSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl CompensatedSum {
+    pub(crate) fn add(&mut self, x: f64) {
+        let t = self.sum + x;
+        // 加数中较小者的低位在 t 中丢失，累积到补偿项
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
         }
+        self.sum = t;
     }
 
-    fn pos_idx(&self, index: isize) -> usize {
-        if index < 0 {
-            self.len() + index as usize
+    pub(crate) fn value(self) -> f64 {
+        // 出现无穷时补偿项为 NaN，保留朴素求和的结果
+        if self.sum.is_finite() {
+            self.sum + self.compensation
         } else {
-            index as usize
+            self.sum
         }
     }
-    pub fn cut_from_to(&self, from: isize, to: isize) -> SignalBase {
-        if !self.is_idx_valid(from) || !self.is_idx_valid(to) {
-            panic!("Index out of bounds");
+}
+
+pub(crate) fn compensated_sum(values: impl Iterator<Item = f64>) -> f64 {
+    let mut acc = CompensatedSum::default();
+    values.for_each(|x| acc.add(x));
+    acc.value()
+}
+
+pub(crate) fn mean_of(values: ArrayView1<'_, f64>) -> Option<f64> {
+    match values.len() {
+        0 => None,
+        len => Some(compensated_sum(values.iter().copied()) / len as f64),
+    }
+}
+
+// 修正的两遍算法：第二遍的偏差之和抵消均值的舍入误差
+pub(crate) fn variance_of(values: ArrayView1<'_, f64>, ddof: usize) -> f64 {
+    let len = values.len();
+    assert!(
+        ddof <= len,
+        "ddof {ddof} must not be greater than the signal length {len}"
+    );
+    let mean = compensated_sum(values.iter().copied()) / len as f64;
+    let (mut deviations, mut squares) = (CompensatedSum::default(), CompensatedSum::default());
+    for &x in values {
+        let d = x - mean;
+        deviations.add(d);
+        squares.add(d * d);
+    }
+    let deviations = deviations.value();
+    (squares.value() - deviations * deviations / len as f64) / (len - ddof) as f64
+}
+
+// 平方用乘法而不是 powf(2.)，后者在多数平台上是慢得多的 libm 调用
+pub(crate) fn sum_of_squares(values: ArrayView1<'_, f64>) -> f64 {
+    compensated_sum(values.iter().map(|&x| x * x))
+}
+
+// 忽略 NaN 后升序排列
+fn sorted_non_nan<'a>(values: impl IntoIterator<Item = &'a f64>) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values
+        .into_iter()
+        .copied()
+        .filter(|x| !x.is_nan())
+        .collect();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+// 在相邻顺序统计量之间线性插值，q 位于 [0, 1]，sorted 非空
+fn quantile_of_sorted(sorted: &[f64], q: f64) -> f64 {
+    let h = (sorted.len() - 1) as f64 * q;
+    let lo = h.floor() as usize;
+    let hi = (lo + 1).min(sorted.len() - 1);
+    let (below, above) = (sorted[lo], sorted[hi]);
+    // 恰好落在样本上或两侧相等时直接返回，避免无穷大样本相减得到 NaN
+    if h == lo as f64 || below == above {
+        return below;
+    }
+    below + (h - lo as f64) * (above - below)
+}
+// 信号截取生成新的信号
+impl SignalBase {
+    // 样本索引，有效范围为 [-len, len)
+    fn sample_pos(&self, idx: isize) -> Result<usize, SliceError> {
+        idx.position(self.len())
+            .ok_or(SliceError::IndexOutOfBounds {
+                index: idx,
+                len: self.len(),
+            })
+    }
+
+    // 样本之间的边界索引，有效范围为 [-len, len]
+    fn boundary_pos(&self, idx: isize) -> Result<usize, SliceError> {
+        let len = self.len();
+        let pos = offset_from_start(idx, len);
+        if (0..=len as isize).contains(&pos) {
+            Ok(pos as usize)
+        } else {
+            Err(SliceError::IndexOutOfBounds { index: idx, len })
         }
-        if self.pos_idx(from) > self.pos_idx(to) {
-            panic!("Invalid range, the positive index of from must be less than to's");
+    }
+
+    fn copy_range(&self, start: usize, end: usize) -> SignalBase {
+        SignalBase::from_array1(self.base.slice(ndarray::s![start..end]).to_owned())
+    }
+
+    // 闭区间 [from, len)
+    pub fn try_cut_from(&self, from: isize) -> Result<SignalBase, SliceError> {
+        let start = self.sample_pos(from)?;
+        Ok(self.copy_range(start, self.len()))
+    }
+
+    // 闭区间 [0, to]
+    pub fn try_cut_to(&self, to: isize) -> Result<SignalBase, SliceError> {
+        let end = self.sample_pos(to)? + 1;
+        Ok(self.copy_range(0, end))
+    }
+
+    // 闭区间 [from, to]，from 恰为 to 的下一个样本时为空
+    pub fn try_cut_from_to(&self, from: isize, to: isize) -> Result<SignalBase, SliceError> {
+        let (start, end) = (self.sample_pos(from)?, self.sample_pos(to)? + 1);
+        if start > end {
+            return Err(SliceError::InvertedRange { from, to });
         }
-        let head_skip = self.pos_idx(from);
-        let tail_skip = self.len() - self.pos_idx(to) - 1;
-        let iter = self
-            .iter()
-            .skip(head_skip)
-            .rev()
-            .skip(tail_skip)
-            .rev()
-            .cloned();
-        SignalBase {
-            base: Array1::from_iter(iter),
+        Ok(self.copy_range(start, end))
+    }
+
+    // 半开区间 [start, end) 换算为从开头计数的位置，两端都是边界索引
+    pub fn resolve_range(&self, start: isize, end: isize) -> Result<(usize, usize), SliceError> {
+        let (from, to) = (self.boundary_pos(start)?, self.boundary_pos(end)?);
+        if from > to {
+            return Err(SliceError::InvertedRange {
+                from: start,
+                to: end,
+            });
         }
+        Ok((from, to))
+    }
+
+    pub fn try_cut_range(&self, start: isize, end: isize) -> Result<SignalBase, SliceError> {
+        let (from, to) = self.resolve_range(start, end)?;
+        Ok(self.copy_range(from, to))
+    }
+
+    pub fn cut_from(&self, from: isize) -> SignalBase {
+        self.try_cut_from(from)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn cut_to(&self, to: isize) -> SignalBase {
+        self.try_cut_to(to).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn cut_from_to(&self, from: isize, to: isize) -> SignalBase {
+        self.try_cut_from_to(from, to)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn cut_range(&self, start: isize, end: isize) -> SignalBase {
+        self.try_cut_range(start, end)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
 // tovec
@@ -282,6 +835,42 @@ impl SignalBase {
         self.base.to_vec()
     }
 }
+// 与 ndarray 互转，不复制数据
+impl SignalBase {
+    pub fn into_array1(self) -> Array1<f64> {
+        self.base
+    }
+
+    pub fn as_array(&self) -> ArrayView1<'_, f64> {
+        self.base.view()
+    }
+
+    pub fn as_array_mut(&mut self) -> ArrayViewMut1<'_, f64> {
+        self.base.view_mut()
+    }
+}
+// 在末尾追加样本，ndarray 按摊还方式扩容
+impl SignalBase {
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut base = Array1::zeros(0);
+        base.reserve(Axis(0), capacity)
+            .expect("capacity exceeds the addressable size");
+        Self::from_array1(base)
+    }
+
+    pub fn push(&mut self, sample: f64) {
+        self.base
+            .push(Axis(0), ndarray::aview0(&sample))
+            .expect("pushing along axis 0 of a 1-D array cannot fail");
+    }
+
+    pub fn extend_from_iter<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        let tail = Array1::from_iter(iter);
+        self.base
+            .append(Axis(0), tail.view())
+            .expect("appending along axis 0 of a 1-D array cannot fail");
+    }
+}
 // 实现自身的加减乘除，只保留引用传递
 impl<'a, 'b> Add<&'b SignalBase> for &'a SignalBase {
     type Output = SignalBase;
@@ -441,21 +1030,341 @@ impl SignalBase {
         ))
     }
 
+    // 先在两端补值再做 order 阶差分，结果长度为 len + 补值个数 - order
+    pub fn diff_n(
+        &self,
+        order: usize,
+        prepend: &[f64],
+        append: &[f64],
+    ) -> Result<SignalBase, DiffError> {
+        let mut values: Vec<f64> = prepend
+            .iter()
+            .chain(self.base.iter())
+            .chain(append)
+            .copied()
+            .collect();
+        if order > 0 && values.len() <= order {
+            return Err(DiffError::ShortLength);
+        }
+        for k in 0..order {
+            for i in 0..values.len() - 1 - k {
+                values[i] = values[i + 1] - values[i];
+            }
+        }
+        values.truncate(values.len() - order);
+        Ok(SignalBase::from_vec(values))
+    }
+
+    // 内部用中心差分，两端用单侧差分
+    pub fn gradient(&self, dx: f64) -> Result<SignalBase, DiffError> {
+        let len = self.len();
+        if len < 2 {
+            return Err(DiffError::ShortLength);
+        }
+        let x = &self.base;
+        let mut grad = Vec::with_capacity(len);
+        grad.push((x[1] - x[0]) / dx);
+        grad.extend(x.windows(3).into_iter().map(|w| (w[2] - w[0]) / (2.0 * dx)));
+        grad.push((x[len - 1] - x[len - 2]) / dx);
+        Ok(SignalBase::from_vec(grad))
+    }
+
     pub fn rev(&self) -> Self {
         SignalBase::from_iter(self.base.iter().rev().cloned())
     }
+
+    pub fn cumsum(&self) -> Self {
+        SignalBase::from_iter(self.base.iter().scan(0.0, |acc, &x| {
+            *acc += x;
+            Some(*acc)
+        }))
+    }
+
+    pub fn cumprod(&self) -> Self {
+        SignalBase::from_iter(self.base.iter().scan(1.0, |acc, &x| {
+            *acc *= x;
+            Some(*acc)
+        }))
+    }
+
+    // 循环移位，k > 0 时样本向后（下标增大方向）移动
+    pub fn roll(&self, k: isize) -> Self {
+        let len = self.len();
+        if len == 0 {
+            return self.clone();
+        }
+        let start = len - k.rem_euclid(len as isize) as usize;
+        SignalBase::from_iter(
+            self.base
+                .iter()
+                .cycle()
+                .skip(start % len)
+                .take(len)
+                .cloned(),
+        )
+    }
+
+    // 线性移位，空出的位置用 fill 填充
+    pub fn shift(&self, k: isize, fill: f64) -> Self {
+        let len = self.len();
+        let n = k.unsigned_abs().min(len);
+        let kept = self.base.iter().cloned();
+        if k >= 0 {
+            SignalBase::from_iter(std::iter::repeat_n(fill, n).chain(kept.take(len - n)))
+        } else {
+            SignalBase::from_iter(kept.skip(n).chain(std::iter::repeat_n(fill, n)))
+        }
+    }
 }
 
+// 拼接与重复
+impl SignalBase {
+    // 按顺序拼接各段，先求总长度并检查 isize::MAX 上限，再一次性分配
+    pub fn concat<'a, I>(parts: I) -> Result<Self, PadError>
+    where
+        I: IntoIterator<Item = &'a SignalBase>,
+        I::IntoIter: Clone,
+    {
+        let parts = parts.into_iter();
+        let total = parts
+            .clone()
+            .try_fold(0usize, |acc, part| acc.checked_add(part.len()))
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(PadError::ResultOutOfBounds)?;
+        let mut values = Vec::with_capacity(total);
+        for part in parts {
+            values.extend(part.base.iter());
+        }
+        Ok(SignalBase::from_vec(values))
+    }
+
+    // 总长度 len * n，超过 isize::MAX 时报错
+    fn check_repeated_len(&self, n: usize) -> Result<(), PadError> {
+        match self.len().checked_mul(n) {
+            Some(total) if total <= isize::MAX as usize => Ok(()),
+            _ => Err(PadError::ResultOutOfBounds),
+        }
+    }
+
+    pub fn tile(&self, n: usize) -> Result<Self, PadError> {
+        // 先检查长度，避免为超大的 n 逐份累加长度
+        self.check_repeated_len(n)?;
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        Self::concat(std::iter::repeat_n(self, n))
+    }
+
+    pub fn repeat_each(&self, n: usize) -> Result<Self, PadError> {
+        self.check_repeated_len(n)?;
+        Ok(SignalBase::from_iter(
+            self.base.iter().flat_map(|&x| std::iter::repeat_n(x, n)),
+        ))
+    }
+}
+
+/// Side(s) of a signal to pad.
+///
+/// 需要填充的信号一侧或两侧。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PadSide {
+    /// Before the first sample.
+    ///
+    /// 第一个样本之前。
     Left,
+    /// After the last sample.
+    ///
+    /// 最后一个样本之后。
     Right,
+    /// Both before and after, `pad_width` samples each.
+    ///
+    /// 前后两侧各填充 `pad_width` 个样本。
     Both,
 }
+impl PadSide {
+    // 转换为 (左侧宽度, 右侧宽度)
+    fn widths(self, pad_width: usize) -> (usize, usize) {
+        match self {
+            PadSide::Left => (pad_width, 0),
+            PadSide::Right => (0, pad_width),
+            PadSide::Both => (pad_width, pad_width),
+        }
+    }
+}
+
+/// Statistic used by `pad_stat` as the padding value.
+///
+/// Every statistic ignores NaN samples. If the samples it is computed from are all NaN, the
+/// padding value is NaN.
+///
+/// `pad_stat` 所用作填充值的统计量。
+///
+/// 所有统计量都忽略 NaN 样本。若用于计算的样本全为 NaN，则填充值为 NaN。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatPad {
+    /// Arithmetic mean.
+    ///
+    /// 算术平均值。
+    Mean,
+    /// Median; the mean of the two middle values for an even count.
+    ///
+    /// 中位数；样本数为偶数时取中间两个值的平均。
+    Median,
+    /// Minimum.
+    ///
+    /// 最小值。
+    Min,
+    /// Maximum.
+    ///
+    /// 最大值。
+    Max,
+}
+
+impl StatPad {
+    fn compute(self, values: &[f64]) -> f64 {
+        // 各统计量都只由非 NaN 样本计算
+        let valid: Vec<f64> = values.iter().copied().filter(|x| !x.is_nan()).collect();
+        if valid.is_empty() {
+            return f64::NAN;
+        }
+        match self {
+            StatPad::Mean => mean_of(ArrayView1::from(&valid)).unwrap_or(f64::NAN),
+            StatPad::Median => median_of(&valid),
+            StatPad::Min => valid.iter().copied().fold(f64::INFINITY, f64::min),
+            StatPad::Max => valid.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+// 中位数，与 SignalBase::median 相同地忽略 NaN，没有非 NaN 值时返回 NaN
+pub(crate) fn median_of(values: &[f64]) -> f64 {
+    let sorted = sorted_non_nan(values);
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    quantile_of_sorted(&sorted, 0.5)
+}
+
+/// How `pad` fills the added samples.
+///
+/// `pad` 填充新增样本的方式。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode {
+    /// A constant value. The only mode that accepts an empty signal.
+    ///
+    /// 常数值。唯一接受空信号的模式。
+    Constant(f64),
+    /// Repeat the first and last samples.
+    ///
+    /// 重复首、尾样本。
+    Edge,
+    /// Mirror about the edge samples without repeating them.
+    ///
+    /// 以端点样本为对称轴镜像，不重复端点样本。
+    Reflect,
+    /// Continue the signal periodically.
+    ///
+    /// 将信号周期延拓。
+    Wrap,
+    /// Linear ramp from the edge sample to the given end value.
+    ///
+    /// 从端点样本到给定终值的线性斜坡。
+    LinearRamp(f64),
+    /// A statistic of the signal, optionally of only the nearest `stat_len` samples per side.
+    ///
+    /// 信号的统计量，可选地只取各侧最近的 `stat_len` 个样本。
+    Stat {
+        stat: StatPad,
+        stat_len: Option<usize>,
+    },
+}
+
 impl SignalBase {
-    fn concat(&self, another: &SignalBase) -> Self {
-        SignalBase {
-            base: concatenate![Axis(0), self.base, another.base],
+    // 通用填充入口：左右两侧宽度可以不同，各专用填充函数均委托至此
+    pub fn pad(
+        &self,
+        left_width: usize,
+        right_width: usize,
+        mode: PadMode,
+    ) -> Result<Self, PadError> {
+        let values = self.to_vec();
+        let len = values.len();
+        match len
+            .checked_add(left_width)
+            .and_then(|n| n.checked_add(right_width))
+        {
+            Some(total) if total <= isize::MAX as usize => {}
+            _ => return Err(PadError::ResultOutOfBounds),
         }
+        if len == 0 {
+            return match mode {
+                PadMode::Constant(c) => Ok(Self::from_elem(c, left_width + right_width)),
+                _ => Err(PadError::ConcatenationError("Empty input".to_string())),
+            };
+        }
+        let (first, last) = (values[0], values[len - 1]);
+        // 按相对原信号的虚拟索引取值，左侧为负索引，右侧为不小于 len 的索引
+        let left_idx = |k: usize| k as isize - left_width as isize;
+        let right_idx = |k: usize| (len + k) as isize;
+
+        let (left, right): (Vec<f64>, Vec<f64>) = match mode {
+            PadMode::Constant(c) => (vec![c; left_width], vec![c; right_width]),
+            PadMode::Edge => (vec![first; left_width], vec![last; right_width]),
+            PadMode::Wrap => {
+                let wrap = |idx: isize| values[idx.rem_euclid(len as isize) as usize];
+                (
+                    (0..left_width).map(|k| wrap(left_idx(k))).collect(),
+                    (0..right_width).map(|k| wrap(right_idx(k))).collect(),
+                )
+            }
+            PadMode::Reflect => {
+                let reflect = |idx: isize| {
+                    if len == 1 {
+                        return first;
+                    }
+                    let period = 2 * (len as isize - 1);
+                    let m = idx.rem_euclid(period);
+                    values[(if m < len as isize { m } else { period - m }) as usize]
+                };
+                (
+                    (0..left_width).map(|k| reflect(left_idx(k))).collect(),
+                    (0..right_width).map(|k| reflect(right_idx(k))).collect(),
+                )
+            }
+            PadMode::LinearRamp(end_value) => {
+                // 最外侧恰为 end_value，向内线性趋近端点样本（不含端点）
+                let ramp = |edge: f64, from_outside: usize, width: usize| {
+                    let fraction = from_outside as f64 / width as f64;
+                    end_value * (1.0 - fraction) + edge * fraction
+                };
+                (
+                    (0..left_width)
+                        .map(|k| ramp(first, k, left_width))
+                        .collect(),
+                    (0..right_width)
+                        .map(|k| ramp(last, right_width - 1 - k, right_width))
+                        .collect(),
+                )
+            }
+            PadMode::Stat { stat, stat_len } => {
+                if stat_len == Some(0) {
+                    return Err(PadError::Other("stat_len must be positive".to_string()));
+                }
+                let n = stat_len.unwrap_or(len).min(len);
+                (
+                    vec![stat.compute(&values[..n]); left_width],
+                    vec![stat.compute(&values[len - n..]); right_width],
+                )
+            }
+        };
+
+        Ok(SignalBase::from_vec([left, values, right].concat()))
+    }
+
+    // 镜像填充：以端点样本为对称轴且不重复端点，宽度超过信号长度时反复折叠
+    pub fn pad_reflect(&self, pad_side: PadSide, pad_width: usize) -> Result<Self, PadError> {
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::Reflect)
     }
 
     pub fn pad_cons<T>(
@@ -467,106 +1376,42 @@ impl SignalBase {
     where
         T: AsPrimitive<f64>,
     {
-        let cons_signal = Self::from_elem(constants.as_(), pad_width);
-        match pad_side {
-            PadSide::Left => {
-                if self.len() + pad_width > isize::MAX as usize {
-                    return Err(PadError::ResultOutOfBounds);
-                }
-                Ok(self.concat(&cons_signal))
-            }
-            PadSide::Right => {
-                if self.len() + pad_width > isize::MAX as usize {
-                    return Err(PadError::ResultOutOfBounds);
-                }
-                Ok(cons_signal.concat(self))
-            }
-            PadSide::Both => {
-                if self.len() + pad_width > isize::MAX as usize {
-                    return Err(PadError::ResultOutOfBounds);
-                }
-                Ok(cons_signal.concat(self).concat(&cons_signal))
-            }
-        }
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::Constant(constants.as_()))
     }
 
-    pub fn pad_wrap(&self, pad_side: PadSide, pad_width: usize) -> Result<Self, PadError> {
-        let len = self.len();
-        if len + 2 * pad_width > isize::MAX as usize {
-            return Err(PadError::ResultOutOfBounds);
-        }
-        if len == 0 {
-            return Err(PadError::ConcatenationError("Empty input".to_string()));
-        }
-        let repeat_count = pad_width / len;
-        let remain = pad_width % len;
-        let views = repeat(self.base.view())
-            .take(repeat_count)
-            .collect::<Vec<_>>();
-        let repeated = match concatenate(Axis(0), views.as_slice()) {
-            Ok(v) => v,
-            Err(e) => {
-                unreachable!("Unexpected concatenation error for Array1: {}", e);
-            }
-        };
+    // 端点延拓填充：重复首、尾样本
+    pub fn pad_edge(&self, pad_side: PadSide, pad_width: usize) -> Result<Self, PadError> {
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::Edge)
+    }
 
-        let padded = match pad_side {
-            PadSide::Left => {
-                let left_iter = self
-                    .base
-                    .as_slice()
-                    .unwrap()
-                    .iter()
-                    .skip(len - remain)
-                    .rev();
-                left_iter
-                    .chain(repeated.iter())
-                    .chain(self.base.iter())
-                    .cloned()
-                    .collect()
-            }
-            PadSide::Right => {
-                let right_iter = self
-                    .base
-                    .as_slice()
-                    .unwrap()
-                    .iter()
-                    .rev()
-                    .skip(len - remain)
-                    .rev();
-                self.base
-                    .iter()
-                    .chain(repeated.iter())
-                    .chain(right_iter)
-                    .cloned()
-                    .collect()
-            }
-            PadSide::Both => {
-                let left_iter = self
-                    .base
-                    .as_slice()
-                    .unwrap()
-                    .iter()
-                    .skip(len - remain)
-                    .rev();
-                let right_iter = self
-                    .base
-                    .as_slice()
-                    .unwrap()
-                    .iter()
-                    .rev()
-                    .skip(len - remain)
-                    .rev();
-                left_iter
-                    .chain(repeated.iter())
-                    .chain(self.base.iter())
-                    .chain(repeated.iter())
-                    .chain(right_iter)
-                    .cloned()
-                    .collect()
-            }
-        };
+    // 线性斜坡填充：与 numpy 的 linear_ramp 一致
+    pub fn pad_linear_ramp(
+        &self,
+        pad_side: PadSide,
+        pad_width: usize,
+        end_value: f64,
+    ) -> Result<Self, PadError> {
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::LinearRamp(end_value))
+    }
+
+    // 统计量填充：统计量取自整个信号，或仅取自靠近各侧端点的 stat_len 个样本
+    pub fn pad_stat(
+        &self,
+        pad_side: PadSide,
+        pad_width: usize,
+        stat: StatPad,
+        stat_len: Option<usize>,
+    ) -> Result<Self, PadError> {
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::Stat { stat, stat_len })
+    }
 
-        Ok(SignalBase { base: padded })
+    // 周期延拓填充：宽度可以超过信号长度
+    pub fn pad_wrap(&self, pad_side: PadSide, pad_width: usize) -> Result<Self, PadError> {
+        let (left, right) = pad_side.widths(pad_width);
+        self.pad(left, right, PadMode::Wrap)
     }
 }
@@ -0,0 +1,91 @@
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+// 原地 FFT（不归一化）。长度为 2 的幂时使用基 2 算法，否则使用 Bluestein 算法
+pub fn fft_in_place(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+}
+
+// 实信号的完整频谱
+pub fn fft_real(x: &[f64]) -> Vec<Complex64> {
+    let mut data: Vec<Complex64> = x.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+    fft_in_place(&mut data, false);
+    data
+}
+
+// 逆 FFT（含 1/N 归一化）
+pub fn ifft(spectrum: &[Complex64]) -> Vec<Complex64> {
+    let mut data = spectrum.to_vec();
+    fft_in_place(&mut data, true);
+    let scale = 1.0 / data.len().max(1) as f64;
+    data.iter_mut().for_each(|v| *v *= scale);
+    data
+}
+
+fn radix2(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let step = Complex64::from_polar(1.0, sign * 2.0 * PI / len as f64);
+        for chunk in data.chunks_mut(len) {
+            let mut w = Complex64::new(1.0, 0.0);
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = *b * w;
+                *b = *a - t;
+                *a += t;
+                w *= step;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+// 将任意长度的 DFT 转换为 2 的幂长度的循环卷积
+fn bluestein(data: &mut [Complex64], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+    // chirp[k] = exp(sign·iπk²/n)，k² 对 2n 取模以保持精度
+    let chirp: Vec<Complex64> = (0..n)
+        .map(|k| {
+            let k2 = (k as u128 * k as u128 % (2 * n as u128)) as f64;
+            Complex64::from_polar(1.0, sign * PI * k2 / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex64::new(0.0, 0.0); m];
+    for k in 0..n {
+        a[k] = data[k] * chirp[k];
+    }
+    let mut b = vec![Complex64::new(0.0, 0.0); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    a.iter_mut().zip(b.iter()).for_each(|(x, y)| *x *= y);
+    radix2(&mut a, true);
+    let scale = 1.0 / m as f64;
+    for k in 0..n {
+        data[k] = a[k] * chirp[k] * scale;
+    }
+}
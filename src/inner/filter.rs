@@ -0,0 +1,182 @@
+use std::f64::consts::PI;
+
+// 归一化 sinc 函数 sin(πx) / (πx)
+pub fn sinc(x: f64) -> f64 {
+    if x == 0.0 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+// 对称 Hamming 窗
+pub fn hamming(len: usize) -> Vec<f64> {
+    if len == 1 {
+        return vec![1.0];
+    }
+    let denom = (len - 1) as f64;
+    (0..len)
+        .map(|n| 0.54 - 0.46 * (2.0 * PI * n as f64 / denom).cos())
+        .collect()
+}
+
+// 第一类零阶修正贝塞尔函数（级数展开）
+pub fn bessel_i0(x: f64) -> f64 {
+    let half = x / 2.0;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-17 {
+        term *= (half / k).powi(2);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+// 对称 Kaiser 窗
+pub fn kaiser(len: usize, beta: f64) -> Vec<f64> {
+    if len == 1 {
+        return vec![1.0];
+    }
+    let denom = (len - 1) as f64;
+    let norm = bessel_i0(beta);
+    (0..len)
+        .map(|n| {
+            let r = 2.0 * n as f64 / denom - 1.0;
+            bessel_i0(beta * (1.0 - r * r).max(0.0).sqrt()) / norm
+        })
+        .collect()
+}
+
+// 窗函数法设计低通 FIR，cutoff 为相对奈奎斯特频率的截止频率 (0, 1]，直流增益归一化为 1
+pub fn firwin_lowpass(window: &[f64], cutoff: f64) -> Vec<f64> {
+    let center = (window.len() as f64 - 1.0) / 2.0;
+    let mut taps: Vec<f64> = window
+        .iter()
+        .enumerate()
+        .map(|(n, w)| w * cutoff * sinc(cutoff * (n as f64 - center)))
+        .collect();
+    let gain: f64 = taps.iter().sum();
+    taps.iter_mut().for_each(|t| *t /= gain);
+    taps
+}
+
+// 直接 II 型转置结构，系数按 a[0] 归一化并补零到相同长度，状态在多次 step 之间保留
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransposedForm {
+    pub b: Vec<f64>,
+    pub a: Vec<f64>,
+    pub state: Vec<f64>,
+}
+
+impl TransposedForm {
+    // a[0] 必须非零
+    pub fn new(b: &[f64], a: &[f64]) -> Self {
+        let order = b.len().max(a.len());
+        let coef = |c: &[f64], i: usize| c.get(i).copied().unwrap_or(0.0) / a[0];
+        TransposedForm {
+            b: (0..order).map(|i| coef(b, i)).collect(),
+            a: (0..order).map(|i| coef(a, i)).collect(),
+            state: vec![0.0; order],
+        }
+    }
+
+    pub fn step(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.state[0];
+        for i in 1..self.b.len() {
+            self.state[i - 1] = self.b[i] * x - self.a[i] * y + self.state[i];
+        }
+        y
+    }
+
+    pub fn reset(&mut self) {
+        self.state.iter_mut().for_each(|s| *s = 0.0);
+    }
+}
+
+// 直接 II 型转置结构的 IIR/FIR 滤波，a[0] 必须非零
+pub fn lfilter(b: &[f64], a: &[f64], x: &[f64], zi: Option<&[f64]>) -> Vec<f64> {
+    let mut form = TransposedForm::new(b, a);
+    if let Some(zi) = zi {
+        form.state[..zi.len()].copy_from_slice(zi);
+    }
+    x.iter().map(|&xn| form.step(xn)).collect()
+}
+
+// 单位阶跃输入下的稳态初始状态，用于消除滤波的起始瞬态
+pub fn lfilter_zi(b: &[f64], a: &[f64]) -> Vec<f64> {
+    let TransposedForm { b, a, .. } = TransposedForm::new(b, a);
+    let order = b.len();
+    let dc_gain = b.iter().sum::<f64>() / a.iter().sum::<f64>();
+    let mut zi = vec![0.0; order - 1];
+    let mut acc = 0.0;
+    for i in (1..order).rev() {
+        acc += b[i] - a[i] * dc_gain;
+        zi[i - 1] = acc;
+    }
+    zi
+}
+
+// 前向-后向零相位滤波，两端以奇对称延拓减小边缘效应
+pub fn filtfilt(b: &[f64], a: &[f64], x: &[f64]) -> Vec<f64> {
+    let len = x.len();
+    if len == 0 {
+        return Vec::new();
+    }
+    let pad = (3 * b.len().max(a.len())).min(len - 1);
+    let first = x[0];
+    let last = x[len - 1];
+    let extended: Vec<f64> = (1..=pad)
+        .rev()
+        .map(|i| 2.0 * first - x[i])
+        .chain(x.iter().copied())
+        .chain((1..=pad).map(|i| 2.0 * last - x[len - 1 - i]))
+        .collect();
+
+    let zi = lfilter_zi(b, a);
+    let scaled = |v: f64| zi.iter().map(|z| z * v).collect::<Vec<f64>>();
+    let mut forward = lfilter(b, a, &extended, Some(&scaled(extended[0])));
+    forward.reverse();
+    let mut backward = lfilter(b, a, &forward, Some(&scaled(forward[0])));
+    backward.reverse();
+    backward[pad..pad + len].to_vec()
+}
+
+// 二阶节：(b0, b1, b2, a1, a2)，a0 = 1
+pub type Biquad = [f64; 5];
+
+// 依次对每个二阶节执行零相位滤波
+pub fn sosfiltfilt(sections: &[Biquad], x: &[f64]) -> Vec<f64> {
+    sections.iter().fold(x.to_vec(), |signal, s| {
+        filtfilt(&s[..3], &[1.0, s[3], s[4]], &signal)
+    })
+}
+
+// 设计 Chebyshev I 型低通滤波器（双线性变换），返回直流增益为 1 的二阶节
+// order 必须为偶数，cutoff 为相对奈奎斯特频率的截止频率 (0, 1)
+pub fn cheby1_lowpass(order: usize, ripple_db: f64, cutoff: f64) -> Vec<Biquad> {
+    let eps = (10f64.powf(ripple_db / 10.0) - 1.0).sqrt();
+    let mu = (1.0 / eps).asinh() / order as f64;
+    // 预畸变（采样率归一化为 2）
+    let warped = 4.0 * (PI * cutoff / 2.0).tan();
+    (1..=order / 2)
+        .map(|k| {
+            let theta = PI * (2 * k - 1) as f64 / (2 * order) as f64;
+            let (re, im) = (
+                -mu.sinh() * theta.sin() * warped,
+                mu.cosh() * theta.cos() * warped,
+            );
+            // z = (4 + s) / (4 - s)
+            let (num_re, num_im) = (4.0 + re, im);
+            let (den_re, den_im) = (4.0 - re, -im);
+            let den = den_re * den_re + den_im * den_im;
+            let z_re = (num_re * den_re + num_im * den_im) / den;
+            let z_im = (num_im * den_re - num_re * den_im) / den;
+            let a1 = -2.0 * z_re;
+            let a2 = z_re * z_re + z_im * z_im;
+            let gain = (1.0 + a1 + a2) / 4.0;
+            [gain, 2.0 * gain, gain, a1, a2]
+        })
+        .collect()
+}
@@ -0,0 +1,8 @@
+// 单位区间上的三次 Hermite 插值：端点值 y0、y1，切线 m0、m1 已按区间长度缩放，t 位于 [0, 1]
+pub fn cubic_hermite(y0: f64, y1: f64, m0: f64, m1: f64, t: f64) -> f64 {
+    let (t2, t3) = (t * t, t * t * t);
+    (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+        + (t3 - 2.0 * t2 + t) * m0
+        + (-2.0 * t3 + 3.0 * t2) * y1
+        + (t3 - t2) * m1
+}
@@ -1,4 +1,7 @@
 pub mod base;
+pub mod fft;
+pub mod filter;
+pub mod interp;
 
 use thiserror::Error;
 
@@ -0,0 +1,143 @@
+use crate::errors::IoError;
+use crate::signal::Signal;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+// CSV 读写
+impl Signal {
+    /// Reads one column of a comma-separated file as a signal.
+    ///
+    /// `column` is zero-based. With `has_header`, the first line is skipped. Blank lines are
+    /// ignored, surrounding whitespace and double quotes are stripped from each field, and any
+    /// number `f64::from_str` accepts is valid, including scientific notation, `NaN` and `inf`.
+    ///
+    /// # Errors
+    /// - `IoError::Io` if the file cannot be opened or read.
+    /// - `IoError::Parse` with the 1-based line number if a line has no such column or the
+    ///   field is not a number.
+    ///
+    /// 将逗号分隔文件中的一列读取为信号。
+    ///
+    /// `column` 从零开始计数。`has_header` 为真时跳过第一行。空行被忽略，每个字段两端的空白与双引号被去除，
+    /// `f64::from_str` 接受的任何数字都有效，包括科学计数法、`NaN` 与 `inf`。
+    ///
+    /// # 错误
+    /// - 文件无法打开或读取时返回 `IoError::Io`。
+    /// - 某一行没有该列或字段不是数字时返回 `IoError::Parse`，并附带从 1 开始的行号。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// // 第二列为电压
+    /// let voltage = Signal::read_csv("scope.csv", 1, true).unwrap();
+    /// ```
+    pub fn read_csv<P: AsRef<Path>>(
+        path: P,
+        column: usize,
+        has_header: bool,
+    ) -> Result<Signal, IoError> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut values = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if (has_header && index == 0) || line.trim().is_empty() {
+                continue;
+            }
+            let parse_error = |message: String| IoError::Parse {
+                line: index + 1,
+                message,
+            };
+            let field = line
+                .split(',')
+                .nth(column)
+                .ok_or_else(|| parse_error(format!("no column {column}")))?;
+            let field = field.trim().trim_matches('"').trim();
+            let value = field
+                .parse::<f64>()
+                .map_err(|_| parse_error(format!("`{field}` is not a number")))?;
+            values.push(value);
+        }
+        Ok(Signal::from_vec(values))
+    }
+
+    /// Writes the signal as a single-column CSV file, one sample per line, optionally preceded
+    /// by a header line.
+    ///
+    /// Samples are written in the shortest form that reads back to the same `f64`, switching to
+    /// scientific notation for very large or small magnitudes.
+    ///
+    /// # Errors
+    /// Returns `IoError::Io` if the file cannot be written.
+    ///
+    /// 将信号写为单列 CSV 文件，每行一个样本，可选地在前面写一行表头。
+    ///
+    /// 样本以能读回相同 `f64` 的最短形式写出，数量级很大或很小时改用科学计数法。
+    ///
+    /// # 错误
+    /// 文件无法写入时返回 `IoError::Io`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.5, -1.0, 2e-9]);
+    /// signal.write_csv("signal.csv", Some("value")).unwrap();
+    /// ```
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P, header: Option<&str>) -> Result<(), IoError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        if let Some(header) = header {
+            writeln!(writer, "{header}")?;
+        }
+        for &x in self.iter() {
+            writeln!(writer, "{}", format_sample(x))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the signal as a two-column `time,value` CSV file, where the time of sample `i` is
+    /// `i / sample_rate` seconds, optionally preceded by a header line with the two column names.
+    ///
+    /// # Errors
+    /// Returns `IoError::Io` if the file cannot be written.
+    ///
+    /// 将信号写为两列 `time,value` 的 CSV 文件，第 `i` 个样本的时间为 `i / sample_rate` 秒，
+    /// 可选地在前面写一行包含两列名称的表头。
+    ///
+    /// # 错误
+    /// 文件无法写入时返回 `IoError::Io`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.5, -1.0]);
+    /// signal.write_csv_with_time("signal.csv", 1000.0, Some(("time", "voltage"))).unwrap();
+    /// ```
+    pub fn write_csv_with_time<P: AsRef<Path>>(
+        &self,
+        path: P,
+        sample_rate: f64,
+        header: Option<(&str, &str)>,
+    ) -> Result<(), IoError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        if let Some((time, value)) = header {
+            writeln!(writer, "{time},{value}")?;
+        }
+        for (i, &x) in self.iter().enumerate() {
+            let t = i as f64 / sample_rate;
+            writeln!(writer, "{},{}", format_sample(t), format_sample(x))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+// Display 与 LowerExp 都输出可精确读回的最短形式，数量级极端时用科学计数法避免过长的小数
+fn format_sample(x: f64) -> String {
+    let magnitude = x.abs();
+    if magnitude != 0.0 && magnitude.is_finite() && !(1e-4..1e15).contains(&magnitude) {
+        format!("{x:e}")
+    } else {
+        format!("{x}")
+    }
+}
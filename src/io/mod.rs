@@ -0,0 +1,9 @@
+mod csv;
+mod npy;
+mod raw;
+#[cfg(feature = "hound")]
+mod wav;
+
+pub use raw::SampleFormat;
+#[cfg(feature = "hound")]
+pub use wav::{OutOfRange, WavFormat};
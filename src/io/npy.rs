@@ -0,0 +1,226 @@
+use crate::errors::IoError;
+use crate::signal::Signal;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+// 魔数、版本号与头部长度之后的数据按 64 字节对齐，与 numpy 一致
+const ALIGN: usize = 64;
+
+// NumPy NPY 读写
+impl Signal {
+    /// Reads a one-dimensional array from a NumPy `.npy` file (format version 1.0), as written
+    /// by `numpy.save`.
+    ///
+    /// Float64 and float32 arrays of either byte order are supported; float32 samples are
+    /// widened to `f64` exactly.
+    ///
+    /// # Errors
+    /// - `IoError::Io` if the file cannot be opened or read.
+    /// - `IoError::Npy` if the file is not a version 1.0 NPY file, the array is not
+    ///   one-dimensional, its dtype is not a float64 or float32, or the data is truncated.
+    ///
+    /// 从 NumPy `.npy` 文件（格式版本 1.0，即 `numpy.save` 写出的文件）读取一维数组。
+    ///
+    /// 支持任意字节序的 float64 与 float32 数组；float32 样本被精确地扩展为 `f64`。
+    ///
+    /// # 错误
+    /// - 文件无法打开或读取时返回 `IoError::Io`。
+    /// - 文件不是 1.0 版 NPY 文件、数组不是一维、数据类型不是 float64 或 float32，或数据被截断时返回
+    ///   `IoError::Npy`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// // numpy.save("capture.npy", x)
+    /// let signal = Signal::read_npy("capture.npy").unwrap();
+    /// ```
+    pub fn read_npy<P: AsRef<Path>>(path: P) -> Result<Signal, IoError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut preamble = [0u8; 10];
+        reader
+            .read_exact(&mut preamble)
+            .map_err(|_| npy_error("file is too short"))?;
+        if &preamble[..6] != MAGIC {
+            return Err(npy_error("missing magic string"));
+        }
+        if preamble[6..8] != [1, 0] {
+            return Err(npy_error(format!(
+                "unsupported format version {}.{}",
+                preamble[6], preamble[7]
+            )));
+        }
+        let header_len = u16::from_le_bytes([preamble[8], preamble[9]]) as usize;
+        let mut header = vec![0u8; header_len];
+        reader
+            .read_exact(&mut header)
+            .map_err(|_| npy_error("header is truncated"))?;
+        let header = std::str::from_utf8(&header).map_err(|_| npy_error("header is not text"))?;
+        let (dtype, len) = parse_header(header)?;
+
+        let expected = len
+            .checked_mul(dtype.size())
+            .ok_or_else(|| npy_error(format!("array length {len} is too large")))?;
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        if data.len() < expected {
+            return Err(npy_error(format!(
+                "expected {expected} bytes of data, found {}",
+                data.len()
+            )));
+        }
+        let values = data[..expected]
+            .chunks_exact(dtype.size())
+            .map(|bytes| dtype.decode(bytes))
+            .collect();
+        Ok(Signal::from_vec(values))
+    }
+
+    /// Writes the signal as a one-dimensional little-endian float64 array in NumPy `.npy`
+    /// format version 1.0, byte for byte as `numpy.save` would write it.
+    ///
+    /// # Errors
+    /// Returns `IoError::Io` if the file cannot be written.
+    ///
+    /// 将信号以 NumPy `.npy` 格式版本 1.0 写为一维小端 float64 数组，逐字节与 `numpy.save` 的输出一致。
+    ///
+    /// # 错误
+    /// 文件无法写入时返回 `IoError::Io`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.5, -1.0, 2e-9]);
+    /// signal.write_npy("signal.npy").unwrap();
+    /// // numpy.load("signal.npy")
+    /// ```
+    pub fn write_npy<P: AsRef<Path>>(&self, path: P) -> Result<(), IoError> {
+        let mut header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({},), }}",
+            self.len()
+        );
+        // numpy 总会补齐空格，即使已经对齐也补满一整块
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat(ALIGN - unpadded % ALIGN));
+        header.push('\n');
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        for &x in self.iter() {
+            writer.write_all(&x.to_le_bytes())?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+// 支持的数据类型
+#[derive(Clone, Copy)]
+enum Dtype {
+    F64 { little_endian: bool },
+    F32 { little_endian: bool },
+}
+
+impl Dtype {
+    fn parse(descr: &str) -> Option<Dtype> {
+        let little_endian = match descr.as_bytes().first()? {
+            b'<' => true,
+            b'>' => false,
+            _ => return None,
+        };
+        match &descr[1..] {
+            "f8" => Some(Dtype::F64 { little_endian }),
+            "f4" => Some(Dtype::F32 { little_endian }),
+            _ => None,
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Dtype::F64 { .. } => 8,
+            Dtype::F32 { .. } => 4,
+        }
+    }
+
+    fn decode(self, bytes: &[u8]) -> f64 {
+        match self {
+            Dtype::F64 { little_endian } => {
+                let bytes = bytes.try_into().unwrap();
+                if little_endian {
+                    f64::from_le_bytes(bytes)
+                } else {
+                    f64::from_be_bytes(bytes)
+                }
+            }
+            Dtype::F32 { little_endian } => {
+                let bytes = bytes.try_into().unwrap();
+                if little_endian {
+                    f32::from_le_bytes(bytes) as f64
+                } else {
+                    f32::from_be_bytes(bytes) as f64
+                }
+            }
+        }
+    }
+}
+
+fn npy_error(message: impl Into<String>) -> IoError {
+    IoError::Npy(message.into())
+}
+
+// 解析形如 {'descr': '<f8', 'fortran_order': False, 'shape': (4,), } 的头部，返回数据类型与长度
+fn parse_header(header: &str) -> Result<(Dtype, usize), IoError> {
+    let descr = header_value(header, "descr")?;
+    let descr = descr
+        .strip_prefix('\'')
+        .and_then(|rest| rest.split('\'').next())
+        .ok_or_else(|| npy_error("malformed 'descr'"))?;
+    let dtype = Dtype::parse(descr).ok_or_else(|| {
+        npy_error(format!(
+            "unsupported dtype '{descr}', expected float64 or float32"
+        ))
+    })?;
+
+    // 一维数组的 C 顺序与 Fortran 顺序相同，只检查取值合法
+    let fortran_order = header_value(header, "fortran_order")?;
+    if !fortran_order.starts_with("False") && !fortran_order.starts_with("True") {
+        return Err(npy_error("malformed 'fortran_order'"));
+    }
+
+    let shape = header_value(header, "shape")?;
+    let dims: Vec<&str> = shape
+        .strip_prefix('(')
+        .and_then(|rest| rest.split(')').next())
+        .ok_or_else(|| npy_error("malformed 'shape'"))?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .collect();
+    if dims.len() != 1 {
+        return Err(npy_error(format!(
+            "expected a one-dimensional array, found shape ({})",
+            dims.join(", ")
+        )));
+    }
+    let len = dims[0]
+        .parse()
+        .map_err(|_| npy_error(format!("malformed length '{}'", dims[0])))?;
+    Ok((dtype, len))
+}
+
+// 返回键之后、冒号之后的剩余文本
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, IoError> {
+    let quoted = format!("'{key}'");
+    let start = header
+        .find(&quoted)
+        .ok_or_else(|| npy_error(format!("header has no '{key}'")))?;
+    header[start + quoted.len()..]
+        .trim_start()
+        .strip_prefix(':')
+        .map(str::trim_start)
+        .ok_or_else(|| npy_error(format!("malformed '{key}'")))
+}
@@ -0,0 +1,239 @@
+use crate::errors::{IoError, ShapeError};
+use crate::signal::Signal;
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+use std::path::Path;
+
+/// Sample format written by `Signal::write_wav`.
+///
+/// `Signal::write_wav` 写入的采样格式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WavFormat {
+    /// 16-bit integer PCM.
+    ///
+    /// 16 位整数 PCM。
+    #[default]
+    Int16,
+    /// 24-bit integer PCM.
+    ///
+    /// 24 位整数 PCM。
+    Int24,
+    /// 32-bit IEEE float.
+    ///
+    /// 32 位 IEEE 浮点数。
+    Float32,
+}
+
+/// What `Signal::write_wav` does with samples outside `[-1, 1]` and with NaN.
+///
+/// `Signal::write_wav` 对超出 `[-1, 1]` 的样本与 NaN 的处理方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutOfRange {
+    /// Clip to `[-1, 1]` and write NaN as silence.
+    ///
+    /// 削波到 `[-1, 1]`，NaN 写为静音。
+    #[default]
+    Clip,
+    /// Return `IoError::SampleOutOfRange` without creating the file.
+    ///
+    /// 返回 `IoError::SampleOutOfRange`，不创建文件。
+    Error,
+}
+
+// WAV 读取
+impl Signal {
+    /// Reads a WAV file and returns one signal per channel together with the sample rate in Hz.
+    ///
+    /// Integer PCM of up to 32 bits (including the common 16 and 24-bit formats) is scaled by
+    /// `1 / 2^(bits - 1)`, so full scale maps to `[-1, 1)`. 32-bit float samples are returned as
+    /// stored. A trailing incomplete frame is dropped, so all channels have the same length.
+    ///
+    /// # Errors
+    /// - `IoError::Wav` if the file cannot be opened or is not a valid WAV file.
+    /// - `IoError::UnsupportedFormat` for float WAVs that are not 32-bit.
+    ///
+    /// 读取 WAV 文件，返回每个通道各一个信号以及以 Hz 为单位的采样率。
+    ///
+    /// 不超过 32 位的整数 PCM（包括常见的 16 位与 24 位格式）按 `1 / 2^(bits - 1)` 缩放，
+    /// 使满量程映射到 `[-1, 1)`。32 位浮点样本按原值返回。末尾不完整的帧被丢弃，因此所有通道等长。
+    ///
+    /// # 错误
+    /// - 文件无法打开或不是有效的 WAV 文件时返回 `IoError::Wav`。
+    /// - 浮点 WAV 不是 32 位时返回 `IoError::UnsupportedFormat`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::signal::Signal;
+    /// let (channels, sample_rate) = Signal::read_wav("recording.wav").unwrap();
+    /// println!("{} channels at {} Hz", channels.len(), sample_rate);
+    /// ```
+    pub fn read_wav<P: AsRef<Path>>(path: P) -> Result<(Vec<Signal>, u32), IoError> {
+        let reader = WavReader::open(path)?;
+        let spec = reader.spec();
+        let interleaved: Signal = match (spec.sample_format, spec.bits_per_sample) {
+            (SampleFormat::Int, bits) => {
+                let scale = 1.0 / 2f64.powi(bits as i32 - 1);
+                reader
+                    .into_samples::<i32>()
+                    .map(|sample| sample.map(|s| s as f64 * scale))
+                    .collect::<Result<Vec<f64>, hound::Error>>()?
+                    .into_iter()
+                    .collect()
+            }
+            (SampleFormat::Float, 32) => reader
+                .into_samples::<f32>()
+                .map(|sample| sample.map(f64::from))
+                .collect::<Result<Vec<f64>, hound::Error>>()?
+                .into_iter()
+                .collect(),
+            (SampleFormat::Float, bits_per_sample) => {
+                return Err(IoError::UnsupportedFormat {
+                    kind: "float",
+                    bits_per_sample,
+                })
+            }
+        };
+        Ok((
+            interleaved.deinterleave(spec.channels as usize),
+            spec.sample_rate,
+        ))
+    }
+}
+
+// WAV 写入
+impl Signal {
+    /// Writes the signal to a mono WAV file. See `write_wav_multi` for the sample conversion.
+    ///
+    /// # Errors
+    /// - `IoError::SampleOutOfRange` if `out_of_range` is `OutOfRange::Error` and a sample lies
+    ///   outside `[-1, 1]` or is NaN.
+    /// - `IoError::Wav` if the file cannot be written.
+    ///
+    /// 将信号写入单声道 WAV 文件。样本转换方式见 `write_wav_multi`。
+    ///
+    /// # 错误
+    /// - `out_of_range` 为 `OutOfRange::Error` 且有样本超出 `[-1, 1]` 或为 NaN 时返回
+    ///   `IoError::SampleOutOfRange`。
+    /// - 文件无法写入时返回 `IoError::Wav`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::generator::Generator;
+    /// use dsp4rust::io::{OutOfRange, WavFormat};
+    /// let generator = Generator::new().sample_rate(44100.0).stop_time(1.0).build().unwrap();
+    /// let tone = generator.sin(440.0, 0.0, 0.5, 0.0);
+    /// tone.write_wav("tone.wav", 44100, WavFormat::Int16, OutOfRange::Clip).unwrap();
+    /// ```
+    pub fn write_wav<P: AsRef<Path>>(
+        &self,
+        path: P,
+        sample_rate: u32,
+        format: WavFormat,
+        out_of_range: OutOfRange,
+    ) -> Result<(), IoError> {
+        Signal::write_wav_multi(&[self], path, sample_rate, format, out_of_range)
+    }
+
+    /// Writes several equally long channels to one WAV file, interleaving them frame by frame.
+    ///
+    /// Samples are expected in `[-1, 1]`. Integer formats scale by `2^(bits - 1)` and round to
+    /// the nearest code, with `1.0` mapped to the largest code, which inverts `read_wav` to
+    /// within half an LSB. `WavFormat::Float32` stores the samples rounded to `f32`. Samples
+    /// outside `[-1, 1]` and NaN are handled according to `out_of_range`; with
+    /// `OutOfRange::Error` every sample is checked before the file is created.
+    ///
+    /// # Errors
+    /// - `IoError::ChannelCount` if `channels` is empty or has more than 65535 entries.
+    /// - `IoError::Shape` if the channels differ in length.
+    /// - `IoError::SampleOutOfRange` if `out_of_range` is `OutOfRange::Error` and a sample lies
+    ///   outside `[-1, 1]` or is NaN.
+    /// - `IoError::Wav` if the file cannot be written.
+    ///
+    /// 将若干等长的通道按帧交织写入同一个 WAV 文件。
+    ///
+    /// 样本应位于 `[-1, 1]` 内。整数格式乘以 `2^(bits - 1)` 后舍入到最近的码，`1.0` 映射为最大码，
+    /// 因此是 `read_wav` 的逆运算，误差不超过半个 LSB。`WavFormat::Float32` 以舍入到 `f32` 的值存储样本。
+    /// 超出 `[-1, 1]` 的样本与 NaN 按 `out_of_range` 处理；使用 `OutOfRange::Error` 时，
+    /// 在创建文件之前检查所有样本。
+    ///
+    /// # 错误
+    /// - `channels` 为空或超过 65535 个时返回 `IoError::ChannelCount`。
+    /// - 各通道长度不同时返回 `IoError::Shape`。
+    /// - `out_of_range` 为 `OutOfRange::Error` 且有样本超出 `[-1, 1]` 或为 NaN 时返回
+    ///   `IoError::SampleOutOfRange`。
+    /// - 文件无法写入时返回 `IoError::Wav`。
+    ///
+    /// # Example
+    /// ```no_run
+    /// use dsp4rust::io::{OutOfRange, WavFormat};
+    /// use dsp4rust::signal::Signal;
+    /// let left = Signal::from_vec(vec![0.0, 0.5, 1.0]);
+    /// let right = Signal::from_vec(vec![0.0, -0.5, -1.0]);
+    /// Signal::write_wav_multi(&[&left, &right], "stereo.wav", 48000, WavFormat::Int24, OutOfRange::Error)
+    ///     .unwrap();
+    /// ```
+    pub fn write_wav_multi<P: AsRef<Path>>(
+        channels: &[&Signal],
+        path: P,
+        sample_rate: u32,
+        format: WavFormat,
+        out_of_range: OutOfRange,
+    ) -> Result<(), IoError> {
+        let channel_count = u16::try_from(channels.len())
+            .ok()
+            .filter(|&n| n > 0)
+            .ok_or(IoError::ChannelCount(channels.len()))?;
+        let len = channels[0].len();
+        if let Some(other) = channels.iter().find(|c| c.len() != len) {
+            return Err(ShapeError::LengthMismatch {
+                left: len,
+                right: other.len(),
+            }
+            .into());
+        }
+        if out_of_range == OutOfRange::Error {
+            for (channel, signal) in channels.iter().enumerate() {
+                if let Some((index, &value)) = signal
+                    .iter()
+                    .enumerate()
+                    .find(|(_, x)| x.is_nan() || x.abs() > 1.0)
+                {
+                    return Err(IoError::SampleOutOfRange {
+                        channel,
+                        index,
+                        value,
+                    });
+                }
+            }
+        }
+
+        let (bits_per_sample, sample_format) = match format {
+            WavFormat::Int16 => (16, SampleFormat::Int),
+            WavFormat::Int24 => (24, SampleFormat::Int),
+            WavFormat::Float32 => (32, SampleFormat::Float),
+        };
+        let spec = WavSpec {
+            channels: channel_count,
+            sample_rate,
+            bits_per_sample,
+            sample_format,
+        };
+        let mut writer = WavWriter::create(path, spec)?;
+        let max_code = 2f64.powi(bits_per_sample as i32 - 1);
+        for i in 0..len {
+            for signal in channels {
+                // 经过上面的检查后，只有 Clip 模式会遇到越界样本与 NaN
+                let x = signal[i as isize];
+                let x = if x.is_nan() { 0.0 } else { x.clamp(-1.0, 1.0) };
+                match format {
+                    WavFormat::Float32 => writer.write_sample(x as f32)?,
+                    _ => {
+                        let code = (x * max_code).round().min(max_code - 1.0);
+                        writer.write_sample(code as i32)?
+                    }
+                }
+            }
+        }
+        writer.finalize()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+use crate::complex_signal::{Complex64, ComplexSignal};
+use crate::errors::IqError;
+use crate::inner::filter;
+use crate::signal::Signal;
+use std::f64::consts::TAU;
+
+fn check_sample_rate(sample_rate: f64) -> Result<(), IqError> {
+    if !sample_rate.is_finite() || sample_rate <= 0.0 {
+        return Err(IqError::InvalidParameter {
+            name: "sample_rate",
+            value: sample_rate,
+        });
+    }
+    Ok(())
+}
+
+fn check_carrier(carrier_freq: f64) -> Result<(), IqError> {
+    if !carrier_freq.is_finite() {
+        return Err(IqError::InvalidParameter {
+            name: "carrier_freq",
+            value: carrier_freq,
+        });
+    }
+    Ok(())
+}
+
+// 第 n 个样本（t = n / fs）处的载波 e^(iωt)
+fn carrier(carrier_freq: f64, sample_rate: f64, n: usize) -> Complex64 {
+    let t = n as f64 / sample_rate;
+    Complex64::from_polar(1.0, TAU * carrier_freq * t)
+}
+
+// 下变频与上变频
+impl Signal {
+    /// Shifts a real passband signal centred on `carrier_freq` down to its complex baseband
+    /// envelope (IQ demodulation).
+    ///
+    /// The signal is multiplied by `2·e^(-2πi·fc·t)`, with `t = n / sample_rate` starting at 0.
+    /// This moves the band around `fc` to DC and leaves an image around `-2·fc`. With
+    /// `lowpass = Some(cutoff)`, the in-phase and quadrature parts are then lowpass filtered at
+    /// `cutoff` Hz to remove the image. The filter is the zero-phase 8th-order Chebyshev type I
+    /// that `decimate` uses. For `x(t) = A(t)·cos(2π·fc·t + φ(t))`, the filtered result is
+    /// `A(t)·e^(iφ(t))`, so its magnitude is the amplitude envelope and its phase the phase
+    /// modulation. The cutoff should lie above the message bandwidth and below `2·fc` minus
+    /// that bandwidth. Pass `None` to get the raw mixer output, e.g. to apply a different
+    /// filter.
+    ///
+    /// # Errors
+    /// Returns `IqError::InvalidParameter` if `sample_rate` is not positive and finite,
+    /// `carrier_freq` is not finite, or `cutoff` is outside `(0, sample_rate / 2)`.
+    ///
+    /// 将以 `carrier_freq` 为中心的实通带信号下变频为复基带包络（IQ 解调）。
+    ///
+    /// 信号乘以 `2·e^(-2πi·fc·t)`，其中 `t = n / sample_rate` 从 0 开始。这会把 `fc` 附近的频带搬移到直流，
+    /// 同时在 `-2·fc` 附近留下一个镜像。`lowpass = Some(cutoff)` 时，随后对同相与正交分量以 `cutoff` Hz
+    /// 进行低通滤波以去除镜像，滤波器为 `decimate` 所用的零相位 8 阶 Chebyshev I 型滤波器。
+    /// 对于 `x(t) = A(t)·cos(2π·fc·t + φ(t))`，滤波后的结果为 `A(t)·e^(iφ(t))`，其模即幅度包络，
+    /// 相位即相位调制。截止频率应高于调制信号带宽，且低于 `2·fc` 减去该带宽。传入 `None` 可得到未滤波的
+    /// 混频输出，例如用于改用其他滤波器。
+    ///
+    /// # 错误
+    /// `sample_rate` 不是有限正数、`carrier_freq` 不是有限数，或 `cutoff` 超出 `(0, sample_rate / 2)` 时
+    /// 返回 `IqError::InvalidParameter`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(8000.0).duration(0.5).build().unwrap();
+    /// // 载波 1 kHz，包络 1 + 0.5·cos(2π·10·t)
+    /// let am = generator.am(1000.0, 10.0, 0.5).unwrap();
+    /// let envelope = am.to_baseband(1000.0, 8000.0, Some(100.0)).unwrap().abs();
+    /// // t = 0.25 s 处包络为 1 + 0.5·cos(5π) = 0.5
+    /// assert!((envelope[2000] - 0.5).abs() < 1e-2);
+    /// ```
+    pub fn to_baseband(
+        &self,
+        carrier_freq: f64,
+        sample_rate: f64,
+        lowpass: Option<f64>,
+    ) -> Result<ComplexSignal, IqError> {
+        check_sample_rate(sample_rate)?;
+        check_carrier(carrier_freq)?;
+        let mixed: ComplexSignal = self
+            .iter()
+            .enumerate()
+            .map(|(n, &x)| 2.0 * x * carrier(carrier_freq, sample_rate, n).conj())
+            .collect();
+        let Some(cutoff) = lowpass else {
+            return Ok(mixed);
+        };
+        // 比较同时拒绝 NaN
+        let valid = cutoff > 0.0 && cutoff < sample_rate / 2.0;
+        if !valid {
+            return Err(IqError::InvalidParameter {
+                name: "cutoff",
+                value: cutoff,
+            });
+        }
+        let sections = filter::cheby1_lowpass(8, 0.05, cutoff / (sample_rate / 2.0));
+        let in_phase = filter::sosfiltfilt(&sections, &mixed.re().to_vec());
+        let quadrature = filter::sosfiltfilt(&sections, &mixed.im().to_vec());
+        Ok(in_phase
+            .into_iter()
+            .zip(quadrature)
+            .map(|(re, im)| Complex64::new(re, im))
+            .collect())
+    }
+}
+
+impl ComplexSignal {
+    /// Shifts a complex baseband envelope up to a real passband signal at `carrier_freq` (IQ
+    /// modulation), `Re{z·e^(2πi·fc·t)}` with `t = n / sample_rate` starting at 0.
+    ///
+    /// This inverts `to_baseband` with a lowpass filter: a signal whose band fits within the
+    /// filter comes back unchanged.
+    ///
+    /// # Errors
+    /// Returns `IqError::InvalidParameter` if `sample_rate` is not positive and finite or
+    /// `carrier_freq` is not finite.
+    ///
+    /// 将复基带包络上变频为载波频率为 `carrier_freq` 的实通带信号（IQ 调制），即 `Re{z·e^(2πi·fc·t)}`，
+    /// 其中 `t = n / sample_rate` 从 0 开始。
+    ///
+    /// 这是带低通滤波的 `to_baseband` 的逆操作：频带落在滤波器通带内的信号可以原样还原。
+    ///
+    /// # 错误
+    /// `sample_rate` 不是有限正数或 `carrier_freq` 不是有限数时返回 `IqError::InvalidParameter`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::complex_signal::{Complex64, ComplexSignal};
+    /// // 恒定包络 1 上变频为余弦载波
+    /// let envelope = ComplexSignal::from_vec(vec![Complex64::new(1.0, 0.0); 4]);
+    /// let passband = envelope.to_passband(1.0, 4.0).unwrap();
+    /// assert!((passband[2] + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn to_passband(&self, carrier_freq: f64, sample_rate: f64) -> Result<Signal, IqError> {
+        check_sample_rate(sample_rate)?;
+        check_carrier(carrier_freq)?;
+        Ok(self
+            .iter()
+            .enumerate()
+            .map(|(n, &z)| (z * carrier(carrier_freq, sample_rate, n)).re)
+            .collect())
+    }
+}
@@ -1,5 +1,26 @@
+pub mod companding;
+pub mod complex_signal;
+pub mod db;
+pub mod delay;
+pub mod detect;
+pub mod filter;
+pub mod frame;
 pub mod generator;
+pub mod hilbert;
+pub mod io;
+pub mod iq;
+pub mod lpc;
+pub mod measure;
+pub mod poly;
+pub mod process;
+pub mod quantize;
+pub mod resample;
+pub mod rolling;
+pub mod sampled;
 pub mod signal;
+pub mod stats;
+pub mod view;
+pub mod window;
 
 pub mod errors;
 mod inner;
@@ -0,0 +1,77 @@
+use crate::errors::LpcError;
+use crate::signal::Signal;
+
+// 线性预测
+impl Signal {
+    /// Computes the linear prediction coefficients of order `order` by the autocorrelation
+    /// method, solved with the Levinson–Durbin recursion.
+    ///
+    /// Returns the prediction polynomial `a = [1, a1, .., a_order]` and the prediction error
+    /// power. The model predicts each sample as `x̂[n] = -(a1·x[n-1] + .. + a_p·x[n-p])`. For an
+    /// autoregressive process `x[n] + a1·x[n-1] + .. + a_p·x[n-p] = e[n]`, the coefficients
+    /// estimate the `a_k` and the error power estimates the variance of `e`. The polynomial is
+    /// ordered like an IIR denominator, so `Iir::new(&[1.0], &a.to_vec())` is the all-pole
+    /// synthesis filter and `Fir::new(&a.to_vec())` the whitening filter. The autocorrelation is
+    /// the biased estimate `r[k] = Σ x[n]·x[n+k] / len`. Its Toeplitz matrix is positive definite
+    /// for any nonzero signal, so the recursion always gives a stable synthesis filter. Order 0
+    /// gives `[1]` and the mean power.
+    ///
+    /// # Errors
+    /// - `LpcError::OrderTooHigh` if `order >= len`.
+    /// - `LpcError::ZeroEnergy` if every sample is zero.
+    ///
+    /// 用自相关法计算 `order` 阶线性预测系数，以 Levinson–Durbin 递推求解。
+    ///
+    /// 返回预测多项式 `a = [1, a1, .., a_order]` 与预测误差功率。模型将每个样本预测为
+    /// `x̂[n] = -(a1·x[n-1] + .. + a_p·x[n-p])`。对于自回归过程 `x[n] + a1·x[n-1] + .. + a_p·x[n-p] = e[n]`，
+    /// 这些系数是 `a_k` 的估计，误差功率是 `e` 方差的估计。多项式的排列方式与 IIR 分母相同，因此
+    /// `Iir::new(&[1.0], &a.to_vec())` 即全极点合成滤波器，`Fir::new(&a.to_vec())` 即白化滤波器。
+    /// 自相关采用有偏估计 `r[k] = Σ x[n]·x[n+k] / len`，其 Toeplitz 矩阵对任何非零信号都是正定的，
+    /// 因此递推得到的合成滤波器总是稳定的。0 阶时返回 `[1]` 与平均功率。
+    ///
+    /// # 错误
+    /// - `order >= len` 时返回 `LpcError::OrderTooHigh`。
+    /// - 所有样本均为零时返回 `LpcError::ZeroEnergy`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// // 指数衰减序列 0.5^n 由一阶模型 x[n] = 0.5·x[n-1] 生成
+    /// let signal: Signal = (0..200).map(|n| 0.5f64.powi(n)).collect();
+    /// let (a, error_power) = signal.lpc(1).unwrap();
+    /// assert!((a[1] + 0.5).abs() < 1e-12);
+    /// assert!(error_power < signal.energy() / 200.0);
+    /// ```
+    pub fn lpc(&self, order: usize) -> Result<(Signal, f64), LpcError> {
+        let len = self.len();
+        if order >= len {
+            return Err(LpcError::OrderTooHigh { order, len });
+        }
+        let x = self.to_vec();
+        let r: Vec<f64> = (0..=order)
+            .map(|lag| {
+                let sum: f64 = x.iter().zip(&x[lag..]).map(|(a, b)| a * b).sum();
+                sum / len as f64
+            })
+            .collect();
+        if r[0] == 0.0 {
+            return Err(LpcError::ZeroEnergy);
+        }
+
+        let mut a = vec![0.0; order + 1];
+        a[0] = 1.0;
+        let mut error = r[0];
+        for i in 1..=order {
+            // 反射系数
+            let acc: f64 = (0..i).map(|j| a[j] * r[i - j]).sum();
+            let k = -acc / error;
+            let previous = a.clone();
+            for j in 1..i {
+                a[j] = previous[j] + k * previous[i - j];
+            }
+            a[i] = k;
+            error *= 1.0 - k * k;
+        }
+        Ok((Signal::from_vec(a), error))
+    }
+}
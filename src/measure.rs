@@ -0,0 +1,225 @@
+use crate::errors::{MeasureError, ShapeError};
+use crate::inner::fft;
+use crate::signal::{check_same_len, Signal};
+use crate::window;
+
+// 单个正弦分量在 Blackman-Harris 窗频谱中占据的半宽（频点数），主瓣半宽为 4
+const TONE_HALF_WIDTH: usize = 5;
+
+/// Returns the signal-to-noise ratio in dB, `10·log10(P_signal / P_noise)`, from separate
+/// signal and noise recordings.
+///
+/// The powers are averages, so the two signals may differ in length. Zero noise power gives
+/// `+∞`. An empty signal has no average power, so the result is NaN if either input is empty.
+///
+/// 根据分别记录的信号与噪声返回以 dB 为单位的信噪比 `10·log10(P_signal / P_noise)`。
+///
+/// 功率为平均功率，因此两个信号的长度可以不同。噪声功率为零时结果为 `+∞`。空信号没有平均功率，
+/// 任一输入为空时结果为 NaN。
+///
+/// # Example
+/// ```
+/// use dsp4rust::measure::snr;
+/// use dsp4rust::signal::Signal;
+/// let signal = Signal::from_vec(vec![1.0, -1.0, 1.0, -1.0]);
+/// let noise = Signal::from_vec(vec![0.1, -0.1]);
+/// assert!((snr(&signal, &noise) - 20.0).abs() < 1e-9);
+/// ```
+pub fn snr(signal: &Signal, noise: &Signal) -> f64 {
+    10.0 * (signal.avg_power() / noise.avg_power()).log10()
+}
+
+/// Returns the SNR in dB of `noisy` against the known clean signal, treating `noisy - clean` as
+/// the noise.
+///
+/// Empty signals give NaN, as in [`snr`].
+///
+/// # Errors
+/// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+///
+/// 以 `noisy - clean` 作为噪声，返回 `noisy` 相对已知纯净信号的信噪比（dB）。
+///
+/// 与 [`snr`] 相同，空信号的结果为 NaN。
+///
+/// # 错误
+/// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::measure::snr_vs_reference;
+/// use dsp4rust::signal::Signal;
+/// let clean = Signal::from_vec(vec![1.0, -1.0]);
+/// let noisy = Signal::from_vec(vec![1.1, -0.9]);
+/// assert!((snr_vs_reference(&noisy, &clean).unwrap() - 20.0).abs() < 1e-9);
+/// ```
+pub fn snr_vs_reference(noisy: &Signal, clean: &Signal) -> Result<f64, ShapeError> {
+    check_same_len(noisy.len(), clean.len())?;
+    Ok(snr(clean, &(noisy - clean)))
+}
+
+/// Returns the total harmonic distortion in dB, `10·log10(Σ P_harmonic / P_fundamental)`.
+///
+/// The spectrum is taken with a periodic 4-term Blackman-Harris window, and the power of each
+/// tone is summed over the bins of its main lobe, so the fundamental need not fall on a bin.
+/// Harmonics `2·f, 3·f, .., (n_harmonics + 1)·f` are included; those too close to the Nyquist
+/// frequency to be resolved are skipped. `n_harmonics = 0` gives `-∞`.
+///
+/// # Errors
+/// - `MeasureError::EmptyInput` if the signal is empty.
+/// - `MeasureError::InvalidParameter` if `sample_rate` is not positive, or `fundamental_hz` is
+///   too close to DC or to the Nyquist frequency for the signal's frequency resolution.
+/// - `MeasureError::ZeroFundamental` if there is no power at the fundamental.
+///
+/// 返回以 dB 为单位的总谐波失真 `10·log10(Σ P_谐波 / P_基波)`。
+///
+/// 频谱使用周期四项 Blackman-Harris 窗计算，每个分量的功率为其主瓣内各频点功率之和，
+/// 因此基波不必恰好落在频点上。计入 `2·f, 3·f, .., (n_harmonics + 1)·f` 次谐波，
+/// 离奈奎斯特频率过近而无法分辨的谐波被跳过。`n_harmonics = 0` 时结果为 `-∞`。
+///
+/// # 错误
+/// - 信号为空时返回 `MeasureError::EmptyInput`。
+/// - `sample_rate` 不为正数，或按信号的频率分辨率 `fundamental_hz` 离直流或奈奎斯特频率过近时，
+///   返回 `MeasureError::InvalidParameter`。
+/// - 基波处没有功率时返回 `MeasureError::ZeroFundamental`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::generator::Generator;
+/// use dsp4rust::measure::thd;
+/// let generator = Generator::new().sample_rate(48000.0).num_samples(8192).build().unwrap();
+/// let signal = generator.multi_tone(&[(1000.0, 1.0, 0.0), (3000.0, 0.1, 0.0)]);
+/// let thd_db = thd(&signal, 1000.0, 48000.0, 5).unwrap();
+/// assert!((thd_db + 20.0).abs() < 0.1);
+/// ```
+pub fn thd(
+    signal: &Signal,
+    fundamental_hz: f64,
+    sample_rate: f64,
+    n_harmonics: usize,
+) -> Result<f64, MeasureError> {
+    let spectrum = Spectrum::new(signal, fundamental_hz, sample_rate)?;
+    let fundamental = spectrum.fundamental_power()?;
+    let harmonics: f64 = (2..n_harmonics + 2)
+        .map_while(|h| spectrum.tone_power(h as f64 * spectrum.fundamental_bin))
+        .sum();
+    Ok(10.0 * (harmonics / fundamental).log10())
+}
+
+/// Returns the signal-to-noise-and-distortion ratio in dB, the power of the fundamental over the
+/// power of everything else except DC.
+///
+/// The spectrum is computed as for `thd`. Bins within the main lobe of DC are excluded, so an
+/// offset does not count as noise.
+///
+/// # Errors
+/// Same as `thd`.
+///
+/// 返回以 dB 为单位的信纳比，即基波功率与除直流外其余全部功率之比。
+///
+/// 频谱的计算方式与 `thd` 相同。直流主瓣内的频点被排除，因此直流偏置不计为噪声。
+///
+/// # 错误
+/// 与 `thd` 相同。
+///
+/// # Example
+/// ```
+/// use dsp4rust::generator::Generator;
+/// use dsp4rust::measure::sinad;
+/// let generator = Generator::new().sample_rate(48000.0).num_samples(8192).build().unwrap();
+/// let signal = generator.multi_tone(&[(1000.0, 1.0, 0.0), (3000.0, 0.01, 0.0)]);
+/// assert!((sinad(&signal, 1000.0, 48000.0).unwrap() - 40.0).abs() < 0.1);
+/// ```
+pub fn sinad(signal: &Signal, fundamental_hz: f64, sample_rate: f64) -> Result<f64, MeasureError> {
+    let spectrum = Spectrum::new(signal, fundamental_hz, sample_rate)?;
+    let fundamental = spectrum.fundamental_power()?;
+    let total: f64 = spectrum.power[TONE_HALF_WIDTH + 1..].iter().sum();
+    Ok(10.0 * (fundamental / (total - fundamental)).log10())
+}
+
+/// Returns the effective number of bits for a SINAD in dB, `(SINAD - 1.76) / 6.02`.
+///
+/// 返回给定 SINAD（dB）对应的有效位数 `(SINAD - 1.76) / 6.02`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::measure::enob;
+/// assert!((enob(74.0) - 12.0).abs() < 0.01);
+/// ```
+pub fn enob(sinad_db: f64) -> f64 {
+    (sinad_db - 1.76) / 6.02
+}
+
+// 加窗后的单边功率谱
+struct Spectrum {
+    power: Vec<f64>,
+    // 基波所在的（小数）频点
+    fundamental_bin: f64,
+}
+
+impl Spectrum {
+    fn new(signal: &Signal, fundamental_hz: f64, sample_rate: f64) -> Result<Self, MeasureError> {
+        let len = signal.len();
+        if len == 0 {
+            return Err(MeasureError::EmptyInput);
+        }
+        if sample_rate.is_nan() || sample_rate <= 0.0 {
+            return Err(MeasureError::InvalidParameter {
+                name: "sample_rate",
+                value: sample_rate,
+            });
+        }
+        // 基波的主瓣既不能与直流重叠，也不能越过奈奎斯特频率
+        let fundamental_bin = fundamental_hz * len as f64 / sample_rate;
+        let half = len / 2;
+        if fundamental_bin.is_nan()
+            || fundamental_bin < (2 * TONE_HALF_WIDTH + 1) as f64
+            || fundamental_bin + TONE_HALF_WIDTH as f64 > half as f64
+        {
+            return Err(MeasureError::InvalidParameter {
+                name: "fundamental_hz",
+                value: fundamental_hz,
+            });
+        }
+
+        let window = window::periodic(len, window::blackman_harris);
+        let windowed: Vec<f64> = signal
+            .iter()
+            .zip(window.iter())
+            .map(|(x, w)| x * w)
+            .collect();
+        let power = fft::fft_real(&windowed)[..=half]
+            .iter()
+            .enumerate()
+            .map(|(k, bin)| {
+                // 单边谱中除直流与奈奎斯特外的频点代表正负两个频率
+                let weight = if k == 0 || 2 * k == len { 1.0 } else { 2.0 };
+                weight * bin.norm_sqr()
+            })
+            .collect();
+        Ok(Spectrum {
+            power,
+            fundamental_bin,
+        })
+    }
+
+    // 以 bin 为中心的主瓣功率；主瓣越过奈奎斯特频率时返回 None
+    fn tone_power(&self, bin: f64) -> Option<f64> {
+        let center = bin.round() as usize;
+        let last = self.power.len() - 1;
+        if center + TONE_HALF_WIDTH > last {
+            return None;
+        }
+        Some(
+            self.power[center - TONE_HALF_WIDTH..=center + TONE_HALF_WIDTH]
+                .iter()
+                .sum(),
+        )
+    }
+
+    fn fundamental_power(&self) -> Result<f64, MeasureError> {
+        match self.tone_power(self.fundamental_bin) {
+            Some(power) if power > 0.0 => Ok(power),
+            _ => Err(MeasureError::ZeroFundamental),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::errors::FitError;
+use crate::signal::Signal;
+
+/// Trend removed by `Signal::detrend`.
+///
+/// `Signal::detrend` 去除的趋势。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetrendMode {
+    /// Subtract the mean.
+    ///
+    /// 减去均值。
+    Constant,
+    /// Subtract the least-squares straight line.
+    ///
+    /// 减去最小二乘直线。
+    #[default]
+    Linear,
+}
+
+// 多项式拟合与去趋势
+impl Signal {
+    /// Fits a polynomial of degree `order` to the samples by least squares.
+    ///
+    /// The abscissa is the sample index `0, 1, .., len - 1`. The coefficients are returned
+    /// highest power first, like `numpy.polyfit`, so they can be passed straight to `polyval`.
+    /// The fit is solved by a Householder QR factorisation on centred and scaled indices rather
+    /// than through the normal equations, which keeps it accurate for long signals.
+    ///
+    /// # Errors
+    /// Returns `FitError::TooFewSamples` if the signal has at most `order` samples.
+    ///
+    /// 用最小二乘法对样本拟合 `order` 次多项式。
+    ///
+    /// 自变量为样本下标 `0, 1, .., len - 1`。系数按幂次从高到低返回，与 `numpy.polyfit` 相同，
+    /// 可以直接传给 `polyval`。拟合在中心化、缩放后的下标上通过 Householder QR 分解求解，
+    /// 而不是使用正规方程，因此对长信号也能保持精度。
+    ///
+    /// # 错误
+    /// 信号样本数不超过 `order` 时返回 `FitError::TooFewSamples`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 3.0, 5.0, 7.0]);
+    /// let coeffs = signal.polyfit(1).unwrap();
+    /// assert!((coeffs[0] - 2.0).abs() < 1e-12 && (coeffs[1] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn polyfit(&self, order: usize) -> Result<Vec<f64>, FitError> {
+        let fit = CenteredFit::new(self, order)?;
+        // 将 t = (x - center) / scale 的多项式展开为 x 的多项式
+        let mut coeffs = vec![0.0; order + 1];
+        let mut power = vec![1.0]; // (x - center)^k / scale^k 的系数，按幂次从低到高
+        for (k, b) in fit.coeffs.iter().enumerate() {
+            for (j, p) in power.iter().enumerate() {
+                coeffs[order - j] += b * p;
+            }
+            if k < order {
+                let mut next = vec![0.0; power.len() + 1];
+                for (j, p) in power.iter().enumerate() {
+                    next[j + 1] += p / fit.scale;
+                    next[j] -= p * fit.center / fit.scale;
+                }
+                power = next;
+            }
+        }
+        Ok(coeffs)
+    }
+
+    /// Evaluates the polynomial `coeffs` (highest power first) at every sample value.
+    ///
+    /// An empty `coeffs` is the zero polynomial. To evaluate a `polyfit` result over the fitted
+    /// range, call this on `Signal::arrange(0.0, len as f64, 1.0)`.
+    ///
+    /// 在每个样本值处计算多项式 `coeffs`（按幂次从高到低）的值。
+    ///
+    /// 空的 `coeffs` 表示零多项式。若要在拟合范围上计算 `polyfit` 的结果，
+    /// 可对 `Signal::arrange(0.0, len as f64, 1.0)` 调用本方法。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let x = Signal::from_vec(vec![0.0, 1.0, 2.0]);
+    /// assert_eq!(x.polyval(&[1.0, 0.0, -1.0]).to_vec(), vec![-1.0, 0.0, 3.0]);
+    /// ```
+    pub fn polyval(&self, coeffs: &[f64]) -> Signal {
+        self.iter()
+            .map(|&x| coeffs.iter().fold(0.0, |acc, c| acc * x + c))
+            .collect()
+    }
+
+    /// Removes a constant or linear trend from the signal.
+    ///
+    /// `DetrendMode::Linear` subtracts the least-squares straight line through the samples,
+    /// which removes slow sensor drift before spectral analysis. A single sample has no slope, so
+    /// it is detrended as a constant. For higher-order trends use `polyfit` and `polyval`.
+    /// An empty signal gives an empty result.
+    ///
+    /// 从信号中去除常数或线性趋势。
+    ///
+    /// `DetrendMode::Linear` 减去样本的最小二乘直线，可在频谱分析前去除缓慢的传感器漂移。
+    /// 单个样本没有斜率，按常数处理。更高阶的趋势可使用 `polyfit` 与 `polyval`。空信号得到空结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::poly::DetrendMode;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 4.0, 5.0, 8.0]);
+    /// let residual = signal.detrend(DetrendMode::Linear);
+    /// assert!(residual.iter().zip([-0.2, 0.6, -0.6, 0.2]).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn detrend(&self, mode: DetrendMode) -> Signal {
+        if mode == DetrendMode::Linear && self.len() >= 2 {
+            if let Ok(fit) = CenteredFit::new(self, 1) {
+                return self
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &y)| y - fit.eval(i))
+                    .collect();
+            }
+        }
+        // 空信号没有均值，也没有需要去除的样本
+        let mean = self.mean().unwrap_or(0.0);
+        self.iter().map(|y| y - mean).collect()
+    }
+}
+
+// 在 t = (x - center) / scale 上的拟合结果，t 落在 [-1, 1] 内
+struct CenteredFit {
+    center: f64,
+    scale: f64,
+    // 按幂次从低到高
+    coeffs: Vec<f64>,
+}
+
+impl CenteredFit {
+    fn new(signal: &Signal, order: usize) -> Result<Self, FitError> {
+        let len = signal.len();
+        if len <= order {
+            return Err(FitError::TooFewSamples { order, len });
+        }
+        let center = (len - 1) as f64 / 2.0;
+        let scale = center.max(1.0);
+        let t: Vec<f64> = (0..len).map(|i| (i as f64 - center) / scale).collect();
+        // 按列存放的 Vandermonde 矩阵
+        let mut columns: Vec<Vec<f64>> = Vec::with_capacity(order + 1);
+        columns.push(vec![1.0; len]);
+        for k in 1..=order {
+            let column = columns[k - 1].iter().zip(&t).map(|(c, t)| c * t).collect();
+            columns.push(column);
+        }
+        let mut rhs = signal.to_vec();
+
+        // Householder QR：依次将第 k 列对角线以下的元素消为零，同时作用于右端项
+        for k in 0..=order {
+            let norm = columns[k][k..].iter().map(|x| x * x).sum::<f64>().sqrt();
+            let alpha = if columns[k][k] > 0.0 { -norm } else { norm };
+            let mut v = columns[k][k..].to_vec();
+            v[0] -= alpha;
+            let v_norm2: f64 = v.iter().map(|x| x * x).sum();
+            if v_norm2 == 0.0 {
+                continue;
+            }
+            let reflect = |x: &mut [f64]| {
+                let factor =
+                    2.0 * v.iter().zip(x.iter()).map(|(a, b)| a * b).sum::<f64>() / v_norm2;
+                x.iter_mut().zip(&v).for_each(|(x, v)| *x -= factor * v);
+            };
+            for column in columns[k..].iter_mut() {
+                reflect(&mut column[k..]);
+            }
+            reflect(&mut rhs[k..]);
+        }
+
+        // 回代求解上三角方程 R b = Qᵀ y
+        let mut coeffs = vec![0.0; order + 1];
+        for k in (0..=order).rev() {
+            let known: f64 = (k + 1..=order).map(|j| columns[j][k] * coeffs[j]).sum();
+            coeffs[k] = (rhs[k] - known) / columns[k][k];
+        }
+        Ok(CenteredFit {
+            center,
+            scale,
+            coeffs,
+        })
+    }
+
+    fn eval(&self, index: usize) -> f64 {
+        let t = (index as f64 - self.center) / self.scale;
+        self.coeffs.iter().rev().fold(0.0, |acc, c| acc * t + c)
+    }
+}
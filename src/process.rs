@@ -0,0 +1,150 @@
+use crate::signal::Signal;
+
+/// A stateful block processor, such as a filter, that can be fed a signal all at once or in
+/// consecutive chunks.
+///
+/// Implementations keep their internal state between calls to `process`, so feeding a signal
+/// in chunks gives exactly the same output as feeding it in one call. `reset` returns the
+/// processor to its initial state, as if it had never seen any input.
+///
+/// 有状态的分块处理器（例如滤波器），既可以一次性输入整个信号，也可以按连续的块输入。
+///
+/// 实现在多次调用 `process` 之间保留内部状态，因此分块输入信号的输出与一次性输入完全相同。
+/// `reset` 将处理器恢复到初始状态，如同从未处理过任何输入。
+///
+/// # Example
+/// ```
+/// use dsp4rust::filter::Iir;
+/// use dsp4rust::process::{Chain, Gain, Processor};
+/// use dsp4rust::signal::Signal;
+/// let mut chain = Chain::new()
+///     .then(Iir::dc_blocker(0.995).unwrap())
+///     .then(Gain::new(2.0));
+/// let input = Signal::from_vec(vec![1.0, 1.0, 1.0, 1.0]);
+/// let whole = chain.process(&input);
+///
+/// chain.reset();
+/// let mut chunked = chain.process(&input.cut_range(0, 3)).to_vec();
+/// chunked.extend(chain.process(&input.cut_range(3, 4)).to_vec());
+/// assert_eq!(whole.to_vec(), chunked);
+/// ```
+pub trait Processor {
+    /// Processes the next block of input and returns an output block of the same length.
+    ///
+    /// 处理下一块输入，返回等长的输出块。
+    fn process(&mut self, input: &Signal) -> Signal;
+
+    /// Clears the internal state.
+    ///
+    /// 清除内部状态。
+    fn reset(&mut self);
+}
+
+/// A memoryless processor that multiplies every sample by a constant gain.
+///
+/// 将每个样本乘以常数增益的无记忆处理器。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gain {
+    gain: f64,
+}
+
+impl Gain {
+    /// Creates a processor with the linear amplitude gain `gain`.
+    ///
+    /// 创建线性幅度增益为 `gain` 的处理器。
+    pub fn new(gain: f64) -> Self {
+        Gain { gain }
+    }
+
+    /// Creates a processor with a gain given in decibels, i.e. `10^(db / 20)`.
+    ///
+    /// 创建以分贝指定增益的处理器，即 `10^(db / 20)`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::process::Gain;
+    /// assert_eq!(Gain::from_db(20.0).gain(), 10.0);
+    /// ```
+    pub fn from_db(db: f64) -> Self {
+        Gain::new(crate::db::db_to_amplitude(db, 1.0))
+    }
+
+    /// Returns the linear amplitude gain.
+    ///
+    /// 返回线性幅度增益。
+    pub fn gain(&self) -> f64 {
+        self.gain
+    }
+}
+
+impl Processor for Gain {
+    fn process(&mut self, input: &Signal) -> Signal {
+        input * self.gain
+    }
+
+    fn reset(&mut self) {}
+}
+
+/// Runs a sequence of processors one after another, feeding each one's output to the next.
+///
+/// A chain is itself a `Processor`, so chains can be nested. An empty chain passes its input
+/// through unchanged.
+///
+/// 依次运行一系列处理器，将每个处理器的输出送入下一个。
+///
+/// 处理链本身也是 `Processor`，因此可以嵌套。空处理链原样输出其输入。
+#[derive(Default)]
+pub struct Chain {
+    stages: Vec<Box<dyn Processor>>,
+}
+
+impl Chain {
+    /// Creates an empty chain.
+    ///
+    /// 创建空的处理链。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a stage and returns the chain, for building it in one expression.
+    ///
+    /// 追加一级处理器并返回处理链，便于在一个表达式中构建。
+    #[must_use]
+    pub fn then<P: Processor + 'static>(mut self, stage: P) -> Self {
+        self.push(stage);
+        self
+    }
+
+    /// Appends a stage.
+    ///
+    /// 追加一级处理器。
+    pub fn push<P: Processor + 'static>(&mut self, stage: P) {
+        self.stages.push(Box::new(stage));
+    }
+
+    /// Returns the number of stages.
+    ///
+    /// 返回处理器的级数。
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Returns `true` if the chain has no stages.
+    ///
+    /// 处理链没有任何处理器时返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl Processor for Chain {
+    fn process(&mut self, input: &Signal) -> Signal {
+        self.stages
+            .iter_mut()
+            .fold(input.clone(), |signal, stage| stage.process(&signal))
+    }
+
+    fn reset(&mut self) {
+        self.stages.iter_mut().for_each(|stage| stage.reset());
+    }
+}
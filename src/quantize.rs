@@ -0,0 +1,148 @@
+use crate::signal::Signal;
+use ndarray_rand::rand::rngs::StdRng;
+use ndarray_rand::rand::{Rng, SeedableRng};
+
+/// How `Signal::quantize` maps a sample to an integer code.
+///
+/// `Signal::quantize` 将样本映射为整数码的方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to the nearest level (mid-tread), ties away from zero.
+    ///
+    /// 舍入到最近的量化电平（中平型），恰在中点时远离零舍入。
+    #[default]
+    Nearest,
+    /// Round down to the level below (truncation).
+    ///
+    /// 向下舍入到下方的量化电平（截断）。
+    Floor,
+    /// Add triangular (TPDF) dither, the sum of two independent uniform ±0.5 LSB variables,
+    /// before rounding to the nearest level. This decorrelates the quantization error from the
+    /// signal. With a seed the dither is reproducible.
+    ///
+    /// 舍入到最近电平之前加入三角概率密度（TPDF）抖动，即两个独立的 ±0.5 LSB 均匀随机变量之和。
+    /// 这使量化误差与信号不相关。指定种子时抖动可复现。
+    StochasticDither { seed: Option<u64> },
+}
+
+// 量化
+impl Signal {
+    /// Simulates an ideal `bits`-bit converter with input range `±full_scale` and returns the
+    /// reconstructed sample values.
+    ///
+    /// The step is `Δ = 2·full_scale / 2^bits`, and the reconstructed value of code `c` is `c·Δ`,
+    /// with codes in the two's complement range `-2^(bits - 1)..=2^(bits - 1) - 1`. Samples
+    /// beyond the range clip to the extreme codes. This is `Signal::from_codes` applied to
+    /// `quantize_codes`, except that NaN samples stay NaN.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not in `1..=53` or `full_scale` is not positive and finite.
+    ///
+    /// 模拟输入范围为 `±full_scale` 的理想 `bits` 位转换器，返回重建后的样本值。
+    ///
+    /// 量化步长为 `Δ = 2·full_scale / 2^bits`，码 `c` 的重建值为 `c·Δ`，码的取值范围为补码范围
+    /// `-2^(bits - 1)..=2^(bits - 1) - 1`。超出范围的样本被削波到最大或最小码。
+    /// 结果等于对 `quantize_codes` 应用 `Signal::from_codes`，但 NaN 样本保持为 NaN。
+    ///
+    /// # 恐慌
+    /// `bits` 不在 `1..=53` 内，或 `full_scale` 不是有限正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::quantize::Rounding;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.3, -0.3, 0.9, -2.0]);
+    /// let quantized = signal.quantize(3, 1.0, Rounding::Nearest);
+    /// assert_eq!(quantized.to_vec(), vec![0.25, -0.25, 0.75, -1.0]);
+    /// ```
+    pub fn quantize(&self, bits: u8, full_scale: f64, rounding: Rounding) -> Signal {
+        let codes = self.quantize_codes(bits, full_scale, rounding);
+        let step = step_size(bits, full_scale);
+        self.iter()
+            .zip(codes)
+            .map(|(x, code)| if x.is_nan() { *x } else { code as f64 * step })
+            .collect()
+    }
+
+    /// Returns the integer codes an ideal `bits`-bit converter with input range `±full_scale`
+    /// produces for the samples. See `quantize` for the mapping. NaN samples map to code 0.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not in `1..=53` or `full_scale` is not positive and finite.
+    ///
+    /// 返回输入范围为 `±full_scale` 的理想 `bits` 位转换器对各样本输出的整数码。映射方式见 `quantize`。
+    /// NaN 样本映射为码 0。
+    ///
+    /// # 恐慌
+    /// `bits` 不在 `1..=53` 内，或 `full_scale` 不是有限正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::quantize::Rounding;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.3, -0.3, 1.0, -1.0]);
+    /// assert_eq!(signal.quantize_codes(3, 1.0, Rounding::Floor), vec![1, -2, 3, -4]);
+    /// ```
+    pub fn quantize_codes(&self, bits: u8, full_scale: f64, rounding: Rounding) -> Vec<i64> {
+        let step = step_size(bits, full_scale);
+        let max_code = (1i64 << (bits - 1)) - 1;
+        let min_code = -(1i64 << (bits - 1));
+        let mut rng = match rounding {
+            Rounding::StochasticDither { seed: Some(seed) } => Some(StdRng::seed_from_u64(seed)),
+            Rounding::StochasticDither { seed: None } => Some(StdRng::from_entropy()),
+            _ => None,
+        };
+        self.iter()
+            .map(|&x| {
+                if x.is_nan() {
+                    return 0;
+                }
+                let level = x.clamp(-full_scale, full_scale) / step;
+                let code = match (rounding, rng.as_mut()) {
+                    (Rounding::Floor, _) => level.floor(),
+                    (Rounding::StochasticDither { .. }, Some(rng)) => {
+                        let dither = rng.gen_range(-0.5..0.5) + rng.gen_range(-0.5..0.5);
+                        (level + dither).round()
+                    }
+                    _ => level.round(),
+                };
+                (code as i64).clamp(min_code, max_code)
+            })
+            .collect()
+    }
+
+    /// Reconstructs the sample values `c·Δ` of integer codes produced by a `bits`-bit converter
+    /// with input range `±full_scale`, where `Δ = 2·full_scale / 2^bits`.
+    ///
+    /// # Panics
+    /// Panics if `bits` is not in `1..=53` or `full_scale` is not positive and finite.
+    ///
+    /// 由输入范围为 `±full_scale` 的 `bits` 位转换器输出的整数码重建样本值 `c·Δ`，
+    /// 其中 `Δ = 2·full_scale / 2^bits`。
+    ///
+    /// # 恐慌
+    /// `bits` 不在 `1..=53` 内，或 `full_scale` 不是有限正数时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_codes(&[-4, 0, 3], 3, 1.0);
+    /// assert_eq!(signal.to_vec(), vec![-1.0, 0.0, 0.75]);
+    /// ```
+    pub fn from_codes(codes: &[i64], bits: u8, full_scale: f64) -> Signal {
+        let step = step_size(bits, full_scale);
+        codes.iter().map(|&code| code as f64 * step).collect()
+    }
+}
+
+fn step_size(bits: u8, full_scale: f64) -> f64 {
+    assert!(
+        (1..=53).contains(&bits),
+        "bits must be in 1..=53, got {bits}"
+    );
+    assert!(
+        full_scale.is_finite() && full_scale > 0.0,
+        "full_scale must be positive and finite, got {full_scale}"
+    );
+    2.0 * full_scale / 2f64.powi(bits as i32)
+}
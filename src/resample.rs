@@ -0,0 +1,489 @@
+use crate::errors::ResampleError;
+use crate::inner::{filter, interp};
+use crate::signal::Signal;
+use std::f64::consts::PI;
+
+/// Anti-aliasing lowpass filter used by `Signal::decimate`.
+///
+/// Both filters cut off at `0.8 / factor` of the original Nyquist frequency and are applied
+/// forward and backward, so the decimated signal has no phase distortion.
+///
+/// `Signal::decimate` 使用的抗混叠低通滤波器。
+///
+/// 两种滤波器的截止频率均为原奈奎斯特频率的 `0.8 / factor`，并以前向-后向方式应用，
+/// 因此抽取后的信号没有相位失真。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimateFilter {
+    /// Chebyshev type I IIR lowpass with 0.05 dB passband ripple. The order must be even.
+    ///
+    /// 通带纹波为 0.05 dB 的 Chebyshev I 型 IIR 低通滤波器，阶数必须为偶数。
+    ChebyshevI { order: usize },
+    /// Hamming-windowed FIR lowpass with the given number of taps.
+    ///
+    /// 指定抽头数的 Hamming 窗 FIR 低通滤波器。
+    Fir { taps: usize },
+}
+
+impl Default for DecimateFilter {
+    /// An 8th-order Chebyshev type I filter.
+    ///
+    /// 8 阶 Chebyshev I 型滤波器。
+    fn default() -> Self {
+        DecimateFilter::ChebyshevI { order: 8 }
+    }
+}
+
+/// Interpolation kernel used by `Signal::resize_interp`.
+///
+/// `Signal::resize_interp` 使用的插值方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpKind {
+    /// Piecewise linear interpolation.
+    ///
+    /// 分段线性插值。
+    Linear,
+    /// Cubic Hermite interpolation with finite-difference tangents. Exact for polynomials up to
+    /// degree two.
+    ///
+    /// 以有限差分为切线的三次 Hermite 插值，对二次及以下多项式是精确的。
+    CubicHermite,
+}
+
+// 抽取与插值
+impl Signal {
+    /// Lowpass filters the signal and keeps every `factor`-th sample.
+    ///
+    /// Filtering before downsampling prevents components above the new Nyquist frequency from
+    /// aliasing into the passband. `factor == 1` returns an unchanged copy.
+    ///
+    /// # Parameters
+    /// * `factor` - The downsampling factor.
+    /// * `filter` - The anti-aliasing filter, `None` for `DecimateFilter::default()`.
+    ///
+    /// # Errors
+    /// * `ResampleError::ZeroFactor` if `factor` is 0.
+    /// * `ResampleError::InvalidFilter` for a zero or odd Chebyshev order, or zero FIR taps.
+    ///
+    /// 对信号进行低通滤波后每隔 `factor` 个样本保留一个。
+    ///
+    /// 在降采样之前滤波可以防止高于新奈奎斯特频率的分量混叠到通带中。`factor == 1` 时返回原信号的副本。
+    ///
+    /// # 参数
+    /// * `factor` - 降采样因子。
+    /// * `filter` - 抗混叠滤波器，`None` 表示使用 `DecimateFilter::default()`。
+    ///
+    /// # 错误
+    /// * `factor` 为 0 时返回 `ResampleError::ZeroFactor`。
+    /// * Chebyshev 阶数为零或奇数、FIR 抽头数为零时返回 `ResampleError::InvalidFilter`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::ones(100);
+    /// let decimated = signal.decimate(4, None).unwrap();
+    /// assert_eq!(decimated.len(), 25);
+    /// assert!((decimated[10] - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn decimate(
+        &self,
+        factor: usize,
+        filter: Option<DecimateFilter>,
+    ) -> Result<Signal, ResampleError> {
+        match factor {
+            0 => return Err(ResampleError::ZeroFactor),
+            1 => return Ok(self.clone()),
+            _ => {}
+        }
+        let cutoff = 0.8 / factor as f64;
+        let data = self.to_vec();
+        let filtered = match filter.unwrap_or_default() {
+            DecimateFilter::ChebyshevI { order } => {
+                if order == 0 || order % 2 != 0 {
+                    return Err(ResampleError::InvalidFilter(format!(
+                        "Chebyshev order must be a positive even number, got {order}"
+                    )));
+                }
+                let sections = filter::cheby1_lowpass(order, 0.05, cutoff);
+                filter::sosfiltfilt(&sections, &data)
+            }
+            DecimateFilter::Fir { taps } => {
+                if taps == 0 {
+                    return Err(ResampleError::InvalidFilter(
+                        "FIR filter needs at least one tap".to_string(),
+                    ));
+                }
+                let b = filter::firwin_lowpass(&filter::hamming(taps), cutoff);
+                filter::filtfilt(&b, &[1.0], &data)
+            }
+        };
+        Ok(filtered.into_iter().step_by(factor).collect())
+    }
+
+    /// Takes every `step`-th sample starting at `offset`.
+    ///
+    /// Unlike `decimate`, no anti-aliasing filter is applied, which makes this suitable for
+    /// thinning data for plots and for splitting a signal into polyphase components. The output
+    /// length is `ceil((len - offset) / step)`, and `offset >= len` gives an empty signal.
+    ///
+    /// # Errors
+    /// Returns `ResampleError::ZeroFactor` if `step` is 0.
+    ///
+    /// 从 `offset` 开始每隔 `step` 个样本取一个样本。
+    ///
+    /// 与 `decimate` 不同，这里不做抗混叠滤波，适用于为绘图稀疏数据以及将信号拆分为多相分量。
+    /// 输出长度为 `ceil((len - offset) / step)`，`offset >= len` 时得到空信号。
+    ///
+    /// # 错误
+    /// `step` 为 0 时返回 `ResampleError::ZeroFactor`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(signal.downsample(3, 1).unwrap().to_vec(), vec![1.0, 4.0]);
+    /// ```
+    pub fn downsample(&self, step: usize, offset: usize) -> Result<Signal, ResampleError> {
+        if step == 0 {
+            return Err(ResampleError::ZeroFactor);
+        }
+        Ok(self.iter().skip(offset).step_by(step).copied().collect())
+    }
+
+    /// Inserts `factor - 1` zeros after every sample.
+    ///
+    /// This is the bare expansion step of upsampling, without any interpolation filter. The
+    /// output length is `len * factor`.
+    ///
+    /// # Errors
+    /// Returns `ResampleError::ZeroFactor` if `factor` is 0.
+    ///
+    /// 在每个样本之后插入 `factor - 1` 个零。
+    ///
+    /// 这是升采样中单纯的扩展步骤，不包含插值滤波。输出长度为 `len * factor`。
+    ///
+    /// # 错误
+    /// `factor` 为 0 时返回 `ResampleError::ZeroFactor`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0]);
+    /// let stuffed = signal.upsample_zero_stuff(3).unwrap();
+    /// assert_eq!(stuffed.to_vec(), vec![1.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+    /// ```
+    pub fn upsample_zero_stuff(&self, factor: usize) -> Result<Signal, ResampleError> {
+        if factor == 0 {
+            return Err(ResampleError::ZeroFactor);
+        }
+        Ok(self
+            .iter()
+            .flat_map(|&x| std::iter::once(x).chain(std::iter::repeat_n(0.0, factor - 1)))
+            .collect())
+    }
+
+    /// Upsamples the signal by an integer factor.
+    ///
+    /// Zeros are inserted between samples and the result is lowpass filtered at the original
+    /// Nyquist frequency by a linear-phase Kaiser-windowed FIR, whose delay is compensated. The
+    /// filter gain is `factor`, so the amplitude of the signal is preserved. The output length is
+    /// `len * factor`. This is `resample_poly(factor, 1)`: the polyphase form skips the products
+    /// with the inserted zeros, so it costs about `1 / factor` of filtering the zero-stuffed
+    /// signal.
+    ///
+    /// # Errors
+    /// Returns `ResampleError::ZeroFactor` if `factor` is 0.
+    ///
+    /// 按整数因子对信号升采样。
+    ///
+    /// 在样本之间插入零，再用线性相位的 Kaiser 窗 FIR 在原奈奎斯特频率处低通滤波，并补偿其延迟。
+    /// 滤波器增益为 `factor`，因此信号幅值保持不变。输出长度为 `len * factor`。
+    /// 这即是 `resample_poly(factor, 1)`：多相结构跳过与插入的零相乘的运算，计算量约为对补零信号滤波的 `1 / factor`。
+    ///
+    /// # 错误
+    /// `factor` 为 0 时返回 `ResampleError::ZeroFactor`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::ones(50);
+    /// let upsampled = signal.upsample(2).unwrap();
+    /// assert_eq!(upsampled.len(), 100);
+    /// assert!((upsampled[50] - 1.0).abs() < 1e-2);
+    /// ```
+    pub fn upsample(&self, factor: usize) -> Result<Signal, ResampleError> {
+        self.resample_poly(factor, 1)
+    }
+
+    /// Resamples the signal by the rational factor `up / down` using polyphase filtering.
+    ///
+    /// `up` and `down` are first reduced by their greatest common divisor. The signal is
+    /// conceptually upsampled by `up`, lowpass filtered by a Kaiser-windowed FIR (β = 5, 20
+    /// taps per phase) with its cutoff at the lower of the two Nyquist frequencies, and
+    /// downsampled by `down`; the polyphase form only evaluates the output samples that are
+    /// kept. The filter delay is compensated, and the output length is `⌈len · up / down⌉`.
+    ///
+    /// # Parameters
+    /// * `up` - The upsampling factor.
+    /// * `down` - The downsampling factor.
+    ///
+    /// # Errors
+    /// Returns `ResampleError::ZeroFactor` if `up` or `down` is 0.
+    ///
+    /// 使用多相滤波按有理因子 `up / down` 对信号重采样。
+    ///
+    /// `up` 与 `down` 首先除以它们的最大公约数。信号在概念上先升采样 `up` 倍，经过 Kaiser 窗 FIR
+    /// （β = 5，每相 20 个抽头）在两个奈奎斯特频率中较低者处低通滤波，再降采样 `down` 倍；
+    /// 多相结构只计算需要保留的输出样本。滤波器延迟已被补偿，输出长度为 `⌈len · up / down⌉`。
+    ///
+    /// # 参数
+    /// * `up` - 升采样因子。
+    /// * `down` - 降采样因子。
+    ///
+    /// # 错误
+    /// `up` 或 `down` 为 0 时返回 `ResampleError::ZeroFactor`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// // 44.1 kHz -> 48 kHz
+    /// let signal = Signal::ones(441);
+    /// let resampled = signal.resample_poly(48000, 44100).unwrap();
+    /// assert_eq!(resampled.len(), 480);
+    /// assert!((resampled[240] - 1.0).abs() < 1e-2);
+    /// ```
+    pub fn resample_poly(&self, up: usize, down: usize) -> Result<Signal, ResampleError> {
+        if up == 0 || down == 0 {
+            return Err(ResampleError::ZeroFactor);
+        }
+        let divisor = gcd(up, down);
+        let (up, down) = (up / divisor, down / divisor);
+        if up == 1 && down == 1 {
+            return Ok(self.clone());
+        }
+
+        let x = self.to_vec();
+        let taps = interpolation_filter(up, down);
+        let delay = (taps.len() - 1) / 2;
+        let out_len = (x.len() * up).div_ceil(down);
+        Ok((0..out_len)
+            .map(|m| {
+                // 输出样本在升采样序列中的位置，只有 p - k 为 up 的倍数的抽头参与运算
+                let p = m * down + delay;
+                (p % up..taps.len().min(p + 1))
+                    .step_by(up)
+                    .filter_map(|k| x.get((p - k) / up).map(|xi| taps[k] * xi))
+                    .sum::<f64>()
+            })
+            .collect())
+    }
+
+    /// Evaluates the band-limited reconstruction of the signal at fractional sample positions.
+    ///
+    /// Each output is the sum of the `2 * half_width` nearest samples weighted by a Hann-tapered
+    /// sinc kernel; samples beyond either end of the signal count as zero, so accuracy degrades
+    /// within `half_width` samples of the edges. Positions that are exact integers return the
+    /// original sample unchanged.
+    ///
+    /// # Parameters
+    /// * `positions` - Sample positions to evaluate, each in `[0, len - 1]`.
+    /// * `half_width` - The number of samples used on each side of a position.
+    ///
+    /// # Errors
+    /// * `ResampleError::PositionOutOfRange` if a position is outside `[0, len - 1]` or NaN.
+    /// * `ResampleError::InvalidFilter` if `half_width` is 0.
+    ///
+    /// 在小数样本位置处计算信号的带限重建值。
+    ///
+    /// 每个输出值为距离该位置最近的 `2 * half_width` 个样本按 Hann 加权 sinc 核的加权和；
+    /// 超出信号两端的样本按零处理，因此在距离边缘 `half_width` 个样本以内精度会下降。
+    /// 恰为整数的位置直接返回原始样本。
+    ///
+    /// # 参数
+    /// * `positions` - 需要计算的样本位置，每个都必须位于 `[0, len - 1]` 内。
+    /// * `half_width` - 每个位置每侧使用的样本数。
+    ///
+    /// # 错误
+    /// * 位置超出 `[0, len - 1]` 或为 NaN 时返回 `ResampleError::PositionOutOfRange`。
+    /// * `half_width` 为 0 时返回 `ResampleError::InvalidFilter`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0]);
+    /// let values = signal.interp_sinc(&[1.0, 2.0], 8).unwrap();
+    /// assert_eq!(values.to_vec(), vec![1.0, 2.0]);
+    /// assert!(signal.interp_sinc(&[3.5], 8).is_err());
+    /// ```
+    pub fn interp_sinc(
+        &self,
+        positions: &[f64],
+        half_width: usize,
+    ) -> Result<Signal, ResampleError> {
+        if half_width == 0 {
+            return Err(ResampleError::InvalidFilter(
+                "Sinc kernel half width must be at least 1".to_string(),
+            ));
+        }
+        let x = self.to_vec();
+        let last = x.len() as f64 - 1.0;
+        let hw = half_width as f64;
+        positions
+            .iter()
+            .map(|&p| {
+                if !(0.0..=last).contains(&p) {
+                    return Err(ResampleError::PositionOutOfRange(p));
+                }
+                if p.fract() == 0.0 {
+                    return Ok(x[p as usize]);
+                }
+                let base = p.floor() as isize;
+                let from = (base - half_width as isize + 1).max(0) as usize;
+                let to = ((base + half_width as isize) as usize).min(x.len() - 1);
+                Ok((from..=to)
+                    .map(|n| {
+                        let d = p - n as f64;
+                        let taper = 0.5 * (1.0 + (PI * d / hw).cos());
+                        x[n] * filter::sinc(d) * taper
+                    })
+                    .sum::<f64>())
+            })
+            .collect::<Result<Vec<f64>, ResampleError>>()
+            .map(Signal::from_vec)
+    }
+
+    /// Linearly interpolates the signal at fractional sample positions.
+    ///
+    /// Positions must be non-decreasing; repeated positions are allowed. Positions outside
+    /// `[0, len - 1]` are clamped to the nearest endpoint.
+    ///
+    /// # Errors
+    /// * `ResampleError::EmptyInput` if the signal is empty.
+    /// * `ResampleError::NonMonotonicPositions` if a position is smaller than the one before it.
+    /// * `ResampleError::PositionOutOfRange` if a position is NaN.
+    ///
+    /// 在小数样本位置处对信号进行线性插值。
+    ///
+    /// 位置必须是非递减的，允许重复。超出 `[0, len - 1]` 的位置会被截断到最近的端点。
+    ///
+    /// # 错误
+    /// * 信号为空时返回 `ResampleError::EmptyInput`。
+    /// * 某个位置小于前一个位置时返回 `ResampleError::NonMonotonicPositions`。
+    /// * 位置为 NaN 时返回 `ResampleError::PositionOutOfRange`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 10.0, 20.0]);
+    /// let values = signal.interp_linear(&[-1.0, 0.5, 1.25, 5.0]).unwrap();
+    /// assert_eq!(values.to_vec(), vec![0.0, 5.0, 12.5, 20.0]);
+    /// assert!(signal.interp_linear(&[1.0, 0.5]).is_err());
+    /// ```
+    pub fn interp_linear(&self, positions: &[f64]) -> Result<Signal, ResampleError> {
+        self.interp_with(positions, InterpKind::Linear)
+    }
+
+    /// Resamples the signal to `new_len` samples by interpolation.
+    ///
+    /// The first and last samples are kept and the new samples are evenly spaced between them.
+    /// This is a quick, non band-limited resize intended for envelopes and control signals.
+    /// `new_len == 1` keeps only the first sample.
+    ///
+    /// # Errors
+    /// Returns `ResampleError::EmptyInput` if the signal is empty and `new_len > 0`.
+    ///
+    /// 通过插值将信号重采样为 `new_len` 个样本。
+    ///
+    /// 保留首尾样本，新样本在两者之间均匀分布。这是一种快速的非带限缩放，适用于包络和控制信号。
+    /// `new_len == 1` 时只保留第一个样本。
+    ///
+    /// # 错误
+    /// 信号为空且 `new_len > 0` 时返回 `ResampleError::EmptyInput`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::resample::InterpKind;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 1.0, 4.0]);
+    /// let resized = signal.resize_interp(5, InterpKind::CubicHermite).unwrap();
+    /// assert_eq!(resized.to_vec(), vec![0.0, 0.25, 1.0, 2.25, 4.0]);
+    /// ```
+    pub fn resize_interp(&self, new_len: usize, kind: InterpKind) -> Result<Signal, ResampleError> {
+        if new_len == 0 {
+            return Ok(Signal::zeros(0));
+        }
+        let step = if new_len > 1 {
+            self.len().saturating_sub(1) as f64 / (new_len - 1) as f64
+        } else {
+            0.0
+        };
+        let positions: Vec<f64> = (0..new_len).map(|k| k as f64 * step).collect();
+        self.interp_with(&positions, kind)
+    }
+
+    // 按指定插值方式在非递减的位置处求值，越界位置截断到端点
+    pub(crate) fn interp_with(
+        &self,
+        positions: &[f64],
+        kind: InterpKind,
+    ) -> Result<Signal, ResampleError> {
+        if self.is_empty() {
+            return Err(ResampleError::EmptyInput);
+        }
+        // NaN 不参与顺序比较，随后作为越界位置报告
+        if let Some(k) = positions.windows(2).position(|w| w[1] < w[0]) {
+            return Err(ResampleError::NonMonotonicPositions { index: k + 1 });
+        }
+        let x = self.to_vec();
+        let last = x.len() - 1;
+        positions
+            .iter()
+            .map(|&p| {
+                if p.is_nan() {
+                    return Err(ResampleError::PositionOutOfRange(p));
+                }
+                let p = p.clamp(0.0, last as f64);
+                let k = (p.floor() as usize).min(last.saturating_sub(1));
+                let t = p - k as f64;
+                if last == 0 || t == 0.0 {
+                    return Ok(x[k]);
+                }
+                Ok(match kind {
+                    InterpKind::Linear => x[k] + t * (x[k + 1] - x[k]),
+                    InterpKind::CubicHermite => {
+                        interp::cubic_hermite(x[k], x[k + 1], tangent(&x, k), tangent(&x, k + 1), t)
+                    }
+                })
+            })
+            .collect::<Result<Vec<f64>, ResampleError>>()
+            .map(Signal::from_vec)
+    }
+}
+
+// 三次 Hermite 插值在第 k 个样本处的切线：内部用中心差分，端点用二阶单侧差分
+fn tangent(x: &[f64], k: usize) -> f64 {
+    let last = x.len() - 1;
+    match (k, last) {
+        (_, 1) => x[1] - x[0],
+        (0, _) => (-3.0 * x[0] + 4.0 * x[1] - x[2]) / 2.0,
+        (k, last) if k == last => (3.0 * x[k] - 4.0 * x[k - 1] + x[k - 2]) / 2.0,
+        (k, _) => (x[k + 1] - x[k - 1]) / 2.0,
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// 升采样/有理重采样使用的 Kaiser 窗低通 FIR：截止频率为 1/max(up, down)，增益为 up
+fn interpolation_filter(up: usize, down: usize) -> Vec<f64> {
+    let max_rate = up.max(down);
+    let half_len = 10 * max_rate;
+    let window = filter::kaiser(2 * half_len + 1, 5.0);
+    let mut taps = filter::firwin_lowpass(&window, 1.0 / max_rate as f64);
+    taps.iter_mut().for_each(|t| *t *= up as f64);
+    taps
+}
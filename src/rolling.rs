@@ -0,0 +1,174 @@
+use crate::signal::Signal;
+use std::collections::VecDeque;
+
+/// Statistic computed over each window by `Signal::rolling`.
+///
+/// `Signal::rolling` 在每个窗口上计算的统计量。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollingStat {
+    /// Arithmetic mean.
+    ///
+    /// 算术平均值。
+    Mean,
+    /// Sample standard deviation (`n - 1` in the denominator). NaN for a window of length 1.
+    ///
+    /// 样本标准差（分母为 `n - 1`）。窗口长度为 1 时为 NaN。
+    Std,
+    /// Minimum.
+    ///
+    /// 最小值。
+    Min,
+    /// Maximum.
+    ///
+    /// 最大值。
+    Max,
+    /// Root mean square.
+    ///
+    /// 均方根。
+    Rms,
+}
+
+/// Which output sample a window's statistic is written to by `Signal::rolling`.
+///
+/// `Signal::rolling` 将窗口统计量写入哪个输出样本。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollingAlign {
+    /// Output `i` covers samples `i..i + window_len`.
+    ///
+    /// 输出 `i` 覆盖样本 `i..i + window_len`。
+    Left,
+    /// Output `i` covers samples `i - window_len / 2..i - window_len / 2 + window_len`.
+    ///
+    /// 输出 `i` 覆盖样本 `i - window_len / 2..i - window_len / 2 + window_len`。
+    Center,
+    /// Output `i` covers samples `i + 1 - window_len..=i`, i.e. only the present and the past.
+    ///
+    /// 输出 `i` 覆盖样本 `i + 1 - window_len..=i`，即只用到当前与过去的样本。
+    #[default]
+    Right,
+}
+
+impl RollingAlign {
+    // 窗口起点到输出位置的偏移
+    fn offset(self, window_len: usize) -> usize {
+        match self {
+            RollingAlign::Left => 0,
+            RollingAlign::Center => window_len / 2,
+            RollingAlign::Right => window_len - 1,
+        }
+    }
+}
+
+// 滑动统计
+impl Signal {
+    /// Computes a statistic over every window of `window_len` consecutive samples.
+    ///
+    /// The output has the same length as the input. `align` decides which output sample each
+    /// window's statistic goes to; outputs whose window would extend past either end of the
+    /// signal are NaN, as are windows containing a NaN sample. Mean, standard deviation and RMS
+    /// are updated incrementally with a sliding Welford recurrence, and min/max with a monotonic
+    /// deque, so the cost is O(len) regardless of `window_len`.
+    ///
+    /// # Panics
+    /// Panics if `window_len` is zero.
+    ///
+    /// 在每个由 `window_len` 个连续样本组成的窗口上计算统计量。
+    ///
+    /// 输出与输入等长。`align` 决定每个窗口的统计量写入哪个输出样本；窗口超出信号任一端的输出为 NaN，
+    /// 含有 NaN 样本的窗口输出也为 NaN。均值、标准差与均方根通过滑动 Welford 递推增量更新，
+    /// 最小值与最大值使用单调队列，因此无论 `window_len` 多大，开销都是 O(len)。
+    ///
+    /// # 恐慌
+    /// `window_len` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::rolling::{RollingAlign, RollingStat};
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 3.0, 2.0, 6.0]);
+    /// let max = signal.rolling(2, RollingStat::Max, RollingAlign::Right);
+    /// assert!(max[0].is_nan());
+    /// assert_eq!(max.to_vec()[1..], [3.0, 3.0, 6.0]);
+    /// ```
+    pub fn rolling(&self, window_len: usize, stat: RollingStat, align: RollingAlign) -> Signal {
+        assert!(window_len > 0, "window_len must be positive");
+        let len = self.len();
+        let mut output = vec![f64::NAN; len];
+        if window_len > len {
+            return Signal::from_vec(output);
+        }
+        // NaN 以 0 参与递推，含 NaN 的窗口最后统一置为 NaN
+        let clean: Vec<f64> = self
+            .iter()
+            .map(|&x| if x.is_nan() { 0.0 } else { x })
+            .collect();
+        let values = match stat {
+            RollingStat::Mean => sliding_moments(&clean, window_len)
+                .map(|(mean, _)| mean)
+                .collect(),
+            // 单样本窗口的样本标准差无定义，递推中的舍入残差不能当作方差
+            RollingStat::Std if window_len == 1 => vec![f64::NAN; len],
+            RollingStat::Std => sliding_moments(&clean, window_len)
+                .map(|(_, m2)| (m2 / (window_len - 1) as f64).sqrt())
+                .collect(),
+            RollingStat::Rms => sliding_moments(&clean, window_len)
+                .map(|(mean, m2)| (mean * mean + m2 / window_len as f64).sqrt())
+                .collect(),
+            RollingStat::Min => sliding_extreme(&clean, window_len, |a, b| a <= b),
+            RollingStat::Max => sliding_extreme(&clean, window_len, |a, b| a >= b),
+        };
+
+        let offset = align.offset(window_len);
+        let is_nan: Vec<bool> = self.iter().map(|x| x.is_nan()).collect();
+        let mut nan_count = is_nan[..window_len].iter().filter(|&&nan| nan).count();
+        for (start, value) in values.into_iter().enumerate() {
+            if start > 0 {
+                nan_count -= is_nan[start - 1] as usize;
+                nan_count += is_nan[start + window_len - 1] as usize;
+            }
+            if nan_count == 0 {
+                output[start + offset] = value;
+            }
+        }
+        Signal::from_vec(output)
+    }
+}
+
+// 滑动 Welford 递推，依次产出每个窗口的 (均值, 离差平方和)
+fn sliding_moments(values: &[f64], window_len: usize) -> impl Iterator<Item = (f64, f64)> + '_ {
+    let (mut mean, mut m2) = (0.0, 0.0);
+    for (k, &x) in values[..window_len].iter().enumerate() {
+        let delta = x - mean;
+        mean += delta / (k + 1) as f64;
+        m2 += delta * (x - mean);
+    }
+    let first = std::iter::once((mean, m2));
+    let rest = values.windows(window_len + 1).map(move |w| {
+        let (old, new) = (w[0], w[window_len]);
+        let old_mean = mean;
+        mean += (new - old) / window_len as f64;
+        // 舍入误差可能使离差平方和略小于零
+        m2 = (m2 + (new - old) * (new - mean + old - old_mean)).max(0.0);
+        (mean, m2)
+    });
+    first.chain(rest)
+}
+
+// 单调队列求每个窗口的极值，dominates(a, b) 为真时较新的 a 使 b 不可能再成为极值
+fn sliding_extreme(values: &[f64], window_len: usize, dominates: fn(f64, f64) -> bool) -> Vec<f64> {
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(window_len);
+    let mut extremes = Vec::with_capacity(values.len() + 1 - window_len);
+    for (i, &x) in values.iter().enumerate() {
+        while deque.back().is_some_and(|&j| dominates(x, values[j])) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+        if deque[0] + window_len <= i {
+            deque.pop_front();
+        }
+        if i + 1 >= window_len {
+            extremes.push(values[deque[0]]);
+        }
+    }
+    extremes
+}
@@ -0,0 +1,255 @@
+use crate::errors::{SampledError, ShapeError};
+use crate::generator::Generator;
+use crate::signal::Signal;
+use std::ops::{Add, Deref, Div, Mul, Sub};
+
+/// A `Signal` together with the sample rate it was taken at, in Hz.
+///
+/// Times are measured in seconds from the first sample, so sample `k` is at `k / sample_rate`.
+/// The wrapper derefs to `Signal`, so every `Signal` method is available directly. Arithmetic
+/// between two sampled signals returns a `Result` and fails with `SampledError::RateMismatch`
+/// when their rates differ, instead of silently combining samples taken at different times.
+///
+/// 带有采样率（单位 Hz）的 `Signal`。
+///
+/// 时间以秒为单位，从第一个样本起算，因此第 `k` 个样本位于 `k / sample_rate`。该类型解引用为 `Signal`，
+/// 可直接调用 `Signal` 的所有方法。两个带采样率信号之间的运算返回 `Result`，采样率不同时返回
+/// `SampledError::RateMismatch`，而不会把不同时刻的样本悄悄组合在一起。
+///
+/// # Example
+/// ```
+/// use dsp4rust::sampled::SampledSignal;
+/// use dsp4rust::signal::Signal;
+/// let a = SampledSignal::new(Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]), 2.0).unwrap();
+/// assert_eq!(a.duration(), 2.0);
+/// assert_eq!(a.time_at(3), 1.5);
+/// assert_eq!(a.index_at(1.5), Some(3));
+///
+/// let b = SampledSignal::new(Signal::ones(4), 4.0).unwrap();
+/// assert!((&a + &b).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledSignal {
+    signal: Signal,
+    sample_rate: f64,
+}
+
+impl SampledSignal {
+    /// Attaches the sample rate `sample_rate` to `signal`.
+    ///
+    /// # Errors
+    /// Returns `SampledError::InvalidSampleRate` if `sample_rate` is not positive and finite.
+    ///
+    /// 为 `signal` 附加采样率 `sample_rate`。
+    ///
+    /// # 错误
+    /// `sample_rate` 不是有限正数时返回 `SampledError::InvalidSampleRate`。
+    pub fn new(signal: Signal, sample_rate: f64) -> Result<Self, SampledError> {
+        if !sample_rate.is_finite() || sample_rate <= 0.0 {
+            return Err(SampledError::InvalidSampleRate(sample_rate));
+        }
+        Ok(SampledSignal {
+            signal,
+            sample_rate,
+        })
+    }
+
+    /// Returns the sample rate in Hz.
+    ///
+    /// 返回采样率（Hz）。
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Returns the samples.
+    ///
+    /// 返回样本。
+    pub fn signal(&self) -> &Signal {
+        &self.signal
+    }
+
+    /// Discards the sample rate and returns the samples.
+    ///
+    /// 丢弃采样率，返回样本。
+    pub fn into_signal(self) -> Signal {
+        self.signal
+    }
+
+    /// Returns the duration `len / sample_rate` in seconds, i.e. the time one past the last
+    /// sample.
+    ///
+    /// 返回时长 `len / sample_rate`（秒），即最后一个样本之后一个采样间隔的时刻。
+    pub fn duration(&self) -> f64 {
+        self.signal.len() as f64 / self.sample_rate
+    }
+
+    /// Returns the time of sample `index` in seconds, `index / sample_rate`. The index is not
+    /// checked against the length.
+    ///
+    /// 返回第 `index` 个样本的时刻（秒），即 `index / sample_rate`。不检查索引是否超出长度。
+    pub fn time_at(&self, index: usize) -> f64 {
+        index as f64 / self.sample_rate
+    }
+
+    /// Returns the index of the sample nearest to `time`, or `None` if that sample lies outside
+    /// the signal.
+    ///
+    /// `index_at(time_at(k))` is `Some(k)` for every `k` in `0..len`.
+    ///
+    /// 返回离 `time` 最近的样本的索引，该样本位于信号之外时返回 `None`。
+    ///
+    /// 对 `0..len` 中的每个 `k`，`index_at(time_at(k))` 均为 `Some(k)`。
+    pub fn index_at(&self, time: f64) -> Option<usize> {
+        let position = (time * self.sample_rate).round();
+        (position >= 0.0 && position < self.signal.len() as f64).then_some(position as usize)
+    }
+
+    /// Returns the sample times `k / sample_rate` for `k` in `0..len`.
+    ///
+    /// 返回 `0..len` 中每个 `k` 对应的样本时刻 `k / sample_rate`。
+    pub fn time_vector(&self) -> Signal {
+        (0..self.signal.len()).map(|k| self.time_at(k)).collect()
+    }
+
+    /// Returns the samples whose times lie in the half-open interval `[start, end)`, at the same
+    /// sample rate.
+    ///
+    /// The interval is clipped to the signal, so times before the first or after the last sample
+    /// select nothing rather than failing. A time within rounding error of a sample time counts
+    /// as that sample time.
+    ///
+    /// # Errors
+    /// Returns `SampledError::InvalidTimeRange` if `start > end` or either end is NaN.
+    ///
+    /// 返回时刻位于半开区间 `[start, end)` 内的样本，采样率不变。
+    ///
+    /// 区间会被裁剪到信号范围内，因此第一个样本之前或最后一个样本之后的时刻不会选中任何样本，而不是报错。
+    /// 与某个样本时刻仅相差舍入误差的时刻视为该样本时刻。
+    ///
+    /// # 错误
+    /// `start > end` 或任一端为 NaN 时返回 `SampledError::InvalidTimeRange`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::sampled::SampledSignal;
+    /// use dsp4rust::signal::Signal;
+    /// let signal = SampledSignal::new(Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0, 4.0]), 10.0).unwrap();
+    /// let middle = signal.slice_time(0.1, 0.3).unwrap();
+    /// assert_eq!(middle.to_vec(), vec![1.0, 2.0]);
+    /// assert_eq!(middle.sample_rate(), 10.0);
+    /// ```
+    pub fn slice_time(&self, start: f64, end: f64) -> Result<SampledSignal, SampledError> {
+        if start.is_nan() || end.is_nan() || start > end {
+            return Err(SampledError::InvalidTimeRange { start, end });
+        }
+        let (from, to) = (self.first_index_from(start), self.first_index_from(end));
+        Ok(SampledSignal {
+            signal: self.signal.cut_range(from as isize, to as isize),
+            sample_rate: self.sample_rate,
+        })
+    }
+
+    // 时刻不早于 time 的第一个样本的索引，裁剪到 0..=len
+    fn first_index_from(&self, time: f64) -> usize {
+        let position = time * self.sample_rate;
+        let nearest = position.round();
+        // 吸收 k / fs * fs 的舍入误差
+        let index = if (position - nearest).abs() <= 1e-9 * nearest.abs().max(1.0) {
+            nearest
+        } else {
+            position.ceil()
+        };
+        index.clamp(0.0, self.signal.len() as f64) as usize
+    }
+
+    fn check_compatible(&self, other: &SampledSignal) -> Result<(), SampledError> {
+        if self.sample_rate != other.sample_rate {
+            return Err(SampledError::RateMismatch {
+                left: self.sample_rate,
+                right: other.sample_rate,
+            });
+        }
+        if self.signal.len() != other.signal.len() {
+            return Err(ShapeError::LengthMismatch {
+                left: self.signal.len(),
+                right: other.signal.len(),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl Deref for SampledSignal {
+    type Target = Signal;
+
+    fn deref(&self) -> &Signal {
+        &self.signal
+    }
+}
+
+// 两个信号间的运算须采样率与长度均一致，与标量的运算保留采样率
+macro_rules! impl_sampled_ops {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&SampledSignal> for &SampledSignal {
+            type Output = Result<SampledSignal, SampledError>;
+            fn $op(self, other: &SampledSignal) -> Self::Output {
+                self.check_compatible(other)?;
+                Ok(SampledSignal {
+                    signal: (&self.signal).$op(&other.signal),
+                    sample_rate: self.sample_rate,
+                })
+            }
+        }
+
+        impl $Op<f64> for &SampledSignal {
+            type Output = SampledSignal;
+            fn $op(self, scalar: f64) -> SampledSignal {
+                SampledSignal {
+                    signal: (&self.signal).$op(scalar),
+                    sample_rate: self.sample_rate,
+                }
+            }
+        }
+    };
+}
+
+impl_sampled_ops!(Add, add);
+impl_sampled_ops!(Sub, sub);
+impl_sampled_ops!(Mul, mul);
+impl_sampled_ops!(Div, div);
+
+// 带采样率的波形
+impl Generator {
+    /// Attaches the generator's sample rate to `signal`, typically one produced by this
+    /// generator.
+    ///
+    /// The generator's `start_time` is not carried over: times of the result are measured from
+    /// its first sample.
+    ///
+    /// 为 `signal`（通常由本生成器产生）附加生成器的采样率。
+    ///
+    /// 生成器的 `start_time` 不会被保留：结果的时刻从其第一个样本起算。
+    pub fn sampled(&self, signal: Signal) -> SampledSignal {
+        SampledSignal {
+            signal,
+            sample_rate: self.sample_rate(),
+        }
+    }
+
+    /// Generates a unit sine wave, as `sin_unit`, together with the generator's sample rate.
+    ///
+    /// 生成单位正弦波（同 `sin_unit`），并附带生成器的采样率。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::generator::Generator;
+    /// let generator = Generator::new().sample_rate(1000.0).duration(0.5).build().unwrap();
+    /// let sine = generator.sin_unit_sampled(50.0, 0.0);
+    /// assert_eq!(sine.sample_rate(), 1000.0);
+    /// assert_eq!(sine.duration(), 0.5);
+    /// ```
+    pub fn sin_unit_sampled(&self, freq: f64, phase: f64) -> SampledSignal {
+        self.sampled(self.sin_unit(freq, phase))
+    }
+}
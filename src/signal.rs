@@ -1,7 +1,24 @@
-use crate::inner::base::SignalBase;
+use crate::errors::{
+    DiffError, NormError, ShapeError, SimilarityError, SliceError, StatError, WindowError,
+};
+use crate::generator::Generator;
+use crate::inner::base::{
+    compensated_sum, median_of, sum_of_squares, variance_of, CompensatedSum, SignalBase,
+};
+use crate::window::GainCorrection;
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq};
+use ndarray::{Array1, ArrayView1, ArrayViewMut1};
 use num_traits::AsPrimitive;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::Display;
-use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+pub use crate::inner::base::{ChunkTail, PadMode, PadSide, SignalIndex, StatPad};
+pub use crate::inner::PadError;
 
 /// Represents a signal structure for digital signal processing.
 ///
@@ -9,18 +26,41 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// It offers a high-level interface for various signal processing operations.
 ///
 /// # Features
-/// - Supports indexing with `isize`, allowing for negative indices (circular indexing).
+/// - Supports indexing with `isize`, allowing for negative indices (circular indexing), as well as
+///   with `usize` and integer literals (see `SignalIndex`).
+/// - Supports slicing with `usize` ranges (`a..b`, `..b`, `a..`, `..`, `a..=b`, `..=b`), which
+///   returns a `&[f64]`.
 /// - Implements basic arithmetic operations (+, -, *, /) between signals.
 /// - Supports in-place arithmetic operations (+=, -=, *=, /=) for signals.
-/// - Supports arithmetic operations with scalars on the right side. These scalars can be any type
-///   that implements the `AsPrimitive<f64>` trait.
+/// - Supports negation (`-&signal`), which flips the sign of every sample; zeros become negative
+///   zeros, which compare equal to zero.
+/// - Supports arithmetic operations with scalars on either side. Scalars on the right can be any
+///   type that implements the `AsPrimitive<f64>` trait; scalars on the left can be `f64`, `f32`,
+///   `i32`, `i64`, `u32` or `usize`.
 /// - Provides methods for creating signals from various sources (vectors, iterators, functions).
 /// - Offers utility functions like `zeros`, `ones`, `linspace`, and `arrange` for signal generation.
+/// - Implements `PartialEq` as exact elementwise equality: signals of different lengths are unequal,
+///   and so are signals containing NaN, following IEEE semantics. Use `allclose` for tolerances.
+///   `PartialOrd` is deliberately not implemented, since signals have no meaningful ordering.
+/// - Iterates like a collection: `for x in &signal` borrows each sample as `&f64`, and
+///   `for x in signal` consumes the signal and yields `f64`.
+/// - Grows incrementally like `Vec`: `Default` is the empty signal, and `push`, `with_capacity`
+///   and `Extend` append samples with amortized reallocation.
+/// - Sums, means, variances and energies use compensated (Neumaier) summation, so a small signal
+///   riding on a large DC offset keeps its precision however long the signal is.
 ///
 /// # Important Notes
-/// - All arithmetic operations (including in-place operations) are implemented using reference passing only.
-///   This design choice ensures a unified interface and prevents unintended ownership transfers.
-/// - Scalar operations are only supported with the scalar on the right side of the operator.
+/// - Binary operators accept signals by reference or by value in any combination. When an operand
+///   is passed by value and the lengths match, its buffer is reused for the result instead of
+///   allocating a new one. In-place operators take the right-hand side by reference.
+/// - Scalars on the right of the operator can be of any `AsPrimitive<f64>` type passed by
+///   reference (`&signal * &2u8`); `f64` and `i32` can also be passed by value
+///   (`&signal * 2.0`), which covers unsuffixed literals.
+/// - The orphan rule only allows scalars on the left of the operator for concrete primitive
+///   types: `f64`, `f32`, `i32`, `i64`, `u32` and `usize`, passed by value (`1.0 - &signal`,
+///   `4usize * &signal`) or by reference (`&2.0f32 * &signal`). An unsuffixed literal falls back
+///   to `f64` or `i32`, but only after the result's type is known: calling a method directly on
+///   `1.0 - &signal` needs a suffix (`(1.0f64 - &signal).sum()`).
 ///
 /// # Underlying Implementation
 /// While the core functionality is implemented in the `SignalBase` struct, `Signal` provides a more
@@ -38,6 +78,9 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// let sum = &signal1 + &signal2;
 /// let product = &signal1 * &2.0;  // Scalar on the right side
 /// let product_int = &signal1 * &2;  // Integer scalar also works
+/// let scaled = &signal1 * 0.5;  // f64 and i32 scalars can be passed by value
+/// let inverted = 1.0 - &signal1;  // Scalar on the left side
+/// let owned = sum + signal2.clone();  // Owned operands reuse their buffers
 ///
 /// // In-place operations
 /// signal1 += &signal2;
@@ -46,6 +89,7 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// // Indexing
 /// assert_eq!(signal1[0], 10.0);  // (1.0 + 4.0) * 2.0
 /// assert_eq!(signal1[-1], 18.0);  // (3.0 + 6.0) * 2.0 (circular indexing)
+/// assert_eq!(&signal1[1..], &[14.0, 18.0]);
 /// ```
 ///
 /// 表示用于数字信号处理的信号结构。
@@ -54,17 +98,29 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// 它为各种信号处理操作提供了高级接口。
 ///
 /// # 特性
-/// - 支持使用 `isize` 进行索引，允许负索引（循环索引）。
+/// - 支持使用 `isize` 进行索引，允许负索引（循环索引），也支持 `usize` 与整数字面量（见 `SignalIndex`）。
+/// - 支持使用 `usize` 区间（`a..b`、`..b`、`a..`、`..`、`a..=b`、`..=b`）切片，返回 `&[f64]`。
 /// - 实现了信号之间的基本算术运算（+, -, *, /）。
 /// - 支持信号的原地算术运算（+=, -=, *=, /=）。
-/// - 支持与标量的算术运算，但标量必须在右。这些标量可以是任何实现了 `AsPrimitive<f64>` trait 的类型。
+/// - 支持取负（`-&signal`），翻转每个样本的符号；零变为负零，与零比较相等。
+/// - 支持与标量的算术运算，标量可以在任一侧。右侧标量可以是任何实现了 `AsPrimitive<f64>` trait 的类型；
+///   左侧标量可以是 `f64`、`f32`、`i32`、`i64`、`u32` 或 `usize`。
 /// - 提供了从各种源（向量、迭代器、函数）创建信号的方法。
 /// - 提供了用于信号生成的实用函数，如 `zeros`、`ones`、`linspace` 和 `arrange`。
+/// - `PartialEq` 为逐元素精确比较：长度不同的信号不相等，按 IEEE 语义含 NaN 的信号也不相等。
+///   需要容差时请使用 `allclose`。信号之间没有有意义的顺序，因此有意不实现 `PartialOrd`。
+/// - 可以像集合一样迭代：`for x in &signal` 以 `&f64` 借用每个样本，`for x in signal` 消耗信号并产出 `f64`。
+/// - 可以像 `Vec` 一样逐步增长：`Default` 为空信号，`push`、`with_capacity` 与 `Extend` 以摊还方式扩容并追加样本。
+/// - 和、均值、方差与能量使用补偿（Neumaier）求和，因此无论信号多长，叠加在大直流偏置上的小信号都不会损失精度。
 ///
 /// # 重要说明
-/// - 所有算术运算（包括原地运算）都只实现了引用传递。
-///   这种设计选择确保了统一的接口，并防止了意外的所有权转移。
-/// - 标量运算只支持标量在运算符的右侧。
+/// - 二元运算符接受按引用或按值传入的信号，可以任意组合。某个操作数按值传入且长度一致时，
+///   结果复用它的缓冲区而不分配新内存。原地运算符的右侧按引用传入。
+/// - 运算符右侧的标量可以是任何按引用传入的 `AsPrimitive<f64>` 类型（`&signal * &2u8`）；
+///   `f64` 与 `i32` 还可以按值传入（`&signal * 2.0`），涵盖了无后缀的字面量。
+/// - 孤儿规则只允许为具体的原生类型实现标量在左侧的运算符：`f64`、`f32`、`i32`、`i64`、`u32` 与 `usize`，
+///   可以按值（`1.0 - &signal`、`4usize * &signal`）或按引用（`&2.0f32 * &signal`）传入。无后缀的字面量会回退为
+///   `f64` 或 `i32`，但要等结果类型确定之后：直接在 `1.0 - &signal` 上调用方法需要加后缀（`(1.0f64 - &signal).sum()`）。
 ///
 /// # 底层实现
 /// 虽然核心功能在 `SignalBase` 结构体中实现，但 `Signal` 提供了更加用户友好的接口。
@@ -82,6 +138,9 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// let sum = &signal1 + &signal2;
 /// let product = &signal1 * &2.0;  // 标量在右侧
 /// let product_int = &signal1 * &2;  // 整数标量也可以
+/// let scaled = &signal1 * 0.5;  // f64 与 i32 标量可以按值传入
+/// let inverted = 1.0 - &signal1;  // 标量在左侧
+/// let owned = sum + signal2.clone();  // 按值传入的操作数复用其缓冲区
 ///
 /// // 原地运算
 /// signal1 += &signal2;
@@ -90,8 +149,9 @@ use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign,
 /// // 索引
 /// assert_eq!(signal1[0], 10.0);  // (1.0 + 4.0) * 2.0
 /// assert_eq!(signal1[-1], 18.0);  // (3.0 + 6.0) * 2.0（循环引）
+/// assert_eq!(&signal1[1..], &[14.0, 18.0]);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Signal {
     signal: SignalBase,
 }
@@ -111,6 +171,48 @@ where
     }
 }
 
+impl IntoIterator for Signal {
+    type Item = f64;
+    type IntoIter = <SignalBase as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.signal.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Signal {
+    type Item = &'a f64;
+    type IntoIter = <&'a SignalBase as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        (&self.signal).into_iter()
+    }
+}
+
+impl Extend<f64> for Signal {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, iter: T) {
+        self.signal.extend_from_iter(iter);
+    }
+}
+
+impl<'a> Extend<&'a f64> for Signal {
+    fn extend<T: IntoIterator<Item = &'a f64>>(&mut self, iter: T) {
+        self.signal.extend_from_iter(iter.into_iter().copied());
+    }
+}
+
+impl From<Array1<f64>> for Signal {
+    fn from(array: Array1<f64>) -> Self {
+        Signal::from_array1(array)
+    }
+}
+
+impl From<Signal> for Array1<f64> {
+    fn from(signal: Signal) -> Self {
+        signal.into_array1()
+    }
+}
+
 impl Deref for Signal {
     type Target = SignalBase;
 
@@ -160,6 +262,23 @@ impl Signal {
         Self::from_base(SignalBase::from_vec(vec))
     }
 
+    /// Creates an empty `Signal` with room for at least `capacity` samples, so that the first
+    /// `capacity` calls to `push` do not reallocate.
+    ///
+    /// 创建一个空 `Signal`，预留至少 `capacity` 个样本的空间，前 `capacity` 次 `push` 不会重新分配内存。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::with_capacity(1024);
+    /// assert!(signal.is_empty());
+    /// signal.push(0.5);
+    /// assert_eq!(signal.len(), 1);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::from_base(SignalBase::with_capacity(capacity))
+    }
+
     /// Creates a `Signal` with a specified element.
     ///
     /// # Parameters
@@ -308,139 +427,2930 @@ impl Signal {
     pub fn arrange(start: f64, end_exclude: f64, step: f64) -> Self {
         Self::from_base(SignalBase::arrange(start, end_exclude, step))
     }
-}
 
-// 实现 Signal 与 Signal 的运算
-impl<'a, 'b> Add<&'b Signal> for &'a Signal {
-    type Output = Signal;
-    fn add(self, other: &'b Signal) -> Signal {
-        Signal::from_base(&self.signal + &other.signal)
+    /// Creates a unit impulse (Kronecker delta) signal.
+    ///
+    /// # Parameters
+    /// * `len` - The length of the signal.
+    /// * `at_index` - The index of the sample set to `1.0`.
+    ///
+    /// # Note
+    /// If `at_index >= len` the impulse lies outside the signal and the result is all zeros.
+    ///
+    /// 创建单位冲激（克罗内克 δ）信号。
+    ///
+    /// # 参数
+    /// * `len` - 信号的长度。
+    /// * `at_index` - 取值为 `1.0` 的样本索引。
+    ///
+    /// # 注意
+    /// 若 `at_index >= len`，冲激位于信号之外，结果为全零。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::impulse(4, 1);
+    /// assert_eq!(signal.to_vec(), vec![0.0, 1.0, 0.0, 0.0]);
+    /// ```
+    pub fn impulse(len: usize, at_index: usize) -> Self {
+        Self::from_len_fn(len, |i| if i == at_index { 1.0 } else { 0.0 })
     }
 }
 
-impl<'a, 'b> Sub<&'b Signal> for &'a Signal {
-    type Output = Signal;
-    fn sub(self, other: &'b Signal) -> Signal {
-        Signal::from_base(&self.signal - &other.signal)
+// 元素访问
+impl Signal {
+    /// Returns the sample at `index`, or `None` if it is out of bounds, instead of panicking
+    /// like `signal[index]`.
+    ///
+    /// Negative indices count from the end as in bracket indexing, so `-len` is the first
+    /// sample and `-len - 1` is out of bounds.
+    ///
+    /// 返回位于 `index` 的样本，越界时返回 `None`，而不像 `signal[index]` 那样恐慌。
+    ///
+    /// 与方括号索引一样，负索引从末尾计数，因此 `-len` 是第一个样本，`-len - 1` 越界。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.get(-1), Some(3.0));
+    /// assert_eq!(signal.get(-3), Some(1.0));
+    /// assert_eq!(signal.get(3), None);
+    /// ```
+    pub fn get<I: SignalIndex>(&self, index: I) -> Option<f64> {
+        self.signal.get(index)
     }
-}
 
-impl<'a, 'b> Mul<&'b Signal> for &'a Signal {
-    type Output = Signal;
-    fn mul(self, other: &'b Signal) -> Signal {
-        Signal::from_base(&self.signal * &other.signal)
+    /// Returns a mutable reference to the sample at `index`, or `None` if it is out of bounds.
+    /// Negative indices count from the end.
+    ///
+    /// 返回位于 `index` 的样本的可变引用，越界时返回 `None`。负索引从末尾计数。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// if let Some(x) = signal.get_mut(-2) {
+    ///     *x = 20.0;
+    /// }
+    /// assert!(signal.get_mut(-4).is_none());
+    /// assert_eq!(signal.to_vec(), vec![1.0, 20.0, 3.0]);
+    /// ```
+    pub fn get_mut<I: SignalIndex>(&mut self, index: I) -> Option<&mut f64> {
+        self.signal.get_mut(index)
     }
-}
 
-impl<'a, 'b> Div<&'b Signal> for &'a Signal {
-    type Output = Signal;
-    fn div(self, other: &'b Signal) -> Signal {
-        Signal::from_base(&self.signal / &other.signal)
+    /// Returns the first sample, or `None` if the signal is empty.
+    ///
+    /// 返回第一个样本，信号为空时返回 `None`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// assert_eq!(Signal::from_vec(vec![1.0, 2.0]).first(), Some(1.0));
+    /// assert_eq!(Signal::zeros(0).first(), None);
+    /// ```
+    pub fn first(&self) -> Option<f64> {
+        self.signal.get(0)
     }
-}
 
-// 实现 Signal 的原地运算
-impl<'a> AddAssign<&'a Signal> for Signal {
-    fn add_assign(&mut self, other: &'a Signal) {
-        self.signal += &other.signal;
+    /// Returns the last sample, or `None` if the signal is empty.
+    ///
+    /// 返回最后一个样本，信号为空时返回 `None`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// assert_eq!(Signal::from_vec(vec![1.0, 2.0]).last(), Some(2.0));
+    /// assert_eq!(Signal::zeros(0).last(), None);
+    /// ```
+    pub fn last(&self) -> Option<f64> {
+        self.signal.get(-1)
     }
-}
 
-impl<'a> SubAssign<&'a Signal> for Signal {
-    fn sub_assign(&mut self, other: &'a Signal) {
-        self.signal -= &other.signal;
+    /// Returns `true` if the signal has no samples.
+    ///
+    /// 信号没有样本时返回 `true`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// assert!(Signal::zeros(0).is_empty());
+    /// assert!(!Signal::zeros(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.signal.is_empty()
     }
 }
 
-impl<'a> MulAssign<&'a Signal> for Signal {
-    fn mul_assign(&mut self, other: &'a Signal) {
-        self.signal *= &other.signal;
+// 与 ndarray 互转
+impl Signal {
+    /// Creates a `Signal` that takes ownership of an `Array1<f64>`.
+    ///
+    /// An array in standard layout is taken over without copying. Any other layout, such as a
+    /// reversed or strided slice, is copied into contiguous storage first, because the signal's
+    /// slice views rely on it.
+    ///
+    /// 创建一个接管 `Array1<f64>` 所有权的 `Signal`。
+    ///
+    /// 标准布局的数组直接接管，不复制数据。其他布局（如反向或带步长的切片）先复制为连续存储，
+    /// 因为信号的切片视图依赖连续存储。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use ndarray::array;
+    /// let signal = Signal::from_array1(array![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.to_vec(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_array1(array: Array1<f64>) -> Self {
+        Self::from_base(SignalBase::from_array1(array))
     }
-}
 
-impl<'a> DivAssign<&'a Signal> for Signal {
-    fn div_assign(&mut self, other: &'a Signal) {
-        self.signal /= &other.signal;
+    /// Consumes the signal and returns the underlying `Array1<f64>` without copying it.
+    ///
+    /// 消耗信号并返回底层的 `Array1<f64>`，不复制数据。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use ndarray::array;
+    /// let array = Signal::from_vec(vec![1.0, 2.0]).into_array1();
+    /// assert_eq!(array, array![1.0, 2.0]);
+    /// ```
+    pub fn into_array1(self) -> Array1<f64> {
+        self.signal.into_array1()
     }
-}
 
-// 实现 Signal 与标量的运算
-impl<'a, T> Add<&'a T> for &'a Signal
-where
-    T: AsPrimitive<f64>,
-{
-    type Output = Signal;
-    fn add(self, other: &'a T) -> Signal {
-        Signal::from_base(&self.signal + other)
+    /// Returns a read-only ndarray view of the samples, for passing to other ndarray-based
+    /// code.
+    ///
+    /// 返回样本的只读 ndarray 视图，便于传给其他基于 ndarray 的代码。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.as_array().sum(), 6.0);
+    /// ```
+    pub fn as_array(&self) -> ArrayView1<'_, f64> {
+        self.signal.as_array()
     }
-}
 
-impl<'a, T> Sub<&'a T> for &'a Signal
-where
-    T: AsPrimitive<f64>,
-{
-    type Output = Signal;
-    fn sub(self, other: &'a T) -> Signal {
-        Signal::from_base(&self.signal - other)
+    /// Returns a mutable ndarray view of the samples, for modifying the signal in place with
+    /// ndarray operations.
+    ///
+    /// 返回样本的可变 ndarray 视图，便于用 ndarray 的运算原地修改信号。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// signal.as_array_mut().mapv_inplace(|x| x * 10.0);
+    /// assert_eq!(signal.to_vec(), vec![10.0, 20.0, 30.0]);
+    /// ```
+    pub fn as_array_mut(&mut self) -> ArrayViewMut1<'_, f64> {
+        self.signal.as_array_mut()
     }
 }
 
-impl<'a, T> Mul<&'a T> for &'a Signal
-where
-    T: AsPrimitive<f64>,
-{
-    type Output = Signal;
-    fn mul(self, other: &'a T) -> Signal {
-        Signal::from_base(&self.signal * other)
+// 逐步增长
+impl Signal {
+    /// Appends one sample to the end of the signal. Like `Vec::push`, the storage grows
+    /// geometrically, so pushing `n` samples costs amortized O(n).
+    ///
+    /// 在信号末尾追加一个样本。与 `Vec::push` 一样，存储空间按几何级数增长，因此追加 `n` 个样本的摊还开销为 O(n)。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::default();
+    /// signal.push(1.0);
+    /// signal.push(-2.0);
+    /// assert_eq!(signal.to_vec(), vec![1.0, -2.0]);
+    /// ```
+    pub fn push(&mut self, sample: f64) {
+        self.signal.push(sample);
     }
 }
 
-impl<'a, T> Div<&'a T> for &'a Signal
-where
-    T: AsPrimitive<f64>,
-{
-    type Output = Signal;
-    fn div(self, other: &'a T) -> Signal {
-        Signal::from_base(&self.signal / other)
+// f32 互转
+impl Signal {
+    /// Creates a `Signal` from `f32` samples, widening each to `f64` exactly.
+    ///
+    /// 从 `f32` 样本创建 `Signal`，每个样本被精确地扩展为 `f64`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_slice_f32(&[0.5, -0.25]);
+    /// assert_eq!(signal.to_vec(), vec![0.5, -0.25]);
+    /// ```
+    pub fn from_slice_f32(samples: &[f32]) -> Self {
+        samples.iter().map(|&x| x as f64).collect()
     }
-}
 
-// 为 Signal 实现与引用标量的原地运算
-impl<'a, T> AddAssign<&'a T> for Signal
-where
-    T: AsPrimitive<f64>,
-{
-    fn add_assign(&mut self, other: &'a T) {
-        self.signal += other;
+    /// Converts the samples to `f32`, e.g. for audio APIs.
+    ///
+    /// Values representable in `f32` convert exactly, so `from_slice_f32` followed by
+    /// `to_vec_f32` is lossless. Other values are rounded to the nearest `f32` (ties to even);
+    /// magnitudes beyond `f32::MAX` become infinite, magnitudes below the smallest subnormal
+    /// become zero with the same sign, and NaN stays NaN.
+    ///
+    /// 将样本转换为 `f32`，例如供音频 API 使用。
+    ///
+    /// 可用 `f32` 表示的值被精确转换，因此先 `from_slice_f32` 再 `to_vec_f32` 是无损的。其他值舍入到最近的
+    /// `f32`（恰在中间时取偶数）；绝对值超过 `f32::MAX` 的值变为无穷大，绝对值小于最小次正规数的值变为同号的零，
+    /// NaN 仍为 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.5, 0.1, 1e300]);
+    /// assert_eq!(signal.to_vec_f32(), vec![0.5, 0.1_f32, f32::INFINITY]);
+    /// ```
+    pub fn to_vec_f32(&self) -> Vec<f32> {
+        self.iter().map(|&x| x as f32).collect()
     }
-}
 
-impl<'a, T> SubAssign<&'a T> for Signal
-where
-    T: AsPrimitive<f64>,
-{
-    fn sub_assign(&mut self, other: &'a T) {
-        self.signal -= other;
+    /// Appends `f32` samples to the end of the signal, widening each to `f64` exactly.
+    ///
+    /// 在信号末尾追加 `f32` 样本，每个样本被精确地扩展为 `f64`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::from_vec(vec![1.0]);
+    /// signal.extend_from_f32(&[2.0, 3.5]);
+    /// assert_eq!(signal.to_vec(), vec![1.0, 2.0, 3.5]);
+    /// ```
+    pub fn extend_from_f32(&mut self, samples: &[f32]) {
+        self.signal
+            .extend_from_iter(samples.iter().map(|&x| x as f64));
     }
 }
 
-impl<'a, T> MulAssign<&'a T> for Signal
-where
-    T: AsPrimitive<f64>,
-{
-    fn mul_assign(&mut self, other: &'a T) {
-        self.signal *= other;
+// 逐元素运算
+impl Signal {
+    /// Returns a copy with every sample limited to `[min, max]`.
+    ///
+    /// A `None` bound leaves that side unbounded. NaN samples pass through unchanged.
+    ///
+    /// # Panics
+    /// Panics if `min > max` or if a bound is NaN.
+    ///
+    /// 返回每个样本都被限制在 `[min, max]` 内的副本。
+    ///
+    /// 某个界为 `None` 时该侧不受限制。NaN 样本原样保留。
+    ///
+    /// # 恐慌
+    /// `min > max` 或某个界为 NaN 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-2.0, 0.5, 3.0]);
+    /// assert_eq!(signal.clip(Some(-1.0), Some(1.0)).to_vec(), vec![-1.0, 0.5, 1.0]);
+    /// assert_eq!(signal.clip(None, Some(1.0)).to_vec(), vec![-2.0, 0.5, 1.0]);
+    /// ```
+    pub fn clip(&self, min: Option<f64>, max: Option<f64>) -> Signal {
+        Signal::from_base(self.signal.clip(min, max))
     }
-}
 
-impl<'a, T> DivAssign<&'a T> for Signal
-where
-    T: AsPrimitive<f64>,
-{
-    fn div_assign(&mut self, other: &'a T) {
-        self.signal /= other;
+    /// Limits every sample to `[min, max]` in place, without allocating.
+    ///
+    /// See `clip` for the handling of `None` bounds and NaN samples.
+    ///
+    /// # Panics
+    /// Panics if `min > max` or if a bound is NaN.
+    ///
+    /// 原地将每个样本限制在 `[min, max]` 内，不分配内存。
+    ///
+    /// `None` 界与 NaN 样本的处理见 `clip`。
+    ///
+    /// # 恐慌
+    /// `min > max` 或某个界为 NaN 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let mut signal = Signal::from_vec(vec![-2.0, 0.5, 3.0]);
+    /// signal.clip_inplace(Some(0.0), None);
+    /// assert_eq!(signal.to_vec(), vec![0.0, 0.5, 3.0]);
+    /// ```
+    pub fn clip_inplace(&mut self, min: Option<f64>, max: Option<f64>) {
+        self.signal.clip_inplace(min, max)
     }
-}
+
+    /// Returns a copy holding the absolute value of each sample.
+    ///
+    /// Negative zero becomes positive zero.
+    ///
+    /// 返回由每个样本的绝对值组成的副本。
+    ///
+    /// 负零变为正零。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-1.5, -0.0, 2.0]);
+    /// assert_eq!(signal.abs().to_vec(), vec![1.5, 0.0, 2.0]);
+    /// ```
+    pub fn abs(&self) -> Signal {
+        Signal::from_base(self.signal.abs())
+    }
+
+    /// Replaces each sample with its absolute value, in place.
+    ///
+    /// 原地将每个样本替换为其绝对值。
+    pub fn abs_inplace(&mut self) {
+        self.signal.abs_inplace()
+    }
+
+    /// Returns a copy holding the sign of each sample, following `f64::signum`.
+    ///
+    /// `0.0` maps to `1.0`, `-0.0` maps to `-1.0` and NaN stays NaN.
+    ///
+    /// 返回由每个样本的符号组成的副本，遵循 `f64::signum`。
+    ///
+    /// `0.0` 映射为 `1.0`，`-0.0` 映射为 `-1.0`，NaN 保持为 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-2.0, 0.0, 3.0]);
+    /// assert_eq!(signal.signum().to_vec(), vec![-1.0, 1.0, 1.0]);
+    /// ```
+    pub fn signum(&self) -> Signal {
+        Signal::from_base(self.signal.signum())
+    }
+
+    /// Replaces each sample with its sign in place, following `f64::signum`.
+    ///
+    /// 原地将每个样本替换为其符号，遵循 `f64::signum`。
+    pub fn signum_inplace(&mut self) {
+        self.signal.signum_inplace()
+    }
+
+    /// Returns a copy holding each sample rounded toward negative infinity.
+    ///
+    /// 返回由每个样本向负无穷取整组成的副本。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-1.5, 0.5, 2.0]);
+    /// assert_eq!(signal.floor().to_vec(), vec![-2.0, 0.0, 2.0]);
+    /// ```
+    pub fn floor(&self) -> Signal {
+        Signal::from_base(self.signal.floor())
+    }
+
+    /// Rounds each sample toward negative infinity, in place.
+    ///
+    /// 原地将每个样本向负无穷取整。
+    pub fn floor_inplace(&mut self) {
+        self.signal.floor_inplace()
+    }
+
+    /// Returns a copy holding each sample rounded toward positive infinity.
+    ///
+    /// 返回由每个样本向正无穷取整组成的副本。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-1.5, 0.5, 2.0]);
+    /// assert_eq!(signal.ceil().to_vec(), vec![-1.0, 1.0, 2.0]);
+    /// ```
+    pub fn ceil(&self) -> Signal {
+        Signal::from_base(self.signal.ceil())
+    }
+
+    /// Rounds each sample toward positive infinity, in place.
+    ///
+    /// 原地将每个样本向正无穷取整。
+    pub fn ceil_inplace(&mut self) {
+        self.signal.ceil_inplace()
+    }
+
+    /// Returns a copy holding each sample rounded to the nearest integer.
+    ///
+    /// Ties round away from zero, as in `f64::round`:
+    /// `2.5` becomes `3.0` and `-2.5` becomes `-3.0`.
+    ///
+    /// 返回由每个样本四舍五入到最近的整数组成的副本。
+    ///
+    /// 与 `f64::round` 相同，恰好位于中间的值向远离零的方向取整：`2.5` 变为 `3.0`，`-2.5` 变为 `-3.0`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-2.5, 0.4, 2.5]);
+    /// assert_eq!(signal.round().to_vec(), vec![-3.0, 0.0, 3.0]);
+    /// ```
+    pub fn round(&self) -> Signal {
+        Signal::from_base(self.signal.round())
+    }
+
+    /// Rounds each sample to the nearest integer, in place, with ties away from zero.
+    ///
+    /// 原地将每个样本四舍五入到最近的整数，中间值向远离零的方向取整。
+    pub fn round_inplace(&mut self) {
+        self.signal.round_inplace()
+    }
+
+    /// Returns a copy holding the reciprocal `1 / x` of each sample.
+    ///
+    /// Follows IEEE division: `0.0` maps to `+inf`, `-0.0` maps to `-inf`, infinities map to
+    /// zeros of the same sign and NaN stays NaN.
+    ///
+    /// 返回由每个样本的倒数 `1 / x` 组成的副本。
+    ///
+    /// 遵循 IEEE 除法：`0.0` 映射为 `+inf`，`-0.0` 映射为 `-inf`，无穷映射为同号的零，NaN 保持为 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![2.0, -4.0, 0.0]);
+    /// assert_eq!(signal.recip().to_vec(), vec![0.5, -0.25, f64::INFINITY]);
+    /// ```
+    pub fn recip(&self) -> Signal {
+        Signal::from_base(self.signal.recip())
+    }
+
+    /// Replaces each sample with its reciprocal `1 / x`, in place.
+    ///
+    /// 原地将每个样本替换为其倒数 `1 / x`。
+    pub fn recip_inplace(&mut self) {
+        self.signal.recip_inplace()
+    }
+}
+
+// 组合映射
+impl Signal {
+    /// Combines two signals elementwise with `f(self[i], other[i])`.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 用 `f(self[i], other[i])` 逐元素组合两个信号。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let a = Signal::from_vec(vec![1.0, 5.0, -2.0]);
+    /// let b = Signal::from_vec(vec![3.0, 2.0, -1.0]);
+    /// assert_eq!(a.zip_map(&b, f64::max).unwrap().to_vec(), vec![3.0, 5.0, -1.0]);
+    /// ```
+    pub fn zip_map<F>(&self, other: &Signal, f: F) -> Result<Signal, ShapeError>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        self.check_same_len(other)?;
+        Ok(Signal::from_base(self.signal.zip_map(&other.signal, f)))
+    }
+
+    /// Maps each sample with `f(i, self[i])`, where `i` is the sample index, e.g. to apply a
+    /// time-varying gain.
+    ///
+    /// 用 `f(i, self[i])` 映射每个样本，其中 `i` 为样本索引，例如用于施加随时间变化的增益。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::ones(4);
+    /// let fade_in = signal.enumerate_map(|i, x| x * i as f64 / 3.0);
+    /// assert_eq!(fade_in.to_vec(), vec![0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+    /// ```
+    pub fn enumerate_map<F>(&self, f: F) -> Signal
+    where
+        F: Fn(usize, f64) -> f64,
+    {
+        Signal::from_base(self.signal.enumerate_map(f))
+    }
+}
+
+// 幂、取余与比较掩码
+impl Signal {
+    /// Returns a copy with each sample raised to the floating-point power `exponent`, following
+    /// `f64::powf`.
+    ///
+    /// A negative sample raised to a non-integer power gives NaN.
+    ///
+    /// 返回每个样本取 `exponent` 次浮点幂后的副本，遵循 `f64::powf`。
+    ///
+    /// 负样本的非整数次幂为 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![4.0, 9.0, -1.0]);
+    /// let roots = signal.powf(0.5);
+    /// assert_eq!(roots.to_vec()[..2], [2.0, 3.0]);
+    /// assert!(roots[2].is_nan());
+    /// ```
+    pub fn powf(&self, exponent: f64) -> Signal {
+        Signal::from_base(self.signal.powf(exponent))
+    }
+
+    /// Returns a copy with each sample raised to the integer power `exponent`, following
+    /// `f64::powi`.
+    ///
+    /// Negative samples keep their sign for odd exponents and become positive for even ones.
+    ///
+    /// 返回每个样本取 `exponent` 次整数幂后的副本，遵循 `f64::powi`。
+    ///
+    /// 奇数次幂保留负样本的符号，偶数次幂使其变为正数。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![-2.0, 3.0]);
+    /// assert_eq!(signal.powi(3).to_vec(), vec![-8.0, 27.0]);
+    /// assert_eq!(signal.powi(2).to_vec(), vec![4.0, 9.0]);
+    /// ```
+    pub fn powi(&self, exponent: i32) -> Signal {
+        Signal::from_base(self.signal.powi(exponent))
+    }
+
+    /// Returns the elementwise remainder `self[i] % other[i]`.
+    ///
+    /// Follows `f64::rem`: the result has the sign of the dividend, and a zero divisor gives NaN.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 返回逐元素的余数 `self[i] % other[i]`。
+    ///
+    /// 遵循 `f64::rem`：结果与被除数同号，除数为零时结果为 NaN。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let x = Signal::from_vec(vec![7.0, -7.0, 7.5]);
+    /// let y = Signal::from_vec(vec![3.0, 3.0, -2.0]);
+    /// assert_eq!(x.rem(&y).unwrap().to_vec(), vec![1.0, -1.0, 1.5]);
+    /// ```
+    pub fn rem(&self, other: &Signal) -> Result<Signal, ShapeError> {
+        self.check_same_len(other)?;
+        Ok(Signal::from_base(
+            self.signal.zip_map(&other.signal, |x, y| x % y),
+        ))
+    }
+
+    /// Returns the remainder of each sample divided by `divisor`, following `f64::rem`.
+    ///
+    /// The result has the sign of the sample; a zero divisor gives NaN.
+    ///
+    /// 返回每个样本除以 `divisor` 的余数，遵循 `f64::rem`。
+    ///
+    /// 结果与样本同号；除数为零时结果为 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let phase = Signal::from_vec(vec![370.0, -10.0]);
+    /// assert_eq!(phase.rem_scalar(360.0).to_vec(), vec![10.0, -10.0]);
+    /// ```
+    pub fn rem_scalar(&self, divisor: f64) -> Signal {
+        Signal::from_base(self.signal.rem_scalar(divisor))
+    }
+
+    /// Returns a mask that is `1.0` where the sample is greater than `threshold` and `0.0`
+    /// elsewhere. NaN samples give `0.0`, as every comparison with NaN is false.
+    ///
+    /// 返回掩码：样本大于 `threshold` 处为 `1.0`，其余为 `0.0`。与 NaN 的比较总为假，因此 NaN 样本得到 `0.0`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.2, 0.8, 0.5]);
+    /// assert_eq!(signal.gt(0.5).to_vec(), vec![0.0, 1.0, 0.0]);
+    /// ```
+    pub fn gt(&self, threshold: f64) -> Signal {
+        Signal::from_base(self.signal.mask(|x| x > threshold))
+    }
+
+    /// Returns a mask that is `1.0` where the sample is less than `threshold` and `0.0`
+    /// elsewhere. NaN samples give `0.0`.
+    ///
+    /// 返回掩码：样本小于 `threshold` 处为 `1.0`，其余为 `0.0`。NaN 样本得到 `0.0`。
+    pub fn lt(&self, threshold: f64) -> Signal {
+        Signal::from_base(self.signal.mask(|x| x < threshold))
+    }
+
+    /// Returns a mask that is `1.0` where the sample is greater than or equal to `threshold`
+    /// and `0.0` elsewhere. NaN samples give `0.0`.
+    ///
+    /// 返回掩码：样本大于或等于 `threshold` 处为 `1.0`，其余为 `0.0`。NaN 样本得到 `0.0`。
+    pub fn ge(&self, threshold: f64) -> Signal {
+        Signal::from_base(self.signal.mask(|x| x >= threshold))
+    }
+
+    /// Returns a mask that is `1.0` where the sample is less than or equal to `threshold` and
+    /// `0.0` elsewhere. NaN samples give `0.0`.
+    ///
+    /// 返回掩码：样本小于或等于 `threshold` 处为 `1.0`，其余为 `0.0`。NaN 样本得到 `0.0`。
+    pub fn le(&self, threshold: f64) -> Signal {
+        Signal::from_base(self.signal.mask(|x| x <= threshold))
+    }
+
+    /// Chooses each sample from `self` where `mask` is nonzero and from `other` where it is zero.
+    ///
+    /// Any nonzero mask value counts, including NaN, so the masks returned by `gt`, `lt`, `ge`
+    /// and `le` and their products (logical and) can be used directly.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if `mask` or `other` differs in length from `self`.
+    ///
+    /// 在 `mask` 非零处取 `self` 的样本，在为零处取 `other` 的样本。
+    ///
+    /// 任何非零的掩码值（包括 NaN）都算作选中，因此 `gt`、`lt`、`ge`、`le` 返回的掩码及其乘积（逻辑与）可以直接使用。
+    ///
+    /// # 错误
+    /// `mask` 或 `other` 与 `self` 长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// // 噪声门：低于阈值的样本置零
+    /// let signal = Signal::from_vec(vec![0.05, -0.8, 0.3, -0.01]);
+    /// let gated = signal.select(&signal.abs().ge(0.1), &Signal::zeros(4)).unwrap();
+    /// assert_eq!(gated.to_vec(), vec![0.0, -0.8, 0.3, 0.0]);
+    /// ```
+    pub fn select(&self, mask: &Signal, other: &Signal) -> Result<Signal, ShapeError> {
+        self.check_same_len(mask)?;
+        self.check_same_len(other)?;
+        Ok(self
+            .iter()
+            .zip(mask.iter())
+            .zip(other.iter())
+            .map(|((&x, &m), &y)| if m != 0.0 { x } else { y })
+            .collect())
+    }
+}
+
+// 初等函数
+impl Signal {
+    /// Returns a copy holding the sine of each sample, taken in radians.
+    ///
+    /// 返回由每个样本的正弦（以弧度为单位）组成的副本。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, std::f64::consts::FRAC_PI_2]);
+    /// assert_eq!(signal.sin().to_vec(), vec![0.0, 1.0]);
+    /// ```
+    pub fn sin(&self) -> Signal {
+        Signal::from_base(self.signal.sin())
+    }
+
+    /// Replaces each sample with its sine, in place.
+    ///
+    /// 原地将每个样本替换为其正弦。
+    pub fn sin_inplace(&mut self) {
+        self.signal.sin_inplace()
+    }
+
+    /// Returns a copy holding the cosine of each sample, taken in radians.
+    ///
+    /// 返回由每个样本的余弦（以弧度为单位）组成的副本。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, std::f64::consts::PI]);
+    /// assert_eq!(signal.cos().to_vec(), vec![1.0, -1.0]);
+    /// ```
+    pub fn cos(&self) -> Signal {
+        Signal::from_base(self.signal.cos())
+    }
+
+    /// Replaces each sample with its cosine, in place.
+    ///
+    /// 原地将每个样本替换为其余弦。
+    pub fn cos_inplace(&mut self) {
+        self.signal.cos_inplace()
+    }
+
+    /// Returns a copy holding the tangent of each sample, taken in radians.
+    ///
+    /// π/2 is not exactly representable, so samples at odd multiples of π/2 give very large finite
+    /// values rather than infinities.
+    ///
+    /// 返回由每个样本的正切（以弧度为单位）组成的副本。
+    ///
+    /// π/2 无法精确表示，因此位于 π/2 奇数倍处的样本得到很大的有限值而不是无穷。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, std::f64::consts::FRAC_PI_2]);
+    /// let tan = signal.tan();
+    /// assert_eq!(tan[0], 0.0);
+    /// assert!(tan[1].is_finite() && tan[1] > 1e15);
+    /// ```
+    pub fn tan(&self) -> Signal {
+        Signal::from_base(self.signal.tan())
+    }
+
+    /// Replaces each sample with its tangent, in place.
+    ///
+    /// 原地将每个样本替换为其正切。
+    pub fn tan_inplace(&mut self) {
+        self.signal.tan_inplace()
+    }
+
+    /// Returns a copy holding `e` raised to the power of each sample.
+    ///
+    /// 返回由以 `e` 为底、以每个样本为指数的幂组成的副本。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, f64::NEG_INFINITY]);
+    /// assert_eq!(signal.exp().to_vec(), vec![1.0, 0.0]);
+    /// ```
+    pub fn exp(&self) -> Signal {
+        Signal::from_base(self.signal.exp())
+    }
+
+    /// Replaces each sample with its exponential, in place.
+    ///
+    /// 原地将每个样本替换为其指数。
+    pub fn exp_inplace(&mut self) {
+        self.signal.exp_inplace()
+    }
+
+    /// Returns a copy holding the natural logarithm of each sample.
+    ///
+    /// Follows IEEE semantics: `0.0` gives `-inf` and negative samples give NaN.
+    ///
+    /// 返回由每个样本的自然对数组成的副本。
+    ///
+    /// 遵循 IEEE 语义：`0.0` 得到 `-inf`，负样本得到 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 0.0, -1.0]);
+    /// let ln = signal.ln();
+    /// assert_eq!(ln[0], 0.0);
+    /// assert_eq!(ln[1], f64::NEG_INFINITY);
+    /// assert!(ln[2].is_nan());
+    /// ```
+    pub fn ln(&self) -> Signal {
+        Signal::from_base(self.signal.ln())
+    }
+
+    /// Replaces each sample with its natural logarithm, in place.
+    ///
+    /// 原地将每个样本替换为其自然对数。
+    pub fn ln_inplace(&mut self) {
+        self.signal.ln_inplace()
+    }
+
+    /// Returns a copy holding the base-10 logarithm of each sample.
+    ///
+    /// Follows IEEE semantics: `0.0` gives `-inf` and negative samples give NaN.
+    ///
+    /// 返回由每个样本以 10 为底的对数组成的副本。
+    ///
+    /// 遵循 IEEE 语义：`0.0` 得到 `-inf`，负样本得到 NaN。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 100.0, 0.0]);
+    /// assert_eq!(signal.log10().to_vec(), vec![0.0, 2.0, f64::NEG_INFINITY]);
+    /// ```
+    pub fn log10(&self) -> Signal {
+        Signal::from_base(self.signal.log10())
+    }
+
+    /// Replaces each sample with its base-10 logarithm, in place.
+    ///
+    /// 原地将每个样本替换为其以 10 为底的对数。
+    pub fn log10_inplace(&mut self) {
+        self.signal.log10_inplace()
+    }
+
+    /// Returns a copy holding the square root of each sample.
+    ///
+    /// Follows IEEE semantics: negative samples give NaN, while `-0.0` gives `-0.0`.
+    ///
+    /// 返回由每个样本的平方根组成的副本。
+    ///
+    /// 遵循 IEEE 语义：负样本得到 NaN，而 `-0.0` 得到 `-0.0`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![4.0, 9.0, -1.0]);
+    /// let roots = signal.sqrt();
+    /// assert_eq!(roots.to_vec()[..2], [2.0, 3.0]);
+    /// assert!(roots[2].is_nan());
+    /// ```
+    pub fn sqrt(&self) -> Signal {
+        Signal::from_base(self.signal.sqrt())
+    }
+
+    /// Replaces each sample with its square root, in place.
+    ///
+    /// 原地将每个样本替换为其平方根。
+    pub fn sqrt_inplace(&mut self) {
+        self.signal.sqrt_inplace()
+    }
+
+    /// Returns a copy holding the hyperbolic tangent of each sample.
+    ///
+    /// The result lies in `[-1, 1]`, so `tanh` also serves as a smooth soft clipper.
+    ///
+    /// 返回由每个样本的双曲正切组成的副本。
+    ///
+    /// 结果位于 `[-1, 1]` 内，因此 `tanh` 也可以用作平滑的软削波。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, f64::INFINITY, f64::NEG_INFINITY]);
+    /// assert_eq!(signal.tanh().to_vec(), vec![0.0, 1.0, -1.0]);
+    /// ```
+    pub fn tanh(&self) -> Signal {
+        Signal::from_base(self.signal.tanh())
+    }
+
+    /// Replaces each sample with its hyperbolic tangent, in place.
+    ///
+    /// 原地将每个样本替换为其双曲正切。
+    pub fn tanh_inplace(&mut self) {
+        self.signal.tanh_inplace()
+    }
+
+    /// Returns the four-quadrant arctangent `atan2(self[i], other[i])`, treating `self` as the
+    /// `y` coordinates and `other` as the `x` coordinates, following `f64::atan2`.
+    ///
+    /// The result lies in `[-π, π]`. This recovers the phase of a quadrature pair
+    /// `(sin θ, cos θ)`.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 返回四象限反正切 `atan2(self[i], other[i])`，`self` 为 `y` 坐标，`other` 为 `x` 坐标，遵循 `f64::atan2`。
+    ///
+    /// 结果位于 `[-π, π]` 内，可以从正交信号对 `(sin θ, cos θ)` 中恢复相位。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let y = Signal::from_vec(vec![1.0, 0.0, -1.0]);
+    /// let x = Signal::from_vec(vec![0.0, -1.0, 0.0]);
+    /// let phase = y.atan2(&x).unwrap();
+    /// assert_eq!(phase.to_vec(), vec![
+    ///     std::f64::consts::FRAC_PI_2,
+    ///     std::f64::consts::PI,
+    ///     -std::f64::consts::FRAC_PI_2,
+    /// ]);
+    /// ```
+    pub fn atan2(&self, other: &Signal) -> Result<Signal, ShapeError> {
+        self.check_same_len(other)?;
+        Ok(Signal::from_base(
+            self.signal.zip_map(&other.signal, f64::atan2),
+        ))
+    }
+
+    /// Replaces each sample `y` with `atan2(y, other[i])`, in place.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length; `self` is left
+    /// unchanged.
+    ///
+    /// 原地将每个样本 `y` 替换为 `atan2(y, other[i])`。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`，`self` 保持不变。
+    pub fn atan2_inplace(&mut self, other: &Signal) -> Result<(), ShapeError> {
+        self.check_same_len(other)?;
+        self.signal.zip_map_inplace(&other.signal, f64::atan2);
+        Ok(())
+    }
+}
+
+// 能量与均方根
+impl Signal {
+    /// Returns the sum of the squared samples, `Σ x[i]²`. An empty signal gives 0.
+    ///
+    /// `energy` and `rms` are both computed from this single pass.
+    ///
+    /// 返回样本平方和 `Σ x[i]²`。空信号得到 0。
+    ///
+    /// `energy` 与 `rms` 都基于这一次遍历计算。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -2.0, 2.0]);
+    /// assert_eq!(signal.sum_of_squares(), 9.0);
+    /// ```
+    pub fn sum_of_squares(&self) -> f64 {
+        self.signal.sum_of_squares()
+    }
+
+    /// Returns the root mean square `sqrt(Σ x[i]² / len)`, or `None` for an empty signal.
+    ///
+    /// 返回均方根 `sqrt(Σ x[i]² / len)`，空信号返回 `None`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -2.0, 2.0]);
+    /// assert_eq!(signal.rms(), Some(3f64.sqrt()));
+    /// assert_eq!(Signal::from_vec(vec![]).rms(), None);
+    /// ```
+    pub fn rms(&self) -> Option<f64> {
+        self.signal.rms()
+    }
+
+    /// Returns the crest factor, the peak absolute value over the RMS.
+    ///
+    /// It is `√2` for a sine, 1 for a square wave and `√len` for a single impulse, so a high
+    /// value flags impacts or a risk of clipping. Use `papr_db` for the same figure in dB.
+    ///
+    /// # Errors
+    /// - `StatError::EmptyInput` if the signal is empty.
+    /// - `StatError::ZeroDenominator` if the RMS is zero.
+    ///
+    /// 返回峰值因数，即绝对值峰值与均方根之比。
+    ///
+    /// 正弦为 `√2`，方波为 1，单个冲激为 `√len`，因此数值偏高提示存在冲击或削波风险。
+    /// 以 dB 表示的同一指标见 `papr_db`。
+    ///
+    /// # 错误
+    /// - 信号为空时返回 `StatError::EmptyInput`。
+    /// - 均方根为零时返回 `StatError::ZeroDenominator`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, -1.0, 1.0, -1.0]);
+    /// assert_eq!(signal.crest_factor().unwrap(), 1.0);
+    /// ```
+    pub fn crest_factor(&self) -> Result<f64, StatError> {
+        let rms = self.rms().ok_or(StatError::EmptyInput)?;
+        if rms == 0.0 {
+            return Err(StatError::ZeroDenominator("rms"));
+        }
+        let peak = self.iter().fold(0.0, |peak: f64, x| peak.max(x.abs()));
+        Ok(peak / rms)
+    }
+
+    /// Returns the peak-to-average power ratio in dB, `20·log10(crest_factor)`.
+    ///
+    /// # Errors
+    /// Same as `crest_factor`.
+    ///
+    /// 返回以 dB 为单位的峰均功率比 `20·log10(crest_factor)`。
+    ///
+    /// # 错误
+    /// 与 `crest_factor` 相同。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let impulse = Signal::from_vec(vec![0.0, 0.0, 0.0, 4.0]);
+    /// assert!((impulse.papr_db().unwrap() - 6.0206).abs() < 1e-4);
+    /// ```
+    pub fn papr_db(&self) -> Result<f64, StatError> {
+        Ok(20.0 * self.crest_factor()?.log10())
+    }
+
+    /// Returns the energy `Σ x[i]²` of every frame of `frame_len` samples, with frames starting
+    /// `hop` samples apart.
+    ///
+    /// Frames are those of `windows_with_hop`, so a partial frame at the end is dropped and a
+    /// signal of length `len >= frame_len` gives `(len - frame_len) / hop + 1` values.
+    ///
+    /// # Panics
+    /// Panics if `frame_len` or `hop` is zero.
+    ///
+    /// 返回每个长度为 `frame_len`、起点间隔 `hop` 的帧的能量 `Σ x[i]²`。
+    ///
+    /// 分帧方式与 `windows_with_hop` 相同，因此末尾不完整的帧被丢弃，长度 `len >= frame_len` 的信号得到
+    /// `(len - frame_len) / hop + 1` 个值。
+    ///
+    /// # 恐慌
+    /// `frame_len` 或 `hop` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 1.0, 2.0, 2.0, 0.0]);
+    /// assert_eq!(signal.frame_energy(2, 2).to_vec(), vec![2.0, 8.0]);
+    /// ```
+    pub fn frame_energy(&self, frame_len: usize, hop: usize) -> Signal {
+        // 直接在视图上累加，不为每一帧分配信号
+        self.signal
+            .windows_view_with_hop(frame_len, hop)
+            .map(sum_of_squares)
+            .collect()
+    }
+
+    /// Returns the RMS of every frame of `frame_len` samples, with frames starting `hop`
+    /// samples apart.
+    ///
+    /// This is `sqrt(frame_energy / frame_len)` and has the same frames.
+    ///
+    /// # Panics
+    /// Panics if `frame_len` or `hop` is zero.
+    ///
+    /// 返回每个长度为 `frame_len`、起点间隔 `hop` 的帧的均方根。
+    ///
+    /// 即 `sqrt(frame_energy / frame_len)`，分帧方式与 `frame_energy` 相同。
+    ///
+    /// # 恐慌
+    /// `frame_len` 或 `hop` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![3.0, -3.0, 0.0, 2.0, -2.0]);
+    /// assert_eq!(signal.frame_rms(2, 3).to_vec(), vec![3.0, 2.0]);
+    /// ```
+    pub fn frame_rms(&self, frame_len: usize, hop: usize) -> Signal {
+        let frame_len_f = frame_len as f64;
+        self.frame_energy(frame_len, hop)
+            .iter()
+            .map(|energy| (energy / frame_len_f).sqrt())
+            .collect()
+    }
+}
+
+// 顺序统计量
+impl Signal {
+    /// Returns the median. For an even number of samples it is the mean of the two middle ones.
+    ///
+    /// NaN samples are ignored. The signal itself is not modified; a sorted copy is used.
+    ///
+    /// # Errors
+    /// Returns `StatError::EmptyInput` if the signal has no non-NaN samples.
+    ///
+    /// 返回中位数。样本数为偶数时取中间两个样本的平均值。
+    ///
+    /// 忽略 NaN 样本。信号本身不会被修改，计算使用排序后的副本。
+    ///
+    /// # 错误
+    /// 信号中没有非 NaN 样本时返回 `StatError::EmptyInput`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![4.0, 1.0, 3.0, 2.0]);
+    /// assert_eq!(signal.median().unwrap(), 2.5);
+    /// ```
+    pub fn median(&self) -> Result<f64, StatError> {
+        self.signal.median()
+    }
+
+    /// Returns the `q`-quantile, interpolating linearly between adjacent order statistics.
+    ///
+    /// With the samples sorted as `s[0] <= … <= s[n - 1]`, the quantile lies at the fractional
+    /// position `(n - 1) * q`, so `q = 0` is the minimum and `q = 1` the maximum. This matches
+    /// NumPy's default `linear` method. NaN samples are ignored.
+    ///
+    /// # Errors
+    /// - `StatError::InvalidQuantile` if `q` is outside `[0, 1]` or is NaN.
+    /// - `StatError::EmptyInput` if the signal has no non-NaN samples.
+    ///
+    /// 返回 `q` 分位数，在相邻的顺序统计量之间线性插值。
+    ///
+    /// 将样本排序为 `s[0] <= … <= s[n - 1]` 后，分位数位于小数位置 `(n - 1) * q`，
+    /// 因此 `q = 0` 为最小值，`q = 1` 为最大值。这与 NumPy 默认的 `linear` 方法一致。忽略 NaN 样本。
+    ///
+    /// # 错误
+    /// - `q` 超出 `[0, 1]` 或为 NaN 时返回 `StatError::InvalidQuantile`。
+    /// - 信号中没有非 NaN 样本时返回 `StatError::EmptyInput`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 10.0, 20.0, 30.0, 40.0]);
+    /// assert_eq!(signal.quantile(0.25).unwrap(), 10.0);
+    /// assert_eq!(signal.quantile(0.3).unwrap(), 12.0);
+    /// ```
+    pub fn quantile(&self, q: f64) -> Result<f64, StatError> {
+        self.signal.quantile(q)
+    }
+
+    /// Returns the given percentiles (in `[0, 100]`), sorting the samples only once.
+    ///
+    /// Each percentile `p` equals `quantile(p / 100)`.
+    ///
+    /// # Errors
+    /// - `StatError::InvalidPercentile` with the offending value if any percentile is outside
+    ///   `[0, 100]` or is NaN.
+    /// - `StatError::EmptyInput` if the signal has no non-NaN samples.
+    ///
+    /// 返回给定的各百分位数（位于 `[0, 100]`），样本只排序一次。
+    ///
+    /// 每个百分位数 `p` 等于 `quantile(p / 100)`。
+    ///
+    /// # 错误
+    /// - 任一百分位数超出 `[0, 100]` 或为 NaN 时返回携带该值的 `StatError::InvalidPercentile`。
+    /// - 信号中没有非 NaN 样本时返回 `StatError::EmptyInput`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal: Signal = (0..=100).map(|i| i as f64).collect();
+    /// assert_eq!(signal.percentiles(&[5.0, 50.0, 95.0]).unwrap(), vec![5.0, 50.0, 95.0]);
+    /// ```
+    pub fn percentiles(&self, percents: &[f64]) -> Result<Vec<f64>, StatError> {
+        self.signal.percentiles(percents)
+    }
+}
+
+// 两个操作数长度不同时返回 ShapeError::LengthMismatch，信号与视图共用
+pub(crate) fn check_same_len(left: usize, right: usize) -> Result<(), ShapeError> {
+    if left != right {
+        return Err(ShapeError::LengthMismatch { left, right });
+    }
+    Ok(())
+}
+
+// 相似度
+impl Signal {
+    fn check_same_len(&self, other: &Signal) -> Result<(), ShapeError> {
+        check_same_len(self.len(), other.len())
+    }
+
+    /// Returns the dot product `Σ x[i]·y[i]`. Two empty signals give 0.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 返回点积 `Σ x[i]·y[i]`。两个空信号得到 0。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let x = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let y = Signal::from_vec(vec![4.0, -5.0, 6.0]);
+    /// assert_eq!(x.dot(&y).unwrap(), 12.0);
+    /// ```
+    pub fn dot(&self, other: &Signal) -> Result<f64, ShapeError> {
+        self.check_same_len(other)?;
+        Ok(compensated_sum(
+            self.iter().zip(other.iter()).map(|(x, y)| x * y),
+        ))
+    }
+
+    /// Returns the Pearson correlation coefficient, in `[-1, 1]`.
+    ///
+    /// It is `1` for a positive affine relation `y = a·x + b` (`a > 0`) and `-1` for a negative
+    /// one.
+    ///
+    /// # Errors
+    /// - `SimilarityError::Shape` if the signals differ in length.
+    /// - `SimilarityError::EmptyInput` if the signals are empty.
+    /// - `SimilarityError::ZeroScale` if either signal is constant, since its variance is zero.
+    ///
+    /// 返回 Pearson 相关系数，取值在 `[-1, 1]` 内。
+    ///
+    /// 对正的仿射关系 `y = a·x + b`（`a > 0`）为 `1`，对负的仿射关系为 `-1`。
+    ///
+    /// # 错误
+    /// - 两个信号长度不同时返回 `SimilarityError::Shape`。
+    /// - 信号为空时返回 `SimilarityError::EmptyInput`。
+    /// - 任一信号为常数（方差为零）时返回 `SimilarityError::ZeroScale`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let x = Signal::from_vec(vec![1.0, 2.0, 4.0]);
+    /// let y = Signal::from_vec(vec![3.0, 1.0, -3.0]);
+    /// assert!((x.pearson(&y).unwrap() + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn pearson(&self, other: &Signal) -> Result<f64, SimilarityError> {
+        self.check_same_len(other)?;
+        if self.is_empty() {
+            return Err(SimilarityError::EmptyInput);
+        }
+        let n = self.len() as f64;
+        let mean_x = compensated_sum(self.iter().copied()) / n;
+        let mean_y = compensated_sum(other.iter().copied()) / n;
+        let mut sums = [CompensatedSum::default(); 3];
+        for (x, y) in self.iter().zip(other.iter()) {
+            let (dx, dy) = (x - mean_x, y - mean_y);
+            sums[0].add(dx * dy);
+            sums[1].add(dx * dx);
+            sums[2].add(dy * dy);
+        }
+        let [sxy, sxx, syy] = sums.map(CompensatedSum::value);
+        if sxx == 0.0 || syy == 0.0 {
+            return Err(SimilarityError::ZeroScale("variance"));
+        }
+        Ok((sxy / (sxx * syy).sqrt()).clamp(-1.0, 1.0))
+    }
+
+    /// Returns the cosine similarity `x·y / (‖x‖·‖y‖)`, in `[-1, 1]`.
+    ///
+    /// Unlike `pearson`, the signals are not centred first.
+    ///
+    /// # Errors
+    /// - `SimilarityError::Shape` if the signals differ in length.
+    /// - `SimilarityError::EmptyInput` if the signals are empty.
+    /// - `SimilarityError::ZeroScale` if either signal is all zero, since its norm is zero.
+    ///
+    /// 返回余弦相似度 `x·y / (‖x‖·‖y‖)`，取值在 `[-1, 1]` 内。
+    ///
+    /// 与 `pearson` 不同，计算前不减去均值。
+    ///
+    /// # 错误
+    /// - 两个信号长度不同时返回 `SimilarityError::Shape`。
+    /// - 信号为空时返回 `SimilarityError::EmptyInput`。
+    /// - 任一信号全为零（范数为零）时返回 `SimilarityError::ZeroScale`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let x = Signal::from_vec(vec![1.0, 0.0]);
+    /// let y = Signal::from_vec(vec![1.0, 1.0]);
+    /// assert!((x.cosine_similarity(&y).unwrap() - 0.5f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn cosine_similarity(&self, other: &Signal) -> Result<f64, SimilarityError> {
+        let dot = self.dot(other)?;
+        if self.is_empty() {
+            return Err(SimilarityError::EmptyInput);
+        }
+        let norms = self.sum_of_squares() * other.sum_of_squares();
+        if norms == 0.0 {
+            return Err(SimilarityError::ZeroScale("norm"));
+        }
+        Ok((dot / norms.sqrt()).clamp(-1.0, 1.0))
+    }
+}
+
+// 差分
+impl Signal {
+    /// Returns the `order`-th forward difference, optionally extending the signal first.
+    ///
+    /// The values in `prepend` and `append` are placed before and after the signal before
+    /// differencing, so the result has `prepend.len() + len + append.len() - order` samples. Prepending
+    /// a single value to a first-order difference keeps the input length. `diff_n(1, &[], &[])` is
+    /// the same as `diff()`, and `order == 0` returns the extended signal unchanged.
+    ///
+    /// # Errors
+    /// Returns `DiffError::ShortLength` if `order > 0` and the extended signal has at most `order`
+    /// samples.
+    ///
+    /// 返回 `order` 阶前向差分，可选地先扩展信号。
+    ///
+    /// 差分前先把 `prepend` 与 `append` 中的值分别放在信号前后，因此结果有
+    /// `prepend.len() + len + append.len() - order` 个样本。对一阶差分在前面补一个值可保持输入长度。
+    /// `diff_n(1, &[], &[])` 与 `diff()` 相同，`order == 0` 时返回扩展后的信号本身。
+    ///
+    /// # 错误
+    /// `order > 0` 且扩展后的信号样本数不超过 `order` 时返回 `DiffError::ShortLength`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 4.0, 9.0, 16.0]);
+    /// assert_eq!(signal.diff_n(2, &[], &[]).unwrap().to_vec(), vec![2.0, 2.0]);
+    /// assert_eq!(signal.diff_n(1, &[0.0], &[]).unwrap().to_vec(), vec![1.0, 3.0, 5.0, 7.0]);
+    /// ```
+    pub fn diff_n(
+        &self,
+        order: usize,
+        prepend: &[f64],
+        append: &[f64],
+    ) -> Result<Signal, DiffError> {
+        Ok(Signal::from_base(
+            self.signal.diff_n(order, prepend, append)?,
+        ))
+    }
+
+    /// Estimates the derivative of a signal sampled every `dx`.
+    ///
+    /// Interior samples use the central difference `(x[i + 1] - x[i - 1]) / (2·dx)`, which is
+    /// exact for quadratics. The first and last samples use one-sided first-order differences.
+    /// The output has the same length as the input.
+    ///
+    /// # Errors
+    /// Returns `DiffError::ShortLength` if the signal has fewer than 2 samples.
+    ///
+    /// 估计以间隔 `dx` 采样的信号的导数。
+    ///
+    /// 内部样本使用中心差分 `(x[i + 1] - x[i - 1]) / (2·dx)`，对二次函数是精确的；首尾样本使用一阶单侧差分。
+    /// 输出与输入等长。
+    ///
+    /// # 错误
+    /// 信号少于 2 个样本时返回 `DiffError::ShortLength`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 1.0, 4.0, 9.0]);
+    /// assert_eq!(signal.gradient(1.0).unwrap().to_vec(), vec![1.0, 2.0, 4.0, 5.0]);
+    /// ```
+    pub fn gradient(&self, dx: f64) -> Result<Signal, DiffError> {
+        Ok(Signal::from_base(self.signal.gradient(dx)?))
+    }
+}
+
+// 数值积分
+impl Signal {
+    /// Integrates a signal sampled every `dx` with the composite trapezoidal rule.
+    ///
+    /// Signals with fewer than 2 samples span no interval and integrate to 0.
+    ///
+    /// 使用复合梯形公式对以间隔 `dx` 采样的信号积分。
+    ///
+    /// 少于 2 个样本的信号不构成区间，积分为 0。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.trapz(0.5), 2.25);
+    /// ```
+    pub fn trapz(&self, dx: f64) -> f64 {
+        let len = self.len();
+        if len < 2 {
+            return 0.0;
+        }
+        let inner = self.sum() - 0.5 * (self[0] + self[-1]);
+        inner * dx
+    }
+
+    /// Integrates a signal sampled every `dx` with the composite Simpson rule.
+    ///
+    /// With an odd number of samples (an even number of intervals) this is the classic 1/3 rule.
+    /// With an even number of samples the 1/3 rule covers all but the last interval, which is
+    /// integrated with a parabola through the last three samples,
+    /// `dx·(5·y[n-1] + 8·y[n-2] - y[n-3]) / 12`, keeping the fourth-order accuracy. Two samples
+    /// fall back to the trapezoidal rule, and fewer than 2 samples integrate to 0.
+    ///
+    /// 使用复合 Simpson 公式对以间隔 `dx` 采样的信号积分。
+    ///
+    /// 样本数为奇数（区间数为偶数）时即经典的 1/3 公式。样本数为偶数时，1/3 公式覆盖除最后一个区间外的部分，
+    /// 最后一个区间用过最后三个样本的抛物线积分，即 `dx·(5·y[n-1] + 8·y[n-2] - y[n-3]) / 12`，
+    /// 从而保持四阶精度。两个样本时退化为梯形公式，少于 2 个样本时积分为 0。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// // x² 在 [0, 2] 上的积分为 8/3，Simpson 公式对二次函数是精确的
+    /// let signal = Signal::from_vec(vec![0.0, 0.25, 1.0, 2.25, 4.0]);
+    /// assert!((signal.simpson(0.5) - 8.0 / 3.0).abs() < 1e-12);
+    /// ```
+    pub fn simpson(&self, dx: f64) -> f64 {
+        let len = self.len();
+        if len < 3 {
+            return self.trapz(dx);
+        }
+        // 1/3 公式覆盖的样本数，必为奇数
+        let odd_len = if len % 2 == 1 { len } else { len - 1 };
+        let mut sum = self[0] + self[odd_len as isize - 1];
+        for i in 1..odd_len - 1 {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            sum += weight * self[i as isize];
+        }
+        let mut integral = sum * dx / 3.0;
+        if odd_len < len {
+            let (a, b, c) = (self[-3], self[-2], self[-1]);
+            integral += dx * (5.0 * c + 8.0 * b - a) / 12.0;
+        }
+        integral
+    }
+
+    /// Returns the running integral of a signal sampled every `dx`, using the trapezoidal rule.
+    ///
+    /// Element `i` of the output is `initial` plus the integral from sample 0 to sample `i`, so
+    /// the output has the same length as the input, starts at `initial`, and its last element
+    /// equals `initial + trapz(dx)`. An empty signal gives an empty result.
+    ///
+    /// 使用梯形公式返回以间隔 `dx` 采样的信号的累积积分。
+    ///
+    /// 输出的第 `i` 个元素为 `initial` 加上从第 0 个样本到第 `i` 个样本的积分，因此输出与输入等长、
+    /// 从 `initial` 开始，且最后一个元素等于 `initial + trapz(dx)`。空信号得到空结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let acceleration = Signal::from_vec(vec![2.0, 2.0, 2.0]);
+    /// let velocity = acceleration.cumtrapz(0.5, 1.0);
+    /// assert_eq!(velocity.to_vec(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    pub fn cumtrapz(&self, dx: f64, initial: f64) -> Signal {
+        let mut previous = None;
+        self.iter()
+            .scan(initial, |acc, &y| {
+                if let Some(p) = previous {
+                    *acc += 0.5 * (p + y) * dx;
+                }
+                previous = Some(y);
+                Some(*acc)
+            })
+            .collect()
+    }
+}
+
+// 近似比较
+impl Signal {
+    /// Returns `true` if the signals have the same length and every pair of samples satisfies
+    /// `|a - b| <= atol + rtol·|b|`, the same test as `numpy.allclose`.
+    ///
+    /// Infinities are close only to an equal infinity. NaN is never close to anything, including
+    /// another NaN, so a signal containing NaN is not close to itself. Signals of different
+    /// lengths are never close.
+    ///
+    /// 两个信号长度相同且每对样本都满足 `|a - b| <= atol + rtol·|b|` 时返回 `true`，
+    /// 判据与 `numpy.allclose` 相同。
+    ///
+    /// 无穷大只与相等的无穷大接近。NaN 与任何值（包括另一个 NaN）都不接近，因此含 NaN 的信号与自身也不接近。
+    /// 长度不同的信号总是不接近。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let a = Signal::from_vec(vec![1.0, 100.0]);
+    /// let b = Signal::from_vec(vec![1.0 + 1e-9, 100.001]);
+    /// assert!(a.allclose(&b, 1e-5, 1e-8));
+    /// assert!(!a.allclose(&b, 0.0, 1e-8));
+    /// ```
+    pub fn allclose(&self, other: &Signal, rtol: f64, atol: f64) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(a, b)| {
+                // 不相等的无穷大之差为无穷大，但右侧的容差也可能是无穷大，需单独排除
+                a == b || (a.is_finite() && b.is_finite() && (a - b).abs() <= atol + rtol * b.abs())
+            })
+    }
+
+    /// Returns the largest absolute difference `max |a[i] - b[i]|` between the signals.
+    ///
+    /// Two empty signals give 0. If any sample of either signal is NaN the result is NaN, so a
+    /// NaN can never hide behind a small error.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 返回两个信号之间的最大绝对误差 `max |a[i] - b[i]|`。
+    ///
+    /// 两个空信号得到 0。任一信号含 NaN 时结果为 NaN，因此 NaN 不会被较小的误差掩盖。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let a = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let b = Signal::from_vec(vec![1.5, 2.0, 2.0]);
+    /// assert_eq!(a.max_abs_error(&b).unwrap(), 1.0);
+    /// ```
+    pub fn max_abs_error(&self, other: &Signal) -> Result<f64, ShapeError> {
+        self.check_same_len(other)?;
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, |max, e| {
+                if e.is_nan() || max.is_nan() {
+                    f64::NAN
+                } else {
+                    max.max(e)
+                }
+            }))
+    }
+}
+
+// 累积运算
+impl Signal {
+    /// Returns the cumulative sum: element `i` is the sum of elements `0..=i`.
+    ///
+    /// An empty signal gives an empty result.
+    ///
+    /// 返回累积和：第 `i` 个元素为第 `0..=i` 个元素之和。
+    ///
+    /// 空信号得到空结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.cumsum().to_vec(), vec![1.0, 3.0, 6.0, 10.0]);
+    /// ```
+    pub fn cumsum(&self) -> Signal {
+        Signal::from_base(self.signal.cumsum())
+    }
+
+    /// Returns the cumulative product: element `i` is the product of elements `0..=i`.
+    ///
+    /// An empty signal gives an empty result.
+    ///
+    /// 返回累积积：第 `i` 个元素为第 `0..=i` 个元素之积。
+    ///
+    /// 空信号得到空结果。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.cumprod().to_vec(), vec![1.0, 2.0, 6.0, 24.0]);
+    /// ```
+    pub fn cumprod(&self) -> Signal {
+        Signal::from_base(self.signal.cumprod())
+    }
+}
+
+// 移位
+impl Signal {
+    /// Rotates the samples circularly by `k` positions.
+    ///
+    /// A positive `k` moves samples toward higher indices, and those pushed past the end
+    /// reappear at the start; a negative `k` rotates the other way. `k` is taken modulo the
+    /// length, so `roll(len)` returns the signal unchanged. An empty signal stays empty.
+    ///
+    /// 将样本循环移动 `k` 个位置。
+    ///
+    /// `k` 为正时样本向下标增大的方向移动，越过末尾的样本回到开头；`k` 为负时反向旋转。
+    /// `k` 按信号长度取模，因此 `roll(len)` 返回原信号。空信号保持为空。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.roll(1).to_vec(), vec![4.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.roll(-1).to_vec(), vec![2.0, 3.0, 4.0, 1.0]);
+    /// ```
+    pub fn roll(&self, k: isize) -> Signal {
+        Signal::from_base(self.signal.roll(k))
+    }
+
+    /// Shifts the samples by `k` positions, filling vacated positions with `fill`.
+    ///
+    /// A positive `k` delays the signal: the first `k` samples become `fill` and the last `k`
+    /// samples are dropped. A negative `k` advances it the other way. The length is unchanged,
+    /// and if `|k| >= len` every sample becomes `fill`.
+    ///
+    /// 将样本移动 `k` 个位置，空出的位置用 `fill` 填充。
+    ///
+    /// `k` 为正时信号延迟：前 `k` 个样本变为 `fill`，末尾 `k` 个样本被丢弃。`k` 为负时向相反方向移动。
+    /// 长度保持不变；若 `|k| >= len`，所有样本都变为 `fill`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.shift(1, 0.0).to_vec(), vec![0.0, 1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.shift(-2, 9.0).to_vec(), vec![3.0, 4.0, 9.0, 9.0]);
+    /// ```
+    pub fn shift(&self, k: isize, fill: f64) -> Signal {
+        Signal::from_base(self.signal.shift(k, fill))
+    }
+}
+
+/// How `normalize` rescales a signal.
+///
+/// `normalize` 缩放信号的方式。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormMode {
+    /// Map the signal's `[min, max]` linearly onto `[lo, hi]`.
+    ///
+    /// 将信号的 `[min, max]` 线性映射到 `[lo, hi]`。
+    MinMax(f64, f64),
+    /// Divide by the largest absolute value, so the peak magnitude becomes 1.
+    ///
+    /// 除以最大绝对值，使峰值幅度变为 1。
+    Peak,
+    /// Scale so that the RMS value equals the given target.
+    ///
+    /// 缩放使均方根值等于给定目标。
+    Rms(f64),
+}
+
+// 归一化
+impl Signal {
+    /// Returns a rescaled copy of the signal according to `mode`.
+    ///
+    /// Every mode follows the same NaN policy as `standardize` and `robust_scale`: NaN samples
+    /// are ignored when computing the scale and stay NaN in the output.
+    ///
+    /// # Errors
+    /// - `NormError::EmptyInput` if the signal has no non-NaN samples.
+    /// - `NormError::ZeroScale` if the signal is constant (`MinMax`) or all zero (`Peak`, `Rms`),
+    ///   since no finite scale factor exists.
+    /// - `NormError::InvalidTarget` if `lo` or `hi` is not finite, or the RMS target is negative
+    ///   or not finite.
+    ///
+    /// 按 `mode` 返回缩放后的信号副本。
+    ///
+    /// 所有模式都采用与 `standardize` 和 `robust_scale` 相同的 NaN 处理方式：计算尺度时忽略 NaN 样本，
+    /// 这些样本在输出中保持为 NaN。
+    ///
+    /// # 错误
+    /// - 信号中没有非 NaN 样本时返回 `NormError::EmptyInput`。
+    /// - 信号为常数（`MinMax`）或全零（`Peak`、`Rms`）时不存在有限的缩放因子，返回 `NormError::ZeroScale`。
+    /// - `lo` 或 `hi` 不是有限数，或均方根目标为负数或不是有限数时，返回 `NormError::InvalidTarget`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{NormMode, Signal};
+    /// let signal = Signal::from_vec(vec![-2.0, 0.0, 4.0]);
+    /// assert_eq!(signal.normalize(NormMode::Peak).unwrap().to_vec(), vec![-0.5, 0.0, 1.0]);
+    /// assert_eq!(
+    ///     signal.normalize(NormMode::MinMax(0.0, 6.0)).unwrap().to_vec(),
+    ///     vec![0.0, 2.0, 6.0]
+    /// );
+    /// ```
+    pub fn normalize(&self, mode: NormMode) -> Result<Signal, NormError> {
+        // 各模式的尺度都只由非 NaN 样本计算
+        let valid = || self.iter().copied().filter(|x| !x.is_nan());
+        if valid().next().is_none() {
+            return Err(NormError::EmptyInput);
+        }
+        match mode {
+            NormMode::MinMax(lo, hi) => {
+                if !lo.is_finite() {
+                    return Err(NormError::InvalidTarget {
+                        name: "lo",
+                        value: lo,
+                    });
+                }
+                if !hi.is_finite() {
+                    return Err(NormError::InvalidTarget {
+                        name: "hi",
+                        value: hi,
+                    });
+                }
+                let min = valid().fold(f64::INFINITY, f64::min);
+                let max = valid().fold(f64::NEG_INFINITY, f64::max);
+                let range = max - min;
+                if range.is_nan() || range <= 0.0 {
+                    return Err(NormError::ZeroScale("range"));
+                }
+                let scale = (hi - lo) / range;
+                Ok(Signal::from_base(
+                    self.signal.map(|&x| lo + (x - min) * scale),
+                ))
+            }
+            NormMode::Peak => {
+                let peak = valid().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+                if peak == 0.0 {
+                    return Err(NormError::ZeroScale("peak"));
+                }
+                Ok(Signal::from_base(self.signal.map(|&x| x / peak)))
+            }
+            NormMode::Rms(target) => {
+                if !(target.is_finite() && target >= 0.0) {
+                    return Err(NormError::InvalidTarget {
+                        name: "rms",
+                        value: target,
+                    });
+                }
+                let sum_sq = compensated_sum(valid().map(|x| x * x));
+                let rms = (sum_sq / valid().count() as f64).sqrt();
+                if rms.is_nan() || rms == 0.0 {
+                    return Err(NormError::ZeroScale("RMS"));
+                }
+                let scale = target / rms;
+                Ok(Signal::from_base(self.signal.map(|&x| x * scale)))
+            }
+        }
+    }
+
+    /// Returns the z-score standardized signal `(x - mean) / std`, using the sample standard
+    /// deviation (`n - 1` in the denominator).
+    ///
+    /// NaN samples are ignored when computing the mean and standard deviation and stay NaN
+    /// in the output.
+    ///
+    /// # Errors
+    /// - `NormError::EmptyInput` if the signal has no non-NaN samples.
+    /// - `NormError::ZeroScale` if the standard deviation is zero or undefined, i.e. the signal
+    ///   is constant or has a single sample.
+    ///
+    /// 返回 z-score 标准化后的信号 `(x - mean) / std`，使用样本标准差（分母为 `n - 1`）。
+    ///
+    /// 计算均值和标准差时忽略 NaN 样本，这些样本在输出中保持为 NaN。
+    ///
+    /// # 错误
+    /// - 信号中没有非 NaN 样本时返回 `NormError::EmptyInput`。
+    /// - 标准差为零或无定义（即信号为常数或只有一个样本）时返回 `NormError::ZeroScale`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.standardize().unwrap().to_vec(), vec![-1.0, 0.0, 1.0]);
+    /// ```
+    pub fn standardize(&self) -> Result<Signal, NormError> {
+        let valid: Array1<f64> = self.iter().copied().filter(|x| !x.is_nan()).collect();
+        if valid.is_empty() {
+            return Err(NormError::EmptyInput);
+        }
+        let mean = compensated_sum(valid.iter().copied()) / valid.len() as f64;
+        let std = variance_of(valid.view(), 1).sqrt();
+        if std.is_nan() || std == 0.0 {
+            return Err(NormError::ZeroScale("standard deviation"));
+        }
+        Ok(Signal::from_base(self.signal.map(|&x| (x - mean) / std)))
+    }
+
+    /// Returns the robustly scaled signal `(x - median) / MAD`, where MAD is the median absolute
+    /// deviation from the median.
+    ///
+    /// The MAD is not multiplied by the 1.4826 consistency factor, so for Gaussian data the
+    /// output spread is larger than that of `standardize`. Unlike the mean and standard
+    /// deviation, the median and MAD are barely affected by a few extreme outliers. NaN samples
+    /// are ignored when computing the statistics and stay NaN in the output.
+    ///
+    /// # Errors
+    /// - `NormError::EmptyInput` if the signal has no non-NaN samples.
+    /// - `NormError::ZeroScale` if the MAD is zero, i.e. at least half of the samples are equal.
+    ///
+    /// 返回稳健缩放后的信号 `(x - median) / MAD`，其中 MAD 为相对中位数的绝对偏差的中位数。
+    ///
+    /// MAD 未乘以 1.4826 的一致性因子，因此对高斯数据，输出的离散程度大于 `standardize` 的结果。
+    /// 与均值和标准差不同，中位数和 MAD 几乎不受少量极端离群值影响。
+    /// 计算统计量时忽略 NaN 样本，这些样本在输出中保持为 NaN。
+    ///
+    /// # 错误
+    /// - 信号中没有非 NaN 样本时返回 `NormError::EmptyInput`。
+    /// - MAD 为零（即至少一半的样本相等）时返回 `NormError::ZeroScale`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 100.0]);
+    /// assert_eq!(signal.robust_scale().unwrap().to_vec(), vec![-2.0, -1.0, 0.0, 1.0, 97.0]);
+    /// ```
+    pub fn robust_scale(&self) -> Result<Signal, NormError> {
+        let valid: Vec<f64> = self.iter().copied().filter(|x| !x.is_nan()).collect();
+        if valid.is_empty() {
+            return Err(NormError::EmptyInput);
+        }
+        let median = median_of(&valid);
+        let deviations: Vec<f64> = valid.iter().map(|x| (x - median).abs()).collect();
+        let mad = median_of(&deviations);
+        if mad == 0.0 {
+            return Err(NormError::ZeroScale("MAD"));
+        }
+        Ok(Signal::from_base(self.signal.map(|&x| (x - median) / mad)))
+    }
+}
+
+// 拆分与分块
+impl Signal {
+    /// Splits the signal into `[0, idx)` and `[idx, len)`.
+    ///
+    /// Negative indices count from the end as elsewhere in the crate, so `split_at(-1)` puts
+    /// only the last sample in the right part. Valid indices are `-len..=len`; both ends give
+    /// an empty part.
+    ///
+    /// # Errors
+    /// Returns `SliceError::IndexOutOfBounds` if `idx` is outside `-len..=len`.
+    ///
+    /// 将信号拆分为 `[0, idx)` 与 `[idx, len)` 两部分。
+    ///
+    /// 与本库其他地方一样，负索引从末尾开始计数，因此 `split_at(-1)` 使右侧部分只包含最后一个样本。
+    /// 有效索引为 `-len..=len`，两端会得到一个空的部分。
+    ///
+    /// # 错误
+    /// `idx` 超出 `-len..=len` 时返回 `SliceError::IndexOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// let (head, tail) = signal.split_at(-1).unwrap();
+    /// assert_eq!(head.to_vec(), vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(tail.to_vec(), vec![4.0]);
+    /// ```
+    pub fn split_at(&self, idx: isize) -> Result<(Signal, Signal), SliceError> {
+        let (left, right) = self.signal.split_at(idx)?;
+        Ok((Signal::from_base(left), Signal::from_base(right)))
+    }
+
+    /// Copies the samples from index `from` to the end.
+    ///
+    /// `from` is a sample index in `-len..len`; negative values count from the end.
+    ///
+    /// # Errors
+    /// Returns `SliceError::IndexOutOfBounds` if `from` is outside `-len..len`.
+    ///
+    /// 复制从索引 `from` 到末尾的样本。
+    ///
+    /// `from` 为 `-len..len` 内的样本索引，负值从末尾计数。
+    ///
+    /// # 错误
+    /// `from` 超出 `-len..len` 时返回 `SliceError::IndexOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.try_cut_from(-2).unwrap().to_vec(), vec![3.0, 4.0]);
+    /// assert!(signal.try_cut_from(4).is_err());
+    /// ```
+    pub fn try_cut_from(&self, from: isize) -> Result<Signal, SliceError> {
+        self.signal.try_cut_from(from).map(Signal::from_base)
+    }
+
+    /// Copies the samples from the start up to and including index `to`.
+    ///
+    /// `to` is a sample index in `-len..len`; negative values count from the end.
+    ///
+    /// # Errors
+    /// Returns `SliceError::IndexOutOfBounds` if `to` is outside `-len..len`.
+    ///
+    /// 复制从开头到索引 `to`（含）的样本。
+    ///
+    /// `to` 为 `-len..len` 内的样本索引，负值从末尾计数。
+    ///
+    /// # 错误
+    /// `to` 超出 `-len..len` 时返回 `SliceError::IndexOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.try_cut_to(-2).unwrap().to_vec(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    pub fn try_cut_to(&self, to: isize) -> Result<Signal, SliceError> {
+        self.signal.try_cut_to(to).map(Signal::from_base)
+    }
+
+    /// Copies the samples from index `from` up to and including index `to`.
+    ///
+    /// Both ends are sample indices in `-len..len` and the range is inclusive, like `from..=to`.
+    /// When `from` resolves to the sample right after `to` the result is empty.
+    ///
+    /// # Errors
+    /// - `SliceError::IndexOutOfBounds` if `from` or `to` is outside `-len..len`.
+    /// - `SliceError::InvertedRange` if `from` resolves to more than one sample past `to`.
+    ///
+    /// 复制从索引 `from` 到索引 `to`（含）的样本。
+    ///
+    /// 两端都是 `-len..len` 内的样本索引，区间为闭区间，与 `from..=to` 相同。`from` 换算后恰为 `to`
+    /// 的下一个样本时结果为空。
+    ///
+    /// # 错误
+    /// - `from` 或 `to` 超出 `-len..len` 时返回 `SliceError::IndexOutOfBounds`。
+    /// - `from` 换算后超过 `to` 的下一个样本时返回 `SliceError::InvertedRange`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.try_cut_from_to(1, -2).unwrap().to_vec(), vec![2.0, 3.0]);
+    /// assert!(signal.try_cut_from_to(2, 1).unwrap().is_empty());
+    /// assert!(signal.try_cut_from_to(3, 1).is_err());
+    /// ```
+    pub fn try_cut_from_to(&self, from: isize, to: isize) -> Result<Signal, SliceError> {
+        self.signal.try_cut_from_to(from, to).map(Signal::from_base)
+    }
+
+    /// Copies the samples in the half-open range from `start` up to but excluding `end`, like
+    /// slicing with `start..end`.
+    ///
+    /// Both ends are boundaries between samples in `-len..=len`, so `start == end` gives an
+    /// empty signal and `(0, len)` the whole signal. Negative values count from the end. The
+    /// ends are separate arguments rather than a `Range<isize>` because ranges such as `1..-1`
+    /// are rejected by Clippy as empty.
+    ///
+    /// # Errors
+    /// - `SliceError::IndexOutOfBounds` if either end is outside `-len..=len`.
+    /// - `SliceError::InvertedRange` if `start` resolves to a position after `end`.
+    ///
+    /// 复制从 `start` 到 `end`（不含）的半开区间内的样本，与用 `start..end` 切片相同。
+    ///
+    /// 两端都是 `-len..=len` 内样本之间的边界，因此 `start == end` 得到空信号，`(0, len)` 得到整个信号。
+    /// 负值从末尾计数。两端作为独立参数而不是 `Range<isize>`，因为 `1..-1` 之类的区间会被 Clippy 当作空区间拒绝。
+    ///
+    /// # 错误
+    /// - 任一端超出 `-len..=len` 时返回 `SliceError::IndexOutOfBounds`。
+    /// - `start` 换算后位于 `end` 之后时返回 `SliceError::InvertedRange`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(signal.try_cut_range(1, -1).unwrap().to_vec(), vec![2.0, 3.0]);
+    /// assert_eq!(signal.try_cut_range(-2, 4).unwrap().to_vec(), vec![3.0, 4.0]);
+    /// assert!(signal.try_cut_range(2, 2).unwrap().is_empty());
+    /// ```
+    pub fn try_cut_range(&self, start: isize, end: isize) -> Result<Signal, SliceError> {
+        self.signal.try_cut_range(start, end).map(Signal::from_base)
+    }
+
+    /// Panicking version of `try_cut_from`.
+    ///
+    /// # Panics
+    /// Panics if `from` is outside `-len..len`.
+    ///
+    /// `try_cut_from` 的恐慌版本。
+    ///
+    /// # 恐慌
+    /// `from` 超出 `-len..len` 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.cut_from(1).to_vec(), vec![2.0, 3.0]);
+    /// ```
+    pub fn cut_from(&self, from: isize) -> Signal {
+        Signal::from_base(self.signal.cut_from(from))
+    }
+
+    /// Panicking version of `try_cut_to`.
+    ///
+    /// # Panics
+    /// Panics if `to` is outside `-len..len`.
+    ///
+    /// `try_cut_to` 的恐慌版本。
+    ///
+    /// # 恐慌
+    /// `to` 超出 `-len..len` 时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.cut_to(1).to_vec(), vec![1.0, 2.0]);
+    /// ```
+    pub fn cut_to(&self, to: isize) -> Signal {
+        Signal::from_base(self.signal.cut_to(to))
+    }
+
+    /// Panicking version of `try_cut_from_to`. The range is inclusive at both ends.
+    ///
+    /// # Panics
+    /// Panics if `from` or `to` is outside `-len..len`, or `from` resolves to more than one
+    /// sample past `to`.
+    ///
+    /// `try_cut_from_to` 的恐慌版本。区间两端都包含在内。
+    ///
+    /// # 恐慌
+    /// `from` 或 `to` 超出 `-len..len`，或 `from` 换算后超过 `to` 的下一个样本时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.cut_from_to(0, 1).to_vec(), vec![1.0, 2.0]);
+    /// ```
+    pub fn cut_from_to(&self, from: isize, to: isize) -> Signal {
+        Signal::from_base(self.signal.cut_from_to(from, to))
+    }
+
+    /// Panicking version of `try_cut_range`. The range is half-open.
+    ///
+    /// # Panics
+    /// Panics if either end is outside `-len..=len`, or `start` resolves to a position after
+    /// `end`.
+    ///
+    /// `try_cut_range` 的恐慌版本。区间为半开区间。
+    ///
+    /// # 恐慌
+    /// 任一端超出 `-len..=len`，或 `start` 换算后位于 `end` 之后时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// assert_eq!(signal.cut_range(0, -1).to_vec(), vec![1.0, 2.0]);
+    /// ```
+    pub fn cut_range(&self, start: isize, end: isize) -> Signal {
+        Signal::from_base(self.signal.cut_range(start, end))
+    }
+
+    /// Splits the signal into consecutive, non-overlapping chunks of `chunk_len` samples.
+    ///
+    /// When the length is not a multiple of `chunk_len`, the last chunk is short and `tail`
+    /// decides whether it is kept, dropped or padded to full length.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    ///
+    /// 将信号拆分为连续、不重叠、每块 `chunk_len` 个样本的块。
+    ///
+    /// 当长度不是 `chunk_len` 的整数倍时，最后一块较短，由 `tail` 决定保留、丢弃还是填充到完整长度。
+    ///
+    /// # 恐慌
+    /// `chunk_len` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{ChunkTail, Signal};
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// let chunks: Vec<Vec<f64>> = signal
+    ///     .chunks(2, ChunkTail::Pad(0.0))
+    ///     .map(|chunk| chunk.to_vec())
+    ///     .collect();
+    /// assert_eq!(chunks, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 0.0]]);
+    /// ```
+    pub fn chunks(&self, chunk_len: usize, tail: ChunkTail) -> impl Iterator<Item = Signal> + '_ {
+        self.signal.chunks(chunk_len, tail).map(Signal::from_base)
+    }
+
+    /// Yields every window of `window_size` consecutive samples as a borrowed view.
+    ///
+    /// This is the zero-copy counterpart of `windows`, which allocates an owned copy per
+    /// window position. The views point into the signal's own buffer, so rolling computations
+    /// over long signals do no per-window heap allocation. A signal shorter than `window_size`
+    /// yields no windows.
+    ///
+    /// # Panics
+    /// Panics if `window_size` is zero.
+    ///
+    /// 以借用视图的形式产出每个由 `window_size` 个连续样本组成的窗口。
+    ///
+    /// 这是 `windows` 的零拷贝版本，后者会为每个窗口位置分配一份拥有所有权的副本。
+    /// 视图直接指向信号自身的缓冲区，因此在长信号上做滑动计算时不会为每个窗口分配堆内存。
+    /// 长度小于 `window_size` 的信号不产出窗口。
+    ///
+    /// # 恐慌
+    /// `window_size` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// let sums: Vec<f64> = signal.windows_view(2).map(|w| w.sum()).collect();
+    /// assert_eq!(sums, vec![3.0, 5.0, 7.0]);
+    /// ```
+    pub fn windows_view(&self, window_size: usize) -> impl Iterator<Item = ArrayView1<'_, f64>> {
+        self.signal.windows_view(window_size)
+    }
+
+    /// Yields overlapping windows of `window_size` samples starting at `0, hop, 2·hop, …`.
+    ///
+    /// A partial window at the end is skipped, so a signal of length `len >= window_size`
+    /// yields `(len - window_size) / hop + 1` windows and a shorter signal yields none.
+    /// `hop = 1` matches `windows`, and `hop = window_size` gives non-overlapping frames.
+    ///
+    /// # Panics
+    /// Panics if `window_size` or `hop` is zero.
+    ///
+    /// 产出起点为 `0, hop, 2·hop, …`、长度为 `window_size` 的重叠窗口。
+    ///
+    /// 末尾不完整的窗口被跳过，因此长度 `len >= window_size` 的信号产出 `(len - window_size) / hop + 1`
+    /// 个窗口，更短的信号不产出窗口。`hop = 1` 与 `windows` 相同，`hop = window_size` 得到不重叠的帧。
+    ///
+    /// # 恐慌
+    /// `window_size` 或 `hop` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let frames: Vec<Vec<f64>> = signal
+    ///     .windows_with_hop(4, 2)
+    ///     .map(|frame| frame.to_vec())
+    ///     .collect();
+    /// assert_eq!(frames, vec![vec![1.0, 2.0, 3.0, 4.0], vec![3.0, 4.0, 5.0, 6.0]]);
+    /// ```
+    pub fn windows_with_hop(
+        &self,
+        window_size: usize,
+        hop: usize,
+    ) -> impl Iterator<Item = Signal> + '_ {
+        self.signal
+            .windows_with_hop(window_size, hop)
+            .map(Signal::from_base)
+    }
+}
+
+// 交织
+impl Signal {
+    /// Interleaves two equally long signals into `[a0, b0, a1, b1, …]`.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the signals differ in length.
+    ///
+    /// 将两个等长信号交织为 `[a0, b0, a1, b1, …]`。
+    ///
+    /// # 错误
+    /// 两个信号长度不同时返回 `ShapeError::LengthMismatch`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let left = Signal::from_vec(vec![1.0, 2.0]);
+    /// let right = Signal::from_vec(vec![-1.0, -2.0]);
+    /// assert_eq!(left.interleave(&right).unwrap().to_vec(), vec![1.0, -1.0, 2.0, -2.0]);
+    /// ```
+    pub fn interleave(&self, other: &Signal) -> Result<Signal, ShapeError> {
+        if self.len() != other.len() {
+            return Err(ShapeError::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .flat_map(|(&a, &b)| [a, b])
+            .collect())
+    }
+
+    /// Splits an interleaved buffer `[c0, c1, …, c(n-1), c0, …]` into `n_channels` signals.
+    ///
+    /// If the length is not a multiple of `n_channels`, the incomplete last frame is dropped,
+    /// so every channel has `len / n_channels` samples.
+    ///
+    /// # Panics
+    /// Panics if `n_channels` is zero.
+    ///
+    /// 将交织的缓冲区 `[c0, c1, …, c(n-1), c0, …]` 拆分为 `n_channels` 个信号。
+    ///
+    /// 若长度不是 `n_channels` 的整数倍，则丢弃不完整的最后一帧，每个通道都有 `len / n_channels` 个样本。
+    ///
+    /// # 恐慌
+    /// `n_channels` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let interleaved = Signal::from_vec(vec![1.0, -1.0, 2.0, -2.0, 3.0]);
+    /// let channels = interleaved.deinterleave(2);
+    /// assert_eq!(channels[0].to_vec(), vec![1.0, 2.0]);
+    /// assert_eq!(channels[1].to_vec(), vec![-1.0, -2.0]);
+    /// ```
+    pub fn deinterleave(&self, n_channels: usize) -> Vec<Signal> {
+        assert!(n_channels > 0, "n_channels must be positive");
+        let frames = self.len() / n_channels;
+        (0..n_channels)
+            .map(|channel| {
+                self.iter()
+                    .skip(channel)
+                    .step_by(n_channels)
+                    .take(frames)
+                    .copied()
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+// 拼接与重复
+impl Signal {
+    /// Returns this signal followed by `other`.
+    ///
+    /// # Errors
+    /// Returns `PadError::ResultOutOfBounds` if the result would be longer than `isize::MAX`.
+    ///
+    /// 返回本信号后接 `other` 得到的信号。
+    ///
+    /// # 错误
+    /// 结果长度超过 `isize::MAX` 时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let a = Signal::from_vec(vec![1.0, 2.0]);
+    /// let b = Signal::from_vec(vec![3.0]);
+    /// assert_eq!(a.concat(&b).unwrap().to_vec(), vec![1.0, 2.0, 3.0]);
+    /// ```
+    pub fn concat(&self, other: &Signal) -> Result<Signal, PadError> {
+        Signal::concat_many(&[self, other])
+    }
+
+    /// Concatenates the signals in order, allocating the result once at its final size.
+    ///
+    /// Empty signals contribute nothing, and an empty slice gives an empty signal.
+    ///
+    /// # Errors
+    /// Returns `PadError::ResultOutOfBounds` if the total length would exceed `isize::MAX`.
+    ///
+    /// 按顺序拼接各信号，结果按最终长度一次性分配。
+    ///
+    /// 空信号不贡献任何样本，空切片得到空信号。
+    ///
+    /// # 错误
+    /// 总长度超过 `isize::MAX` 时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let a = Signal::from_vec(vec![1.0]);
+    /// let b = Signal::from_vec(vec![2.0, 3.0]);
+    /// let joined = Signal::concat_many(&[&a, &b, &a]).unwrap();
+    /// assert_eq!(joined.to_vec(), vec![1.0, 2.0, 3.0, 1.0]);
+    /// ```
+    pub fn concat_many(signals: &[&Signal]) -> Result<Signal, PadError> {
+        SignalBase::concat(signals.iter().map(|s| &s.signal)).map(Signal::from_base)
+    }
+
+    /// Concatenates `n` copies of the signal. `n = 0` gives an empty signal.
+    ///
+    /// # Errors
+    /// Returns `PadError::ResultOutOfBounds` if the result would be longer than `isize::MAX`.
+    ///
+    /// 将信号的 `n` 个副本首尾相接。`n = 0` 时得到空信号。
+    ///
+    /// # 错误
+    /// 结果长度超过 `isize::MAX` 时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0]);
+    /// assert_eq!(signal.tile(3).unwrap().to_vec(), vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    pub fn tile(&self, n: usize) -> Result<Signal, PadError> {
+        self.signal.tile(n).map(Signal::from_base)
+    }
+
+    /// Repeats each sample `n` times in a row, i.e. sample-and-hold upsampling by `n`.
+    /// `n = 0` gives an empty signal.
+    ///
+    /// # Errors
+    /// Returns `PadError::ResultOutOfBounds` if the result would be longer than `isize::MAX`.
+    ///
+    /// 将每个样本连续重复 `n` 次，即按 `n` 倍进行采样保持上采样。`n = 0` 时得到空信号。
+    ///
+    /// # 错误
+    /// 结果长度超过 `isize::MAX` 时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0]);
+    /// assert_eq!(signal.repeat_each(3).unwrap().to_vec(), vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    /// ```
+    pub fn repeat_each(&self, n: usize) -> Result<Signal, PadError> {
+        self.signal.repeat_each(n).map(Signal::from_base)
+    }
+}
+
+// 填充
+impl Signal {
+    /// Pads the signal with possibly different widths on each side.
+    ///
+    /// This is the general entry point behind the side-based helpers such as `pad_reflect`
+    /// and `pad_edge`; `mode` selects how the new samples are filled. Either width may be zero.
+    ///
+    /// # Parameters
+    /// * `left_width` - The number of samples added before the signal.
+    /// * `right_width` - The number of samples added after the signal.
+    /// * `mode` - How the added samples are filled.
+    ///
+    /// # Errors
+    /// * `PadError::ResultOutOfBounds` if the padded length exceeds `isize::MAX`.
+    /// * `PadError::ConcatenationError` if the signal is empty and `mode` is not `Constant`.
+    /// * `PadError::Other` for a `Stat` mode with `stat_len` equal to `Some(0)`.
+    ///
+    /// 在两侧以可能不同的宽度填充信号。
+    ///
+    /// 这是 `pad_reflect`、`pad_edge` 等按侧填充函数背后的通用入口；`mode` 决定新样本的填充方式。
+    /// 任一侧宽度都可以为零。
+    ///
+    /// # 参数
+    /// * `left_width` - 在信号之前增加的样本数。
+    /// * `right_width` - 在信号之后增加的样本数。
+    /// * `mode` - 新增样本的填充方式。
+    ///
+    /// # 错误
+    /// * 填充后的长度超过 `isize::MAX` 时返回 `PadError::ResultOutOfBounds`。
+    /// * 信号为空且 `mode` 不是 `Constant` 时返回 `PadError::ConcatenationError`。
+    /// * `Stat` 模式的 `stat_len` 为 `Some(0)` 时返回 `PadError::Other`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{PadMode, Signal};
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let padded = signal.pad(1, 2, PadMode::Wrap).unwrap();
+    /// assert_eq!(padded.to_vec(), vec![3.0, 1.0, 2.0, 3.0, 1.0, 2.0]);
+    /// ```
+    pub fn pad(
+        &self,
+        left_width: usize,
+        right_width: usize,
+        mode: PadMode,
+    ) -> Result<Signal, PadError> {
+        self.signal
+            .pad(left_width, right_width, mode)
+            .map(Signal::from_base)
+    }
+
+    /// Pads the signal by mirroring it about its edge samples.
+    ///
+    /// This follows numpy's `"reflect"` convention: the edge sample itself is not repeated, so
+    /// `[1, 2, 3]` padded by two on the left becomes `[3, 2, 1, 2, 3]`. Pad widths larger than the
+    /// signal fold back and forth repeatedly. A single-sample signal is simply repeated.
+    ///
+    /// # Parameters
+    /// * `pad_side` - Which side(s) to pad.
+    /// * `pad_width` - The number of samples added on each padded side.
+    ///
+    /// # Errors
+    /// * `PadError::ConcatenationError` if the signal is empty.
+    /// * `PadError::ResultOutOfBounds` if the padded length is not representable.
+    ///
+    /// 以端点样本为对称轴镜像填充信号。
+    ///
+    /// 采用 numpy 的 `"reflect"` 约定：端点样本本身不重复，因此 `[1, 2, 3]` 在左侧填充两个样本后
+    /// 变为 `[3, 2, 1, 2, 3]`。填充宽度大于信号长度时反复来回折叠。只有一个样本的信号直接重复该样本。
+    ///
+    /// # 参数
+    /// * `pad_side` - 填充哪一侧。
+    /// * `pad_width` - 每个填充侧增加的样本数。
+    ///
+    /// # 错误
+    /// * 信号为空时返回 `PadError::ConcatenationError`。
+    /// * 填充后的长度无法表示时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{PadSide, Signal};
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let padded = signal.pad_reflect(PadSide::Both, 2).unwrap();
+    /// assert_eq!(padded.to_vec(), vec![3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0]);
+    /// ```
+    pub fn pad_reflect(&self, pad_side: PadSide, pad_width: usize) -> Result<Signal, PadError> {
+        self.signal
+            .pad_reflect(pad_side, pad_width)
+            .map(Signal::from_base)
+    }
+
+    /// Pads the signal by repeating its first and last samples.
+    ///
+    /// # Parameters
+    /// * `pad_side` - Which side(s) to pad.
+    /// * `pad_width` - The number of samples added on each padded side.
+    ///
+    /// # Errors
+    /// * `PadError::ConcatenationError` if the signal is empty.
+    /// * `PadError::ResultOutOfBounds` if the padded length is not representable.
+    ///
+    /// 重复首、尾样本填充信号。
+    ///
+    /// # 参数
+    /// * `pad_side` - 填充哪一侧。
+    /// * `pad_width` - 每个填充侧增加的样本数。
+    ///
+    /// # 错误
+    /// * 信号为空时返回 `PadError::ConcatenationError`。
+    /// * 填充后的长度无法表示时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{PadSide, Signal};
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    /// let padded = signal.pad_edge(PadSide::Both, 2).unwrap();
+    /// assert_eq!(padded.to_vec(), vec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0]);
+    /// ```
+    pub fn pad_edge(&self, pad_side: PadSide, pad_width: usize) -> Result<Signal, PadError> {
+        self.signal
+            .pad_edge(pad_side, pad_width)
+            .map(Signal::from_base)
+    }
+
+    /// Pads the signal with linear ramps from the edge samples to `end_value`.
+    ///
+    /// This matches numpy's `"linear_ramp"` mode: the outermost padded sample is exactly
+    /// `end_value` and the ramp approaches the adjacent edge sample in steps of
+    /// `(edge - end_value) / pad_width`, without repeating the edge sample. With
+    /// `PadSide::Both`, each side ramps from its own edge sample.
+    ///
+    /// # Parameters
+    /// * `pad_side` - Which side(s) to pad.
+    /// * `pad_width` - The number of samples added on each padded side.
+    /// * `end_value` - The value of the outermost padded sample.
+    ///
+    /// # Errors
+    /// * `PadError::ConcatenationError` if the signal is empty.
+    /// * `PadError::ResultOutOfBounds` if the padded length is not representable.
+    ///
+    /// 用从端点样本到 `end_value` 的线性斜坡填充信号。
+    ///
+    /// 与 numpy 的 `"linear_ramp"` 模式一致：最外侧的填充样本恰为 `end_value`，斜坡以
+    /// `(edge - end_value) / pad_width` 为步长趋近相邻的端点样本，且不重复端点样本。
+    /// 使用 `PadSide::Both` 时，两侧分别从各自的端点样本出发。
+    ///
+    /// # 参数
+    /// * `pad_side` - 填充哪一侧。
+    /// * `pad_width` - 每个填充侧增加的样本数。
+    /// * `end_value` - 最外侧填充样本的值。
+    ///
+    /// # 错误
+    /// * 信号为空时返回 `PadError::ConcatenationError`。
+    /// * 填充后的长度无法表示时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{PadSide, Signal};
+    /// let signal = Signal::from_vec(vec![4.0, 1.0]);
+    /// let padded = signal.pad_linear_ramp(PadSide::Both, 4, 0.0).unwrap();
+    /// assert_eq!(
+    ///     padded.to_vec(),
+    ///     vec![0.0, 1.0, 2.0, 3.0, 4.0, 1.0, 0.75, 0.5, 0.25, 0.0]
+    /// );
+    /// ```
+    pub fn pad_linear_ramp(
+        &self,
+        pad_side: PadSide,
+        pad_width: usize,
+        end_value: f64,
+    ) -> Result<Signal, PadError> {
+        self.signal
+            .pad_linear_ramp(pad_side, pad_width, end_value)
+            .map(Signal::from_base)
+    }
+
+    /// Pads the signal with a statistic of its samples.
+    ///
+    /// Without `stat_len` the statistic is computed over the whole signal. With
+    /// `stat_len = Some(n)` each side uses only its nearest `n` samples, so with
+    /// `PadSide::Both` the two sides may get different values; an `n` larger than the signal is
+    /// clamped to the signal length. NaN samples are ignored by every statistic.
+    ///
+    /// # Parameters
+    /// * `pad_side` - Which side(s) to pad.
+    /// * `pad_width` - The number of samples added on each padded side.
+    /// * `stat` - The statistic used as padding value.
+    /// * `stat_len` - Optional number of edge samples the statistic is computed from.
+    ///
+    /// # Errors
+    /// * `PadError::ConcatenationError` if the signal is empty.
+    /// * `PadError::Other` if `stat_len` is `Some(0)`.
+    /// * `PadError::ResultOutOfBounds` if the padded length is not representable.
+    ///
+    /// 用信号样本的统计量填充信号。
+    ///
+    /// 未给出 `stat_len` 时统计量基于整个信号计算。给出 `stat_len = Some(n)` 时，每一侧只使用靠近该侧的
+    /// `n` 个样本，因此使用 `PadSide::Both` 时两侧的值可能不同；`n` 大于信号长度时按信号长度处理。
+    /// 所有统计量都忽略 NaN 样本。
+    ///
+    /// # 参数
+    /// * `pad_side` - 填充哪一侧。
+    /// * `pad_width` - 每个填充侧增加的样本数。
+    /// * `stat` - 用作填充值的统计量。
+    /// * `stat_len` - 可选，计算统计量所用的端点附近样本数。
+    ///
+    /// # 错误
+    /// * 信号为空时返回 `PadError::ConcatenationError`。
+    /// * `stat_len` 为 `Some(0)` 时返回 `PadError::Other`。
+    /// * 填充后的长度无法表示时返回 `PadError::ResultOutOfBounds`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::{PadSide, Signal, StatPad};
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 6.0, 9.0]);
+    /// let padded = signal.pad_stat(PadSide::Both, 1, StatPad::Mean, None).unwrap();
+    /// assert_eq!(padded.to_vec(), vec![4.5, 1.0, 2.0, 6.0, 9.0, 4.5]);
+    /// let padded = signal.pad_stat(PadSide::Both, 1, StatPad::Max, Some(2)).unwrap();
+    /// assert_eq!(padded.to_vec(), vec![2.0, 1.0, 2.0, 6.0, 9.0, 9.0]);
+    /// ```
+    pub fn pad_stat(
+        &self,
+        pad_side: PadSide,
+        pad_width: usize,
+        stat: StatPad,
+        stat_len: Option<usize>,
+    ) -> Result<Signal, PadError> {
+        self.signal
+            .pad_stat(pad_side, pad_width, stat, stat_len)
+            .map(Signal::from_base)
+    }
+}
+
+// 加窗
+impl Signal {
+    /// Multiplies the signal by a window, optionally compensating the window gain.
+    ///
+    /// # Parameters
+    /// * `window` - The window, which must have the same length as the signal.
+    /// * `correction` - How to compensate the amplitude or power lost to windowing.
+    ///
+    /// # Errors
+    /// * `WindowError::LengthMismatch` if the lengths differ.
+    /// * `WindowError::ZeroGain` if the selected gain of the window is zero.
+    ///
+    /// 将信号与窗相乘，并可选地补偿窗的增益。
+    ///
+    /// # 参数
+    /// * `window` - 窗，长度必须与信号相同。
+    /// * `correction` - 补偿加窗造成的幅值或功率损失的方式。
+    ///
+    /// # 错误
+    /// * 长度不一致时返回 `WindowError::LengthMismatch`。
+    /// * 所选的窗增益为零时返回 `WindowError::ZeroGain`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use dsp4rust::window::{self, GainCorrection};
+    /// let signal = Signal::ones(8);
+    /// let w = window::tukey(8, 0.5).unwrap();
+    /// let windowed = signal.apply_window(&w, GainCorrection::Coherent).unwrap();
+    /// assert!((windowed.mean().unwrap() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn apply_window(
+        &self,
+        window: &Signal,
+        correction: GainCorrection,
+    ) -> Result<Signal, WindowError> {
+        if self.len() != window.len() {
+            return Err(WindowError::LengthMismatch {
+                signal: self.len(),
+                window: window.len(),
+            });
+        }
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        let gain = match correction {
+            GainCorrection::None => 1.0,
+            GainCorrection::Coherent => SignalBase::sum(window) / window.len() as f64,
+            GainCorrection::Noise => window.avg_power().sqrt(),
+        };
+        if gain == 0.0 {
+            return Err(WindowError::ZeroGain);
+        }
+        let mut windowed = self * window;
+        if gain != 1.0 {
+            windowed /= &gain;
+        }
+        Ok(windowed)
+    }
+}
+
+// 加噪声
+impl Signal {
+    /// Adds white Gaussian noise at a given signal-to-noise ratio.
+    ///
+    /// The signal power is measured with `avg_power()`, and freshly drawn zero-mean Gaussian
+    /// noise is rescaled so that its own average power is exactly
+    /// `avg_power() / 10^(snr_db / 10)`. The SNR of the result is therefore exact, not just
+    /// correct on average. An empty signal, a zero-power signal or `snr_db = +∞` is returned
+    /// unchanged.
+    ///
+    /// # Parameters
+    /// * `snr_db` - The desired signal-to-noise ratio in decibels.
+    /// * `seed` - Optional seed making the noise reproducible.
+    ///
+    /// 以给定的信噪比叠加高斯白噪声。
+    ///
+    /// 用 `avg_power()` 测量信号功率，再将新生成的零均值高斯噪声缩放，使其平均功率恰为
+    /// `avg_power() / 10^(snr_db / 10)`。因此结果的信噪比是精确的，而不只是在平均意义上正确。
+    /// 空信号、零功率信号或 `snr_db = +∞` 时原样返回。
+    ///
+    /// # 参数
+    /// * `snr_db` - 期望的信噪比（分贝）。
+    /// * `seed` - 可选的随机种子，使噪声可复现。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::ones(1000);
+    /// let noisy = signal.add_noise_snr(10.0, Some(1));
+    /// let noise = &noisy - &signal;
+    /// assert!((noise.avg_power() - 0.1).abs() < 1e-12);
+    /// ```
+    pub fn add_noise_snr(&self, snr_db: f64, seed: Option<u64>) -> Signal {
+        let noise_power = self.avg_power() / 10f64.powf(snr_db / 10.0);
+        if self.is_empty() || noise_power == 0.0 {
+            return self.clone();
+        }
+        let mut generator = Generator::new().sample_rate(1.0).num_samples(self.len());
+        if let Some(seed) = seed {
+            generator = generator.seed(seed);
+        }
+        let noise = generator
+            .build()
+            .expect("a non-empty generator at unit sample rate is valid")
+            .gaussian_white_noise(0.0, 1.0);
+        let scale = (noise_power / noise.avg_power()).sqrt();
+        self + &(&noise * scale)
+    }
+}
+
+// 与 approx 集成，逐元素比较，长度不同的信号不相等
+#[cfg(feature = "approx")]
+impl AbsDiffEq for Signal {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl RelativeEq for Signal {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+// 与 serde 集成，序列化为普通的 f64 序列，与 numpy 导出的 JSON 数组互通
+#[cfg(feature = "serde")]
+impl Serialize for Signal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Signal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<f64>::deserialize(deserializer).map(Signal::from_vec)
+    }
+}
+
+// 实现 Signal 与 Signal 的运算
+impl<'a, 'b> Add<&'b Signal> for &'a Signal {
+    type Output = Signal;
+    fn add(self, other: &'b Signal) -> Signal {
+        Signal::from_base(&self.signal + &other.signal)
+    }
+}
+
+impl<'a, 'b> Sub<&'b Signal> for &'a Signal {
+    type Output = Signal;
+    fn sub(self, other: &'b Signal) -> Signal {
+        Signal::from_base(&self.signal - &other.signal)
+    }
+}
+
+impl<'a, 'b> Mul<&'b Signal> for &'a Signal {
+    type Output = Signal;
+    fn mul(self, other: &'b Signal) -> Signal {
+        Signal::from_base(&self.signal * &other.signal)
+    }
+}
+
+impl<'a, 'b> Div<&'b Signal> for &'a Signal {
+    type Output = Signal;
+    fn div(self, other: &'b Signal) -> Signal {
+        Signal::from_base(&self.signal / &other.signal)
+    }
+}
+
+// 实现 Signal 的原地运算
+impl<'a> AddAssign<&'a Signal> for Signal {
+    fn add_assign(&mut self, other: &'a Signal) {
+        self.signal += &other.signal;
+    }
+}
+
+impl<'a> SubAssign<&'a Signal> for Signal {
+    fn sub_assign(&mut self, other: &'a Signal) {
+        self.signal -= &other.signal;
+    }
+}
+
+impl<'a> MulAssign<&'a Signal> for Signal {
+    fn mul_assign(&mut self, other: &'a Signal) {
+        self.signal *= &other.signal;
+    }
+}
+
+impl<'a> DivAssign<&'a Signal> for Signal {
+    fn div_assign(&mut self, other: &'a Signal) {
+        self.signal /= &other.signal;
+    }
+}
+
+// 实现 Signal 与标量的运算
+impl<'a, T> Add<&'a T> for &'a Signal
+where
+    T: AsPrimitive<f64>,
+{
+    type Output = Signal;
+    fn add(self, other: &'a T) -> Signal {
+        Signal::from_base(&self.signal + other)
+    }
+}
+
+impl<'a, T> Sub<&'a T> for &'a Signal
+where
+    T: AsPrimitive<f64>,
+{
+    type Output = Signal;
+    fn sub(self, other: &'a T) -> Signal {
+        Signal::from_base(&self.signal - other)
+    }
+}
+
+impl<'a, T> Mul<&'a T> for &'a Signal
+where
+    T: AsPrimitive<f64>,
+{
+    type Output = Signal;
+    fn mul(self, other: &'a T) -> Signal {
+        Signal::from_base(&self.signal * other)
+    }
+}
+
+impl<'a, T> Div<&'a T> for &'a Signal
+where
+    T: AsPrimitive<f64>,
+{
+    type Output = Signal;
+    fn div(self, other: &'a T) -> Signal {
+        Signal::from_base(&self.signal / other)
+    }
+}
+
+// 为 Signal 实现与引用标量的原地运算
+impl<'a, T> AddAssign<&'a T> for Signal
+where
+    T: AsPrimitive<f64>,
+{
+    fn add_assign(&mut self, other: &'a T) {
+        self.signal += other;
+    }
+}
+
+impl<'a, T> SubAssign<&'a T> for Signal
+where
+    T: AsPrimitive<f64>,
+{
+    fn sub_assign(&mut self, other: &'a T) {
+        self.signal -= other;
+    }
+}
+
+impl<'a, T> MulAssign<&'a T> for Signal
+where
+    T: AsPrimitive<f64>,
+{
+    fn mul_assign(&mut self, other: &'a T) {
+        self.signal *= other;
+    }
+}
+
+impl<'a, T> DivAssign<&'a T> for Signal
+where
+    T: AsPrimitive<f64>,
+{
+    fn div_assign(&mut self, other: &'a T) {
+        self.signal /= other;
+    }
+}
+
+// 取负：逐元素翻转符号，零变为负零
+impl Neg for &Signal {
+    type Output = Signal;
+    fn neg(self) -> Signal {
+        Signal::from_array1(self.as_array().mapv(|x| -x))
+    }
+}
+
+impl Neg for Signal {
+    type Output = Signal;
+    fn neg(mut self) -> Signal {
+        self.signal.neg_inplace();
+        self
+    }
+}
+
+// 按值与混合所有权的运算：按值传入的信号在长度一致时复用其缓冲区，否则退回引用版本（保留广播语义）
+macro_rules! impl_owned_ops {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<Signal> for Signal {
+            type Output = Signal;
+            fn $op(self, other: Signal) -> Signal {
+                self.$op(&other)
+            }
+        }
+
+        impl $Op<&Signal> for Signal {
+            type Output = Signal;
+            fn $op(mut self, other: &Signal) -> Signal {
+                if self.len() == other.len() {
+                    self.$op_assign(other);
+                    self
+                } else {
+                    (&self).$op(other)
+                }
+            }
+        }
+
+        impl $Op<Signal> for &Signal {
+            type Output = Signal;
+            fn $op(self, mut other: Signal) -> Signal {
+                if self.len() == other.len() {
+                    other
+                        .as_array_mut()
+                        .zip_mut_with(&self.as_array(), |y, &x| *y = x.$op(*y));
+                    other
+                } else {
+                    self.$op(&other)
+                }
+            }
+        }
+
+        impl<T> $Op<&T> for Signal
+        where
+            T: AsPrimitive<f64>,
+        {
+            type Output = Signal;
+            fn $op(mut self, other: &T) -> Signal {
+                self.$op_assign(other);
+                self
+            }
+        }
+
+        // 按值的标量只实现 f64 与 i32，使无后缀字面量的类型可以唯一确定
+        impl $Op<f64> for Signal {
+            type Output = Signal;
+            fn $op(self, other: f64) -> Signal {
+                self.$op(&other)
+            }
+        }
+
+        impl $Op<i32> for Signal {
+            type Output = Signal;
+            fn $op(self, other: i32) -> Signal {
+                self.$op(&other)
+            }
+        }
+
+        impl $Op<f64> for &Signal {
+            type Output = Signal;
+            fn $op(self, other: f64) -> Signal {
+                self.$op(&other)
+            }
+        }
+
+        impl $Op<i32> for &Signal {
+            type Output = Signal;
+            fn $op(self, other: i32) -> Signal {
+                self.$op(&other)
+            }
+        }
+    };
+}
+
+impl_owned_ops!(Add, add, AddAssign, add_assign);
+impl_owned_ops!(Sub, sub, SubAssign, sub_assign);
+impl_owned_ops!(Mul, mul, MulAssign, mul_assign);
+impl_owned_ops!(Div, div, DivAssign, div_assign);
+
+// 标量在左侧的运算：孤儿规则只允许为具体的原生类型实现，按值传入的信号原地复用其缓冲区
+macro_rules! impl_scalar_lhs_ops {
+    ($Op:ident, $op:ident; $($t:ty),*) => {
+        $(
+            impl $Op<&Signal> for $t {
+                type Output = Signal;
+                fn $op(self, other: &Signal) -> Signal {
+                    let scalar: f64 = self.as_();
+                    Signal::from_array1(other.as_array().mapv(|y| scalar.$op(y)))
+                }
+            }
+
+            impl $Op<Signal> for $t {
+                type Output = Signal;
+                fn $op(self, mut other: Signal) -> Signal {
+                    let scalar: f64 = self.as_();
+                    other.as_array_mut().mapv_inplace(|y| scalar.$op(y));
+                    other
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs_ops!(Add, add; f64, f32, i32, i64, u32, usize, &f64, &f32, &i32, &i64, &u32, &usize);
+impl_scalar_lhs_ops!(Sub, sub; f64, f32, i32, i64, u32, usize, &f64, &f32, &i32, &i64, &u32, &usize);
+impl_scalar_lhs_ops!(Mul, mul; f64, f32, i32, i64, u32, usize, &f64, &f32, &i32, &i64, &u32, &usize);
+impl_scalar_lhs_ops!(Div, div; f64, f32, i32, i64, u32, usize, &f64, &f32, &i32, &i64, &u32, &usize);
 
 /// Creates a Signal from a list of values or repeated value.
 ///
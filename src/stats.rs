@@ -0,0 +1,282 @@
+use crate::inner::base::CompensatedSum;
+use crate::signal::Signal;
+use crate::view::SignalView;
+use ndarray::ArrayView1;
+
+/// Count, sum and sum of squares of a signal, gathered in a single pass by `Signal::moments`.
+///
+/// Both sums use the same compensated summation as `Signal::sum` and `Signal::sum_of_squares`,
+/// so they agree with those methods bit for bit while reading the samples only once.
+///
+/// 由 `Signal::moments` 一次遍历得到的信号样本数、和与平方和。
+///
+/// 两个和使用与 `Signal::sum`、`Signal::sum_of_squares` 相同的补偿求和，因此与这些方法的结果逐位一致，
+/// 但只读取一遍样本。
+///
+/// # Example
+/// ```
+/// use dsp4rust::signal::Signal;
+/// let moments = Signal::from_vec(vec![1.0, -2.0, 3.0]).moments();
+/// assert_eq!(moments.count, 3);
+/// assert_eq!(moments.sum, 2.0);
+/// assert_eq!(moments.sum_of_squares, 14.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Moments {
+    /// Number of samples.
+    ///
+    /// 样本数。
+    pub count: usize,
+    /// Sum of the samples `Σ x[i]`.
+    ///
+    /// 样本之和 `Σ x[i]`。
+    pub sum: f64,
+    /// Sum of the squared samples `Σ x[i]²`, i.e. the energy.
+    ///
+    /// 样本平方和 `Σ x[i]²`，即能量。
+    pub sum_of_squares: f64,
+}
+
+impl Moments {
+    /// Returns the arithmetic mean, or `None` if there are no samples.
+    ///
+    /// 返回算术平均值，没有样本时返回 `None`。
+    pub fn mean(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            count => Some(self.sum / count as f64),
+        }
+    }
+
+    /// Returns the root mean square, or `None` if there are no samples.
+    ///
+    /// 返回均方根，没有样本时返回 `None`。
+    pub fn rms(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            count => Some((self.sum_of_squares / count as f64).sqrt()),
+        }
+    }
+}
+
+// 信号与视图共用的单遍内核
+pub(crate) fn moments_of(values: ArrayView1<'_, f64>) -> Moments {
+    let (mut sum, mut sum_of_squares) = (CompensatedSum::default(), CompensatedSum::default());
+    for &x in values {
+        sum.add(x);
+        sum_of_squares.add(x * x);
+    }
+    Moments {
+        count: values.len(),
+        sum: sum.value(),
+        sum_of_squares: sum_of_squares.value(),
+    }
+}
+
+// 单遍矩
+impl Signal {
+    /// Returns the count, sum and sum of squares in a single pass over the samples.
+    ///
+    /// Prefer this over separate calls to `sum` and `energy` when both are needed on a long
+    /// signal.
+    ///
+    /// 一次遍历样本，返回样本数、和与平方和。
+    ///
+    /// 对长信号同时需要 `sum` 与 `energy` 时，应优先使用本方法而不是分别调用。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![3.0, 4.0]);
+    /// let moments = signal.moments();
+    /// assert_eq!(moments.mean(), signal.mean());
+    /// assert_eq!(moments.rms(), signal.rms());
+    /// ```
+    pub fn moments(&self) -> Moments {
+        moments_of(self.as_array())
+    }
+}
+
+impl SignalView<'_> {
+    /// Returns the count, sum and sum of squares in a single pass, as `Signal::moments`.
+    ///
+    /// 一次遍历样本，返回样本数、和与平方和，与 `Signal::moments` 相同。
+    pub fn moments(&self) -> Moments {
+        moments_of(self.as_array())
+    }
+}
+
+/// Streaming accumulator of count, mean, variance, extremes and RMS for samples that arrive one
+/// at a time or in blocks, without holding the whole signal.
+///
+/// The mean and variance use Welford's algorithm, which stays accurate on long streams with a
+/// large DC offset, and `merge` combines the statistics of separately processed blocks with the
+/// pairwise update of Chan et al. NaN samples propagate to the mean, variance and RMS, while
+/// `min` and `max` skip them, as `f64::min` and `f64::max` do.
+///
+/// 流式累加器，逐个或逐块接收样本，在不保存整个信号的情况下统计样本数、均值、方差、极值与均方根。
+///
+/// 均值与方差使用 Welford 算法，在带有大直流偏置的长数据流上仍保持精度；`merge` 使用 Chan 等人的成对更新公式
+/// 合并分块处理得到的统计量。NaN 样本会传播到均值、方差与均方根，而 `min` 与 `max` 会像 `f64::min`、
+/// `f64::max` 一样跳过它们。
+///
+/// # Example
+/// ```
+/// use dsp4rust::signal::Signal;
+/// use dsp4rust::stats::RunningStats;
+/// let mut stats = RunningStats::new();
+/// stats.push_signal(&Signal::from_vec(vec![1.0, 2.0]));
+/// stats.push(6.0);
+/// assert_eq!(stats.count(), 3);
+/// assert_eq!(stats.mean(), Some(3.0));
+/// assert_eq!(stats.max(), Some(6.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RunningStats {
+    count: usize,
+    mean: f64,
+    // 离差平方和
+    m2: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl RunningStats {
+    /// Creates an empty accumulator.
+    ///
+    /// 创建空的累加器。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds one sample.
+    ///
+    /// 加入一个样本。
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        if !x.is_nan() {
+            self.min = Some(self.min.map_or(x, |min| min.min(x)));
+            self.max = Some(self.max.map_or(x, |max| max.max(x)));
+        }
+    }
+
+    /// Adds every sample of `signal`, in order.
+    ///
+    /// 按顺序加入 `signal` 的每个样本。
+    pub fn push_signal(&mut self, signal: &Signal) {
+        signal.iter().for_each(|&x| self.push(x));
+    }
+
+    /// Combines the statistics of another block into this one, as if its samples had been pushed
+    /// here.
+    ///
+    /// 将另一块数据的统计量合并到本累加器中，效果如同把它的样本加入本累加器。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// use dsp4rust::stats::RunningStats;
+    /// let (mut left, mut right) = (RunningStats::new(), RunningStats::new());
+    /// left.push_signal(&Signal::from_vec(vec![1.0, 2.0]));
+    /// right.push_signal(&Signal::from_vec(vec![3.0, 4.0]));
+    /// left.merge(&right);
+    /// assert_eq!(left.mean(), Some(2.5));
+    /// assert_eq!(left.min(), Some(1.0));
+    /// ```
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+        self.min = extreme(self.min, other.min, f64::min);
+        self.max = extreme(self.max, other.max, f64::max);
+    }
+
+    /// Returns the number of samples pushed so far.
+    ///
+    /// 返回目前已加入的样本数。
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the arithmetic mean, or `None` if no samples have been pushed.
+    ///
+    /// 返回算术平均值，尚未加入样本时返回 `None`。
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance (`n` in the denominator), NaN if no samples have been
+    /// pushed.
+    ///
+    /// 返回总体方差（分母为 `n`），尚未加入样本时为 NaN。
+    pub fn var_pop(&self) -> f64 {
+        self.m2 / self.count as f64
+    }
+
+    /// Returns the sample variance (`n - 1` in the denominator), NaN for fewer than two samples.
+    ///
+    /// 返回样本方差（分母为 `n - 1`），样本少于两个时为 NaN。
+    pub fn var_sample(&self) -> f64 {
+        match self.count {
+            0 | 1 => f64::NAN,
+            count => self.m2 / (count - 1) as f64,
+        }
+    }
+
+    /// Returns the population standard deviation (`n` in the denominator).
+    ///
+    /// 返回总体标准差（分母为 `n`）。
+    pub fn std_pop(&self) -> f64 {
+        self.var_pop().sqrt()
+    }
+
+    /// Returns the sample standard deviation (`n - 1` in the denominator).
+    ///
+    /// 返回样本标准差（分母为 `n - 1`）。
+    pub fn std_sample(&self) -> f64 {
+        self.var_sample().sqrt()
+    }
+
+    /// Returns the smallest non-NaN sample, or `None` if there is none.
+    ///
+    /// 返回最小的非 NaN 样本，没有时返回 `None`。
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Returns the largest non-NaN sample, or `None` if there is none.
+    ///
+    /// 返回最大的非 NaN 样本，没有时返回 `None`。
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Returns the root mean square, or `None` if no samples have been pushed.
+    ///
+    /// 返回均方根，尚未加入样本时返回 `None`。
+    pub fn rms(&self) -> Option<f64> {
+        // 均方值等于均值的平方加总体方差
+        self.mean()
+            .map(|mean| (mean * mean + self.var_pop()).sqrt())
+    }
+}
+
+fn extreme(a: Option<f64>, b: Option<f64>, pick: fn(f64, f64) -> f64) -> Option<f64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(pick(a, b)),
+        (a, b) => a.or(b),
+    }
+}
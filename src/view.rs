@@ -0,0 +1,241 @@
+use crate::errors::{ShapeError, SliceError};
+use crate::inner::base;
+use crate::signal::{check_same_len, Signal};
+use ndarray::iter::Iter;
+use ndarray::{ArrayView1, Ix1};
+use ndarray_stats::errors::MinMaxError;
+use ndarray_stats::QuantileExt;
+
+/// A borrowed, read-only window into a `Signal` that does not copy the samples.
+///
+/// Obtained from `Signal::view`, `Signal::view_range` or `Signal::view_windows`. The statistics
+/// are computed exactly as on `Signal`, so a view gives bit-identical results to the copy made
+/// by `cut_range`, without the allocation. A view borrows its parent, so the parent cannot be
+/// modified or dropped while the view is alive. Use `to_signal` to get an owned copy.
+///
+/// 对 `Signal` 的借用只读窗口，不复制样本。
+///
+/// 通过 `Signal::view`、`Signal::view_range` 或 `Signal::view_windows` 获得。统计量的计算方式与 `Signal`
+/// 完全相同，因此视图给出的结果与 `cut_range` 复制出的信号逐位一致，但没有内存分配。视图借用其父信号，
+/// 在视图存活期间父信号不能被修改或释放。需要拥有所有权的副本时请使用 `to_signal`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::signal::Signal;
+/// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+/// let view = signal.view_range(1, 3).unwrap();
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view.mean(), Some(2.5));
+/// assert_eq!(view.to_signal(), signal.cut_range(1, 3));
+/// ```
+///
+/// A view cannot outlive the signal it borrows from:
+///
+/// 视图的生命周期不能超过其借用的信号：
+///
+/// ```compile_fail
+/// use dsp4rust::signal::Signal;
+/// let view = {
+///     let signal = Signal::from_vec(vec![1.0, 2.0]);
+///     signal.view()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SignalView<'a> {
+    view: ArrayView1<'a, f64>,
+}
+
+impl<'a> SignalView<'a> {
+    pub(crate) fn new(view: ArrayView1<'a, f64>) -> Self {
+        SignalView { view }
+    }
+
+    /// Returns the number of samples in the view.
+    ///
+    /// 返回视图中的样本数。
+    pub fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    /// Returns `true` if the view has no samples.
+    ///
+    /// 视图没有样本时返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.view.is_empty()
+    }
+
+    /// Returns an iterator over the samples, borrowing from the parent signal.
+    ///
+    /// 返回样本的迭代器，借用自父信号。
+    pub fn iter(&self) -> Iter<'a, f64, Ix1> {
+        self.view.into_iter()
+    }
+
+    /// Returns the underlying ndarray view.
+    ///
+    /// 返回底层的 ndarray 视图。
+    pub fn as_array(&self) -> ArrayView1<'a, f64> {
+        self.view
+    }
+
+    /// Copies the samples into a new `Signal`.
+    ///
+    /// 将样本复制到一个新的 `Signal` 中。
+    pub fn to_signal(&self) -> Signal {
+        Signal::from_array1(self.view.to_owned())
+    }
+
+    /// Returns the sum of the samples.
+    ///
+    /// 返回样本之和。
+    pub fn sum(&self) -> f64 {
+        base::compensated_sum(self.view.iter().copied())
+    }
+
+    /// Returns the arithmetic mean, or `None` for an empty view.
+    ///
+    /// 返回算术平均值，视图为空时返回 `None`。
+    pub fn mean(&self) -> Option<f64> {
+        base::mean_of(self.view)
+    }
+
+    /// Returns the population standard deviation (`n` in the denominator).
+    ///
+    /// 返回总体标准差（分母为 `n`）。
+    pub fn std_pop(&self) -> f64 {
+        base::variance_of(self.view, 0).sqrt()
+    }
+
+    /// Returns the sample standard deviation (`n - 1` in the denominator).
+    ///
+    /// 返回样本标准差（分母为 `n - 1`）。
+    pub fn std_sample(&self) -> f64 {
+        base::variance_of(self.view, 1).sqrt()
+    }
+
+    /// Returns the minimum.
+    ///
+    /// # Errors
+    /// Returns `MinMaxError` if the view is empty or the samples cannot be ordered (NaN).
+    ///
+    /// 返回最小值。
+    ///
+    /// # 错误
+    /// 视图为空或样本无法排序（含 NaN）时返回 `MinMaxError`。
+    pub fn min(&self) -> Result<f64, MinMaxError> {
+        Ok(*self.view.min()?)
+    }
+
+    /// Returns the maximum.
+    ///
+    /// # Errors
+    /// Returns `MinMaxError` if the view is empty or the samples cannot be ordered (NaN).
+    ///
+    /// 返回最大值。
+    ///
+    /// # 错误
+    /// 视图为空或样本无法排序（含 NaN）时返回 `MinMaxError`。
+    pub fn max(&self) -> Result<f64, MinMaxError> {
+        Ok(*self.view.max()?)
+    }
+
+    /// Returns the energy `Σ x[i]²`.
+    ///
+    /// 返回能量 `Σ x[i]²`。
+    pub fn energy(&self) -> f64 {
+        base::sum_of_squares(self.view)
+    }
+
+    /// Returns the root mean square, or `None` for an empty view.
+    ///
+    /// 返回均方根，视图为空时返回 `None`。
+    pub fn rms(&self) -> Option<f64> {
+        match self.len() {
+            0 => None,
+            len => Some((self.energy() / len as f64).sqrt()),
+        }
+    }
+
+    /// Returns the dot product `Σ x[i]·y[i]` with another view. Two empty views give 0.
+    ///
+    /// # Errors
+    /// Returns `ShapeError::LengthMismatch` if the views differ in length.
+    ///
+    /// 返回与另一个视图的点积 `Σ x[i]·y[i]`。两个空视图的点积为 0。
+    ///
+    /// # 错误
+    /// 两个视图长度不同时返回 `ShapeError::LengthMismatch`。
+    pub fn dot(&self, other: SignalView<'_>) -> Result<f64, ShapeError> {
+        check_same_len(self.len(), other.len())?;
+        Ok(base::compensated_sum(
+            self.iter().zip(other.iter()).map(|(x, y)| x * y),
+        ))
+    }
+}
+
+// 零拷贝视图
+impl Signal {
+    /// Returns a view of the whole signal.
+    ///
+    /// 返回整个信号的视图。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![3.0, 4.0]);
+    /// assert_eq!(signal.view().rms(), signal.rms());
+    /// ```
+    pub fn view(&self) -> SignalView<'_> {
+        SignalView::new(self.as_array())
+    }
+
+    /// Returns a view of the half-open range from `start` up to but excluding `end`, with the
+    /// same index conventions as `try_cut_range` but without copying.
+    ///
+    /// # Errors
+    /// - `SliceError::IndexOutOfBounds` if either end is outside `-len..=len`.
+    /// - `SliceError::InvertedRange` if `start` resolves to a position after `end`.
+    ///
+    /// 返回从 `start` 到 `end`（不含）的半开区间的视图，索引约定与 `try_cut_range` 相同，但不复制数据。
+    ///
+    /// # 错误
+    /// - 任一端超出 `-len..=len` 时返回 `SliceError::IndexOutOfBounds`。
+    /// - `start` 换算后位于 `end` 之后时返回 `SliceError::InvertedRange`。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    /// let tail = signal.view_range(-2, 4).unwrap();
+    /// assert_eq!(tail.sum(), 7.0);
+    /// ```
+    pub fn view_range(&self, start: isize, end: isize) -> Result<SignalView<'_>, SliceError> {
+        let (start, end) = self.resolve_range(start, end)?;
+        Ok(SignalView::new(
+            self.as_array().slice_move(ndarray::s![start..end]),
+        ))
+    }
+
+    /// Returns an iterator over views of every window of `window_len` consecutive samples,
+    /// advancing one sample at a time, without copying.
+    ///
+    /// # Panics
+    /// Panics if `window_len` is zero.
+    ///
+    /// 返回每个由 `window_len` 个连续样本组成的窗口的视图迭代器，每次前进一个样本，不复制数据。
+    ///
+    /// # 恐慌
+    /// `window_len` 为零时发生恐慌。
+    ///
+    /// # Example
+    /// ```
+    /// use dsp4rust::signal::Signal;
+    /// let signal = Signal::from_vec(vec![1.0, 3.0, 2.0]);
+    /// let peaks: Vec<f64> = signal.view_windows(2).map(|w| w.max().unwrap()).collect();
+    /// assert_eq!(peaks, vec![3.0, 3.0]);
+    /// ```
+    pub fn view_windows(&self, window_len: usize) -> impl Iterator<Item = SignalView<'_>> {
+        assert!(window_len > 0, "window_len must be positive");
+        self.windows_view(window_len).map(SignalView::new)
+    }
+}
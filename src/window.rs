@@ -0,0 +1,307 @@
+use crate::errors::WindowError;
+use crate::generator;
+use crate::signal::Signal;
+use std::f64::consts::TAU;
+
+/// Gain compensation applied by `Signal::apply_window`.
+///
+/// 由 `Signal::apply_window` 执行的增益补偿方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GainCorrection {
+    /// No compensation, plain elementwise multiplication.
+    ///
+    /// 不补偿，仅逐元素相乘。
+    None,
+    /// Divide by the coherent gain (the window mean), preserving the amplitude of tones.
+    ///
+    /// 除以相干增益（窗的均值），保持单频信号的幅值。
+    Coherent,
+    /// Divide by the noise gain (the window RMS), preserving the power of broadband noise.
+    ///
+    /// 除以噪声增益（窗的均方根），保持宽带噪声的功率。
+    Noise,
+}
+
+/// Window shape applied to finite excitations such as `Generator::tone_burst`.
+///
+/// 用于有限长度激励（如 `Generator::tone_burst`）的窗形状。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowKind {
+    /// Constant `1.0`, i.e. no shaping.
+    ///
+    /// 恒为 `1.0`，即不做整形。
+    Rectangular,
+    /// Raised cosine `0.5·(1 - cos(2π·x))`, zero at both ends.
+    ///
+    /// 升余弦 `0.5·(1 - cos(2π·x))`，两端为零。
+    Hann,
+}
+
+impl WindowKind {
+    // 在归一化位置 x ∈ [0, 1] 处的窗函数值
+    pub(crate) fn value_at(self, x: f64) -> f64 {
+        match self {
+            WindowKind::Rectangular => 1.0,
+            WindowKind::Hann => 0.5 * (1.0 - (TAU * x).cos()),
+        }
+    }
+}
+
+/// Generates a symmetric Tukey (tapered cosine) window.
+///
+/// The window is flat in the middle and tapers to zero at both ends with a raised cosine.
+/// Samples whose distance `m` to the nearer edge satisfies `m <= floor(alpha * (len - 1) / 2)` lie
+/// on the taper, all other samples are exactly `1.0`. `alpha = 0` gives a rectangular window and
+/// `alpha = 1` gives a Hann window.
+///
+/// # Parameters
+/// * `len` - The length of the window.
+/// * `alpha` - Fraction of the window inside the cosine tapers. Range: [0.0, 1.0]
+///
+/// # Errors
+/// Returns `WindowError::ParameterOutOfRange` if `alpha` is NaN or outside [0.0, 1.0].
+///
+/// 生成对称的 Tukey（锥形余弦）窗。
+///
+/// 窗的中间部分平坦，两端以升余弦过渡到零。到较近端点距离 `m` 满足
+/// `m <= floor(alpha * (len - 1) / 2)` 的样本位于过渡段，其余样本恰好为 `1.0`。`alpha = 0` 得到
+/// 矩形窗，`alpha = 1` 得到 Hann 窗。
+///
+/// # 参数
+/// * `len` - 窗的长度。
+/// * `alpha` - 余弦过渡段占整个窗的比例。范围：[0.0, 1.0]
+///
+/// # 错误
+/// 当 `alpha` 为 NaN 或超出 [0.0, 1.0] 时返回 `WindowError::ParameterOutOfRange`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::tukey(11, 0.4).unwrap();
+/// assert_eq!(w[0], 0.0);
+/// assert_eq!(w[5], 1.0);
+/// ```
+pub fn tukey(len: usize, alpha: f64) -> Result<Signal, WindowError> {
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(WindowError::ParameterOutOfRange {
+            name: "alpha",
+            value: alpha,
+        });
+    }
+    let taper = alpha * len.saturating_sub(1) as f64 / 2.0;
+    // 过渡段样本数取整，与 SciPy 一致
+    let edge = taper.floor();
+    Ok(Signal::from_len_fn(len, |n| {
+        let m = n.min(len - 1 - n) as f64;
+        if taper > 0.0 && m <= edge {
+            0.5 * (1.0 - (TAU * m / (2.0 * taper)).cos())
+        } else {
+            1.0
+        }
+    }))
+}
+
+/// Generates a symmetric flat-top window.
+///
+/// Uses the standard five-term coefficient set. The very flat main lobe makes the FFT peak
+/// report the amplitude of a tone accurately no matter where it falls between bins.
+///
+/// # Parameters
+/// * `len` - The length of the window.
+///
+/// 生成对称的平顶窗。
+///
+/// 使用标准的五项系数。其主瓣非常平坦，无论单频信号落在两个频点之间的何处，FFT 峰值都能准确反映幅值。
+///
+/// # 参数
+/// * `len` - 窗的长度。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::flattop(65);
+/// assert!((w[32] - 1.0).abs() < 1e-8);
+/// ```
+pub fn flattop(len: usize) -> Signal {
+    general_cosine(
+        len,
+        &[
+            0.215_578_95,
+            0.416_631_58,
+            0.277_263_158,
+            0.083_578_947,
+            0.006_947_368,
+        ],
+    )
+}
+
+/// Generates a symmetric 4-term Blackman-Harris window.
+///
+/// # Parameters
+/// * `len` - The length of the window.
+///
+/// 生成对称的四项 Blackman-Harris 窗。
+///
+/// # 参数
+/// * `len` - 窗的长度。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::blackman_harris(65);
+/// assert!((w[32] - 1.0).abs() < 1e-12);
+/// ```
+pub fn blackman_harris(len: usize) -> Signal {
+    general_cosine(len, &[0.35875, 0.48829, 0.14128, 0.01168])
+}
+
+// 广义余弦窗：w[n] = Σ (-1)^k a_k cos(2πkn / (len - 1))
+fn general_cosine(len: usize, coeffs: &[f64]) -> Signal {
+    if len == 1 {
+        return Signal::ones(1);
+    }
+    let denom = len.saturating_sub(1) as f64;
+    Signal::from_len_fn(len, |n| {
+        coeffs
+            .iter()
+            .enumerate()
+            .map(|(k, a)| {
+                let sign = if k % 2 == 0 { 1.0 } else { -1.0 };
+                sign * a * (TAU * k as f64 * n as f64 / denom).cos()
+            })
+            .sum()
+    })
+}
+
+/// Generates a symmetric Gaussian window.
+///
+/// The window peaks at `1.0` in the middle of the window, index `(len - 1) / 2`, and follows
+/// `exp(-(n - center)² / (2σ²))` with `σ` measured in samples.
+///
+/// # Parameters
+/// * `len` - The length of the window.
+/// * `sigma` - The standard deviation in samples. Must be positive and finite.
+///
+/// # Errors
+/// Returns `WindowError::ParameterOutOfRange` if `sigma` is not positive and finite.
+///
+/// 生成对称的高斯窗。
+///
+/// 窗在中点（索引 `(len - 1) / 2`）处取峰值 `1.0`，形状为 `exp(-(n - center)² / (2σ²))`，
+/// 其中 `σ` 以样本数为单位。
+///
+/// # 参数
+/// * `len` - 窗的长度。
+/// * `sigma` - 以样本数为单位的标准差，必须为正的有限值。
+///
+/// # 错误
+/// 当 `sigma` 不是正的有限值时返回 `WindowError::ParameterOutOfRange`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::gaussian(9, 2.0).unwrap();
+/// assert_eq!(w[4], 1.0);
+/// ```
+pub fn gaussian(len: usize, sigma: f64) -> Result<Signal, WindowError> {
+    if !(sigma > 0.0 && sigma.is_finite()) {
+        return Err(WindowError::ParameterOutOfRange {
+            name: "sigma",
+            value: sigma,
+        });
+    }
+    let center = len.saturating_sub(1) as f64 / 2.0;
+    Ok(Signal::from_len_fn(len, |n| {
+        generator::gaussian(n as f64, center, sigma)
+    }))
+}
+
+/// Generates an exponential (Poisson) window.
+///
+/// The window follows `exp(-|n - center| / tau)`. When `center` is `None` the window is centered
+/// at index `(len - 1) / 2` and is symmetric.
+///
+/// # Parameters
+/// * `len` - The length of the window.
+/// * `center` - The peak position in samples, or `None` for the middle of the window.
+/// * `tau` - The decay constant in samples: the window falls by a factor of `e` every `tau`
+///   samples. Must be positive and finite.
+///
+/// # Errors
+/// Returns `WindowError::ParameterOutOfRange` if `tau` is not positive and finite, or if
+/// `center` is not finite.
+///
+/// 生成指数（泊松）窗。
+///
+/// 窗的形状为 `exp(-|n - center| / tau)`。当 `center` 为 `None` 时，窗以索引 `(len - 1) / 2`
+/// 为中心且对称。
+///
+/// # 参数
+/// * `len` - 窗的长度。
+/// * `center` - 以样本为单位的峰值位置，`None` 表示窗的中点。
+/// * `tau` - 以样本为单位的衰减常数：每经过 `tau` 个样本，窗值衰减为原来的 `1/e`。必须为正的有限值。
+///
+/// # 错误
+/// 当 `tau` 不是正的有限值或 `center` 不是有限值时返回 `WindowError::ParameterOutOfRange`。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::exponential(9, None, 2.0).unwrap();
+/// assert_eq!(w[4], 1.0);
+/// assert!((w[6] - (-1.0f64).exp()).abs() < 1e-12);
+/// ```
+pub fn exponential(len: usize, center: Option<f64>, tau: f64) -> Result<Signal, WindowError> {
+    if !(tau > 0.0 && tau.is_finite()) {
+        return Err(WindowError::ParameterOutOfRange {
+            name: "tau",
+            value: tau,
+        });
+    }
+    let center = center.unwrap_or(len.saturating_sub(1) as f64 / 2.0);
+    if !center.is_finite() {
+        return Err(WindowError::ParameterOutOfRange {
+            name: "center",
+            value: center,
+        });
+    }
+    Ok(Signal::from_len_fn(len, |n| {
+        (-(n as f64 - center).abs() / tau).exp()
+    }))
+}
+
+/// Turns a symmetric window into its periodic (DFT-even) counterpart.
+///
+/// All windows in this module are symmetric, which suits filter design. For spectral analysis
+/// the periodic form is often preferred: it is built by generating the symmetric window of
+/// length `len + 1` and dropping the last sample.
+///
+/// # Parameters
+/// * `len` - The length of the periodic window.
+/// * `symmetric` - A function producing the symmetric window of a given length.
+///
+/// 将对称窗转换为对应的周期（DFT 偶对称）窗。
+///
+/// 本模块中的窗函数均为对称窗，适用于滤波器设计。频谱分析通常更适合使用周期窗：
+/// 其构造方式为生成长度为 `len + 1` 的对称窗并去掉最后一个样本。
+///
+/// # 参数
+/// * `len` - 周期窗的长度。
+/// * `symmetric` - 根据长度生成对称窗的函数。
+///
+/// # Example
+/// ```
+/// use dsp4rust::window;
+/// let w = window::periodic(8, window::blackman_harris);
+/// assert_eq!(w.len(), 8);
+/// assert_eq!(w[4], window::blackman_harris(9)[4]);
+///
+/// let g = window::periodic(8, |n| window::gaussian(n, 2.0).unwrap());
+/// assert_eq!(g[4], 1.0);
+/// ```
+pub fn periodic<F>(len: usize, symmetric: F) -> Signal
+where
+    F: FnOnce(usize) -> Signal,
+{
+    symmetric(len + 1).iter().take(len).copied().collect()
+}
@@ -0,0 +1,90 @@
+use dsp4rust::signal::Signal;
+
+fn test_input() -> Signal {
+    // 覆盖小幅度与大幅度区间的样本，包括 A 律分段点附近
+    (-1000..=1000)
+        .map(|i| {
+            let x = i as f64 / 1000.0;
+            x * x * x
+        })
+        .collect()
+}
+
+#[test]
+fn test_mu_law_round_trip() {
+    let input = test_input();
+    for mu in [1.0, 100.0, 255.0] {
+        let compressed = input.mu_law_compress(mu);
+        assert!(compressed.iter().all(|y| y.abs() <= 1.0));
+        let restored = compressed.mu_law_expand(mu);
+        for (got, want) in restored.iter().zip(input.iter()) {
+            assert!((got - want).abs() < 1e-12, "mu {mu}: {got} vs {want}");
+        }
+    }
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_mu_law_reference_points() {
+    // μ = 255 曲线上的参考值
+    let input = Signal::from_vec(vec![0.001, 0.01, 0.1, 0.5, 1.0]);
+    let expected = [0.040961, 0.228477, 0.590990, 0.875703, 1.0];
+    let compressed = input.mu_law_compress(255.0);
+    for (got, want) in compressed.iter().zip(expected) {
+        assert!((got - want).abs() < 1e-6, "{got} vs {want}");
+    }
+    // 奇对称
+    let negated = (&input * &-1.0).mu_law_compress(255.0);
+    for (a, b) in negated.iter().zip(compressed.iter()) {
+        assert_eq!(*a, -b);
+    }
+}
+
+#[test]
+fn test_a_law_round_trip_and_reference_points() {
+    let input = test_input();
+    for a in [1.0, 10.0, 87.6] {
+        let restored = input.a_law_compress(a).a_law_expand(a);
+        for (got, want) in restored.iter().zip(input.iter()) {
+            assert!((got - want).abs() < 1e-12, "a {a}: {got} vs {want}");
+        }
+    }
+    // A = 1 时为恒等映射
+    let identity = input.a_law_compress(1.0);
+    for (got, want) in identity.iter().zip(input.iter()) {
+        assert!((got - want).abs() < 1e-15);
+    }
+
+    // A = 87.6 曲线上的参考值，0.005 与 0.01 位于线性段
+    let input = Signal::from_vec(vec![0.005, 0.01, 0.1, 0.5, 1.0]);
+    let expected = [0.080032, 0.160065, 0.579266, 0.873346, 1.0];
+    for (got, want) in input.a_law_compress(87.6).iter().zip(expected) {
+        assert!((got - want).abs() < 1e-6, "{got} vs {want}");
+    }
+}
+
+#[test]
+fn test_companding_clips_and_keeps_nan() {
+    let signal = Signal::from_vec(vec![3.0, -1.5, f64::NAN]);
+    for compressed in [signal.mu_law_compress(255.0), signal.a_law_compress(87.6)] {
+        let values = compressed.to_vec();
+        assert_eq!(values[..2], [1.0, -1.0]);
+        assert!(values[2].is_nan());
+    }
+    let expanded = signal.mu_law_expand(255.0).to_vec();
+    assert!((expanded[0] - 1.0).abs() < 1e-12 && (expanded[1] + 1.0).abs() < 1e-12);
+    assert!(expanded[2].is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_a_law_below_one_panics() {
+    Signal::ones(2).a_law_compress(0.5);
+}
+
+#[test]
+#[should_panic]
+fn test_mu_law_zero_panics() {
+    Signal::ones(2).mu_law_expand(0.0);
+}
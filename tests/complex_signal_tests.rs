@@ -0,0 +1,121 @@
+use dsp4rust::complex_signal::{Complex64, ComplexSignal};
+use dsp4rust::errors::ShapeError;
+use dsp4rust::hilbert::EnvelopeMethod;
+use dsp4rust::signal::Signal;
+use std::f64::consts::{PI, TAU};
+
+fn c(re: f64, im: f64) -> Complex64 {
+    Complex64::new(re, im)
+}
+
+fn close(a: &ComplexSignal, b: &ComplexSignal, tol: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| (x - y).norm() <= tol)
+}
+
+#[test]
+fn test_polar_round_trip() {
+    let magnitude: Signal = (0..32).map(|i| 0.5 + i as f64).collect();
+    // 相位取 (-π, π] 内的值，保证 arg 原样返回
+    let phase: Signal = (0..32).map(|i| PI - 0.19 * i as f64).collect();
+    let z = ComplexSignal::from_polar(&magnitude, &phase).unwrap();
+    assert!(z.abs().allclose(&magnitude, 1e-14, 0.0));
+    assert!(z.arg().allclose(&phase, 0.0, 1e-12));
+
+    let back = ComplexSignal::from_polar(&z.abs(), &z.arg()).unwrap();
+    assert!(close(&back, &z, 1e-12));
+
+    let parts = ComplexSignal::from_re_im(&z.re(), &z.im()).unwrap();
+    assert_eq!(parts, z);
+}
+
+#[test]
+fn test_construction_length_mismatch() {
+    let (a, b) = (Signal::ones(3), Signal::ones(4));
+    let mismatch = ShapeError::LengthMismatch { left: 3, right: 4 };
+    assert_eq!(ComplexSignal::from_re_im(&a, &b), Err(mismatch.clone()));
+    assert_eq!(ComplexSignal::from_polar(&a, &b), Err(mismatch));
+}
+
+#[test]
+fn test_conjugate_product_is_squared_magnitude() {
+    let z = ComplexSignal::from_vec(vec![c(3.0, 4.0), c(-1.0, 2.0), c(0.0, -5.0), c(0.0, 0.0)]);
+    let product = &z * &z.conj();
+    assert_eq!(product.re().to_vec(), vec![25.0, 5.0, 25.0, 0.0]);
+    assert!(product.im().iter().all(|&x| x == 0.0));
+    assert_eq!(product.re(), z.norm_sqr());
+}
+
+#[test]
+fn test_elementwise_arithmetic() {
+    let a = ComplexSignal::from_vec(vec![c(1.0, 2.0), c(3.0, -1.0)]);
+    let b = ComplexSignal::from_vec(vec![c(0.0, 1.0), c(2.0, 2.0)]);
+    assert_eq!((&a + &b).to_vec(), vec![c(1.0, 3.0), c(5.0, 1.0)]);
+    assert_eq!((&a - &b).to_vec(), vec![c(1.0, 1.0), c(1.0, -3.0)]);
+    assert_eq!((&a * &b).to_vec(), vec![c(-2.0, 1.0), c(8.0, 4.0)]);
+    assert!(close(&(&(&a * &b) / &b), &a, 1e-15));
+
+    // 按值传入与原地运算与引用版本一致
+    assert_eq!(a.clone() + &b, &a + &b);
+    assert_eq!(a.clone() * b.clone(), &a * &b);
+    assert_eq!(&a - b.clone(), &a - &b);
+    let mut sum = a.clone();
+    sum += &b;
+    assert_eq!(sum, &a + &b);
+
+    assert_eq!((-&a).to_vec(), vec![c(-1.0, -2.0), c(-3.0, 1.0)]);
+}
+
+#[test]
+fn test_real_and_scalar_operands() {
+    let z = ComplexSignal::from_vec(vec![c(1.0, 2.0), c(3.0, -1.0)]);
+    let window = Signal::from_vec(vec![0.5, 2.0]);
+    assert_eq!((&z * &window).to_vec(), vec![c(0.5, 1.0), c(6.0, -2.0)]);
+    assert_eq!((&z + &window).to_vec(), vec![c(1.5, 2.0), c(5.0, -1.0)]);
+
+    assert_eq!((&z * 2.0).to_vec(), vec![c(2.0, 4.0), c(6.0, -2.0)]);
+    assert_eq!((2.0 * &z).to_vec(), (&z * 2.0).to_vec());
+    assert_eq!((&z - 1.0).to_vec(), vec![c(0.0, 2.0), c(2.0, -1.0)]);
+    assert_eq!((1.0 - &z).to_vec(), vec![c(0.0, -2.0), c(-2.0, 1.0)]);
+
+    let i = c(0.0, 1.0);
+    assert_eq!((&z * i).to_vec(), vec![c(-2.0, 1.0), c(1.0, 3.0)]);
+    assert_eq!((i * &z).to_vec(), (&z * i).to_vec());
+    assert_eq!((&z / i).to_vec(), vec![c(2.0, -1.0), c(-1.0, -3.0)]);
+}
+
+#[test]
+fn test_fft_matches_direct_dft() {
+    // 非 2 的幂长度走 Bluestein 路径
+    for len in [1, 8, 12] {
+        let x: ComplexSignal = (0..len)
+            .map(|n| c((n as f64 * 0.7).cos(), n as f64 / 3.0))
+            .collect();
+        let direct: ComplexSignal = (0..len)
+            .map(|k| {
+                (0..len)
+                    .map(|n| x[n] * Complex64::from_polar(1.0, -TAU * (k * n) as f64 / len as f64))
+                    .sum()
+            })
+            .collect();
+        assert!(close(&x.fft(), &direct, 1e-9), "len = {len}");
+        assert!(close(&x.fft().ifft(), &x, 1e-12), "len = {len}");
+    }
+
+    let real = Signal::from_vec(vec![1.0, -2.0, 0.5, 4.0, 3.0]);
+    assert!(close(
+        &real.fft(),
+        &ComplexSignal::from_real(&real).fft(),
+        1e-12
+    ));
+}
+
+#[test]
+fn test_analytic_signal() {
+    let signal: Signal = (0..64)
+        .map(|i| (TAU * 5.0 * i as f64 / 64.0).cos())
+        .collect();
+    let analytic = signal.analytic_signal();
+    assert!(analytic.re().allclose(&signal, 0.0, 1e-12));
+    assert_eq!(analytic.abs(), signal.envelope(EnvelopeMethod::Hilbert));
+    assert_eq!(analytic.arg(), signal.instantaneous_phase());
+}
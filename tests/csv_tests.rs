@@ -0,0 +1,95 @@
+use dsp4rust::errors::IoError;
+use dsp4rust::signal::Signal;
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dsp4rust-{}-{name}.csv", std::process::id()))
+}
+
+fn values() -> Signal {
+    Signal::from_vec(vec![
+        0.0,
+        -0.0,
+        1.5,
+        -273.15,
+        6.02214076e23,
+        -1.602176634e-19,
+        0.1 + 0.2,
+        1e-300,
+        f64::MAX,
+    ])
+}
+
+#[test]
+fn test_csv_round_trip() {
+    let signal = values();
+    let path = temp_path("round-trip");
+    signal.write_csv(&path, None).unwrap();
+    let text = std::fs::read_to_string(&path).unwrap();
+    // 极端数量级使用科学计数法
+    assert!(text.contains("1e-300") && text.contains("6.02214076e23"));
+    assert_eq!(text.lines().count(), signal.len());
+    let back = Signal::read_csv(&path, 0, false).unwrap();
+    assert_eq!(back, signal);
+
+    signal.write_csv(&path, Some("voltage")).unwrap();
+    assert!(std::fs::read_to_string(&path)
+        .unwrap()
+        .starts_with("voltage\n"));
+    let back = Signal::read_csv(&path, 0, true).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(back, signal);
+}
+
+#[test]
+fn test_csv_with_time() {
+    let signal = values();
+    let path = temp_path("with-time");
+    signal
+        .write_csv_with_time(&path, 250.0, Some(("time", "value")))
+        .unwrap();
+    let time = Signal::read_csv(&path, 0, true).unwrap();
+    let back = Signal::read_csv(&path, 1, true).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(back, signal);
+    assert_eq!(time, (0..signal.len()).map(|i| i as f64 / 250.0).collect());
+}
+
+#[test]
+fn test_read_instrument_csv() {
+    // 仪器导出的文件常带引号、空白、Windows 换行与空行
+    let path = temp_path("instrument");
+    std::fs::write(
+        &path,
+        "\"Time (s)\",\"CH1 (V)\"\r\n0, \"1.25\"\r\n\r\n1e-3 , -2.5E-2\r\n2e-3,3\r\n",
+    )
+    .unwrap();
+    let ch1 = Signal::read_csv(&path, 1, true).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(ch1, Signal::from_vec(vec![1.25, -0.025, 3.0]));
+}
+
+#[test]
+fn test_read_csv_errors() {
+    // 解析失败报告行号，与 I/O 错误区分
+    let path = temp_path("bad-number");
+    std::fs::write(&path, "value\n1.0\n2.0\nabc\n").unwrap();
+    let result = Signal::read_csv(&path, 0, true);
+    assert!(matches!(result, Err(IoError::Parse { line: 4, .. })));
+    let message = result.unwrap_err().to_string();
+    assert!(
+        message.contains("line 4") && message.contains("abc"),
+        "{message}"
+    );
+
+    std::fs::write(&path, "1,2\n3\n").unwrap();
+    let result = Signal::read_csv(&path, 1, false);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(result, Err(IoError::Parse { line: 2, .. })));
+
+    let missing = temp_path("does-not-exist");
+    assert!(matches!(
+        Signal::read_csv(&missing, 0, false),
+        Err(IoError::Io(_))
+    ));
+}
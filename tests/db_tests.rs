@@ -0,0 +1,73 @@
+use dsp4rust::db::{amplitude_to_db, db_to_amplitude, db_to_power, power_to_db};
+use dsp4rust::signal::Signal;
+
+#[test]
+fn test_db_round_trip() {
+    let amplitudes = Signal::from_vec(vec![1e-6, 0.003, 0.5, 1.0, 7.0, 1234.5]);
+    let db = amplitudes.amplitude_to_db(1.0, -200.0);
+    for (back, original) in db.db_to_amplitude(1.0).iter().zip(amplitudes.iter()) {
+        assert!((back - original).abs() < 1e-12 * original.max(1.0));
+    }
+    let db = amplitudes.power_to_db(2.0, -200.0);
+    for (back, original) in db.db_to_power(2.0).iter().zip(amplitudes.iter()) {
+        assert!((back - original).abs() < 1e-12 * original.max(1.0));
+    }
+
+    // 幅度与功率的换算关系：功率为幅度的平方时分贝值相同
+    for a in [0.01, 0.3, 2.0, 50.0] {
+        let from_amplitude = amplitude_to_db(a, 1.0, -200.0);
+        let from_power = power_to_db(a * a, 1.0, -200.0);
+        assert!((from_amplitude - from_power).abs() < 1e-12);
+    }
+    assert!((db_to_amplitude(20.0, 1.0) - 10.0).abs() < 1e-12);
+    assert!((db_to_power(20.0, 1.0) - 100.0).abs() < 1e-12);
+
+    // 负幅度取绝对值
+    assert_eq!(amplitude_to_db(-10.0, 1.0, -200.0), 20.0);
+}
+
+#[test]
+fn test_db_floor() {
+    let signal = Signal::from_vec(vec![0.0, 1e-12, 1.0, -0.0]);
+    let db = signal.amplitude_to_db(1.0, -80.0);
+    assert_eq!(db.to_vec(), vec![-80.0, -80.0, 0.0, -80.0]);
+    let db = signal.power_to_db(1.0, -80.0);
+    assert_eq!(db.to_vec(), vec![-80.0, -80.0, 0.0, -80.0]);
+
+    // 取消下限时零得到 -∞
+    assert_eq!(
+        amplitude_to_db(0.0, 1.0, f64::NEG_INFINITY),
+        f64::NEG_INFINITY
+    );
+    assert_eq!(power_to_db(0.0, 1.0, f64::NEG_INFINITY), f64::NEG_INFINITY);
+
+    // NaN 与负功率不会被下限掩盖
+    assert!(amplitude_to_db(f64::NAN, 1.0, -80.0).is_nan());
+    assert!(power_to_db(f64::NAN, 1.0, -80.0).is_nan());
+    assert!(power_to_db(-1.0, 1.0, -80.0).is_nan());
+}
+
+#[test]
+fn test_db_reference() {
+    // 16 位满量程为参考的 dBFS 与相对 1.0 的分贝值相差 20·log10(32767)
+    let full_scale = 32767.0;
+    let samples = Signal::from_vec(vec![32767.0, 16383.5, 3276.7]);
+    let dbfs = samples.amplitude_to_db(full_scale, -120.0);
+    let expected = [0.0, -6.0206, -20.0];
+    for (got, want) in dbfs.iter().zip(expected) {
+        assert!((got - want).abs() < 1e-4, "{got} vs {want}");
+    }
+    let offset = 20.0 * full_scale.log10();
+    let re_one = samples.amplitude_to_db(1.0, -120.0);
+    for (absolute, relative) in re_one.iter().zip(dbfs.iter()) {
+        assert!((absolute - relative - offset).abs() < 1e-9);
+    }
+    assert!((db_to_amplitude(-6.0206, full_scale) - 16383.5).abs() < 0.01);
+    assert!((power_to_db(2e-3, 1e-3, -120.0) - 3.0103).abs() < 1e-4);
+}
+
+#[test]
+#[should_panic]
+fn test_db_non_positive_reference_panics() {
+    Signal::ones(3).amplitude_to_db(0.0, -120.0);
+}
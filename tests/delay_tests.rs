@@ -0,0 +1,82 @@
+use dsp4rust::delay::DelayLine;
+use dsp4rust::signal::Signal;
+
+#[test]
+fn test_tap_across_wrap_around() {
+    let mut line = DelayLine::new(4);
+    assert_eq!(line.capacity(), 4);
+    // 初始为零
+    assert_eq!(line.tap(3), 0.0);
+
+    // 写入 10 个样本，写指针绕回两次以上
+    for x in 1..=10 {
+        line.push(x as f64);
+        for delay in 0..4 {
+            let expected = (x - delay).max(0) as f64;
+            assert_eq!(
+                line.tap(delay as usize),
+                expected,
+                "x = {x}, delay = {delay}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_push_returns_sample_falling_out() {
+    let mut line = DelayLine::new(3);
+    let out: Vec<f64> = (1..=6).map(|x| line.push(x as f64)).collect();
+    assert_eq!(out, vec![0.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+
+    // 容量为 1 时 push 即单样本延迟
+    let mut unit = DelayLine::new(1);
+    assert_eq!(unit.push(5.0), 0.0);
+    assert_eq!(unit.tap(0), 5.0);
+    assert_eq!(unit.push(6.0), 5.0);
+}
+
+#[test]
+fn test_block_delay_matches_shift() {
+    let signal = Signal::from_len_fn(50, |i| (i as f64 * 0.7).sin() + i as f64);
+    for k in [0, 1, 5, 13] {
+        let block_len = 8;
+        let mut line = DelayLine::new(k + block_len);
+        let mut delayed = Vec::new();
+        for block in signal.to_vec().chunks(block_len) {
+            let block = Signal::from_vec(block.to_vec());
+            line.write_block(&block);
+            delayed.extend(line.read_block(k, block.len()).to_vec());
+        }
+        assert_eq!(Signal::from_vec(delayed), signal.shift(k as isize, 0.0));
+    }
+}
+
+#[test]
+fn test_read_block() {
+    let mut line = DelayLine::new(5);
+    line.write_block(&Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]));
+    assert_eq!(
+        line.read_block(0, 5).to_vec(),
+        vec![3.0, 4.0, 5.0, 6.0, 7.0]
+    );
+    assert_eq!(line.read_block(2, 2).to_vec(), vec![4.0, 5.0]);
+    assert!(line.read_block(5, 0).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "delay 4 is out of range")]
+fn test_tap_out_of_range() {
+    DelayLine::new(4).tap(4);
+}
+
+#[test]
+#[should_panic(expected = "does not fit")]
+fn test_read_block_out_of_range() {
+    DelayLine::new(4).read_block(2, 3);
+}
+
+#[test]
+#[should_panic(expected = "capacity must be positive")]
+fn test_zero_capacity() {
+    DelayLine::new(0);
+}
@@ -0,0 +1,327 @@
+use dsp4rust::detect::{PeakOptions, ZeroPolicy};
+use dsp4rust::signal::Signal;
+use std::f64::consts::TAU;
+
+#[test]
+fn test_zero_crossings_of_sine() {
+    // 相位偏移半个样本以避开恰好为零的样本；第 1001 个样本越过第 10 个周期的终点
+    let sine: Signal = (0..=1000)
+        .map(|n| (TAU * 10.0 * (n as f64 + 0.5) / 1000.0).sin())
+        .collect();
+    for policy in [ZeroPolicy::Positive, ZeroPolicy::Hold] {
+        let crossings = sine.zero_crossings(policy);
+        assert_eq!(crossings.len(), 20);
+        assert_eq!(sine.zero_crossing_count(policy), 20);
+        // 每半个周期（50 个样本）一次
+        for (k, &i) in crossings.iter().enumerate() {
+            assert_eq!(i, 49 + 50 * k);
+        }
+    }
+
+    let constant = Signal::from_vec(vec![2.0; 10]);
+    assert_eq!(constant.zero_crossing_count(ZeroPolicy::Positive), 0);
+    assert_eq!(
+        Signal::zeros(10).zero_crossing_count(ZeroPolicy::Positive),
+        0
+    );
+    assert_eq!(Signal::zeros(10).zero_crossing_count(ZeroPolicy::Hold), 0);
+    assert!(Signal::from_vec(vec![])
+        .zero_crossings(ZeroPolicy::Hold)
+        .is_empty());
+}
+
+#[test]
+fn test_zero_crossings_exact_zero_policy() {
+    let touch_from_below = Signal::from_vec(vec![-1.0, 0.0, -1.0]);
+    let touch_from_above = Signal::from_vec(vec![1.0, 0.0, 1.0]);
+    let through_zeros = Signal::from_vec(vec![-1.0, 0.0, 0.0, 1.0]);
+
+    // 零视为正数
+    assert_eq!(
+        touch_from_below.zero_crossings(ZeroPolicy::Positive),
+        vec![0, 1]
+    );
+    assert!(touch_from_above
+        .zero_crossings(ZeroPolicy::Positive)
+        .is_empty());
+    assert_eq!(through_zeros.zero_crossings(ZeroPolicy::Positive), vec![0]);
+
+    // 零沿用上一个非零样本的符号
+    assert!(touch_from_below.zero_crossings(ZeroPolicy::Hold).is_empty());
+    assert!(touch_from_above.zero_crossings(ZeroPolicy::Hold).is_empty());
+    assert_eq!(through_zeros.zero_crossings(ZeroPolicy::Hold), vec![2]);
+
+    assert_eq!(ZeroPolicy::default(), ZeroPolicy::Positive);
+
+    // NaN 样本被跳过
+    let with_nan = Signal::from_vec(vec![1.0, f64::NAN, -1.0]);
+    assert_eq!(with_nan.zero_crossings(ZeroPolicy::Positive), vec![1]);
+}
+
+#[test]
+fn test_zero_crossing_rate() {
+    // 前两帧交替变号，后两帧恒正，最后 3 个样本不足一帧
+    let mut values: Vec<f64> = (0..8)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+        .collect();
+    values.extend([1.0; 8]);
+    values.extend([-1.0, 1.0, -1.0]);
+    let signal = Signal::from_vec(values);
+
+    let zcr = signal.zero_crossing_rate(4, ZeroPolicy::Positive);
+    assert_eq!(zcr.to_vec(), vec![0.75, 0.75, 0.0, 0.0]);
+    assert_eq!(signal.zero_crossing_rate(1, ZeroPolicy::Hold).len(), 19);
+    assert!(signal
+        .zero_crossing_rate(1, ZeroPolicy::Hold)
+        .iter()
+        .all(|&r| r == 0.0));
+    assert_eq!(signal.zero_crossing_rate(20, ZeroPolicy::Hold).len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_crossing_rate_zero_frame_panics() {
+    Signal::ones(4).zero_crossing_rate(0, ZeroPolicy::Positive);
+}
+
+fn peak_indices(signal: &Signal, options: PeakOptions) -> Vec<usize> {
+    signal.find_peaks(options).iter().map(|p| p.index).collect()
+}
+
+#[test]
+fn test_find_peaks_plateaus_and_ends() {
+    // 奇数宽度平顶取中点，偶数宽度取偏左的一个
+    let odd = Signal::from_vec(vec![0.0, 1.0, 3.0, 3.0, 3.0, 1.0, 0.0]);
+    assert_eq!(peak_indices(&odd, PeakOptions::default()), vec![3]);
+    let even = Signal::from_vec(vec![0.0, 2.0, 2.0, 0.0]);
+    assert_eq!(peak_indices(&even, PeakOptions::default()), vec![1]);
+    // 平顶之后继续上升不算峰
+    let shoulder = Signal::from_vec(vec![0.0, 2.0, 2.0, 3.0, 0.0]);
+    assert_eq!(peak_indices(&shoulder, PeakOptions::default()), vec![3]);
+
+    // 首尾样本不是峰
+    let ends = Signal::from_vec(vec![5.0, 1.0, 2.0, 1.0, 5.0]);
+    assert_eq!(peak_indices(&ends, PeakOptions::default()), vec![2]);
+    let flat_end = Signal::from_vec(vec![0.0, 1.0, 1.0]);
+    assert!(flat_end.find_peaks(PeakOptions::default()).is_empty());
+    assert!(Signal::from_vec(vec![1.0, 2.0])
+        .find_peaks(PeakOptions::default())
+        .is_empty());
+}
+
+#[test]
+fn test_find_peaks_height_distance_prominence() {
+    let signal = Signal::from_vec(vec![0.0, 3.0, 0.0, 5.0, 0.0, 4.0, 0.0, 1.0, 0.0]);
+    assert_eq!(
+        peak_indices(&signal, PeakOptions::default()),
+        vec![1, 3, 5, 7]
+    );
+
+    let tall = PeakOptions {
+        min_height: Some(3.5),
+        ..PeakOptions::default()
+    };
+    assert_eq!(peak_indices(&signal, tall), vec![3, 5]);
+
+    // 距离约束优先保留较高的峰
+    let spaced = |d| PeakOptions {
+        min_distance: Some(d),
+        ..PeakOptions::default()
+    };
+    assert_eq!(peak_indices(&signal, spaced(2)), vec![1, 3, 5, 7]);
+    assert_eq!(peak_indices(&signal, spaced(3)), vec![3, 7]);
+    assert_eq!(peak_indices(&signal, spaced(5)), vec![3]);
+    // 高度相同时靠右的峰优先
+    let equal = Signal::from_vec(vec![0.0, 2.0, 0.0, 2.0, 0.0]);
+    assert_eq!(peak_indices(&equal, spaced(3)), vec![3]);
+
+    // 突出度：两侧基点中较高者到峰顶的距离
+    let ridge = Signal::from_vec(vec![0.0, 2.0, 1.0, 3.0, 0.5, 2.5, 0.0]);
+    let peaks = ridge.find_peaks(PeakOptions::default());
+    let prominences: Vec<f64> = peaks.iter().map(|p| p.prominence).collect();
+    assert_eq!(prominences, vec![1.0, 3.0, 2.0]);
+    assert_eq!(peaks[1].value, 3.0);
+    let prominent = PeakOptions {
+        min_prominence: Some(1.5),
+        ..PeakOptions::default()
+    };
+    assert_eq!(peak_indices(&ridge, prominent), vec![3, 5]);
+}
+
+#[test]
+fn test_find_peaks_noisy_multitone() {
+    use dsp4rust::generator::Generator;
+
+    // 5 Hz 主音叠加 60 Hz 纹波和噪声，只有主音的 5 个波峰足够突出
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .stop_time(1.0)
+        .seed(3)
+        .build()
+        .unwrap();
+    let tones = generator.multi_tone(&[(5.0, 1.0, 0.0), (60.0, 0.3, 0.0)]);
+    let noise = generator.gaussian_white_noise(0.0, 0.05);
+    let signal = &tones + &noise;
+
+    let all = signal.find_peaks(PeakOptions::default());
+    assert!(all.len() > 50);
+
+    let peaks = signal.find_peaks(PeakOptions {
+        min_prominence: Some(1.0),
+        ..PeakOptions::default()
+    });
+    assert_eq!(peaks.len(), 5);
+    for (k, peak) in peaks.iter().enumerate() {
+        let expected = 50 + 200 * k;
+        assert!(peak.index.abs_diff(expected) <= 15, "{k}: {}", peak.index);
+        assert!(peak.value > 1.0);
+    }
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_find_valleys_mirror_peaks() {
+    use dsp4rust::generator::Generator;
+
+    let x = Generator::new()
+        .sample_rate(1.0)
+        .num_samples(300)
+        .seed(5)
+        .build()
+        .unwrap()
+        .gaussian_white_noise(0.0, 1.0);
+    let negated = &x * &-1.0;
+    let options = [
+        PeakOptions::default(),
+        PeakOptions {
+            min_height: Some(0.5),
+            min_distance: Some(4),
+            min_prominence: Some(0.8),
+        },
+    ];
+    for options in options {
+        let peaks = negated.find_peaks(options);
+        let valleys = x.find_valleys(options);
+        assert!(!valleys.is_empty());
+        assert_eq!(peaks.len(), valleys.len());
+        for (peak, valley) in peaks.iter().zip(&valleys) {
+            assert_eq!(peak.index, valley.index);
+            assert_eq!(peak.prominence, valley.prominence);
+            // 值取自原信号
+            assert_eq!(valley.value, x[valley.index as isize]);
+            assert_eq!(valley.value, -peak.value);
+        }
+    }
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_argrel_extrema() {
+    let signal = Signal::from_vec(vec![1.0, 0.0, 2.0, 1.0, 3.0, 1.0, 1.0, 0.0]);
+    assert_eq!(signal.argrelmax(1), vec![2, 4]);
+    assert_eq!(signal.argrelmax(2), vec![4]);
+    assert_eq!(signal.argrelmin(1), vec![1, 3]);
+    // order = 2 时下标 3 与下标 1 比较而失去极小值资格
+    assert_eq!(signal.argrelmin(2), vec![1]);
+
+    // 端点：首尾样本不报告，靠近端点的样本只与存在的相邻样本比较
+    let edges = Signal::from_vec(vec![5.0, 4.0, 0.0, 4.0, 5.0]);
+    assert!(edges.argrelmax(1).is_empty());
+    assert_eq!(edges.argrelmin(1), vec![2]);
+    assert_eq!(edges.argrelmin(10), vec![2]);
+    let near_edge = Signal::from_vec(vec![0.0, 3.0, 1.0, 2.0, 0.0]);
+    assert_eq!(near_edge.argrelmax(3), vec![1]);
+
+    // 平顶不是严格极值
+    let plateau = Signal::from_vec(vec![0.0, 2.0, 2.0, 0.0]);
+    assert!(plateau.argrelmax(1).is_empty());
+
+    // x 上的极大值与 -x 上的极小值对称
+    let negated = &signal * &-1.0;
+    for order in 1..4 {
+        assert_eq!(signal.argrelmax(order), negated.argrelmin(order));
+        assert_eq!(signal.argrelmin(order), negated.argrelmax(order));
+    }
+    assert!(Signal::from_vec(vec![1.0]).argrelmax(1).is_empty());
+    assert!(Signal::from_vec(vec![]).argrelmin(1).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_argrelmax_zero_order_panics() {
+    Signal::ones(5).argrelmax(0);
+}
+
+#[test]
+fn test_threshold_crossings() {
+    use dsp4rust::detect::Direction;
+
+    let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 1.0, 0.0, 1.0]);
+    assert_eq!(signal.crossings(1.0, Direction::Rising), vec![1, 5]);
+    assert_eq!(signal.crossings(1.0, Direction::Falling), vec![4]);
+    assert_eq!(signal.crossings(1.0, Direction::Both), vec![1, 4, 5]);
+    assert_eq!(signal.crossings(1.5, Direction::Both), vec![2, 3]);
+
+    // 起始就在阈值之上：开头没有上升穿越
+    let starts_high = Signal::from_vec(vec![3.0, 3.0, 0.0, 3.0]);
+    assert_eq!(starts_high.crossings(1.0, Direction::Rising), vec![3]);
+    assert_eq!(starts_high.crossings(1.0, Direction::Falling), vec![2]);
+    assert_eq!(
+        starts_high.crossings_hysteresis(2.0, 1.0),
+        vec![(2, Direction::Falling), (3, Direction::Rising)]
+    );
+
+    assert!(Signal::from_vec(vec![])
+        .crossings(0.0, Direction::Both)
+        .is_empty());
+    assert!(Signal::from_vec(vec![0.0, f64::NAN, 2.0])
+        .crossings(1.0, Direction::Both)
+        .is_empty());
+}
+
+#[test]
+fn test_hysteresis_suppresses_chatter() {
+    use dsp4rust::detect::Direction;
+    use dsp4rust::generator::Generator;
+
+    // 从 0 到 1 的缓慢上升沿，叠加噪声
+    let noise = Generator::new()
+        .sample_rate(1.0)
+        .num_samples(1000)
+        .seed(9)
+        .build()
+        .unwrap()
+        .gaussian_white_noise(0.0, 0.05);
+    let ramp: Signal = (0..1000)
+        .map(|i| (i as f64 / 1000.0 * 2.0 - 0.5).clamp(0.0, 1.0))
+        .collect();
+    let edge = &ramp + &noise;
+
+    // 单一阈值在噪声边沿上来回抖动
+    let plain = edge.crossings(0.5, Direction::Both);
+    assert!(plain.len() > 3, "{plain:?}");
+
+    // 迟滞只报告一次上升事件
+    let events = edge.crossings_hysteresis(0.7, 0.3);
+    assert_eq!(events.len(), 1);
+    let (index, direction) = events[0];
+    assert_eq!(direction, Direction::Rising);
+    assert!(edge[index as isize] >= 0.7);
+    assert!((550..750).contains(&index));
+
+    // 上下电平相同时与普通阈值检测一致
+    let both: Vec<usize> = edge
+        .crossings_hysteresis(0.5, 0.5)
+        .iter()
+        .map(|&(i, _)| i)
+        .collect();
+    assert_eq!(both, plain);
+}
+
+#[test]
+#[should_panic]
+fn test_hysteresis_inverted_levels_panics() {
+    Signal::ones(3).crossings_hysteresis(0.0, 1.0);
+}
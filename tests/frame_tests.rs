@@ -0,0 +1,152 @@
+use dsp4rust::errors::FrameError;
+use dsp4rust::frame::SignalFrame;
+use dsp4rust::signal::Signal;
+
+fn frame(channels: &[&[f64]]) -> SignalFrame {
+    SignalFrame::new(
+        channels
+            .iter()
+            .map(|c| Signal::from_vec(c.to_vec()))
+            .collect(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_interleave_round_trip() {
+    // 交错数据的第 i 个样本等于 i
+    for channels in 1..=8 {
+        let interleaved: Signal = (0..channels * 5).map(|i| i as f64).collect();
+        let split = SignalFrame::from_interleaved(&interleaved, channels).unwrap();
+        assert_eq!(split.channels(), channels);
+        assert_eq!(split.len(), 5);
+        for c in 0..channels {
+            let expected: Vec<f64> = (0..5).map(|n| (n * channels + c) as f64).collect();
+            assert_eq!(split.channel(c).to_vec(), expected);
+        }
+        assert_eq!(split.to_interleaved(), interleaved);
+    }
+
+    let stereo = frame(&[&[1.0, 2.0], &[10.0, 20.0]]);
+    let round_trip = SignalFrame::from_interleaved(&stereo.to_interleaved(), 2).unwrap();
+    assert_eq!(round_trip, stereo);
+}
+
+#[test]
+fn test_from_interleaved_ragged_and_errors() {
+    // 与 deinterleave 一样丢弃不完整的最后一帧
+    let interleaved: Signal = (0..7).map(|x| x as f64).collect();
+    let split = SignalFrame::from_interleaved(&interleaved, 2).unwrap();
+    assert_eq!(split.into_channels(), interleaved.deinterleave(2));
+    assert_eq!(
+        SignalFrame::from_interleaved(&interleaved, 3)
+            .unwrap()
+            .to_interleaved(),
+        interleaved.cut_range(0, 6)
+    );
+    assert_eq!(
+        SignalFrame::from_interleaved(&interleaved, 0),
+        Err(FrameError::NoChannels)
+    );
+    // 空信号拆分为长度为零的通道
+    let empty = SignalFrame::from_interleaved(&Signal::zeros(0), 3).unwrap();
+    assert_eq!(empty.channels(), 3);
+    assert!(empty.is_empty());
+    assert!(empty.to_interleaved().is_empty());
+}
+
+#[test]
+fn test_new_rejects_unequal_channels() {
+    let result = SignalFrame::new(vec![Signal::ones(4), Signal::ones(4), Signal::ones(3)]);
+    assert_eq!(
+        result,
+        Err(FrameError::ChannelLength {
+            channel: 2,
+            expected: 4,
+            found: 3
+        })
+    );
+    assert_eq!(SignalFrame::new(vec![]), Err(FrameError::NoChannels));
+}
+
+#[test]
+fn test_channel_arithmetic() {
+    let a = frame(&[&[1.0, 2.0], &[3.0, 4.0]]);
+    let b = frame(&[&[4.0, 8.0], &[1.0, 2.0]]);
+    assert_eq!((&a + &b).unwrap(), frame(&[&[5.0, 10.0], &[4.0, 6.0]]));
+    assert_eq!((&b - &a).unwrap(), frame(&[&[3.0, 6.0], &[-2.0, -2.0]]));
+    assert_eq!((&a * &b).unwrap(), frame(&[&[4.0, 16.0], &[3.0, 8.0]]));
+    assert_eq!((&b / &a).unwrap(), frame(&[&[4.0, 4.0], &[1.0 / 3.0, 0.5]]));
+    assert_eq!(&a * 2.0, frame(&[&[2.0, 4.0], &[6.0, 8.0]]));
+    assert_eq!(&a - 1.0, frame(&[&[0.0, 1.0], &[2.0, 3.0]]));
+}
+
+#[test]
+fn test_arithmetic_shape_mismatch() {
+    let stereo = frame(&[&[1.0, 2.0], &[3.0, 4.0]]);
+    let mono = frame(&[&[1.0, 2.0]]);
+    let short = frame(&[&[1.0], &[3.0]]);
+    assert_eq!(
+        &stereo + &mono,
+        Err(FrameError::ShapeMismatch {
+            left: (2, 2),
+            right: (1, 2)
+        })
+    );
+    assert_eq!(
+        &stereo * &short,
+        Err(FrameError::ShapeMismatch {
+            left: (2, 2),
+            right: (2, 1)
+        })
+    );
+}
+
+#[test]
+fn test_channel_mut_and_map_channels() {
+    let mut stereo = frame(&[&[1.0, -2.0, 3.0], &[4.0, 5.0, -6.0]]);
+    stereo.channel_mut(0).mapv_inplace(|x| x * 10.0);
+    assert_eq!(stereo.channel(0).to_vec(), vec![10.0, -20.0, 30.0]);
+    assert_eq!(stereo.channel(1).to_vec(), vec![4.0, 5.0, -6.0]);
+
+    let rectified = stereo.map_channels(|c| c.abs()).unwrap();
+    assert_eq!(rectified, frame(&[&[10.0, 20.0, 30.0], &[4.0, 5.0, 6.0]]));
+
+    // 每个通道截取相同长度
+    let head = stereo.map_channels(|c| c.cut_range(0, 2)).unwrap();
+    assert_eq!(head.len(), 2);
+
+    // 通道长度不一致的结果被拒绝
+    let mut keep = 3;
+    let ragged = stereo.map_channels(|c| {
+        keep -= 1;
+        c.cut_range(0, keep)
+    });
+    assert_eq!(
+        ragged,
+        Err(FrameError::ChannelLength {
+            channel: 1,
+            expected: 2,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn test_channel_statistics() {
+    let stereo = frame(&[&[1.0, 3.0, 5.0, 7.0], &[3.0, -3.0, 3.0, -3.0]]);
+    assert_eq!(stereo.channel_means(), vec![4.0, 0.0]);
+    assert_eq!(stereo.channel_energy(), vec![84.0, 36.0]);
+    assert_eq!(stereo.channel_rms(), vec![21.0_f64.sqrt(), 3.0]);
+    assert_eq!(stereo.channel_std_pop(), vec![5.0_f64.sqrt(), 3.0]);
+    assert_eq!(stereo.channel_stat(|c| c.max().unwrap()), vec![7.0, 3.0]);
+
+    // 各统计量与逐通道调用 Signal 方法的结果一致
+    for (c, channel) in stereo.iter().enumerate() {
+        assert_eq!(stereo.channel_means()[c], channel.mean().unwrap());
+    }
+
+    let empty = SignalFrame::from_interleaved(&Signal::zeros(0), 2).unwrap();
+    assert!(empty.channel_means().iter().all(|m| m.is_nan()));
+    assert_eq!(empty.channel_energy(), vec![0.0, 0.0]);
+}
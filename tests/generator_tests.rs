@@ -0,0 +1,847 @@
+use dsp4rust::generator::Generator;
+use dsp4rust::signal::Signal;
+use std::f64::consts::TAU;
+
+// 上升/下降过零点的时间（线性插值），单位为秒
+fn crossing_times(x: &Signal, sample_rate: f64, start_time: f64) -> Vec<f64> {
+    let x = x.to_vec();
+    (0..x.len() - 1)
+        .filter(|&i| (x[i] <= 0.0) != (x[i + 1] <= 0.0))
+        .map(|i| start_time + (i as f64 + x[i] / (x[i] - x[i + 1])) / sample_rate)
+        .collect()
+}
+
+#[test]
+fn test_chirp_linear_instantaneous_frequency() {
+    let fs = 44100.0;
+    let (f0, f1) = (100.0, 1000.0);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.5)
+        .stop_time(1.5)
+        .build()
+        .unwrap();
+    let chirp = generator.chirp_linear(f0, f1, 0.0);
+    assert_eq!(chirp.len(), 44100);
+    assert_eq!(chirp[0], 0.0);
+
+    // 相邻过零点间隔为半个周期
+    let crossings = crossing_times(&chirp, fs, 0.5);
+    let estimates: Vec<(f64, f64)> = crossings
+        .windows(2)
+        .map(|w| ((w[0] + w[1]) / 2.0, 1.0 / (2.0 * (w[1] - w[0]))))
+        .collect();
+    let (_, first) = estimates[0];
+    let (_, last) = estimates[estimates.len() - 1];
+    assert!((first - f0).abs() / f0 < 0.05, "start frequency {first}");
+    assert!((last - f1).abs() / f1 < 0.05, "end frequency {last}");
+    for (t, f) in estimates {
+        let expected = f0 + (f1 - f0) * (t - 0.5);
+        assert!((f - expected).abs() / expected < 0.01);
+    }
+}
+
+#[test]
+fn test_chirp_log_geometric_midpoint() {
+    let fs = 48000.0;
+    let (f0, f1) = (100.0, 1600.0);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(2.0)
+        .build()
+        .unwrap();
+    let sweep = generator.chirp_log(f0, f1, 0.0).unwrap();
+    assert_eq!(sweep.len(), 96000);
+
+    // 中点时刻的瞬时频率为几何平均值 sqrt(f0·f1) = 400 Hz
+    let crossings = crossing_times(&sweep, fs, 0.0);
+    let (t_mid, f_mid) = crossings
+        .windows(2)
+        .map(|w| ((w[0] + w[1]) / 2.0, 1.0 / (2.0 * (w[1] - w[0]))))
+        .min_by(|a, b| (a.0 - 1.0).abs().total_cmp(&(b.0 - 1.0).abs()))
+        .unwrap();
+    assert!((t_mid - 1.0).abs() < 2e-3);
+    assert!(
+        (f_mid - (f0 * f1).sqrt()).abs() / 400.0 < 0.01,
+        "midpoint {f_mid}"
+    );
+
+    // 每个倍频程耗时相同：0.5 s 处为 200 Hz，1.5 s 处为 800 Hz
+    for (t, f) in crossings
+        .windows(2)
+        .map(|w| ((w[0] + w[1]) / 2.0, 1.0 / (2.0 * (w[1] - w[0]))))
+    {
+        let expected = f0 * (f1 / f0).powf(t / 2.0);
+        assert!((f - expected).abs() / expected < 0.01);
+    }
+
+    // f0 == f1 时退化为正弦波
+    let flat = generator.chirp_log(440.0, 440.0, 0.3).unwrap();
+    let sine = generator.sin_unit(440.0, 0.3);
+    for i in 0..1000 {
+        assert!((flat[i] - sine[i]).abs() < 1e-9);
+    }
+
+    assert!(generator.chirp_log(0.0, 1000.0, 0.0).is_err());
+    assert!(generator.chirp_log(100.0, -1.0, 0.0).is_err());
+    assert!(generator.chirp_log(f64::NAN, 1000.0, 0.0).is_err());
+}
+
+// 按倍频程分带估计周期图，返回 log2(带内平均功率) 对倍频程序号的回归斜率
+fn octave_psd_slope(x: &Signal) -> f64 {
+    let x = x.to_vec();
+    let n = x.len();
+    let bands: Vec<(f64, f64)> = (4..11)
+        .map(|octave| {
+            let (lo, hi) = (1usize << octave, 1usize << (octave + 1));
+            let step = ((hi - lo) / 32).max(1);
+            let bins: Vec<usize> = (lo..hi).step_by(step).collect();
+            let power = bins
+                .iter()
+                .map(|&k| {
+                    let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &v)| {
+                        let angle = TAU * k as f64 * i as f64 / n as f64;
+                        (re + v * angle.cos(), im - v * angle.sin())
+                    });
+                    re * re + im * im
+                })
+                .sum::<f64>()
+                / bins.len() as f64;
+            (octave as f64, power.log2())
+        })
+        .collect();
+    let mean_x = bands.iter().map(|b| b.0).sum::<f64>() / bands.len() as f64;
+    let mean_y = bands.iter().map(|b| b.1).sum::<f64>() / bands.len() as f64;
+    let cov: f64 = bands.iter().map(|b| (b.0 - mean_x) * (b.1 - mean_y)).sum();
+    let var: f64 = bands.iter().map(|b| (b.0 - mean_x).powi(2)).sum();
+    cov / var
+}
+
+#[test]
+fn test_colored_noise_spectral_slope_and_rms() {
+    let generator = Generator::new()
+        .sample_rate(8192.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let cases = [
+        (generator.brown_noise(0.3), -2.0),
+        (generator.blue_noise(0.3), 1.0),
+        (generator.violet_noise(0.3), 2.0),
+        (generator.colored_noise(0.0, 0.3), 0.0),
+    ];
+    for (noise, exponent) in cases {
+        assert_eq!(noise.len(), 8192);
+        assert!((noise.avg_power().sqrt() - 0.3).abs() < 1e-9);
+        assert!(noise.mean().unwrap().abs() < 1e-9);
+        let slope = octave_psd_slope(&noise);
+        assert!(
+            (slope - exponent).abs() < 0.3,
+            "exponent {exponent}: slope {slope}"
+        );
+    }
+
+    // 非 2 的幂长度同样适用
+    let odd = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap()
+        .brown_noise(2.0);
+    assert_eq!(odd.len(), 1000);
+    assert!((odd.avg_power().sqrt() - 2.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_uniform_white_noise_statistics() {
+    let generator = Generator::new()
+        .sample_rate(200000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let (low, high) = (-0.5, 2.5);
+    let noise = generator.uniform_white_noise(low, high).unwrap();
+    assert_eq!(noise.len(), 200000);
+    assert!(noise.iter().all(|&x| (low..high).contains(&x)));
+
+    // 均值 (low + high) / 2 = 1，方差 (high - low)² / 12 = 0.75
+    assert!((noise.mean().unwrap() - 1.0).abs() < 0.01);
+    assert!((noise.var_pop() - 0.75).abs() < 0.01);
+
+    assert!(generator.uniform_white_noise(1.0, 1.0).is_err());
+    assert!(generator.uniform_white_noise(2.0, 1.0).is_err());
+    assert!(generator
+        .uniform_white_noise(f64::NEG_INFINITY, 1.0)
+        .is_err());
+    assert!(generator.uniform_white_noise(0.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_seeded_noise_is_reproducible() {
+    let seeded = |seed| {
+        Generator::new()
+            .sample_rate(1000.0)
+            .start_time(0.0)
+            .stop_time(1.0)
+            .seed(seed)
+            .build()
+            .unwrap()
+    };
+    let (a, b, c) = (seeded(7), seeded(7), seeded(8));
+
+    let gaussian = |g: &Generator| g.gaussian_white_noise(0.0, 1.0).to_vec();
+    let uniform = |g: &Generator| g.uniform_white_noise(-1.0, 1.0).unwrap().to_vec();
+    let brown = |g: &Generator| g.brown_noise(1.0).to_vec();
+
+    assert_eq!(gaussian(&a), gaussian(&b));
+    assert_eq!(gaussian(&a), gaussian(&a));
+    assert_ne!(gaussian(&a), gaussian(&c));
+    assert_eq!(uniform(&a), uniform(&b));
+    assert_ne!(uniform(&a), uniform(&c));
+    assert_eq!(brown(&a), brown(&b));
+    assert_ne!(brown(&a), brown(&c));
+
+    // 未设置种子时每次调用结果不同
+    let unseeded = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    assert_ne!(gaussian(&unseeded), gaussian(&unseeded));
+}
+
+#[test]
+fn test_impulse_unit_and_signal_impulse() {
+    let generator = Generator::new()
+        .sample_rate(100.0)
+        .start_time(1.0)
+        .stop_time(2.0)
+        .build()
+        .unwrap();
+    let impulse_at = |t: f64| {
+        let impulse = generator.impulse_unit(t);
+        assert_eq!(impulse.len(), 100);
+        let ones: Vec<usize> = (0..100).filter(|&i| impulse[i as isize] == 1.0).collect();
+        assert!(impulse.iter().all(|&x| x == 0.0 || x == 1.0));
+        ones
+    };
+
+    // 就近取整到采样点
+    assert_eq!(impulse_at(1.0), vec![0]);
+    assert_eq!(impulse_at(1.304), vec![30]);
+    assert_eq!(impulse_at(1.306), vec![31]);
+    assert_eq!(impulse_at(1.99), vec![99]);
+    // 起始时间前半个采样间隔以内仍落在第一个样本上
+    assert_eq!(impulse_at(0.996), vec![0]);
+    // 超出范围时为全零
+    assert!(impulse_at(0.5).is_empty());
+    assert!(impulse_at(2.0).is_empty());
+    assert!(impulse_at(3.0).is_empty());
+
+    assert_eq!(Signal::impulse(3, 0).to_vec(), vec![1.0, 0.0, 0.0]);
+    assert_eq!(Signal::impulse(3, 2).to_vec(), vec![0.0, 0.0, 1.0]);
+    assert_eq!(Signal::impulse(3, 3).to_vec(), vec![0.0; 3]);
+    assert_eq!(Signal::impulse(0, 0).len(), 0);
+}
+
+// 单边幅度谱：第 bin 个频点上的正弦幅度
+fn dft_amplitude(x: &[f64], bin: usize) -> f64 {
+    let n = x.len() as f64;
+    let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &xi)| {
+        let angle = TAU * bin as f64 * i as f64 / n;
+        (re + xi * angle.cos(), im - xi * angle.sin())
+    });
+    2.0 * (re * re + im * im).sqrt() / n
+}
+
+#[test]
+fn test_multi_tone_spectrum() {
+    // 采样率 1000 Hz、时长 1 s，频率分辨率为 1 Hz，各分量恰好落在频点上
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let tones = [(50.0, 1.0, 0.3), (120.0, 0.5, 1.0), (333.0, 0.25, -2.0)];
+    let signal = generator.multi_tone(&tones);
+    assert_eq!(signal.len(), 1000);
+
+    let x = signal.to_vec();
+    let spectrum: Vec<f64> = (0..500).map(|bin| dft_amplitude(&x, bin)).collect();
+    let peaks: Vec<usize> = (0..500).filter(|&bin| spectrum[bin] > 0.1).collect();
+    assert_eq!(peaks, vec![50, 120, 333]);
+    for (freq, amplitude, _) in tones {
+        assert!((spectrum[freq as usize] - amplitude).abs() < 1e-9);
+    }
+    // 其余频点几乎为零
+    assert!((0..500)
+        .filter(|bin| !peaks.contains(bin))
+        .all(|bin| spectrum[bin] < 1e-9));
+
+    // 与逐个生成再相加的结果一致
+    let expected: Vec<f64> = (0..1000)
+        .map(|i| {
+            tones
+                .iter()
+                .map(|&(f, a, p)| a * (TAU * f * i as f64 / 1000.0 + p).sin())
+                .sum()
+        })
+        .collect();
+    assert!(x.iter().zip(&expected).all(|(a, b)| (a - b).abs() < 1e-9));
+
+    assert_eq!(generator.multi_tone(&[]).to_vec(), vec![0.0; 1000]);
+}
+
+#[test]
+fn test_am_sidebands() {
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let am = generator.am(200.0, 30.0, 1.0).unwrap().to_vec();
+    let spectrum: Vec<f64> = (0..500).map(|bin| dft_amplitude(&am, bin)).collect();
+
+    // 载波加上 fc ± fm 处的两个边带
+    let peaks: Vec<usize> = (0..500).filter(|&bin| spectrum[bin] > 1e-6).collect();
+    assert_eq!(peaks, vec![170, 200, 230]);
+    assert!((spectrum[200] - 1.0).abs() < 1e-9);
+    // m = 1 时边带比载波低约 6 dB
+    for bin in [170, 230] {
+        let relative_db = 20.0 * (spectrum[bin] / spectrum[200]).log10();
+        assert!((relative_db + 6.0206).abs() < 1e-3, "{relative_db}");
+    }
+
+    // 过调制是允许的，包络峰值为 1 + m
+    let over = generator.am(200.0, 30.0, 1.5).unwrap();
+    let peak = over.iter().fold(0.0_f64, |acc, x| acc.max(x.abs()));
+    assert!((peak - 2.5).abs() < 1e-9);
+    assert!(generator
+        .am(200.0, 30.0, 0.0)
+        .unwrap()
+        .iter()
+        .all(|x| x.abs() <= 1.0));
+
+    assert!(generator.am(200.0, 30.0, -0.1).is_err());
+    assert!(generator.am(200.0, 30.0, f64::NAN).is_err());
+}
+
+#[test]
+fn test_fm_and_pm_instantaneous_frequency() {
+    let fs = 48000.0;
+    let (fc, fm, deviation) = (1000.0, 5.0, 200.0);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let signal = generator.fm(fc, fm, deviation).unwrap();
+    assert_eq!(signal.len(), 48000);
+
+    // 由过零点估计瞬时频率，应在 fc ± Δf 之间按 fm 正弦摆动
+    let crossings = crossing_times(&signal, fs, 0.0);
+    let estimates: Vec<(f64, f64)> = crossings
+        .windows(2)
+        .map(|w| ((w[0] + w[1]) / 2.0, 1.0 / (2.0 * (w[1] - w[0]))))
+        .collect();
+    let highest = estimates.iter().map(|e| e.1).fold(f64::MIN, f64::max);
+    let lowest = estimates.iter().map(|e| e.1).fold(f64::MAX, f64::min);
+    assert!((highest - (fc + deviation)).abs() < 2.0, "{highest}");
+    assert!((lowest - (fc - deviation)).abs() < 2.0, "{lowest}");
+    for (t, f) in estimates {
+        let expected = fc + deviation * (TAU * fm * t).cos();
+        assert!((f - expected).abs() < 2.0);
+    }
+
+    // 调相与调频共用实现：Δφ = Δf / fm
+    let pm = generator.pm(fc, fm, deviation / fm);
+    assert_eq!(pm.to_vec(), signal.to_vec());
+    // 调制深度为零时退化为载波本身
+    let carrier = generator.multi_tone(&[(fc, 1.0, TAU / 4.0)]);
+    assert!(generator
+        .pm(fc, fm, 0.0)
+        .iter()
+        .zip(carrier.iter())
+        .all(|(a, b)| (a - b).abs() < 1e-9));
+
+    assert!(generator.fm(fc, 0.0, deviation).is_err());
+}
+
+// 任意频率处的离散时间傅里叶变换幅度
+fn dtft_magnitude(x: &[f64], freq: f64, sample_rate: f64) -> f64 {
+    let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &xi)| {
+        let angle = TAU * freq * i as f64 / sample_rate;
+        (re + xi * angle.cos(), im - xi * angle.sin())
+    });
+    (re * re + im * im).sqrt()
+}
+
+#[test]
+fn test_gauss_pulse_envelope_and_bandwidth() {
+    let fs = 1e6;
+    let (fc, bandwidth, center) = (50e3, 0.5, 4e-4);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(1e-3)
+        .build()
+        .unwrap();
+    let pulse = generator
+        .gauss_pulse(fc, bandwidth, center)
+        .unwrap()
+        .to_vec();
+    assert_eq!(pulse.len(), 1000);
+
+    // 包络峰值位于 center_time
+    let peak_index = (0..pulse.len())
+        .max_by(|&a, &b| pulse[a].abs().total_cmp(&pulse[b].abs()))
+        .unwrap();
+    assert_eq!(peak_index, 400);
+    assert!((pulse[400] - 1.0).abs() < 1e-12);
+    assert!(pulse[0].abs() < 1e-12 && pulse[999].abs() < 1e-12);
+
+    // 以 50 Hz 步长扫描频谱
+    let spectrum: Vec<(f64, f64)> = (0..=2000)
+        .map(|k| {
+            let f = k as f64 * 50.0;
+            (f, dtft_magnitude(&pulse, f, fs))
+        })
+        .collect();
+    let (peak_freq, peak) = spectrum
+        .iter()
+        .copied()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    assert!((peak_freq - fc).abs() / fc < 0.02, "{peak_freq}");
+
+    // -6 dB 带宽与请求的相对带宽一致
+    let threshold = peak * 10f64.powf(-6.0 / 20.0);
+    let above: Vec<f64> = spectrum
+        .iter()
+        .filter(|(_, m)| *m >= threshold)
+        .map(|(f, _)| *f)
+        .collect();
+    let measured = (above[above.len() - 1] - above[0]) / fc;
+    assert!(
+        (measured - bandwidth).abs() / bandwidth < 0.03,
+        "{measured}"
+    );
+
+    assert!(generator.gauss_pulse(0.0, bandwidth, center).is_err());
+    assert!(generator.gauss_pulse(fc, -0.5, center).is_err());
+}
+
+#[test]
+fn test_prbs_period_and_autocorrelation() {
+    // 各阶序列的周期均为 2^order - 1
+    let long = Generator::new()
+        .sample_rate(1.0)
+        .start_time(0.0)
+        .stop_time(2.0 * ((1 << 20) - 1) as f64)
+        .build()
+        .unwrap();
+    for order in 3..=20u8 {
+        let period = (1usize << order) - 1;
+        let x = long.prbs(order, 1).unwrap().to_vec();
+        assert!(x.iter().all(|&v| v == 1.0 || v == -1.0));
+        assert!((0..period).all(|i| x[i] == x[i + period]));
+        // 一个周期内 +1 比 -1 多一个
+        let ones = x[..period].iter().filter(|&&v| v == 1.0).count();
+        assert_eq!(ones, period.div_ceil(2), "order {order}");
+        // 周期不会更短
+        assert!((1..period)
+            .filter(|&p| period.is_multiple_of(p))
+            .all(|p| (0..period).any(|i| x[i] != x[i + p])));
+    }
+
+    // 循环自相关为冲激，非零延迟处为 -1/N
+    let order = 9;
+    let period = (1usize << order) - 1;
+    let generator = Generator::new()
+        .sample_rate(1.0)
+        .start_time(0.0)
+        .stop_time(period as f64)
+        .build()
+        .unwrap();
+    let x = generator.prbs(order, 0x1a5).unwrap().to_vec();
+    assert_eq!(x.len(), period);
+    for lag in 0..period {
+        let r = (0..period)
+            .map(|i| x[i] * x[(i + lag) % period])
+            .sum::<f64>()
+            / period as f64;
+        let expected = if lag == 0 { 1.0 } else { -1.0 / period as f64 };
+        assert!((r - expected).abs() < 1e-12, "lag {lag}: {r}");
+    }
+
+    assert!(generator.prbs(2, 1).is_err());
+    assert!(generator.prbs(21, 1).is_err());
+    assert!(generator.prbs(order, 0).is_err());
+    assert!(generator.prbs(order, 1 << order).is_err());
+}
+
+#[test]
+fn test_ramp_and_triangle_envelope_endpoints() {
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.25)
+        .stop_time(1.25)
+        .build()
+        .unwrap();
+
+    // 首尾样本精确等于给定值
+    let ramp = generator.ramp(0.3, 0.7).to_vec();
+    assert_eq!(ramp.len(), 1000);
+    assert_eq!(ramp[0], 0.3);
+    assert_eq!(ramp[999], 0.7);
+    assert!(ramp
+        .windows(2)
+        .all(|w| (w[1] - w[0] - 0.4 / 999.0).abs() < 1e-12));
+    assert_eq!(generator.ramp(-2.0, -2.0).to_vec(), vec![-2.0; 1000]);
+
+    let envelope = generator.triangle_envelope(0.5).unwrap().to_vec();
+    assert_eq!(envelope.len(), 1000);
+    assert_eq!(envelope[0], 0.0);
+    assert_eq!(envelope[250], 1.0);
+    assert_eq!(envelope[999], 0.0);
+    assert!(envelope[..=250].windows(2).all(|w| w[1] > w[0]));
+    assert!(envelope[250..].windows(2).all(|w| w[1] < w[0]));
+    assert!((envelope[125] - 0.5).abs() < 1e-12);
+
+    // 峰值位于起点或终点时只有单边
+    let falling = generator.triangle_envelope(0.25).unwrap().to_vec();
+    assert_eq!((falling[0], falling[999]), (1.0, 0.0));
+    let rising = generator.triangle_envelope(1.25).unwrap().to_vec();
+    assert_eq!((rising[0], rising[999]), (0.0, 1.0));
+
+    assert!(generator.triangle_envelope(0.2).is_err());
+    assert!(generator.triangle_envelope(1.3).is_err());
+    assert!(generator.triangle_envelope(f64::NAN).is_err());
+}
+
+#[test]
+fn test_time_vector_matches_generator_methods() {
+    let generator = Generator::new()
+        .sample_rate(44100.0)
+        .start_time(0.3)
+        .stop_time(1.3)
+        .build()
+        .unwrap();
+    let t = generator.time_vector();
+    assert_eq!(generator.num_samples(), 44100);
+    assert_eq!(t.len(), generator.num_samples());
+    assert_eq!(t[0], 0.3);
+
+    // fn_wave 与 time_vector 逐样本一致
+    assert_eq!(generator.fn_wave(|t| t).to_vec(), t.to_vec());
+
+    // 其他方法使用同一时间轴
+    let sine: Vec<f64> = t.iter().map(|&t| (TAU * 440.0 * t).sin()).collect();
+    assert_eq!(generator.sin_unit(440.0, 0.0).to_vec(), sine);
+    let step: Vec<f64> = t.iter().map(|&t| if t < 0.8 { 0.0 } else { 1.0 }).collect();
+    assert_eq!(generator.step_unit(0.8).to_vec(), step);
+}
+
+#[test]
+fn test_builder_end_alternatives_and_validation() {
+    use dsp4rust::errors::GeneratorError;
+
+    // duration 与 num_samples 可替代 stop_time
+    let by_duration = Generator::new()
+        .sample_rate(100.0)
+        .start_time(2.0)
+        .duration(1.5)
+        .build()
+        .unwrap();
+    assert_eq!(by_duration.num_samples(), 150);
+    assert_eq!(by_duration.time_vector()[0], 2.0);
+    let by_samples = Generator::new()
+        .sample_rate(44100.0)
+        .start_time(0.1)
+        .num_samples(1000)
+        .build()
+        .unwrap();
+    assert_eq!(by_samples.num_samples(), 1000);
+    assert_eq!(by_samples.sin_unit(440.0, 0.0).len(), 1000);
+    assert_eq!(by_samples.chirp_linear(100.0, 200.0, 0.0).len(), 1000);
+
+    // 以最后一次设置为准
+    let last_wins = Generator::new()
+        .sample_rate(10.0)
+        .num_samples(7)
+        .stop_time(3.0)
+        .build()
+        .unwrap();
+    assert_eq!(last_wins.num_samples(), 30);
+    let last_wins = Generator::new()
+        .sample_rate(10.0)
+        .stop_time(3.0)
+        .num_samples(7)
+        .build()
+        .unwrap();
+    assert_eq!(last_wins.num_samples(), 7);
+
+    // 各种非法配置
+    let invalid = |name, value| Some(GeneratorError::InvalidParameter { name, value });
+    let base = || Generator::new().sample_rate(1000.0).start_time(0.0);
+    assert!(matches!(
+        Generator::new().stop_time(1.0).build(),
+        Err(GeneratorError::InvalidParameter {
+            name: "sample_rate",
+            ..
+        })
+    ));
+    assert!(base().sample_rate(-1.0).stop_time(1.0).build().is_err());
+    assert!(base().sample_rate(f64::NAN).stop_time(1.0).build().is_err());
+    assert!(base()
+        .sample_rate(f64::INFINITY)
+        .stop_time(1.0)
+        .build()
+        .is_err());
+    assert!(base().start_time(f64::NAN).stop_time(1.0).build().is_err());
+    assert_eq!(base().build().err(), invalid("duration", 0.0));
+    assert_eq!(
+        base().start_time(1.0).stop_time(1.0).build().err(),
+        invalid("stop_time", 1.0)
+    );
+    assert_eq!(
+        base().stop_time(-1.0).build().err(),
+        invalid("stop_time", -1.0)
+    );
+    assert!(base().stop_time(f64::NAN).build().is_err());
+    assert_eq!(
+        base().duration(-0.5).build().err(),
+        invalid("duration", -0.5)
+    );
+    assert!(base().duration(f64::NAN).build().is_err());
+    assert_eq!(
+        base().num_samples(0).build().err(),
+        invalid("num_samples", 0.0)
+    );
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_scaled_waveforms_match_unit_waveforms() {
+    let generator = Generator::new()
+        .sample_rate(8000.0)
+        .start_time(0.1)
+        .stop_time(0.6)
+        .build()
+        .unwrap();
+    let (amplitude, offset) = (2.0, 1.0);
+    let scaled = |unit: Signal| (&(&unit * &amplitude) + &offset).to_vec();
+
+    // 一次生成的结果与先生成单位波形再缩放、平移逐样本相等
+    let (f, p) = (440.0, 0.7);
+    assert_eq!(
+        generator.sin(f, p, amplitude, offset).to_vec(),
+        scaled(generator.sin_unit(f, p))
+    );
+    assert_eq!(
+        generator.pulse(f, p, 0.3, amplitude, offset).to_vec(),
+        scaled(generator.pulse_unit(f, p, 0.3))
+    );
+    assert_eq!(
+        generator.square(f, p, amplitude, offset).to_vec(),
+        scaled(generator.square_unit(f, p))
+    );
+    assert_eq!(
+        generator.triangle(f, p, amplitude, offset).to_vec(),
+        scaled(generator.triangle_unit(f, p))
+    );
+    assert_eq!(
+        generator.sawtooth(f, p, amplitude, offset).to_vec(),
+        scaled(generator.sawtooth_unit(f, p))
+    );
+
+    // 方波取值只有 offset ± amplitude
+    let square = generator.square(100.0, 0.0, 0.5, 0.5);
+    assert!(square.iter().all(|&x| x == 0.0 || x == 1.0));
+}
+
+// 最强混叠分量相对基波的电平（dB）：信号恰含整数个周期时，非谐波频点上的能量均为混叠
+fn worst_alias_db(x: &Signal, fundamental_bin: usize) -> f64 {
+    let x = x.to_vec();
+    let fundamental = dft_amplitude(&x, fundamental_bin);
+    let worst = (1..x.len() / 2)
+        .filter(|bin| bin % fundamental_bin != 0)
+        .map(|bin| dft_amplitude(&x, bin))
+        .fold(0.0, f64::max);
+    20.0 * (worst / fundamental).log10()
+}
+
+#[test]
+fn test_band_limited_waveforms_reduce_aliasing() {
+    // 0.1 s 内恰含 100 个周期，频率分辨率 10 Hz，基波位于第 100 个频点
+    let (fs, f) = (44100.0, 1000.0);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(0.1)
+        .build()
+        .unwrap();
+    // 朴素波形按精确频率直接采样（sawtooth_unit 等会把周期取整为 44 个样本，混叠折叠到谐波上无法区分）
+    let phase = |t: f64| (f * t).rem_euclid(1.0);
+    let naive_saw = generator.fn_wave(|t| 2.0 * phase(t) - 1.0);
+    let naive_square = generator.fn_wave(|t| if phase(t) < 0.5 { 1.0 } else { -1.0 });
+
+    // 带限波形的最强混叠分量比朴素波形至少低 30 dB
+    let naive_db = worst_alias_db(&naive_saw, 100);
+    assert!(naive_db > -30.0, "{naive_db}");
+    let saw_db = worst_alias_db(&generator.sawtooth_bl(f, 0.0), 100);
+    assert!(saw_db < naive_db - 30.0, "{saw_db} vs {naive_db}");
+    let naive_db = worst_alias_db(&naive_square, 100);
+    let square_db = worst_alias_db(&generator.square_bl(f, 0.0), 100);
+    assert!(square_db < naive_db - 30.0, "{square_db} vs {naive_db}");
+    assert!(worst_alias_db(&generator.triangle_bl(f, 0.0), 100) < -100.0);
+
+    // 谐波幅度与理想锯齿波一致：2/(πk)
+    let saw = generator.sawtooth_bl(f, 0.0).to_vec();
+    for k in [1, 2, 5, 22] {
+        let expected = 2.0 / (std::f64::consts::PI * k as f64);
+        assert!((dft_amplitude(&saw, 100 * k) - expected).abs() < 1e-9);
+    }
+
+    // 三角波一致收敛，低频时与朴素波形几乎重合
+    let low = 100.0;
+    let triangle = generator.triangle_bl(low, 0.0);
+    let reference = generator.triangle_unit(low, 0.0);
+    assert!(triangle
+        .iter()
+        .zip(reference.iter())
+        .all(|(a, b)| (a - b).abs() < 0.01));
+
+    // 不低于奈奎斯特频率时为全零
+    assert!(generator
+        .sawtooth_bl(fs / 2.0, 0.0)
+        .iter()
+        .all(|&x| x == 0.0));
+}
+
+#[test]
+fn test_tone_burst_extent_and_cycles() {
+    use dsp4rust::window::WindowKind;
+
+    let fs = 48000.0;
+    let (f, cycles, start_at) = (1100.0, 7, 0.01);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(0.05)
+        .build()
+        .unwrap();
+
+    // 猝发占据 [start_at, start_at + 7 / 1100) 内的样本
+    let first = 480;
+    let last = ((start_at + cycles as f64 / f) * fs).ceil() as usize - 1;
+    for window in [WindowKind::Rectangular, WindowKind::Hann] {
+        let burst = generator.tone_burst(f, cycles, start_at, window).unwrap();
+        let x = burst.to_vec();
+        assert_eq!(x.len(), 2400);
+        assert!(x[..=first].iter().all(|&v| v == 0.0));
+        assert!(x[first + 1..=last].iter().all(|&v| v != 0.0));
+        assert!(x[last + 1..].iter().all(|&v| v == 0.0));
+
+        // 7 个完整周期在内部有 13 次过零
+        let crossings = x[first + 1..=last]
+            .windows(2)
+            .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+            .count();
+        assert_eq!(crossings, 2 * cycles - 1);
+    }
+
+    // 矩形窗下为纯正弦，Hann 窗下两端渐变为零
+    let rectangular = generator
+        .tone_burst(f, cycles, start_at, WindowKind::Rectangular)
+        .unwrap();
+    let hann = generator
+        .tone_burst(f, cycles, start_at, WindowKind::Hann)
+        .unwrap();
+    let tau = (first + 10) as f64 / fs - start_at;
+    assert!((rectangular[(first + 10) as isize] - (TAU * f * tau).sin()).abs() < 1e-9);
+    assert!(hann[(first + 1) as isize].abs() < 1e-3);
+    assert!(hann[last as isize].abs() < 1e-3);
+
+    // 超出生成范围的部分被截断
+    let late = generator
+        .tone_burst(f, cycles, 0.049, WindowKind::Rectangular)
+        .unwrap();
+    assert!(late.iter().take(2352).all(|&v| v == 0.0));
+    assert!(generator
+        .tone_burst(f, cycles, 1.0, WindowKind::Hann)
+        .unwrap()
+        .iter()
+        .all(|&v| v == 0.0));
+
+    assert!(generator
+        .tone_burst(0.0, cycles, start_at, WindowKind::Hann)
+        .is_err());
+    assert!(generator
+        .tone_burst(f, cycles, f64::NAN, WindowKind::Hann)
+        .is_err());
+}
+
+#[test]
+fn test_pulse_train_count_and_width() {
+    let fs = 48000.0;
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(0.1)
+        .build()
+        .unwrap();
+
+    // 记录每个脉冲的起点与样本宽度
+    let pulses = |x: &Signal| {
+        let x = x.to_vec();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < x.len() {
+            if x[i] != 0.0 {
+                let start = i;
+                while i < x.len() && x[i] != 0.0 {
+                    i += 1;
+                }
+                found.push((start, i - start));
+            } else {
+                i += 1;
+            }
+        }
+        found
+    };
+
+    // 1 ms 脉冲、重复频率 300 Hz：0.1 s 内共 30 个脉冲，每个 48 个样本
+    let train = generator.pulse_train(300.0, 0.001, 2.5).unwrap();
+    assert!(train.iter().all(|&x| x == 0.0 || x == 2.5));
+    let found = pulses(&train);
+    assert_eq!(found.len(), 30);
+    for (k, &(start, width)) in found.iter().enumerate() {
+        assert_eq!(start, (k as f64 * 160.0).round() as usize);
+        assert_eq!(width, 48);
+    }
+
+    // 非整数周期（48000 / 65 样本）时脉宽依然恒定；最后一个脉冲被末尾截断
+    let train = generator.pulse_train(65.0, 0.009, 1.0).unwrap();
+    let found = pulses(&train);
+    assert_eq!(found.len(), 7);
+    assert!(found[..6].iter().all(|&(_, width)| width == 432));
+    let (last_start, last_width) = found[6];
+    assert_eq!(last_start, (6.0 * fs / 65.0_f64).round() as usize);
+    assert_eq!(last_width, 4800 - last_start);
+    assert!(last_width < 432);
+
+    assert!(generator.pulse_train(0.0, 0.001, 1.0).is_err());
+    assert!(generator.pulse_train(300.0, 0.0, 1.0).is_err());
+    assert!(generator.pulse_train(300.0, 1.0 / 300.0, 1.0).is_err());
+    assert!(generator.pulse_train(300.0, f64::NAN, 1.0).is_err());
+}
@@ -0,0 +1,150 @@
+use dsp4rust::generator::Generator;
+use dsp4rust::hilbert::EnvelopeMethod;
+use dsp4rust::resample::InterpKind;
+use dsp4rust::signal::Signal;
+use std::f64::consts::{PI, TAU};
+
+const METHODS: [EnvelopeMethod; 3] = [
+    EnvelopeMethod::Hilbert,
+    EnvelopeMethod::Peaks {
+        interp: InterpKind::Linear,
+    },
+    EnvelopeMethod::Peaks {
+        interp: InterpKind::CubicHermite,
+    },
+];
+
+#[test]
+fn test_envelope_of_gauss_pulse() {
+    let (fs, fc, bandwidth, center) = (1e6, 50e3, 0.1, 1e-3);
+    let generator = Generator::new()
+        .sample_rate(fs)
+        .start_time(0.0)
+        .stop_time(2e-3)
+        .build()
+        .unwrap();
+    let pulse = generator.gauss_pulse(fc, bandwidth, center).unwrap();
+    // 与 gauss_pulse 相同的高斯包络 exp(-a·τ²)
+    let a = -(PI * fc * bandwidth).powi(2) / (4.0 * 10f64.powf(-6.0 / 20.0).ln());
+    let expected: Vec<f64> = (0..pulse.len())
+        .map(|i| (-a * (i as f64 / fs - center).powi(2)).exp())
+        .collect();
+
+    for method in METHODS {
+        let envelope = pulse.envelope(method);
+        assert_eq!(envelope.len(), pulse.len());
+        // 中心区域（真实包络大于 0.5）内误差在百分之几以内
+        for (i, want) in expected.iter().enumerate().filter(|(_, &e)| e > 0.5) {
+            let got = envelope[i as isize];
+            assert!(
+                (got - want).abs() < 0.03 * want,
+                "{method:?} at {i}: {got} vs {want}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_envelope_of_am_tone() {
+    // 调幅信号的包络为 1 + 0.5·cos(2π·fm·t)
+    let n = 4096;
+    let envelope_at = |i: usize| 1.0 + 0.5 * (TAU * 4.0 * i as f64 / n as f64).cos();
+    let signal: Signal = (0..n)
+        .map(|i| envelope_at(i) * (TAU * 256.0 * i as f64 / n as f64).cos())
+        .collect();
+    let hilbert = signal.envelope(EnvelopeMethod::Hilbert);
+    for i in 0..n {
+        assert!((hilbert[i as isize] - envelope_at(i)).abs() < 1e-9);
+    }
+    let peaks = signal.envelope(EnvelopeMethod::Peaks {
+        interp: InterpKind::CubicHermite,
+    });
+    for i in 16..n - 16 {
+        assert!((peaks[i as isize] - envelope_at(i)).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn test_envelope_edge_cases() {
+    for method in METHODS {
+        assert_eq!(Signal::from_vec(vec![]).envelope(method).len(), 0);
+    }
+    // 没有局部极大值时返回 |x|
+    let monotonic = Signal::from_vec(vec![-3.0, -2.0, -1.0]);
+    let peaks = monotonic.envelope(EnvelopeMethod::Peaks {
+        interp: InterpKind::Linear,
+    });
+    assert_eq!(peaks.to_vec(), vec![3.0, 2.0, 1.0]);
+
+    // 节点之间线性插值，节点以外保持端点值
+    let signal = Signal::from_vec(vec![0.0, 2.0, 0.0, 0.0, -4.0, 1.0, 0.0]);
+    let peaks = signal.envelope(EnvelopeMethod::Peaks {
+        interp: InterpKind::Linear,
+    });
+    let expected = [2.0, 2.0, 8.0 / 3.0, 10.0 / 3.0, 4.0, 4.0, 4.0];
+    assert_eq!(peaks.len(), expected.len());
+    for (got, want) in peaks.iter().zip(expected) {
+        assert!((got - want).abs() < 1e-12, "{got} vs {want}");
+    }
+
+    // 平顶与 find_peaks 一样取中点作为节点
+    let plateau = Signal::from_vec(vec![0.0, 1.0, 3.0, 3.0, 3.0, 1.0, 0.0, 2.0, 0.0]);
+    let peaks = plateau.envelope(EnvelopeMethod::Peaks {
+        interp: InterpKind::Linear,
+    });
+    assert_eq!(
+        peaks.to_vec(),
+        vec![3.0, 3.0, 3.0, 3.0, 2.75, 2.5, 2.25, 2.0, 2.0]
+    );
+}
+
+#[test]
+fn test_instantaneous_frequency_of_sine() {
+    // 信号不是整数个周期，边缘处的泄漏随距离衰减，只检查距两端 0.25 s 以外的部分
+    let fs = 8000.0;
+    let signal = Generator::new()
+        .sample_rate(fs)
+        .num_samples(16050)
+        .build()
+        .unwrap()
+        .sin(440.0, 0.3, 1.0, 0.0);
+    let frequency = signal.instantaneous_frequency(fs).unwrap();
+    assert_eq!(frequency.len(), signal.len());
+    for i in 2000..16050 - 2000 {
+        let f = frequency[i as isize];
+        assert!((f - 440.0).abs() < 0.5, "{f} at {i}");
+    }
+
+    let phase = signal.instantaneous_phase();
+    assert!(phase.iter().all(|p| p.abs() <= PI));
+    assert!(Signal::from_vec(vec![1.0])
+        .instantaneous_frequency(fs)
+        .is_err());
+}
+
+#[test]
+fn test_unwrap_phase() {
+    // 线性相位卷绕成锯齿后，解卷绕恢复为直线
+    let line: Vec<f64> = (0..500).map(|i| -1.0 + 0.37 * i as f64).collect();
+    let sawtooth: Signal = line.iter().map(|p| (p + PI).rem_euclid(TAU) - PI).collect();
+    assert!(sawtooth.iter().any(|&p| p < 0.0));
+    let unwrapped = sawtooth.unwrap_phase(PI);
+    for (got, want) in unwrapped.iter().zip(&line) {
+        assert!((got - want).abs() < 1e-9, "{got} vs {want}");
+    }
+
+    // 递减的相位同样可以恢复
+    let falling: Signal = line.iter().map(|p| (-p).sin().atan2((-p).cos())).collect();
+    let unwrapped = falling.unwrap_phase(PI);
+    for (got, want) in unwrapped.iter().zip(&line) {
+        assert!((got + want).abs() < 1e-9);
+    }
+
+    // 大于 π 的 discont 会容忍更大的跳变
+    let jumpy = Signal::from_vec(vec![0.0, 4.0, 8.0]);
+    assert_eq!(jumpy.unwrap_phase(5.0).to_vec(), vec![0.0, 4.0, 8.0]);
+    let unwrapped = jumpy.unwrap_phase(PI).to_vec();
+    assert!((unwrapped[1] - (4.0 - TAU)).abs() < 1e-12);
+    assert!((unwrapped[2] - (8.0 - 2.0 * TAU)).abs() < 1e-12);
+    assert_eq!(Signal::from_vec(vec![]).unwrap_phase(PI).len(), 0);
+}
@@ -0,0 +1,208 @@
+#![cfg(feature = "hound")]
+
+use dsp4rust::errors::IoError;
+use dsp4rust::signal::Signal;
+use hound::{SampleFormat, WavSpec, WavWriter};
+use std::path::PathBuf;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dsp4rust-{}-{name}.wav", std::process::id()))
+}
+
+// 写入按帧交织的整数样本
+fn write_int(name: &str, channels: u16, bits: u16, sample_rate: u32, samples: &[i32]) -> PathBuf {
+    let path = temp_path(name);
+    let spec = WavSpec {
+        channels,
+        sample_rate,
+        bits_per_sample: bits,
+        sample_format: SampleFormat::Int,
+    };
+    let mut writer = WavWriter::create(&path, spec).unwrap();
+    for &s in samples {
+        writer.write_sample(s).unwrap();
+    }
+    writer.finalize().unwrap();
+    path
+}
+
+#[test]
+fn test_read_mono_16_bit() {
+    let samples = [0, 16384, -16384, 32767, -32768, 1];
+    let path = write_int("mono16", 1, 16, 44100, &samples);
+    let (channels, sample_rate) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample_rate, 44100);
+    assert_eq!(channels.len(), 1);
+    let expected: Signal = samples.iter().map(|&s| s as f64 / 32768.0).collect();
+    assert_eq!(channels[0], expected);
+    assert_eq!(channels[0][3], 32767.0 / 32768.0);
+    assert_eq!(channels[0][4], -1.0);
+}
+
+#[test]
+fn test_read_stereo_24_bit() {
+    // 左右声道交织存储
+    let left = [0, 4_194_304, -8_388_608, 8_388_607];
+    let right = [100, -4_194_304, 2, -1];
+    let interleaved: Vec<i32> = left
+        .iter()
+        .zip(&right)
+        .flat_map(|(&l, &r)| [l, r])
+        .collect();
+    let path = write_int("stereo24", 2, 24, 48000, &interleaved);
+    let (channels, sample_rate) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sample_rate, 48000);
+    assert_eq!(channels.len(), 2);
+    let scale = 1.0 / 8_388_608.0;
+    assert_eq!(
+        channels[0],
+        left.iter().map(|&s| s as f64 * scale).collect()
+    );
+    assert_eq!(
+        channels[1],
+        right.iter().map(|&s| s as f64 * scale).collect()
+    );
+    assert_eq!(channels[0][1], 0.5);
+    assert_eq!(channels[0][2], -1.0);
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_read_stereo_16_bit_and_float() {
+    let interleaved = [1, -1, 2, -2, 3, -3];
+    let path = write_int("stereo16", 2, 16, 8000, &interleaved);
+    let (channels, _) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        channels[0],
+        &Signal::from_vec(vec![1.0, 2.0, 3.0]) * &(1.0 / 32768.0)
+    );
+    assert_eq!(
+        channels[1],
+        &Signal::from_vec(vec![-1.0, -2.0, -3.0]) * &(1.0 / 32768.0)
+    );
+
+    // 32 位浮点样本按原值读取
+    let path = temp_path("float32");
+    let spec = WavSpec {
+        channels: 1,
+        sample_rate: 22050,
+        bits_per_sample: 32,
+        sample_format: SampleFormat::Float,
+    };
+    let mut writer = WavWriter::create(&path, spec).unwrap();
+    for s in [0.25f32, -0.75, 1.5] {
+        writer.write_sample(s).unwrap();
+    }
+    writer.finalize().unwrap();
+    let (channels, sample_rate) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(sample_rate, 22050);
+    assert_eq!(channels[0], Signal::from_vec(vec![0.25, -0.75, 1.5]));
+}
+
+#[test]
+fn test_read_wav_errors() {
+    let missing = temp_path("does-not-exist");
+    assert!(matches!(Signal::read_wav(&missing), Err(IoError::Wav(_))));
+
+    let garbage = temp_path("garbage");
+    std::fs::write(&garbage, b"not a wav file").unwrap();
+    let result = Signal::read_wav(&garbage);
+    std::fs::remove_file(&garbage).unwrap();
+    assert!(matches!(result, Err(IoError::Wav(_))));
+}
+
+#[test]
+fn test_write_read_round_trip() {
+    use dsp4rust::generator::Generator;
+    use dsp4rust::io::{OutOfRange, WavFormat};
+
+    let generator = Generator::new()
+        .sample_rate(8000.0)
+        .num_samples(2000)
+        .seed(5)
+        .build()
+        .unwrap();
+    let tone = generator.sin(440.0, 0.3, 0.9, 0.0);
+    let noise = generator.uniform_white_noise(-1.0, 1.0).unwrap();
+
+    // Float32 逐样本精确重建（精度为 f32）
+    let path = temp_path("write-float32");
+    tone.write_wav(&path, 8000, WavFormat::Float32, OutOfRange::Error)
+        .unwrap();
+    let (channels, sample_rate) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(sample_rate, 8000);
+    let as_f32: Signal = tone.iter().map(|&x| x as f32 as f64).collect();
+    assert_eq!(channels, vec![as_f32]);
+
+    // 整数格式误差在 1 LSB 以内
+    for (format, bits) in [(WavFormat::Int16, 16), (WavFormat::Int24, 24)] {
+        let lsb = 1.0 / 2f64.powi(bits - 1);
+        let path = temp_path(&format!("write-int{bits}"));
+        Signal::write_wav_multi(&[&tone, &noise], &path, 8000, format, OutOfRange::Error).unwrap();
+        let (channels, _) = Signal::read_wav(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(channels.len(), 2);
+        assert!(channels[0].max_abs_error(&tone).unwrap() <= lsb);
+        assert!(channels[1].max_abs_error(&noise).unwrap() <= lsb);
+    }
+}
+
+#[test]
+fn test_write_out_of_range() {
+    use dsp4rust::io::{OutOfRange, WavFormat};
+
+    let hot = Signal::from_vec(vec![0.5, 1.5, -2.0, f64::NAN, 1.0, -1.0]);
+    let path = temp_path("write-error");
+    let result = hot.write_wav(&path, 8000, WavFormat::Int16, OutOfRange::Error);
+    assert!(matches!(
+        result,
+        Err(IoError::SampleOutOfRange {
+            channel: 0,
+            index: 1,
+            value
+        }) if value == 1.5
+    ));
+    // 检查在创建文件之前进行
+    assert!(!path.exists());
+
+    // 削波模式把越界样本限制在满量程，NaN 写为静音
+    let path = temp_path("write-clip");
+    hot.write_wav(&path, 8000, WavFormat::Int16, OutOfRange::Clip)
+        .unwrap();
+    let (channels, _) = Signal::read_wav(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let max = 32767.0 / 32768.0;
+    assert_eq!(
+        channels[0],
+        Signal::from_vec(vec![0.5, max, -1.0, 0.0, max, -1.0])
+    );
+}
+
+#[test]
+fn test_write_wav_multi_errors() {
+    use dsp4rust::errors::ShapeError;
+    use dsp4rust::io::{OutOfRange, WavFormat};
+
+    let path = temp_path("write-shape");
+    let (a, b) = (Signal::zeros(3), Signal::zeros(4));
+    let result =
+        Signal::write_wav_multi(&[&a, &b], &path, 8000, WavFormat::Int16, OutOfRange::Clip);
+    assert!(matches!(
+        result,
+        Err(IoError::Shape(ShapeError::LengthMismatch {
+            left: 3,
+            right: 4
+        }))
+    ));
+    let result = Signal::write_wav_multi(&[], &path, 8000, WavFormat::Int16, OutOfRange::Clip);
+    assert!(matches!(result, Err(IoError::ChannelCount(0))));
+    assert!(!path.exists());
+}
@@ -0,0 +1,131 @@
+use dsp4rust::complex_signal::{Complex64, ComplexSignal};
+use dsp4rust::errors::IqError;
+use dsp4rust::generator::Generator;
+use dsp4rust::signal::Signal;
+use std::f64::consts::TAU;
+
+const FS: f64 = 10_000.0;
+const FC: f64 = 1_000.0;
+const FM: f64 = 20.0;
+
+fn generator() -> Generator {
+    Generator::new()
+        .sample_rate(FS)
+        .start_time(0.0)
+        .duration(1.0)
+        .build()
+        .unwrap()
+}
+
+// 首尾各 5% 的样本受滤波器边缘效应影响，比较时排除
+fn interior(signal: &Signal) -> Signal {
+    let edge = signal.len() as isize / 20;
+    signal.cut_range(edge, signal.len() as isize - edge)
+}
+
+fn max_abs_diff(a: &Signal, b: &Signal) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_am_envelope_recovered_after_filtering() {
+    let generator = generator();
+    let modulation_index = 0.5;
+    let am = generator.am(FC, FM, modulation_index).unwrap();
+    let message: Signal = generator
+        .time_vector()
+        .iter()
+        .map(|t| 1.0 + modulation_index * (TAU * FM * t).cos())
+        .collect();
+
+    let baseband = am.to_baseband(FC, FS, Some(200.0)).unwrap();
+    assert_eq!(baseband.len(), am.len());
+    let magnitude = interior(&baseband.abs());
+    let error = max_abs_diff(&magnitude, &interior(&message));
+    // 相对包络峰值 1.5 的误差在 1% 以内
+    assert!(error < 0.015, "max envelope error {error}");
+    // 余弦载波没有相位调制
+    assert!(interior(&baseband.arg()).iter().all(|p| p.abs() < 1e-2));
+}
+
+#[test]
+fn test_unfiltered_mixer_output_keeps_image() {
+    let tone = generator().sin(FC, 0.0, 1.0, 0.0);
+    let mixed = tone.to_baseband(FC, FS, None).unwrap();
+    // 2·sin(ωt)·e^(-iωt) = -i + i·e^(-2iωt)：直流分量为 -i，镜像幅度为 1
+    let mean_im = mixed.im().mean().unwrap();
+    assert!(
+        (mean_im + 1.0).abs() < 1e-9,
+        "mean imaginary part {mean_im}"
+    );
+    assert!(mixed.abs().max().unwrap() > 1.9);
+}
+
+#[test]
+fn test_carrier_phase_appears_in_baseband_phase() {
+    let phase = 0.7;
+    // sin(ωt + φ) = cos(ωt + φ - π/2)
+    let tone = generator().sin(FC, phase, 2.0, 0.0);
+    let baseband = tone.to_baseband(FC, FS, Some(200.0)).unwrap();
+    let expected_phase = phase - TAU / 4.0;
+    assert!(interior(&baseband.abs())
+        .iter()
+        .all(|a| (a - 2.0).abs() < 1e-3));
+    assert!(interior(&baseband.arg())
+        .iter()
+        .all(|p| (p - expected_phase).abs() < 5e-3));
+}
+
+#[test]
+fn test_passband_round_trip() {
+    let generator = generator();
+    let am = generator.am(FC, FM, 0.8).unwrap();
+    let baseband = am.to_baseband(FC, FS, Some(200.0)).unwrap();
+    let passband = baseband.to_passband(FC, FS).unwrap();
+    let error = max_abs_diff(&interior(&passband), &interior(&am));
+    assert!(error < 0.02, "max round-trip error {error}");
+}
+
+#[test]
+fn test_to_passband_of_constant_envelope() {
+    let envelope = ComplexSignal::from_vec(vec![Complex64::new(0.0, -1.0); 100]);
+    // Re{-i·e^(iωt)} = sin(ωt)
+    let passband = envelope.to_passband(FC, FS).unwrap();
+    let expected: Signal = (0..100)
+        .map(|n| (TAU * FC * (n as f64 / FS)).sin())
+        .collect();
+    assert!(passband.allclose(&expected, 0.0, 1e-12));
+}
+
+#[test]
+fn test_invalid_parameters() {
+    let signal = Signal::ones(16);
+    let invalid = |name, value| Err(IqError::InvalidParameter { name, value });
+    assert_eq!(
+        signal.to_baseband(FC, 0.0, None),
+        invalid("sample_rate", 0.0)
+    );
+    assert_eq!(
+        signal.to_baseband(f64::INFINITY, FS, None),
+        invalid("carrier_freq", f64::INFINITY)
+    );
+    assert_eq!(
+        signal.to_baseband(FC, FS, Some(0.0)),
+        invalid("cutoff", 0.0)
+    );
+    assert_eq!(
+        signal.to_baseband(FC, FS, Some(FS / 2.0)),
+        invalid("cutoff", FS / 2.0)
+    );
+    assert!(signal.to_baseband(FC, FS, Some(f64::NAN)).is_err());
+    assert_eq!(
+        ComplexSignal::from_real(&signal).to_passband(FC, -1.0),
+        Err(IqError::InvalidParameter {
+            name: "sample_rate",
+            value: -1.0
+        })
+    );
+}
@@ -0,0 +1,93 @@
+use dsp4rust::errors::LpcError;
+use dsp4rust::filter::{Fir, Iir};
+use dsp4rust::generator::Generator;
+use dsp4rust::process::Processor;
+use dsp4rust::signal::Signal;
+
+fn white_noise(len: usize, seed: u64) -> Signal {
+    Generator::new()
+        .sample_rate(1.0)
+        .start_time(0.0)
+        .num_samples(len)
+        .seed(seed)
+        .build()
+        .unwrap()
+        .gaussian_white_noise(0.0, 1.0)
+}
+
+#[test]
+fn test_recovers_ar2_coefficients() {
+    // x[n] = 1.3·x[n-1] - 0.6·x[n-2] + e[n]，极点模为 √0.6
+    let a_true = [1.0, -1.3, 0.6];
+    for seed in [1, 2, 3] {
+        let mut synthesis = Iir::new(&[1.0], &a_true).unwrap();
+        let process = synthesis.process(&white_noise(20_000, seed));
+
+        let (a, error_power) = process.lpc(2).unwrap();
+        assert_eq!(a.len(), 3);
+        assert_eq!(a[0], 1.0);
+        for k in 1..3 {
+            let relative = (a[k] - a_true[k]).abs() / a_true[k].abs();
+            assert!(relative < 0.03, "seed {seed}: a[{k}] = {}", a[k]);
+        }
+        // 误差功率估计驱动噪声的方差 1
+        assert!(
+            (error_power - 1.0).abs() < 0.05,
+            "seed {seed}: {error_power}"
+        );
+    }
+}
+
+#[test]
+fn test_whitening_filter_leaves_error_power() {
+    let mut synthesis = Iir::new(&[1.0], &[1.0, -0.9]).unwrap();
+    let process = synthesis.process(&white_noise(10_000, 7));
+    let (a, error_power) = process.lpc(1).unwrap();
+
+    let residual = Fir::new(&a.to_vec()).unwrap().process(&process);
+    let residual_power = residual.energy() / residual.len() as f64;
+    assert!((residual_power - error_power).abs() / error_power < 0.01);
+}
+
+#[test]
+fn test_higher_order_fit_of_ar2_has_small_extra_coefficients() {
+    let mut synthesis = Iir::new(&[1.0], &[1.0, -1.3, 0.6]).unwrap();
+    let process = synthesis.process(&white_noise(20_000, 11));
+    let (a2, error2) = process.lpc(2).unwrap();
+    let (a4, error4) = process.lpc(4).unwrap();
+    assert!(a4[3].abs() < 0.05 && a4[4].abs() < 0.05);
+    assert!((a4[1] - a2[1]).abs() < 0.05);
+    // 阶数增加时误差功率不增
+    assert!(error4 <= error2);
+}
+
+#[test]
+fn test_order_zero() {
+    let signal = Signal::from_vec(vec![1.0, -2.0, 2.0]);
+    let (a, error_power) = signal.lpc(0).unwrap();
+    assert_eq!(a.to_vec(), vec![1.0]);
+    assert_eq!(error_power, 3.0);
+}
+
+#[test]
+fn test_rejects_order_at_or_above_length() {
+    let signal = Signal::from_vec(vec![1.0, 0.5, 0.25]);
+    assert!(signal.lpc(2).is_ok());
+    assert_eq!(
+        signal.lpc(3),
+        Err(LpcError::OrderTooHigh { order: 3, len: 3 })
+    );
+    assert_eq!(
+        signal.lpc(10),
+        Err(LpcError::OrderTooHigh { order: 10, len: 3 })
+    );
+    assert_eq!(
+        Signal::zeros(0).lpc(0),
+        Err(LpcError::OrderTooHigh { order: 0, len: 0 })
+    );
+}
+
+#[test]
+fn test_rejects_zero_signal() {
+    assert_eq!(Signal::zeros(8).lpc(2), Err(LpcError::ZeroEnergy));
+}
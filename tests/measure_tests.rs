@@ -0,0 +1,122 @@
+use dsp4rust::errors::{MeasureError, ShapeError};
+use dsp4rust::generator::Generator;
+use dsp4rust::measure::{enob, sinad, snr, snr_vs_reference, thd};
+use dsp4rust::signal::Signal;
+
+const FS: f64 = 48000.0;
+
+fn generator(len: usize) -> Generator {
+    Generator::new()
+        .sample_rate(FS)
+        .num_samples(len)
+        .seed(3)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_snr() {
+    let generator = generator(10000);
+    let clean = generator.sin(1000.0, 0.0, 2.0, 0.0);
+    let noise = generator.gaussian_white_noise(0.0, 0.02);
+    // 正弦功率 2，噪声功率约 4e-4，SNR 约 37 dB
+    let expected = 10.0 * (2.0 / 4e-4f64).log10();
+    assert!((snr(&clean, &noise) - expected).abs() < 0.2);
+    let measured = snr_vs_reference(&(&clean + &noise), &clean).unwrap();
+    assert!((measured - snr(&clean, &noise)).abs() < 1e-9);
+
+    assert_eq!(snr(&clean, &Signal::zeros(4)), f64::INFINITY);
+    assert_eq!(
+        snr_vs_reference(&clean, &Signal::zeros(4)),
+        Err(ShapeError::LengthMismatch {
+            left: 10000,
+            right: 4
+        })
+    );
+
+    // 空信号没有平均功率
+    assert!(snr(&Signal::zeros(0), &clean).is_nan());
+    assert!(snr_vs_reference(&Signal::zeros(0), &Signal::zeros(0))
+        .unwrap()
+        .is_nan());
+}
+
+#[test]
+fn test_thd_of_known_harmonic() {
+    // 三次谐波幅度为基波的 a3 时 THD 为 20·log10(a3)
+    let generator = generator(16384);
+    for (f0, a3) in [(1000.0, 0.01), (997.0, 0.05), (2345.6, 0.003)] {
+        let signal = generator.multi_tone(&[(f0, 1.0, 0.3), (3.0 * f0, a3, 1.1)]);
+        let measured = thd(&signal, f0, FS, 5).unwrap();
+        let expected = 20.0 * f64::log10(a3);
+        assert!(
+            (measured - expected).abs() < 0.1,
+            "{measured} vs {expected}"
+        );
+    }
+
+    // 多个谐波的功率相加
+    let signal =
+        generator.multi_tone(&[(1000.0, 1.0, 0.0), (2000.0, 0.03, 0.0), (5000.0, 0.04, 0.0)]);
+    let expected = 20.0 * 0.05f64.log10();
+    assert!((thd(&signal, 1000.0, FS, 9).unwrap() - expected).abs() < 0.1);
+    // 只计入前两个谐波时 5 次谐波被排除
+    let expected = 20.0 * 0.03f64.log10();
+    assert!((thd(&signal, 1000.0, FS, 2).unwrap() - expected).abs() < 0.1);
+    assert_eq!(thd(&signal, 1000.0, FS, 0).unwrap(), f64::NEG_INFINITY);
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_sinad_of_quantized_sine() {
+    // 理想 N 位量化正弦的 SINAD 约为 6.02·N + 1.76 dB
+    let generator = generator(65536);
+    let sine = generator.sin(997.0, 0.2, 1.0, 0.0);
+    for bits in [8, 10, 12] {
+        let step = 2.0 / 2f64.powi(bits);
+        let quantized: Signal = sine.iter().map(|x| (x / step).round() * step).collect();
+        let measured = sinad(&quantized, 997.0, FS).unwrap();
+        let ideal = 6.02 * bits as f64 + 1.76;
+        assert!(
+            (measured - ideal).abs() < 0.5,
+            "{bits} bits: {measured} vs {ideal}"
+        );
+        assert!((enob(measured) - bits as f64).abs() < 0.1);
+    }
+
+    // 直流偏置不计为噪声
+    let offset = &sine + &0.5;
+    let clean = sinad(&sine, 997.0, FS).unwrap();
+    assert!((sinad(&offset, 997.0, FS).unwrap() - clean).abs() < 0.1);
+}
+
+#[test]
+fn test_measure_errors() {
+    let signal = generator(4096).sin(1000.0, 0.0, 1.0, 0.0);
+    assert_eq!(
+        thd(&Signal::from_vec(vec![]), 1000.0, FS, 3),
+        Err(MeasureError::EmptyInput)
+    );
+    assert!(matches!(
+        thd(&signal, 1000.0, 0.0, 3),
+        Err(MeasureError::InvalidParameter {
+            name: "sample_rate",
+            ..
+        })
+    ));
+    // 基波离直流或奈奎斯特频率过近
+    for f0 in [10.0, 23990.0, f64::NAN] {
+        assert!(matches!(
+            sinad(&signal, f0, FS),
+            Err(MeasureError::InvalidParameter {
+                name: "fundamental_hz",
+                ..
+            })
+        ));
+    }
+    assert_eq!(
+        thd(&Signal::zeros(4096), 1000.0, FS, 3),
+        Err(MeasureError::ZeroFundamental)
+    );
+}
@@ -0,0 +1,113 @@
+use dsp4rust::errors::IoError;
+use dsp4rust::signal::Signal;
+use std::path::PathBuf;
+
+// 测试数据按 numpy.save 的格式生成：
+// numpy.save("golden_f64.npy", numpy.array([0.0, -1.5, 0.25, numpy.pi, -2.5e-10, 1e20]))
+// numpy.save("golden_f32.npy", numpy.array([0.5, -2.0, 0.125, 1024.0], dtype=numpy.float32))
+fn data_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(name)
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dsp4rust-{}-{name}.npy", std::process::id()))
+}
+
+fn golden() -> Signal {
+    Signal::from_vec(vec![0.0, -1.5, 0.25, std::f64::consts::PI, -2.5e-10, 1e20])
+}
+
+// 手工拼出 NPY 文件，用于构造异常输入
+fn npy_bytes(header: &str, data: &[u8]) -> Vec<u8> {
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn test_read_golden_npy() {
+    let signal = Signal::read_npy(data_path("golden_f64.npy")).unwrap();
+    assert_eq!(signal, golden());
+
+    let signal = Signal::read_npy(data_path("golden_f32.npy")).unwrap();
+    assert_eq!(signal, Signal::from_vec(vec![0.5, -2.0, 0.125, 1024.0]));
+}
+
+#[test]
+fn test_write_npy_is_byte_exact() {
+    let path = temp_path("golden");
+    golden().write_npy(&path).unwrap();
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, std::fs::read(data_path("golden_f64.npy")).unwrap());
+}
+
+#[test]
+fn test_npy_round_trip() {
+    // 包括头部恰好对齐时仍需补齐一整块的长度
+    for len in [0, 1, 1000, 100_000] {
+        let signal: Signal = (0..len).map(|i| (i as f64 * 0.37).sin() * 1e3).collect();
+        let path = temp_path(&format!("round-trip-{len}"));
+        signal.write_npy(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        // 数据起点按 64 字节对齐
+        assert_eq!((bytes.len() - len * 8) % 64, 0);
+        let back = Signal::read_npy(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(back, signal);
+    }
+}
+
+#[test]
+fn test_read_big_endian_npy() {
+    let data: Vec<u8> = [1.5f64, -3.0]
+        .iter()
+        .flat_map(|x| x.to_be_bytes())
+        .collect();
+    let path = temp_path("big-endian");
+    let header = "{'descr': '>f8', 'fortran_order': False, 'shape': (2,), }\n";
+    std::fs::write(&path, npy_bytes(header, &data)).unwrap();
+    let signal = Signal::read_npy(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(signal, Signal::from_vec(vec![1.5, -3.0]));
+}
+
+#[test]
+fn test_read_npy_errors() {
+    let path = temp_path("errors");
+    let read = |bytes: Vec<u8>| {
+        std::fs::write(&path, bytes).unwrap();
+        Signal::read_npy(&path)
+    };
+    let data = [0u8; 32];
+
+    // 数据被截断
+    let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (5,), }\n";
+    assert!(matches!(
+        read(npy_bytes(header, &data)),
+        Err(IoError::Npy(_))
+    ));
+    // 二维数组
+    let header = "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 2), }\n";
+    let message = read(npy_bytes(header, &data)).unwrap_err().to_string();
+    assert!(message.contains("one-dimensional"), "{message}");
+    // 整数类型
+    let header = "{'descr': '<i8', 'fortran_order': False, 'shape': (4,), }\n";
+    let message = read(npy_bytes(header, &data)).unwrap_err().to_string();
+    assert!(message.contains("<i8"), "{message}");
+    // 不是 NPY 文件
+    assert!(matches!(
+        read(b"a,b,c\n1,2,3\n".to_vec()),
+        Err(IoError::Npy(_))
+    ));
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        Signal::read_npy(temp_path("does-not-exist")),
+        Err(IoError::Io(_))
+    ));
+}
@@ -0,0 +1,88 @@
+use approx::assert_relative_eq;
+use dsp4rust::errors::FitError;
+use dsp4rust::poly::DetrendMode;
+use dsp4rust::signal::Signal;
+use std::f64::consts::TAU;
+
+fn max_abs(signal: &Signal) -> f64 {
+    signal.iter().fold(0.0, |m, x| m.max(x.abs()))
+}
+
+#[test]
+fn test_polyfit_recovers_cubic() {
+    let coeffs = [0.002, -0.3, 4.5, -7.0];
+    let x = Signal::arrange(0.0, 200.0, 1.0);
+    let cubic = x.polyval(&coeffs);
+    let fitted = cubic.polyfit(3).unwrap();
+    assert_eq!(fitted.len(), 4);
+    for (got, want) in fitted.iter().zip(coeffs) {
+        assert!((got - want).abs() < 1e-9, "{got} vs {want}");
+    }
+
+    // 阶数高于数据时高次项系数为零
+    let fitted = cubic.polyfit(5).unwrap();
+    assert!(fitted[0].abs() < 1e-9 && fitted[1].abs() < 1e-9);
+    assert!(max_abs(&(&x.polyval(&fitted) - &cubic)) < 1e-6);
+
+    // 样本数恰好等于系数个数时插值通过每个点
+    let points = Signal::from_vec(vec![2.0, -1.0, 0.5]);
+    let fitted = points.polyfit(2).unwrap();
+    let at = Signal::from_vec(vec![0.0, 1.0, 2.0]).polyval(&fitted);
+    assert!(max_abs(&(&at - &points)) < 1e-12);
+
+    assert_eq!(
+        points.polyfit(3),
+        Err(FitError::TooFewSamples { order: 3, len: 3 })
+    );
+    assert_eq!(Signal::from_vec(vec![5.0]).polyfit(0).unwrap(), vec![5.0]);
+}
+
+#[test]
+fn test_polyval() {
+    let x = Signal::from_vec(vec![-1.0, 0.0, 2.0]);
+    assert_eq!(x.polyval(&[3.0, 0.0, 1.0]).to_vec(), vec![4.0, 1.0, 13.0]);
+    assert_eq!(x.polyval(&[]).to_vec(), vec![0.0; 3]);
+    assert_eq!(x.polyval(&[7.0]).to_vec(), vec![7.0; 3]);
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_detrend() {
+    // 纯斜坡去线性趋势后残差接近零
+    let ramp = &(&Signal::arrange(0.0, 1000.0, 1.0) * &0.37) + &-12.0;
+    assert!(max_abs(&ramp.detrend(DetrendMode::Linear)) < 1e-9);
+    assert_relative_eq!(
+        ramp.detrend(DetrendMode::Constant).iter().sum::<f64>(),
+        0.0,
+        epsilon = 1e-8
+    );
+
+    // 正弦加斜坡去趋势后保留正弦的幅度
+    let n = 2048;
+    let sine: Signal = (0..n)
+        .map(|i| 1.5 * (TAU * 13.0 * i as f64 / n as f64).sin())
+        .collect();
+    let drift = &(&Signal::arrange(0.0, n as f64, 1.0) * &0.01) + &3.0;
+    let residual = (&sine + &drift).detrend(DetrendMode::Linear);
+    // 正弦与直线并不完全正交，残差中留有很小的倾斜，因此用均方根估计幅度
+    let amplitude = residual.rms().unwrap() * 2f64.sqrt();
+    assert!((amplitude - 1.5).abs() < 0.015, "{amplitude}");
+    assert!(residual.mean().unwrap().abs() < 1e-9);
+
+    // 常数去趋势即减去均值
+    let x = Signal::from_vec(vec![1.0, 2.0, 6.0]);
+    assert_eq!(
+        x.detrend(DetrendMode::Constant).to_vec(),
+        vec![-2.0, -1.0, 3.0]
+    );
+    assert_eq!(DetrendMode::default(), DetrendMode::Linear);
+
+    // 边界情况
+    let single = Signal::from_vec(vec![4.0]);
+    assert_eq!(single.detrend(DetrendMode::Linear).to_vec(), vec![0.0]);
+    assert_eq!(
+        Signal::from_vec(vec![]).detrend(DetrendMode::Linear).len(),
+        0
+    );
+}
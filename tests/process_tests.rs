@@ -0,0 +1,154 @@
+use dsp4rust::errors::FilterError;
+use dsp4rust::filter::{Biquad, Fir, Iir};
+use dsp4rust::process::{Chain, Gain, Processor};
+use dsp4rust::signal::Signal;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+// 直流偏置上周期为 50 个样本的正弦，加上高频干扰
+fn tone(len: usize, interference: f64) -> Signal {
+    Signal::from_len_fn(len, |i| {
+        let t = i as f64;
+        3.0 + (2.0 * PI * t / 50.0).sin() + interference * (2.5 * t).sin()
+    })
+}
+
+fn test_signal() -> Signal {
+    tone(1000, 0.3)
+}
+
+fn pipeline() -> Chain {
+    Chain::new()
+        .then(Iir::dc_blocker(0.995).unwrap())
+        .then(Gain::new(2.0))
+        .then(Biquad::lowpass(0.1, FRAC_1_SQRT_2).unwrap())
+        .then(Fir::lowpass(15, 0.2).unwrap())
+}
+
+fn process_in_chunks(processor: &mut dyn Processor, input: &Signal, lengths: &[usize]) -> Signal {
+    let mut output = Vec::new();
+    let mut start = 0;
+    for &len in lengths.iter().cycle() {
+        if start >= input.len() {
+            break;
+        }
+        let end = (start + len).min(input.len());
+        let block = input.cut_range(start as isize, end as isize);
+        output.extend(processor.process(&block).to_vec());
+        start = end;
+    }
+    Signal::from_vec(output)
+}
+
+#[test]
+fn test_chain_chunked_matches_one_shot() {
+    let input = test_signal();
+    let whole = pipeline().process(&input);
+    assert_eq!(whole.len(), input.len());
+
+    for lengths in [&[1][..], &[7, 64, 3], &[999, 1], &[0, 250]] {
+        let chunked = process_in_chunks(&mut pipeline(), &input, lengths);
+        assert_eq!(chunked, whole, "chunk lengths {lengths:?}");
+    }
+}
+
+#[test]
+fn test_chain_reset() {
+    let input = test_signal();
+    let mut chain = pipeline();
+    let first = chain.process(&input);
+    // 不重置时状态延续，输出不同
+    assert_ne!(chain.process(&input), first);
+    chain.reset();
+    assert_eq!(chain.process(&input), first);
+}
+
+#[test]
+fn test_chain_filters_the_signal() {
+    let input = tone(5000, 0.3);
+    let output = pipeline().process(&input);
+    // 稳态下直流被去除，通带内的正弦放大两倍，高频干扰被抑制
+    let tail = output.cut_from(-500);
+    assert!(tail.mean().unwrap().abs() < 0.01);
+    let rms = tail.rms().unwrap();
+    assert!((rms - 2.0 * FRAC_1_SQRT_2).abs() < 0.05, "rms = {rms}");
+    let clean = pipeline().process(&tone(5000, 0.0)).cut_from(-500);
+    assert!((&tail - &clean).iter().all(|x| x.abs() < 0.01));
+
+    assert_eq!(Chain::new().process(&input), input);
+    assert!(Chain::new().is_empty());
+    assert_eq!(pipeline().len(), 4);
+}
+
+#[test]
+fn test_filters() {
+    let impulse = Signal::from_vec(vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+
+    let mut fir = Fir::new(&[0.5, 0.25, 0.125]).unwrap();
+    assert_eq!(
+        fir.process(&impulse).to_vec(),
+        vec![0.5, 0.25, 0.125, 0.0, 0.0]
+    );
+    let lowpass = Fir::lowpass(31, 0.3).unwrap();
+    assert_eq!(lowpass.taps().len(), 31);
+    assert!((lowpass.taps().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+    // 系数按 a[0] 归一化：y[n] = x[n] + 0.5 y[n-1]
+    let mut iir = Iir::new(&[2.0], &[2.0, -1.0]).unwrap();
+    assert_eq!(
+        iir.process(&impulse).to_vec(),
+        vec![1.0, 0.5, 0.25, 0.125, 0.0625]
+    );
+
+    let mut blocker = Iir::dc_blocker(0.9).unwrap();
+    assert_eq!(
+        blocker.process(&Signal::ones(3)).to_vec(),
+        vec![1.0, 0.9, 0.81]
+    );
+
+    let mut biquad = Biquad::new(1.0, 0.0, 0.0, 0.0, 0.0);
+    assert_eq!(biquad.process(&impulse), impulse);
+
+    let mut gain = Gain::from_db(-20.0);
+    assert!((gain.process(&Signal::ones(2)) - 0.1)
+        .iter()
+        .all(|x| x.abs() < 1e-15));
+}
+
+#[test]
+fn test_filter_errors() {
+    assert_eq!(Fir::new(&[]), Err(FilterError::EmptyCoefficients));
+    assert_eq!(
+        Iir::new(&[1.0], &[0.0, 1.0]),
+        Err(FilterError::ZeroLeadingDenominator)
+    );
+    assert_eq!(Iir::new(&[1.0], &[]), Err(FilterError::EmptyCoefficients));
+    assert_eq!(
+        Iir::dc_blocker(1.0),
+        Err(FilterError::InvalidParameter {
+            name: "pole",
+            value: 1.0
+        })
+    );
+    assert_eq!(
+        Fir::lowpass(0, 0.5),
+        Err(FilterError::InvalidParameter {
+            name: "num_taps",
+            value: 0.0
+        })
+    );
+    assert!(Fir::lowpass(5, 1.0).is_ok());
+    assert_eq!(
+        Biquad::lowpass(1.0, 0.7),
+        Err(FilterError::InvalidParameter {
+            name: "cutoff",
+            value: 1.0
+        })
+    );
+    assert_eq!(
+        Biquad::lowpass(0.5, 0.0),
+        Err(FilterError::InvalidParameter {
+            name: "q",
+            value: 0.0
+        })
+    );
+}
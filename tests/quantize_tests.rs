@@ -0,0 +1,96 @@
+use dsp4rust::generator::Generator;
+use dsp4rust::quantize::Rounding;
+use dsp4rust::signal::Signal;
+
+fn full_scale_sine(len: usize) -> Signal {
+    Generator::new()
+        .sample_rate(48000.0)
+        .num_samples(len)
+        .build()
+        .unwrap()
+        .sin(997.0, 0.1, 1.0, 0.0)
+}
+
+#[test]
+fn test_quantization_noise_power() {
+    // 满量程正弦在 12 位量化下的噪声功率约为 Δ²/12
+    let sine = full_scale_sine(100_000);
+    let step = 2.0 / 4096.0;
+    let expected = step * step / 12.0;
+    let error = &sine.quantize(12, 1.0, Rounding::Nearest) - &sine;
+    let power = error.avg_power();
+    assert!(
+        (power / expected - 1.0).abs() < 0.1,
+        "{power} vs {expected}"
+    );
+    // 最大码为 2047·Δ，正峰值附近的样本被削波，其余样本误差不超过半个步长
+    let unclipped = sine
+        .iter()
+        .zip(error.iter())
+        .filter(|(x, _)| **x < 1.0 - step);
+    assert!(unclipped
+        .map(|(_, e)| e)
+        .all(|e| e.abs() <= step / 2.0 + 1e-15));
+
+    // 截断的误差范围为 [0, Δ)，功率为 Δ²/3
+    let error = &sine.quantize(12, 1.0, Rounding::Floor) - &sine;
+    let unclipped = sine
+        .iter()
+        .zip(error.iter())
+        .filter(|(x, _)| **x < 1.0 - step);
+    assert!(unclipped
+        .map(|(_, e)| *e)
+        .all(|e| e <= 0.0 && e > -step - 1e-15));
+    assert!((error.avg_power() / (step * step / 3.0) - 1.0).abs() < 0.1);
+
+    // TPDF 抖动使误差功率变为 Δ²/12 + Δ²/6 = Δ²/4
+    let dithered = sine.quantize(12, 1.0, Rounding::StochasticDither { seed: Some(9) });
+    let error = &dithered - &sine;
+    assert!((error.avg_power() / (step * step / 4.0) - 1.0).abs() < 0.1);
+    assert!(error.iter().all(|e| e.abs() <= 2.0 * step + 1e-15));
+    let again = sine.quantize(12, 1.0, Rounding::StochasticDither { seed: Some(9) });
+    assert_eq!(dithered.to_vec(), again.to_vec());
+}
+
+#[test]
+fn test_codes_round_trip() {
+    let sine = full_scale_sine(5000);
+    for rounding in [
+        Rounding::Nearest,
+        Rounding::Floor,
+        Rounding::StochasticDither { seed: Some(1) },
+    ] {
+        let codes = sine.quantize_codes(10, 2.5, rounding);
+        assert!(codes.iter().all(|c| (-512..=511).contains(c)));
+        let values = sine.quantize(10, 2.5, rounding);
+        assert_eq!(
+            Signal::from_codes(&codes, 10, 2.5).to_vec(),
+            values.to_vec()
+        );
+        // 重建值再量化得到相同的码
+        assert_eq!(values.quantize_codes(10, 2.5, Rounding::Nearest), codes);
+    }
+}
+
+#[test]
+fn test_quantize_clipping_and_nan() {
+    let signal = Signal::from_vec(vec![5.0, -5.0, 1.0, -1.0, f64::NAN]);
+    assert_eq!(
+        signal.quantize_codes(8, 1.0, Rounding::Nearest),
+        vec![127, -128, 127, -128, 0]
+    );
+    let quantized = signal.quantize(8, 1.0, Rounding::Nearest).to_vec();
+    assert_eq!(quantized[..4], [127.0 / 128.0, -1.0, 127.0 / 128.0, -1.0]);
+    assert!(quantized[4].is_nan());
+
+    // 1 位量化器只有两个电平
+    let one_bit = Signal::from_vec(vec![0.7, -0.2, 0.0]).quantize(1, 1.0, Rounding::Floor);
+    assert_eq!(one_bit.to_vec(), vec![0.0, -1.0, 0.0]);
+    assert_eq!(Rounding::default(), Rounding::Nearest);
+}
+
+#[test]
+#[should_panic]
+fn test_quantize_zero_bits_panics() {
+    Signal::ones(3).quantize(0, 1.0, Rounding::Nearest);
+}
@@ -0,0 +1,112 @@
+use dsp4rust::errors::IoError;
+use dsp4rust::io::SampleFormat;
+use dsp4rust::signal::Signal;
+use std::path::PathBuf;
+
+const FORMATS: [SampleFormat; 6] = [
+    SampleFormat::F32Le,
+    SampleFormat::F32Be,
+    SampleFormat::F64Le,
+    SampleFormat::F64Be,
+    SampleFormat::I16Le,
+    SampleFormat::I16Be,
+];
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("dsp4rust-{}-{name}.bin", std::process::id()))
+}
+
+#[test]
+fn test_raw_round_trip() {
+    // 远大于读取缓冲区，覆盖样本跨越缓冲区边界的情况
+    let signal: Signal = (0..50_001).map(|i| (i as f64 * 0.01).sin() * 0.9).collect();
+    for format in FORMATS {
+        let path = temp_path(&format!("round-trip-{format:?}"));
+        signal.write_raw(&path, format).unwrap();
+        let bytes = std::fs::metadata(&path).unwrap().len();
+        assert_eq!(bytes, (signal.len() * format.sample_size()) as u64);
+        let back = Signal::read_raw(&path, format, 0).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(back.len(), signal.len());
+        let tolerance = match format {
+            SampleFormat::F64Le | SampleFormat::F64Be => 0.0,
+            SampleFormat::F32Le | SampleFormat::F32Be => 1e-7,
+            _ => 0.5 / 32768.0,
+        };
+        for (x, y) in signal.iter().zip(back.iter()) {
+            assert!((x - y).abs() <= tolerance, "{format:?}: {x} vs {y}");
+        }
+    }
+}
+
+#[test]
+fn test_read_raw_i16_scaling() {
+    let codes: [i16; 5] = [i16::MIN, -16384, 0, 16384, i16::MAX];
+    let path = temp_path("i16-scaling");
+    let bytes: Vec<u8> = codes.iter().flat_map(|c| c.to_le_bytes()).collect();
+    std::fs::write(&path, bytes).unwrap();
+    let signal = Signal::read_raw(&path, SampleFormat::I16Le, 0).unwrap();
+    assert_eq!(
+        signal,
+        Signal::from_vec(vec![-1.0, -0.5, 0.0, 0.5, 32767.0 / 32768.0])
+    );
+    assert!(signal.iter().all(|&x| (-1.0..1.0).contains(&x)));
+
+    // 写入时削波到整数范围，NaN 写为 0
+    Signal::from_vec(vec![1.0, -1.0, 3.0, f64::NAN])
+        .write_raw(&path, SampleFormat::I16Be)
+        .unwrap();
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let written: Vec<i16> = written
+        .chunks_exact(2)
+        .map(|b| i16::from_be_bytes([b[0], b[1]]))
+        .collect();
+    assert_eq!(written, [i16::MAX, i16::MIN, i16::MAX, 0]);
+}
+
+#[test]
+fn test_read_raw_offset() {
+    let signal = Signal::from_vec(vec![0.25, -8.0, 1e-30, 6.5]);
+    let path = temp_path("offset");
+    signal.write_raw(&path, SampleFormat::F64Le).unwrap();
+    let mut bytes = b"HDR".to_vec();
+    bytes.extend(std::fs::read(&path).unwrap());
+    std::fs::write(&path, bytes).unwrap();
+
+    let back = Signal::read_raw(&path, SampleFormat::F64Le, 3).unwrap();
+    assert_eq!(back, signal);
+    // 偏移恰为文件长度时得到空信号，超出时报错
+    assert_eq!(
+        Signal::read_raw(&path, SampleFormat::F64Le, 35)
+            .unwrap()
+            .len(),
+        0
+    );
+    let past_end = Signal::read_raw(&path, SampleFormat::F64Le, 36);
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(past_end, Err(IoError::Io(_))));
+}
+
+#[test]
+fn test_read_raw_truncated() {
+    let path = temp_path("truncated");
+    std::fs::write(&path, [0u8; 11]).unwrap();
+    let result = Signal::read_raw(&path, SampleFormat::F32Le, 0);
+    assert!(matches!(
+        result,
+        Err(IoError::TruncatedSample {
+            trailing_bytes: 3,
+            sample_size: 4
+        })
+    ));
+    // 与偏移无关，只看偏移之后的数据
+    assert_eq!(
+        Signal::read_raw(&path, SampleFormat::I16Le, 1)
+            .unwrap()
+            .len(),
+        5
+    );
+    std::fs::remove_file(&path).unwrap();
+}
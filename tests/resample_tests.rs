@@ -0,0 +1,295 @@
+use dsp4rust::errors::ResampleError;
+use dsp4rust::resample::{DecimateFilter, InterpKind};
+use dsp4rust::signal::Signal;
+use std::f64::consts::TAU;
+
+// 单频点 DFT 估计指定频率处的幅值（要求信号包含整数个周期）
+fn tone_amplitude(x: &Signal, freq: f64, sample_rate: f64) -> f64 {
+    let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &xi)| {
+        let angle = TAU * freq * i as f64 / sample_rate;
+        (re + xi * angle.cos(), im - xi * angle.sin())
+    });
+    2.0 * (re * re + im * im).sqrt() / x.len() as f64
+}
+
+fn two_tone(sample_rate: f64, len: usize, f1: f64, f2: f64) -> Signal {
+    Signal::from_len_fn(len, |n| {
+        let t = n as f64 / sample_rate;
+        (TAU * f1 * t).sin() + (TAU * f2 * t).sin()
+    })
+}
+
+#[test]
+fn test_decimate_suppresses_alias() {
+    // 1 kHz 采样，抽取 4 倍后奈奎斯特频率为 125 Hz；200 Hz 分量会混叠到 50 Hz
+    let fs = 1000.0;
+    let signal = two_tone(fs, 4000, 20.0, 200.0);
+    let new_fs = fs / 4.0;
+
+    let naive: Signal = signal.iter().step_by(4).copied().collect();
+    assert!(tone_amplitude(&naive, 50.0, new_fs) > 0.9);
+
+    for filter in [
+        None,
+        Some(DecimateFilter::ChebyshevI { order: 8 }),
+        Some(DecimateFilter::Fir { taps: 30 }),
+    ] {
+        let decimated = signal.decimate(4, filter).unwrap();
+        assert_eq!(decimated.len(), 1000);
+        let in_band = tone_amplitude(&decimated, 20.0, new_fs);
+        let alias = tone_amplitude(&decimated, 50.0, new_fs);
+        assert!(
+            (in_band - 1.0).abs() < 0.02,
+            "{filter:?}: in-band {in_band}"
+        );
+        assert!(
+            20.0 * (alias / in_band).log10() < -40.0,
+            "{filter:?}: alias {alias}"
+        );
+    }
+}
+
+#[test]
+fn test_decimate_factor_and_filter_validation() {
+    let signal = Signal::linspace(0.0, 1.0, 10);
+    assert_eq!(
+        signal.decimate(0, None).unwrap_err(),
+        ResampleError::ZeroFactor
+    );
+    assert_eq!(signal.decimate(1, None).unwrap().to_vec(), signal.to_vec());
+    assert!(matches!(
+        signal.decimate(2, Some(DecimateFilter::ChebyshevI { order: 3 })),
+        Err(ResampleError::InvalidFilter(_))
+    ));
+    assert!(matches!(
+        signal.decimate(2, Some(DecimateFilter::Fir { taps: 0 })),
+        Err(ResampleError::InvalidFilter(_))
+    ));
+    // 长度不能被因子整除时保留 ⌈N / factor⌉ 个样本
+    assert_eq!(signal.decimate(3, None).unwrap().len(), 4);
+    assert_eq!(Signal::zeros(0).decimate(3, None).unwrap().len(), 0);
+}
+
+#[test]
+fn test_upsample_preserves_tone_without_images() {
+    let fs = 1000.0;
+    let factor = 4;
+    let new_fs = fs * factor as f64;
+    let sine = Signal::from_len_fn(2000, |n| (TAU * 50.0 * n as f64 / fs).sin());
+
+    let stuffed = sine.upsample_zero_stuff(factor).unwrap();
+    assert_eq!(stuffed.len(), 8000);
+    assert!(tone_amplitude(&stuffed, fs - 50.0, new_fs) > 0.2);
+
+    let upsampled = sine.upsample(factor).unwrap();
+    assert_eq!(upsampled.len(), 8000);
+    // 原始样本位置保持不变（远离边缘处）
+    for n in 100..1900 {
+        assert!((upsampled[(n * factor) as isize] - sine[n as isize]).abs() < 1e-2);
+    }
+    let tone = tone_amplitude(&upsampled, 50.0, new_fs);
+    assert!((tone - 1.0).abs() < 0.01, "tone {tone}");
+    for image in [fs - 50.0, fs + 50.0, 2.0 * fs - 50.0, 2.0 * fs + 50.0] {
+        let level = 20.0 * (tone_amplitude(&upsampled, image, new_fs) / tone).log10();
+        assert!(level < -40.0, "image at {image} Hz: {level} dB");
+    }
+
+    assert_eq!(upsampled, sine.resample_poly(factor, 1).unwrap());
+    assert_eq!(sine.upsample(1).unwrap().to_vec(), sine.to_vec());
+    assert_eq!(sine.upsample(0).unwrap_err(), ResampleError::ZeroFactor);
+    assert_eq!(
+        sine.upsample_zero_stuff(0).unwrap_err(),
+        ResampleError::ZeroFactor
+    );
+}
+
+// 由过零点（线性插值）估计信号中段的频率
+fn zero_crossing_frequency(x: &Signal, sample_rate: f64) -> f64 {
+    let x = x.to_vec();
+    let margin = x.len() / 10;
+    let crossings: Vec<f64> = (margin..x.len() - margin)
+        .filter(|&i| x[i] <= 0.0 && x[i + 1] > 0.0)
+        .map(|i| i as f64 + x[i] / (x[i] - x[i + 1]))
+        .collect();
+    let periods = (crossings.len() - 1) as f64;
+    periods * sample_rate / (crossings[crossings.len() - 1] - crossings[0])
+}
+
+#[test]
+fn test_resample_poly_44100_to_48000() {
+    let sine = Signal::from_len_fn(44100, |n| (TAU * 1000.0 * n as f64 / 44100.0).sin());
+    let resampled = sine.resample_poly(48000, 44100).unwrap();
+    assert_eq!(resampled.len(), 48000);
+    let freq = zero_crossing_frequency(&resampled, 48000.0);
+    assert!((freq - 1000.0).abs() / 1000.0 < 1e-3, "frequency {freq}");
+    let amplitude = tone_amplitude(&resampled, 1000.0, 48000.0);
+    assert!((amplitude - 1.0).abs() < 0.01, "amplitude {amplitude}");
+}
+
+#[test]
+fn test_resample_poly_lengths_and_factors() {
+    let ramp = Signal::linspace(0.0, 1.0, 10);
+    // 输出长度为 ⌈N·up/down⌉，up/down 先约去最大公约数
+    assert_eq!(ramp.resample_poly(3, 2).unwrap().len(), 15);
+    assert_eq!(ramp.resample_poly(2, 3).unwrap().len(), 7);
+    assert_eq!(
+        ramp.resample_poly(4, 6).unwrap().to_vec(),
+        ramp.resample_poly(2, 3).unwrap().to_vec()
+    );
+    assert_eq!(ramp.resample_poly(5, 5).unwrap().to_vec(), ramp.to_vec());
+    // down = 1 时等价于 upsample
+    let up = ramp.resample_poly(3, 1).unwrap();
+    let expected = ramp.upsample(3).unwrap();
+    for i in 0..30 {
+        assert!((up[i] - expected[i]).abs() < 1e-12);
+    }
+    assert_eq!(
+        ramp.resample_poly(0, 2).unwrap_err(),
+        ResampleError::ZeroFactor
+    );
+    assert_eq!(
+        ramp.resample_poly(2, 0).unwrap_err(),
+        ResampleError::ZeroFactor
+    );
+    assert_eq!(Signal::zeros(0).resample_poly(3, 2).unwrap().len(), 0);
+}
+
+#[test]
+fn test_interp_sinc_reconstruction() {
+    let freq = 0.02; // 周期 / 样本
+    let sine = Signal::from_len_fn(400, |n| (TAU * freq * n as f64).sin());
+
+    let positions: Vec<f64> = (0..100).map(|k| 150.0 + k as f64 * 0.37).collect();
+    let values = sine.interp_sinc(&positions, 32).unwrap();
+    for (p, v) in positions.iter().zip(values.iter()) {
+        let expected = (TAU * freq * p).sin();
+        assert!((v - expected).abs() < 1e-4, "at {p}: {v} vs {expected}");
+    }
+
+    // 整数位置返回原始样本，包括端点
+    let on_grid = sine.interp_sinc(&[0.0, 17.0, 399.0], 4).unwrap();
+    assert_eq!(on_grid.to_vec(), vec![sine[0], sine[17], sine[399]]);
+
+    // 分数延迟：整体平移半个样本
+    let delayed: Vec<f64> = (100..300).map(|n| n as f64 + 0.5).collect();
+    let shifted = sine.interp_sinc(&delayed, 32).unwrap();
+    assert!((shifted[0] - (TAU * freq * 100.5).sin()).abs() < 1e-4);
+
+    assert_eq!(
+        sine.interp_sinc(&[-0.1], 4).unwrap_err(),
+        ResampleError::PositionOutOfRange(-0.1)
+    );
+    assert!(sine.interp_sinc(&[399.5], 4).is_err());
+    assert!(sine.interp_sinc(&[f64::NAN], 4).is_err());
+    assert!(matches!(
+        sine.interp_sinc(&[1.5], 0),
+        Err(ResampleError::InvalidFilter(_))
+    ));
+    assert!(Signal::zeros(0).interp_sinc(&[0.0], 4).is_err());
+    assert_eq!(Signal::zeros(0).interp_sinc(&[], 4).unwrap().len(), 0);
+}
+
+#[test]
+fn test_linear_and_cubic_interpolation() {
+    // 线性插值重采样斜坡信号保持线性
+    let ramp = Signal::linspace(-2.0, 3.0, 11);
+    for new_len in [2, 7, 11, 23, 101] {
+        let resized = ramp.resize_interp(new_len, InterpKind::Linear).unwrap();
+        let expected = Signal::linspace(-2.0, 3.0, new_len);
+        assert_eq!(resized.len(), new_len);
+        for i in 0..new_len as isize {
+            assert!((resized[i] - expected[i]).abs() < 1e-12);
+        }
+        assert_eq!(resized[0], -2.0);
+        assert_eq!(resized[-1], 3.0);
+    }
+
+    // 三次 Hermite 插值对抛物线精确（包括端点附近）
+    let parabola = Signal::from_len_fn(9, |n| {
+        let x = n as f64;
+        0.5 * x * x - 3.0 * x + 1.0
+    });
+    let resized = parabola
+        .resize_interp(33, InterpKind::CubicHermite)
+        .unwrap();
+    for (k, v) in resized.iter().enumerate() {
+        let x = k as f64 * 8.0 / 32.0;
+        assert!((v - (0.5 * x * x - 3.0 * x + 1.0)).abs() < 1e-12);
+    }
+
+    // 越界位置截断到端点，允许重复位置
+    let values = ramp.interp_linear(&[-3.0, 0.25, 9.5, 9.5, 42.0]).unwrap();
+    assert_eq!(values[0], -2.0);
+    assert!((values[1] - (-1.875)).abs() < 1e-12);
+    assert!((values[2] - 2.75).abs() < 1e-12);
+    assert_eq!(values[3], values[2]);
+    assert_eq!(values[4], 3.0);
+
+    // 位置必须非递减
+    assert_eq!(
+        ramp.interp_linear(&[0.0, 2.0, 1.5, 3.0]).unwrap_err(),
+        ResampleError::NonMonotonicPositions { index: 2 }
+    );
+
+    // 边界情况
+    let single = Signal::from_vec(vec![4.0]);
+    assert_eq!(
+        single.interp_linear(&[0.0, 2.5]).unwrap().to_vec(),
+        vec![4.0, 4.0]
+    );
+    assert_eq!(
+        single
+            .resize_interp(3, InterpKind::CubicHermite)
+            .unwrap()
+            .to_vec(),
+        vec![4.0; 3]
+    );
+    assert_eq!(
+        ramp.resize_interp(1, InterpKind::Linear).unwrap().to_vec(),
+        vec![-2.0]
+    );
+    assert_eq!(ramp.resize_interp(0, InterpKind::Linear).unwrap().len(), 0);
+    let empty = Signal::zeros(0);
+    assert_eq!(
+        empty.interp_linear(&[0.0]).unwrap_err(),
+        ResampleError::EmptyInput
+    );
+    assert_eq!(
+        empty.resize_interp(4, InterpKind::Linear).unwrap_err(),
+        ResampleError::EmptyInput
+    );
+    assert!(ramp.interp_linear(&[f64::NAN]).is_err());
+}
+
+#[test]
+fn test_downsample() {
+    let ramp: Signal = (0..10).map(|i| i as f64).collect();
+
+    assert_eq!(ramp.downsample(1, 0).unwrap().to_vec(), ramp.to_vec());
+    assert_eq!(
+        ramp.downsample(3, 0).unwrap().to_vec(),
+        vec![0.0, 3.0, 6.0, 9.0]
+    );
+    assert_eq!(ramp.downsample(4, 2).unwrap().to_vec(), vec![2.0, 6.0]);
+
+    // 长度为 ceil((N - offset) / step)
+    for step in 1..12 {
+        for offset in 0..12 {
+            let out = ramp.downsample(step, offset).unwrap();
+            assert_eq!(out.len(), 10usize.saturating_sub(offset).div_ceil(step));
+            for (k, &x) in out.iter().enumerate() {
+                assert_eq!(x, (offset + k * step) as f64);
+            }
+        }
+    }
+    assert_eq!(ramp.downsample(2, 10).unwrap().len(), 0);
+    assert_eq!(ramp.downsample(2, usize::MAX).unwrap().len(), 0);
+
+    // 多相分解：偶数相与奇数相各取一半样本
+    let even = ramp.downsample(2, 0).unwrap();
+    let odd = ramp.downsample(2, 1).unwrap();
+    assert_eq!((even.len(), odd.len()), (5, 5));
+    assert_eq!(even[1] + 1.0, odd[1]);
+
+    assert_eq!(ramp.downsample(0, 0).err(), Some(ResampleError::ZeroFactor));
+}
@@ -0,0 +1,136 @@
+use dsp4rust::generator::Generator;
+use dsp4rust::rolling::{RollingAlign, RollingStat};
+use dsp4rust::signal::Signal;
+
+const STATS: [RollingStat; 5] = [
+    RollingStat::Mean,
+    RollingStat::Std,
+    RollingStat::Min,
+    RollingStat::Max,
+    RollingStat::Rms,
+];
+
+fn noise(len: usize, seed: u64) -> Signal {
+    Generator::new()
+        .sample_rate(1.0)
+        .num_samples(len)
+        .seed(seed)
+        .build()
+        .unwrap()
+        .gaussian_white_noise(1.0, 2.0)
+}
+
+// 逐窗口直接计算统计量，作为参考
+fn brute_force(window: &[f64], stat: RollingStat) -> f64 {
+    let n = window.len() as f64;
+    let mean = window.iter().sum::<f64>() / n;
+    match stat {
+        RollingStat::Mean => mean,
+        RollingStat::Std => {
+            (window.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        }
+        RollingStat::Min => window.iter().copied().fold(f64::INFINITY, f64::min),
+        RollingStat::Max => window.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        RollingStat::Rms => (window.iter().map(|x| x * x).sum::<f64>() / n).sqrt(),
+    }
+}
+
+#[test]
+fn test_rolling_matches_brute_force() {
+    let signal = noise(500, 7);
+    for window_len in [1, 2, 7, 64] {
+        let expected: Vec<Vec<f64>> = STATS
+            .iter()
+            .map(|&stat| {
+                signal
+                    .windows(window_len)
+                    .map(|w| brute_force(&w.to_vec(), stat))
+                    .collect()
+            })
+            .collect();
+        for (stat, expected) in STATS.iter().zip(expected) {
+            let rolled = signal.rolling(window_len, *stat, RollingAlign::Left);
+            for (start, want) in expected.iter().enumerate() {
+                let got = rolled[start as isize];
+                if want.is_nan() {
+                    // 窗口长度为 1 时样本标准差无定义
+                    assert!(got.is_nan());
+                } else {
+                    assert!(
+                        (got - want).abs() < 1e-9,
+                        "{stat:?} window {window_len} at {start}: {got} vs {want}"
+                    );
+                }
+            }
+            let tail = rolled.to_vec()[expected.len()..].to_vec();
+            assert!(tail.iter().all(|x| x.is_nan()));
+        }
+    }
+}
+
+#[test]
+fn test_rolling_alignment() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mean = |align| signal.rolling(4, RollingStat::Mean, align).to_vec();
+    let nan = f64::NAN;
+    let same = |a: Vec<f64>, b: Vec<f64>| {
+        a.len() == b.len()
+            && a.iter()
+                .zip(&b)
+                .all(|(x, y)| x == y || (x.is_nan() && y.is_nan()))
+    };
+
+    assert!(same(
+        mean(RollingAlign::Left),
+        vec![2.5, 3.5, 4.5, nan, nan, nan]
+    ));
+    assert!(same(
+        mean(RollingAlign::Center),
+        vec![nan, nan, 2.5, 3.5, 4.5, nan]
+    ));
+    assert!(same(
+        mean(RollingAlign::Right),
+        vec![nan, nan, nan, 2.5, 3.5, 4.5]
+    ));
+    assert_eq!(RollingAlign::default(), RollingAlign::Right);
+
+    // 窗口比信号长时全部为 NaN
+    let too_long = signal.rolling(7, RollingStat::Max, RollingAlign::Right);
+    assert_eq!(too_long.len(), 6);
+    assert!(too_long.iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn test_rolling_nan_windows() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0, 7.0]);
+    for stat in STATS {
+        let rolled = signal.rolling(2, stat, RollingAlign::Left).to_vec();
+        // 含 NaN 的窗口为 NaN，其余窗口不受影响
+        assert!(!rolled[0].is_nan());
+        assert!(rolled[1].is_nan() && rolled[2].is_nan());
+        assert!(rolled[3..6].iter().all(|x| !x.is_nan()));
+    }
+    let max = signal.rolling(2, RollingStat::Max, RollingAlign::Left);
+    assert_eq!(max.to_vec()[3..6], [5.0, 6.0, 7.0]);
+}
+
+#[test]
+fn test_rolling_is_linear_time() {
+    // 逐窗口重新计算需要约 10^10 次运算，线性算法可以很快完成
+    let signal = noise(1_000_000, 11);
+    let window_len = 10_000;
+    for stat in STATS {
+        let rolled = signal.rolling(window_len, stat, RollingAlign::Right);
+        assert_eq!(rolled.len(), 1_000_000);
+        // 抽查最后一个窗口，检验长时间递推后的累积误差
+        let last = signal.to_vec()[1_000_000 - window_len..].to_vec();
+        let want = brute_force(&last, stat);
+        assert!((rolled[-1] - want).abs() < 1e-8, "{stat:?}");
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_rolling_zero_window_panics() {
+    Signal::ones(4).rolling(0, RollingStat::Mean, RollingAlign::Left);
+}
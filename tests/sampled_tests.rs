@@ -0,0 +1,159 @@
+use dsp4rust::errors::{SampledError, ShapeError};
+use dsp4rust::generator::Generator;
+use dsp4rust::sampled::SampledSignal;
+use dsp4rust::signal::Signal;
+
+fn ramp(len: usize, sample_rate: f64) -> SampledSignal {
+    SampledSignal::new((0..len).map(|k| k as f64).collect(), sample_rate).unwrap()
+}
+
+#[test]
+fn test_time_index_round_trip() {
+    // 采样间隔不能精确表示为二进制小数的采样率
+    for sample_rate in [3.0, 44100.0, 48000.0, 0.3, 1e-3] {
+        let signal = ramp(1000, sample_rate);
+        for k in 0..signal.len() {
+            assert_eq!(
+                signal.index_at(signal.time_at(k)),
+                Some(k),
+                "fs = {sample_rate}, k = {k}"
+            );
+        }
+        assert_eq!(signal.time_vector().len(), signal.len());
+        assert_eq!(signal.time_vector()[-1], signal.time_at(999));
+    }
+}
+
+#[test]
+fn test_index_at_rounds_and_rejects_outside_times() {
+    let signal = ramp(4, 10.0);
+    assert_eq!(signal.index_at(0.14), Some(1));
+    assert_eq!(signal.index_at(0.16), Some(2));
+    assert_eq!(signal.index_at(-0.04), Some(0));
+    assert_eq!(signal.index_at(-0.06), None);
+    assert_eq!(signal.index_at(0.34), Some(3));
+    assert_eq!(signal.index_at(0.36), None);
+    assert_eq!(signal.index_at(f64::NAN), None);
+}
+
+#[test]
+fn test_duration() {
+    assert_eq!(ramp(48000, 48000.0).duration(), 1.0);
+    assert_eq!(ramp(0, 8000.0).duration(), 0.0);
+}
+
+#[test]
+fn test_slice_time() {
+    let signal = ramp(10, 44100.0);
+    // 以 time_at 给出的端点切片，结果与按索引切片一致
+    for (from, to) in [(0, 10), (2, 7), (3, 3), (9, 10)] {
+        let sliced = signal
+            .slice_time(signal.time_at(from), signal.time_at(to))
+            .unwrap();
+        assert_eq!(
+            sliced.signal(),
+            &signal.cut_range(from as isize, to as isize)
+        );
+        assert_eq!(sliced.sample_rate(), 44100.0);
+    }
+
+    // 端点落在样本之间时，取时刻位于区间内的样本
+    let signal = ramp(10, 10.0);
+    assert_eq!(
+        signal.slice_time(0.15, 0.45).unwrap().to_vec(),
+        vec![2.0, 3.0, 4.0]
+    );
+    // 超出信号的部分被裁剪
+    assert_eq!(
+        signal.slice_time(-1.0, 0.2).unwrap().to_vec(),
+        vec![0.0, 1.0]
+    );
+    assert_eq!(signal.slice_time(0.85, 5.0).unwrap().to_vec(), vec![9.0]);
+    assert!(signal.slice_time(2.0, 3.0).unwrap().is_empty());
+}
+
+#[test]
+fn test_slice_time_rejects_invalid_ranges() {
+    let signal = ramp(10, 10.0);
+    assert_eq!(
+        signal.slice_time(0.5, 0.2),
+        Err(SampledError::InvalidTimeRange {
+            start: 0.5,
+            end: 0.2
+        })
+    );
+    assert!(signal.slice_time(f64::NAN, 0.2).is_err());
+}
+
+#[test]
+fn test_invalid_sample_rate() {
+    for sample_rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            SampledSignal::new(Signal::ones(3), sample_rate),
+            Err(SampledError::InvalidSampleRate(_))
+        ));
+    }
+}
+
+#[test]
+fn test_arithmetic_with_matching_rates() {
+    let a = SampledSignal::new(Signal::from_vec(vec![1.0, 2.0, 3.0]), 100.0).unwrap();
+    let b = SampledSignal::new(Signal::from_vec(vec![4.0, 5.0, 6.0]), 100.0).unwrap();
+
+    let sum = (&a + &b).unwrap();
+    assert_eq!(sum.to_vec(), vec![5.0, 7.0, 9.0]);
+    assert_eq!(sum.sample_rate(), 100.0);
+    assert_eq!((&b - &a).unwrap().to_vec(), vec![3.0, 3.0, 3.0]);
+    assert_eq!((&a * &b).unwrap().to_vec(), vec![4.0, 10.0, 18.0]);
+    assert_eq!((&b / &a).unwrap().to_vec(), vec![4.0, 2.5, 2.0]);
+
+    let scaled = &a * 2.0;
+    assert_eq!(scaled.to_vec(), vec![2.0, 4.0, 6.0]);
+    assert_eq!(scaled.sample_rate(), 100.0);
+}
+
+#[test]
+fn test_arithmetic_rate_mismatch() {
+    let a = SampledSignal::new(Signal::ones(3), 44100.0).unwrap();
+    let b = SampledSignal::new(Signal::ones(3), 48000.0).unwrap();
+    let mismatch = SampledError::RateMismatch {
+        left: 44100.0,
+        right: 48000.0,
+    };
+    assert_eq!(&a + &b, Err(mismatch.clone()));
+    assert_eq!(&a - &b, Err(mismatch.clone()));
+    assert_eq!(&a * &b, Err(mismatch.clone()));
+    assert_eq!(&a / &b, Err(mismatch));
+}
+
+#[test]
+fn test_arithmetic_length_mismatch() {
+    let a = SampledSignal::new(Signal::ones(3), 100.0).unwrap();
+    let b = SampledSignal::new(Signal::ones(4), 100.0).unwrap();
+    assert_eq!(
+        &a + &b,
+        Err(SampledError::Shape(ShapeError::LengthMismatch {
+            left: 3,
+            right: 4
+        }))
+    );
+}
+
+#[test]
+fn test_generator_constructors() {
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .start_time(0.0)
+        .duration(0.25)
+        .build()
+        .unwrap();
+    let sine = generator.sin_unit_sampled(50.0, 0.0);
+    assert_eq!(sine.sample_rate(), generator.sample_rate());
+    assert_eq!(sine.signal(), &generator.sin_unit(50.0, 0.0));
+    assert_eq!(sine.time_vector(), generator.time_vector());
+    assert_eq!(sine.duration(), 0.25);
+
+    let noise = generator.sampled(generator.gaussian_unit());
+    assert_eq!(noise.len(), generator.num_samples());
+    assert!((&sine + &noise).is_ok());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "serde")]
+
+use dsp4rust::signal::Signal;
+
+#[test]
+fn test_json_round_trip() {
+    let signal = Signal::from_vec(vec![0.0, -1.5, 1e-300, 123456.789, f64::MAX]);
+    let json = serde_json::to_string(&signal).unwrap();
+    assert!(json.starts_with('[') && json.ends_with(']'));
+    let back: Signal = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, signal);
+
+    // 与 numpy 导出的普通 JSON 数组互通
+    let plain = serde_json::to_string(&vec![1.0, 2.5, -3.0]).unwrap();
+    assert_eq!(
+        serde_json::to_string(&Signal::from_vec(vec![1.0, 2.5, -3.0])).unwrap(),
+        plain
+    );
+
+    let empty: Signal = serde_json::from_str("[]").unwrap();
+    assert_eq!(empty, Signal::from_vec(vec![]));
+}
+
+#[test]
+fn test_json_integers_coerce_to_f64() {
+    let signal: Signal = serde_json::from_str("[1, -2, 3, 4000000000]").unwrap();
+    assert_eq!(signal, Signal::from_vec(vec![1.0, -2.0, 3.0, 4e9]));
+    let mixed: Signal = serde_json::from_str("[1, 2.5]").unwrap();
+    assert_eq!(mixed, Signal::from_vec(vec![1.0, 2.5]));
+
+    assert!(serde_json::from_str::<Signal>("[1, \"a\"]").is_err());
+    assert!(serde_json::from_str::<Signal>("{\"a\": 1}").is_err());
+}
+
+#[test]
+fn test_binary_round_trip() {
+    let signal: Signal = (0..1000).map(|i| (i as f64 * 0.37).sin()).collect();
+    let bytes = bincode::serialize(&signal).unwrap();
+    // 长度前缀加每个样本 8 字节
+    assert_eq!(bytes.len(), 8 + 8 * 1000);
+    let back: Signal = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(back, signal);
+
+    // NaN 与无穷大在二进制格式中按位保留
+    let special = Signal::from_vec(vec![f64::NAN, f64::INFINITY, -0.0]);
+    let back: Signal = bincode::deserialize(&bincode::serialize(&special).unwrap()).unwrap();
+    assert!(back[0].is_nan());
+    assert_eq!(back[1], f64::INFINITY);
+    assert!(back[2] == 0.0 && back[2].is_sign_negative());
+}
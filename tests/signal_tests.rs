@@ -44,3 +44,2111 @@ fn test_signal_creation() {
     assert_eq!(arrange.len(), 4);
     assert_eq!(arrange.to_vec(), vec![0.0, 0.25, 0.5, 0.75]);
 }
+
+#[test]
+fn test_add_noise_snr() {
+    use dsp4rust::generator::Generator;
+
+    let generator = Generator::new()
+        .sample_rate(8000.0)
+        .start_time(0.0)
+        .stop_time(1.0)
+        .build()
+        .unwrap();
+    let sine = generator.sin(440.0, 0.0, 3.0, 0.0);
+
+    // 信号功率与残差（输出减输入）功率之比为 20 dB
+    let noisy = sine.add_noise_snr(20.0, None);
+    let residual = &noisy - &sine;
+    let snr = 10.0 * (sine.avg_power() / residual.avg_power()).log10();
+    assert!((snr - 20.0).abs() < 0.5, "{snr}");
+    assert!(residual.mean().unwrap().abs() < 0.05);
+
+    // 固定种子时可复现
+    let a = sine.add_noise_snr(0.0, Some(3));
+    let b = sine.add_noise_snr(0.0, Some(3));
+    assert_eq!(a.to_vec(), b.to_vec());
+    assert_ne!(a.to_vec(), sine.add_noise_snr(0.0, Some(4)).to_vec());
+
+    // 零功率信号与空信号原样返回
+    assert_eq!(
+        Signal::zeros(10).add_noise_snr(20.0, None).to_vec(),
+        vec![0.0; 10]
+    );
+    assert_eq!(Signal::zeros(0).add_noise_snr(20.0, None).len(), 0);
+    assert_eq!(
+        sine.add_noise_snr(f64::INFINITY, None).to_vec(),
+        sine.to_vec()
+    );
+}
+
+#[test]
+fn test_pad_reflect() {
+    use dsp4rust::signal::{PadError, PadSide};
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    let pad = |side, width| signal.pad_reflect(side, width).unwrap().to_vec();
+
+    // 宽度小于信号长度：端点不重复
+    assert_eq!(pad(PadSide::Left, 2), vec![3.0, 2.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(pad(PadSide::Right, 2), vec![1.0, 2.0, 3.0, 4.0, 3.0, 2.0]);
+    assert_eq!(
+        pad(PadSide::Both, 3),
+        vec![4.0, 3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0]
+    );
+    assert_eq!(pad(PadSide::Both, 0), vec![1.0, 2.0, 3.0, 4.0]);
+
+    // 宽度大于信号长度：反复折叠
+    assert_eq!(
+        pad(PadSide::Left, 8),
+        vec![3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        pad(PadSide::Right, 7),
+        vec![1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0]
+    );
+    let short = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    assert_eq!(
+        short.pad_reflect(PadSide::Both, 5).unwrap().to_vec(),
+        vec![2.0, 1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0]
+    );
+
+    // 单个样本直接重复，空信号报错
+    let single = Signal::from_vec(vec![5.0]);
+    assert_eq!(
+        single.pad_reflect(PadSide::Both, 2).unwrap().to_vec(),
+        vec![5.0; 5]
+    );
+    assert!(matches!(
+        Signal::zeros(0).pad_reflect(PadSide::Left, 1),
+        Err(PadError::ConcatenationError(_))
+    ));
+}
+
+#[test]
+fn test_pad_edge() {
+    use dsp4rust::signal::{PadError, PadSide};
+
+    let ramp = Signal::linspace(-1.0, 2.0, 7);
+    let width = 4;
+
+    // 两侧延拓部分平坦且等于端点值
+    let padded = ramp.pad_edge(PadSide::Both, width).unwrap().to_vec();
+    assert_eq!(padded.len(), 7 + 2 * width);
+    assert!(padded[..width].iter().all(|&x| x == -1.0));
+    assert!(padded[width + 7..].iter().all(|&x| x == 2.0));
+    assert_eq!(&padded[width..width + 7], ramp.to_vec().as_slice());
+
+    let left = ramp.pad_edge(PadSide::Left, width).unwrap().to_vec();
+    assert_eq!(left.len(), 7 + width);
+    assert!(left[..width].iter().all(|&x| x == -1.0));
+    assert_eq!(&left[width..], ramp.to_vec().as_slice());
+
+    let right = ramp.pad_edge(PadSide::Right, width).unwrap().to_vec();
+    assert_eq!(right.len(), 7 + width);
+    assert_eq!(&right[..7], ramp.to_vec().as_slice());
+    assert!(right[7..].iter().all(|&x| x == 2.0));
+
+    // 与其他填充函数一致，空信号报错
+    assert!(matches!(
+        Signal::zeros(0).pad_edge(PadSide::Both, 2),
+        Err(PadError::ConcatenationError(_))
+    ));
+}
+
+#[test]
+fn test_pad_linear_ramp() {
+    use dsp4rust::signal::{PadError, PadSide};
+
+    let signal = Signal::from_vec(vec![3.0, -1.0, 0.5, -2.0]);
+    let width = 5;
+
+    // 两侧各自从端点值出发，最外侧恰为 end_value
+    let padded = signal
+        .pad_linear_ramp(PadSide::Both, width, 1.0)
+        .unwrap()
+        .to_vec();
+    assert_eq!(padded.len(), 4 + 2 * width);
+    assert_eq!(padded[0], 1.0);
+    assert_eq!(padded[padded.len() - 1], 1.0);
+    let left = &padded[..=width];
+    let right = &padded[width + 3..];
+    assert_eq!((left[width], right[0]), (3.0, -2.0));
+    assert!(left.windows(2).all(|w| w[1] > w[0]));
+    assert!(right.windows(2).all(|w| w[1] > w[0]));
+    for k in 0..width {
+        assert!((left[k] - (1.0 + 2.0 * k as f64 / width as f64)).abs() < 1e-12);
+        assert!((right[width - k] - (1.0 - 3.0 * k as f64 / width as f64)).abs() < 1e-12);
+    }
+
+    // 单侧填充
+    let ramp_to_zero = |side| signal.pad_linear_ramp(side, 2, 0.0).unwrap().to_vec();
+    assert_eq!(
+        ramp_to_zero(PadSide::Left),
+        vec![0.0, 1.5, 3.0, -1.0, 0.5, -2.0]
+    );
+    assert_eq!(
+        ramp_to_zero(PadSide::Right),
+        vec![3.0, -1.0, 0.5, -2.0, -1.0, 0.0]
+    );
+
+    assert!(matches!(
+        Signal::zeros(0).pad_linear_ramp(PadSide::Both, 2, 0.0),
+        Err(PadError::ConcatenationError(_))
+    ));
+}
+
+#[test]
+fn test_pad_stat() {
+    use dsp4rust::signal::{PadError, PadSide, StatPad};
+
+    let signal = Signal::from_vec(vec![4.0, -2.0, 7.0, 1.0, 10.0]);
+    let pad = |side, stat, stat_len| signal.pad_stat(side, 2, stat, stat_len).unwrap().to_vec();
+    let body = signal.to_vec();
+    let expected = |left: &[f64], right: &[f64]| [left, body.as_slice(), right].concat();
+
+    // 基于整个信号的各统计量
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Mean, None),
+        expected(&[4.0, 4.0], &[4.0, 4.0])
+    );
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Median, None),
+        expected(&[4.0, 4.0], &[4.0, 4.0])
+    );
+    assert_eq!(
+        pad(PadSide::Left, StatPad::Min, None),
+        expected(&[-2.0, -2.0], &[])
+    );
+    assert_eq!(
+        pad(PadSide::Right, StatPad::Max, None),
+        expected(&[], &[10.0, 10.0])
+    );
+
+    // 仅使用靠近各侧端点的 stat_len 个样本，两侧相互独立
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Mean, Some(2)),
+        expected(&[1.0, 1.0], &[5.5, 5.5])
+    );
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Median, Some(4)),
+        expected(&[2.5, 2.5], &[4.0, 4.0])
+    );
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Min, Some(1)),
+        expected(&[4.0, 4.0], &[10.0, 10.0])
+    );
+    assert_eq!(
+        pad(PadSide::Both, StatPad::Max, Some(3)),
+        expected(&[7.0, 7.0], &[10.0, 10.0])
+    );
+
+    // stat_len 大于信号长度时等同于整个信号
+    for stat in [StatPad::Mean, StatPad::Median, StatPad::Min, StatPad::Max] {
+        assert_eq!(
+            pad(PadSide::Both, stat, Some(100)),
+            pad(PadSide::Both, stat, None)
+        );
+    }
+
+    // 所有统计量都忽略 NaN，全为 NaN 时填充 NaN
+    let with_nan = Signal::from_vec(vec![f64::NAN, 3.0, 1.0, 8.0]);
+    let pad_nan = |stat| with_nan.pad_stat(PadSide::Right, 1, stat, None).unwrap()[-1];
+    assert_eq!(pad_nan(StatPad::Mean), 4.0);
+    assert_eq!(pad_nan(StatPad::Median), with_nan.median().unwrap());
+    assert_eq!(pad_nan(StatPad::Min), 1.0);
+    assert_eq!(pad_nan(StatPad::Max), 8.0);
+    for stat in [StatPad::Mean, StatPad::Median, StatPad::Min, StatPad::Max] {
+        let padded = with_nan.pad_stat(PadSide::Left, 1, stat, Some(1)).unwrap();
+        assert!(padded[0].is_nan());
+    }
+
+    assert!(matches!(
+        Signal::zeros(0).pad_stat(PadSide::Both, 2, StatPad::Median, None),
+        Err(PadError::ConcatenationError(_))
+    ));
+    assert!(matches!(
+        signal.pad_stat(PadSide::Both, 2, StatPad::Mean, Some(0)),
+        Err(PadError::Other(_))
+    ));
+}
+
+#[test]
+fn test_pad_cons_sides() {
+    use dsp4rust::signal::PadSide;
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let pad = |side| signal.pad_cons(side, 9, 2).unwrap().to_vec();
+
+    // Left 在前面填充，Right 在后面填充
+    assert_eq!(pad(PadSide::Left), vec![9.0, 9.0, 1.0, 2.0, 3.0]);
+    assert_eq!(pad(PadSide::Right), vec![1.0, 2.0, 3.0, 9.0, 9.0]);
+    assert_eq!(pad(PadSide::Both), vec![9.0, 9.0, 1.0, 2.0, 3.0, 9.0, 9.0]);
+    assert_eq!(
+        signal.pad_cons(PadSide::Left, 0.5, 0).unwrap().to_vec(),
+        vec![1.0, 2.0, 3.0]
+    );
+}
+
+#[test]
+fn test_pad_asymmetric_widths() {
+    use dsp4rust::signal::{PadError, PadMode, PadSide, StatPad};
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    let pad = |left, right, mode| signal.pad(left, right, mode).unwrap().to_vec();
+
+    assert_eq!(
+        pad(3, 1, PadMode::Constant(0.0)),
+        vec![0.0, 0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 0.0]
+    );
+    assert_eq!(
+        pad(1, 3, PadMode::Edge),
+        vec![1.0, 1.0, 2.0, 3.0, 4.0, 4.0, 4.0, 4.0]
+    );
+    assert_eq!(
+        pad(2, 1, PadMode::Reflect),
+        vec![3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0]
+    );
+    assert_eq!(
+        pad(5, 2, PadMode::Wrap),
+        vec![4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0]
+    );
+    assert_eq!(
+        pad(2, 1, PadMode::LinearRamp(0.0)),
+        vec![0.0, 0.5, 1.0, 2.0, 3.0, 4.0, 0.0]
+    );
+    assert_eq!(
+        pad(
+            1,
+            2,
+            PadMode::Stat {
+                stat: StatPad::Mean,
+                stat_len: Some(2)
+            }
+        ),
+        vec![1.5, 1.0, 2.0, 3.0, 4.0, 3.5, 3.5]
+    );
+
+    // 一侧为零、两侧都为零
+    assert_eq!(pad(0, 2, PadMode::Edge), vec![1.0, 2.0, 3.0, 4.0, 4.0, 4.0]);
+    assert_eq!(pad(2, 0, PadMode::Edge), vec![1.0, 1.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(pad(0, 0, PadMode::Reflect), signal.to_vec());
+
+    // 与按侧填充的函数一致
+    assert_eq!(
+        pad(3, 3, PadMode::Reflect),
+        signal.pad_reflect(PadSide::Both, 3).unwrap().to_vec()
+    );
+    assert_eq!(
+        pad(3, 0, PadMode::Constant(7.0)),
+        signal.pad_cons(PadSide::Left, 7.0, 3).unwrap().to_vec()
+    );
+
+    // 超出 isize::MAX 的宽度
+    let huge = isize::MAX as usize;
+    assert_eq!(
+        signal.pad(huge, 0, PadMode::Edge).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+    assert_eq!(
+        signal.pad(usize::MAX, usize::MAX, PadMode::Wrap).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+    assert_eq!(
+        signal.pad_cons(PadSide::Both, 0.0, huge / 2).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+
+    // 常数模式允许空信号，其余模式报错
+    assert_eq!(
+        Signal::zeros(0)
+            .pad(1, 2, PadMode::Constant(5.0))
+            .unwrap()
+            .to_vec(),
+        vec![5.0; 3]
+    );
+    assert!(matches!(
+        Signal::zeros(0).pad(1, 2, PadMode::Reflect),
+        Err(PadError::ConcatenationError(_))
+    ));
+}
+
+#[test]
+fn test_pad_wrap_widths_and_periodicity() {
+    use dsp4rust::signal::{PadError, PadSide};
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    let pad = |side, width| signal.pad_wrap(side, width).unwrap().to_vec();
+
+    // 宽度为 0、len - 1、len、len + 1
+    assert_eq!(pad(PadSide::Both, 0), vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(
+        pad(PadSide::Left, 3),
+        vec![2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        pad(PadSide::Right, 3),
+        vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0]
+    );
+    assert_eq!(
+        pad(PadSide::Left, 4),
+        vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        pad(PadSide::Both, 4),
+        vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        pad(PadSide::Both, 5),
+        vec![4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0, 1.0]
+    );
+
+    // 斜坡经周期填充后以原长度为周期
+    for len in 1..6 {
+        let ramp = Signal::arrange(0.0, len as f64, 1.0);
+        for width in [0, 1, len - 1, len, len + 1, 3 * len + 2] {
+            let padded = ramp.pad_wrap(PadSide::Both, width).unwrap().to_vec();
+            assert_eq!(padded.len(), len + 2 * width);
+            assert!((0..padded.len() - len).all(|i| padded[i] == padded[i + len]));
+            assert_eq!(&padded[width..width + len], ramp.to_vec().as_slice());
+        }
+    }
+
+    assert!(matches!(
+        Signal::zeros(0).pad_wrap(PadSide::Both, 3),
+        Err(PadError::ConcatenationError(_))
+    ));
+    assert_eq!(
+        signal.pad_wrap(PadSide::Both, usize::MAX).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+}
+
+#[test]
+fn test_cumsum_and_cumprod() {
+    let x = Signal::from_vec(vec![2.0, -1.0, 4.5, 0.25, 3.0]);
+
+    // cumsum(diff(x)) + x[0] 还原 x
+    let reconstructed: Vec<f64> = std::iter::once(x[0])
+        .chain(x.diff().unwrap().cumsum().iter().map(|d| d + x[0]))
+        .collect();
+    assert_eq!(reconstructed, x.to_vec());
+
+    assert_eq!(x.cumsum().to_vec(), vec![2.0, 1.0, 5.5, 5.75, 8.75]);
+    assert_eq!(x.cumprod().to_vec(), vec![2.0, -2.0, -9.0, -2.25, -6.75]);
+
+    // 空信号得到空结果
+    assert_eq!(Signal::zeros(0).cumsum().len(), 0);
+    assert_eq!(Signal::zeros(0).cumprod().len(), 0);
+}
+
+#[test]
+fn test_clip() {
+    let signal = Signal::from_vec(vec![-3.0, -0.5, 0.0, 0.5, 3.0]);
+
+    assert_eq!(
+        signal.clip(Some(-1.0), Some(1.0)).to_vec(),
+        vec![-1.0, -0.5, 0.0, 0.5, 1.0]
+    );
+    // 单侧限幅
+    assert_eq!(
+        signal.clip(Some(0.0), None).to_vec(),
+        vec![0.0, 0.0, 0.0, 0.5, 3.0]
+    );
+    assert_eq!(
+        signal.clip(None, Some(-1.0)).to_vec(),
+        vec![-3.0, -1.0, -1.0, -1.0, -1.0]
+    );
+    assert_eq!(signal.clip(None, None).to_vec(), signal.to_vec());
+    assert_eq!(signal.clip(Some(0.5), Some(0.5)).to_vec(), vec![0.5; 5]);
+
+    // NaN 样本原样保留
+    let with_nan = Signal::from_vec(vec![f64::NAN, 5.0]).clip(Some(0.0), Some(1.0));
+    assert!(with_nan[0].is_nan());
+    assert_eq!(with_nan[1], 1.0);
+
+    // 原地版本不重新分配内存
+    let mut in_place = signal.clone();
+    let before = in_place.as_slice().unwrap().as_ptr();
+    in_place.clip_inplace(Some(-1.0), Some(1.0));
+    assert_eq!(in_place.as_slice().unwrap().as_ptr(), before);
+    assert_eq!(
+        in_place.to_vec(),
+        signal.clip(Some(-1.0), Some(1.0)).to_vec()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_clip_inverted_bounds_panics() {
+    Signal::ones(3).clip(Some(1.0), Some(-1.0));
+}
+
+#[test]
+fn test_elementwise_rounding_and_sign() {
+    let signal = Signal::from_vec(vec![-2.5, -0.5, -0.0, 0.0, 0.5, 1.5, 2.5]);
+
+    let abs = signal.abs();
+    assert_eq!(abs.to_vec(), vec![2.5, 0.5, 0.0, 0.0, 0.5, 1.5, 2.5]);
+    assert!(abs[2].is_sign_positive());
+
+    // f64::signum: +0.0 -> 1.0, -0.0 -> -1.0
+    assert_eq!(
+        signal.signum().to_vec(),
+        vec![-1.0, -1.0, -1.0, 1.0, 1.0, 1.0, 1.0]
+    );
+    assert!(Signal::from_vec(vec![f64::NAN]).signum()[0].is_nan());
+
+    assert_eq!(
+        signal.floor().to_vec(),
+        vec![-3.0, -1.0, -0.0, 0.0, 0.0, 1.0, 2.0]
+    );
+    assert_eq!(
+        signal.ceil().to_vec(),
+        vec![-2.0, -0.0, -0.0, 0.0, 1.0, 2.0, 3.0]
+    );
+    // 中间值向远离零的方向取整
+    let rounded = signal.round();
+    assert_eq!(rounded.to_vec(), vec![-3.0, -1.0, -0.0, 0.0, 1.0, 2.0, 3.0]);
+    assert!(rounded[2].is_sign_negative());
+
+    // 原地版本与返回副本的版本一致
+    type Copying = fn(&Signal) -> Signal;
+    type InPlace = fn(&mut Signal);
+    let ops: [(Copying, InPlace); 5] = [
+        (Signal::abs, Signal::abs_inplace),
+        (Signal::signum, Signal::signum_inplace),
+        (Signal::floor, Signal::floor_inplace),
+        (Signal::ceil, Signal::ceil_inplace),
+        (Signal::round, Signal::round_inplace),
+    ];
+    for (copying, in_place) in ops {
+        let mut target = signal.clone();
+        in_place(&mut target);
+        assert_eq!(target.to_vec(), copying(&signal).to_vec());
+    }
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_normalize() {
+    use dsp4rust::signal::NormMode;
+    use std::f64::consts::PI;
+
+    let signal = Signal::from_vec(vec![-1.0, 0.0, 1.0, 3.0]);
+
+    // MinMax 映射端点并保持线性
+    let mapped = signal.normalize(NormMode::MinMax(-1.0, 1.0)).unwrap();
+    assert_eq!(mapped.to_vec(), vec![-1.0, -0.5, 0.0, 1.0]);
+    let back = mapped.normalize(NormMode::MinMax(-1.0, 3.0)).unwrap();
+    for (a, b) in back.iter().zip(signal.iter()) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    // Peak：±3 的正弦归一化后落在 [-1, 1] 且峰值为 1
+    let sine: Signal = (0..1000)
+        .map(|i| 3.0 * (2.0 * PI * i as f64 / 100.0 + 0.1).sin())
+        .collect();
+    let peak = sine.normalize(NormMode::Peak).unwrap();
+    assert!(peak.iter().all(|x| (-1.0..=1.0).contains(x)));
+    let max_abs = peak.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+    assert_eq!(max_abs, 1.0);
+    // 缩放因子可以恢复原信号
+    let factor = sine.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+    for (a, b) in (&peak * &factor).iter().zip(sine.iter()) {
+        assert!((a - b).abs() < 1e-12);
+    }
+
+    // RMS 缩放到目标值
+    let rms = sine.normalize(NormMode::Rms(0.5)).unwrap();
+    assert!((rms.avg_power().sqrt() - 0.5).abs() < 1e-12);
+    let ratio = rms[1] / sine[1];
+    assert!((ratio - 0.5 / sine.avg_power().sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn test_normalize_degenerate_inputs() {
+    use dsp4rust::errors::NormError;
+    use dsp4rust::signal::NormMode;
+
+    let constant = Signal::from_vec(vec![2.0; 4]);
+    let zeros = Signal::zeros(4);
+
+    assert_eq!(
+        constant.normalize(NormMode::MinMax(0.0, 1.0)).err(),
+        Some(NormError::ZeroScale("range"))
+    );
+    assert_eq!(
+        zeros.normalize(NormMode::Peak).err(),
+        Some(NormError::ZeroScale("peak"))
+    );
+    assert_eq!(
+        zeros.normalize(NormMode::Rms(1.0)).err(),
+        Some(NormError::ZeroScale("RMS"))
+    );
+    assert_eq!(
+        Signal::from_vec(vec![]).normalize(NormMode::Peak).err(),
+        Some(NormError::EmptyInput)
+    );
+    assert!(matches!(
+        constant.normalize(NormMode::Rms(-1.0)),
+        Err(NormError::InvalidTarget { name: "rms", .. })
+    ));
+    assert!(matches!(
+        constant.normalize(NormMode::MinMax(f64::NAN, 1.0)),
+        Err(NormError::InvalidTarget { name: "lo", .. })
+    ));
+
+    // NaN 样本不参与尺度计算，输出中保持为 NaN
+    let with_nan = Signal::from_vec(vec![f64::NAN, -2.0, 1.0]);
+    let peak = with_nan.normalize(NormMode::Peak).unwrap();
+    assert!(peak[0].is_nan());
+    assert_eq!(peak[1], -1.0);
+    assert_eq!(peak[2], 0.5);
+    let min_max = with_nan.normalize(NormMode::MinMax(0.0, 3.0)).unwrap();
+    assert!(min_max[0].is_nan());
+    assert_eq!((min_max[1], min_max[2]), (0.0, 3.0));
+    let rms = with_nan.normalize(NormMode::Rms(1.0)).unwrap();
+    assert!(rms[0].is_nan());
+    assert_relative_eq!(rms.iter().skip(1).map(|x| x * x).sum::<f64>(), 2.0);
+
+    // 没有非 NaN 样本时所有模式都与 standardize 一样返回 EmptyInput
+    let all_nan = Signal::from_vec(vec![f64::NAN; 3]);
+    for mode in [
+        NormMode::MinMax(0.0, 1.0),
+        NormMode::Peak,
+        NormMode::Rms(1.0),
+    ] {
+        assert_eq!(all_nan.normalize(mode), Err(NormError::EmptyInput));
+    }
+    assert_eq!(all_nan.standardize(), Err(NormError::EmptyInput));
+}
+
+#[test]
+fn test_standardize() {
+    use dsp4rust::errors::NormError;
+
+    let signal: Signal = (0..200)
+        .map(|i| 5.0 + 2.0 * (i as f64 * 0.37).sin() + 0.01 * i as f64)
+        .collect();
+    let z = signal.standardize().unwrap();
+    let n = z.len() as f64;
+    let mean = z.iter().sum::<f64>() / n;
+    let std = (z.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt();
+    assert!(mean.abs() < 1e-12);
+    assert!((std - 1.0).abs() < 1e-12);
+
+    // NaN 样本不参与统计量计算
+    let with_nan = Signal::from_vec(vec![1.0, f64::NAN, 3.0])
+        .standardize()
+        .unwrap();
+    assert!(with_nan[1].is_nan());
+    assert_relative_eq!(with_nan[0], -1.0 / 2f64.sqrt());
+
+    assert_eq!(
+        Signal::from_vec(vec![4.0; 5]).standardize().err(),
+        Some(NormError::ZeroScale("standard deviation"))
+    );
+    assert_eq!(
+        Signal::from_vec(vec![4.0]).standardize().err(),
+        Some(NormError::ZeroScale("standard deviation"))
+    );
+    assert_eq!(
+        Signal::from_vec(vec![]).standardize().err(),
+        Some(NormError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_robust_scale() {
+    use dsp4rust::errors::NormError;
+
+    let values: Vec<f64> = (0..1001).map(|i| (i as f64 * 0.71).sin()).collect();
+    let scaled = Signal::from_vec(values.clone()).robust_scale().unwrap();
+
+    // 加入一个极端离群值后，其余样本的缩放结果几乎不变
+    let mut with_outlier = values.clone();
+    with_outlier.push(1e9);
+    let scaled_outlier = Signal::from_vec(with_outlier).robust_scale().unwrap();
+    for (a, b) in scaled.iter().zip(scaled_outlier.iter()) {
+        assert!((a - b).abs() < 0.05);
+    }
+    // 作为对比，z-score 标准化会被离群值压缩到几乎无法区分
+    let mut z_outlier = values.clone();
+    z_outlier.push(1e9);
+    let z = Signal::from_vec(z_outlier).standardize().unwrap();
+    assert!((z[1] - z[0]).abs() < 1e-3);
+    assert!((scaled_outlier[1] - scaled_outlier[0]).abs() > 0.5);
+
+    assert_eq!(
+        Signal::from_vec(vec![1.0, 1.0, 1.0, 2.0])
+            .robust_scale()
+            .err(),
+        Some(NormError::ZeroScale("MAD"))
+    );
+    assert_eq!(
+        Signal::from_vec(vec![f64::NAN]).robust_scale().err(),
+        Some(NormError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_roll() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    assert_eq!(signal.roll(0).to_vec(), signal.to_vec());
+    assert_eq!(signal.roll(5).to_vec(), signal.to_vec());
+    assert_eq!(signal.roll(-5).to_vec(), signal.to_vec());
+    assert_eq!(signal.roll(2).to_vec(), vec![4.0, 5.0, 1.0, 2.0, 3.0]);
+    assert_eq!(signal.roll(-1).to_vec(), vec![2.0, 3.0, 4.0, 5.0, 1.0]);
+    // |k| > len 时按长度取模
+    assert_eq!(signal.roll(7).to_vec(), vec![4.0, 5.0, 1.0, 2.0, 3.0]);
+    assert_eq!(signal.roll(-6).to_vec(), vec![2.0, 3.0, 4.0, 5.0, 1.0]);
+    assert_eq!(signal.roll(isize::MIN).len(), 5);
+
+    assert_eq!(Signal::from_vec(vec![]).roll(3).len(), 0);
+}
+
+#[test]
+fn test_shift() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    assert_eq!(signal.shift(0, 0.0).to_vec(), signal.to_vec());
+    assert_eq!(signal.shift(2, 0.0).to_vec(), vec![0.0, 0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(
+        signal.shift(-1, 0.0).to_vec(),
+        vec![2.0, 3.0, 4.0, 5.0, 0.0]
+    );
+    // |k| >= len 时全部为填充值
+    assert_eq!(signal.shift(5, -1.0).to_vec(), vec![-1.0; 5]);
+    assert_eq!(signal.shift(-5, -1.0).to_vec(), vec![-1.0; 5]);
+    assert_eq!(signal.shift(8, 7.0).to_vec(), vec![7.0; 5]);
+    assert_eq!(signal.shift(isize::MIN, 7.0).to_vec(), vec![7.0; 5]);
+
+    assert_eq!(Signal::from_vec(vec![]).shift(-2, 1.0).len(), 0);
+}
+
+#[test]
+fn test_tile_and_repeat_each() {
+    use dsp4rust::signal::PadError;
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let tiled = signal.tile(4).unwrap();
+    assert_eq!(tiled.len(), 12);
+    assert_eq!(
+        tiled.to_vec(),
+        vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0]
+    );
+    assert_eq!(signal.tile(1).unwrap().to_vec(), signal.to_vec());
+    assert_eq!(signal.tile(0).unwrap().len(), 0);
+    assert_eq!(Signal::from_vec(vec![]).tile(usize::MAX).unwrap().len(), 0);
+
+    let held = Signal::from_vec(vec![1.0, 2.0]).repeat_each(3).unwrap();
+    assert_eq!(held.to_vec(), vec![1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    assert_eq!(signal.repeat_each(0).unwrap().len(), 0);
+
+    // 结果长度超过 isize::MAX
+    assert_eq!(
+        signal.tile(usize::MAX / 2).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+    assert_eq!(
+        signal.repeat_each(usize::MAX / 2).err(),
+        Some(PadError::ResultOutOfBounds)
+    );
+}
+
+#[test]
+fn test_concat() {
+    let a = Signal::from_vec(vec![1.0, 2.0]);
+    let b = Signal::from_vec(vec![3.0, 4.0, 5.0]);
+    let empty = Signal::from_vec(vec![]);
+
+    let joined = a.concat(&b).unwrap();
+    assert_eq!(joined.len(), 5);
+    assert_eq!(joined.to_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(
+        b.concat(&a).unwrap().to_vec(),
+        vec![3.0, 4.0, 5.0, 1.0, 2.0]
+    );
+
+    // 空信号出现在任意位置
+    assert_eq!(empty.concat(&a).unwrap().to_vec(), a.to_vec());
+    assert_eq!(a.concat(&empty).unwrap().to_vec(), a.to_vec());
+    assert_eq!(empty.concat(&empty).unwrap().len(), 0);
+
+    let many = Signal::concat_many(&[&empty, &a, &empty, &b, &a, &empty]).unwrap();
+    assert_eq!(many.len(), 7);
+    assert_eq!(many.to_vec(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 1.0, 2.0]);
+    assert_eq!(Signal::concat_many(&[]).unwrap().len(), 0);
+    assert_eq!(Signal::concat_many(&[&empty]).unwrap().len(), 0);
+    assert_eq!(Signal::concat_many(&[&b]).unwrap().to_vec(), b.to_vec());
+}
+
+#[test]
+fn test_split_at() {
+    use dsp4rust::errors::SliceError;
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+    let (left, right) = signal.split_at(2).unwrap();
+    assert_eq!(left.to_vec(), vec![1.0, 2.0]);
+    assert_eq!(right.to_vec(), vec![3.0, 4.0, 5.0]);
+
+    // 负索引从末尾计数
+    let (left, right) = signal.split_at(-2).unwrap();
+    assert_eq!(left.to_vec(), vec![1.0, 2.0, 3.0]);
+    assert_eq!(right.to_vec(), vec![4.0, 5.0]);
+
+    // 两端
+    let (left, right) = signal.split_at(0).unwrap();
+    assert_eq!((left.len(), right.len()), (0, 5));
+    let (left, right) = signal.split_at(5).unwrap();
+    assert_eq!((left.len(), right.len()), (5, 0));
+    let (left, right) = signal.split_at(-5).unwrap();
+    assert_eq!((left.len(), right.len()), (0, 5));
+
+    assert_eq!(
+        signal.split_at(6).err(),
+        Some(SliceError::IndexOutOfBounds { index: 6, len: 5 })
+    );
+    assert_eq!(
+        signal.split_at(-6).err(),
+        Some(SliceError::IndexOutOfBounds { index: -6, len: 5 })
+    );
+    assert!(signal.split_at(isize::MIN).is_err());
+}
+
+#[test]
+fn test_chunks() {
+    use dsp4rust::signal::ChunkTail;
+
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    let collect = |tail| -> Vec<Vec<f64>> { signal.chunks(3, tail).map(|c| c.to_vec()).collect() };
+
+    assert_eq!(
+        collect(ChunkTail::Keep),
+        vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0], vec![7.0]]
+    );
+    assert_eq!(
+        collect(ChunkTail::Drop),
+        vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]
+    );
+    assert_eq!(
+        collect(ChunkTail::Pad(-1.0)),
+        vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, -1.0, -1.0]
+        ]
+    );
+
+    // 长度整除时各策略一致
+    for tail in [ChunkTail::Keep, ChunkTail::Drop, ChunkTail::Pad(0.0)] {
+        assert_eq!(signal.chunks(7, tail).count(), 1);
+        assert_eq!(signal.chunks(1, tail).count(), 7);
+    }
+    // 块长大于信号长度
+    assert_eq!(signal.chunks(10, ChunkTail::Drop).count(), 0);
+    assert_eq!(
+        signal.chunks(10, ChunkTail::Pad(0.0)).next().unwrap().len(),
+        10
+    );
+    assert_eq!(
+        Signal::from_vec(vec![])
+            .chunks(3, ChunkTail::Pad(0.0))
+            .count(),
+        0
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_chunks_zero_length_panics() {
+    use dsp4rust::signal::ChunkTail;
+
+    let _ = Signal::ones(3).chunks(0, ChunkTail::Keep);
+}
+
+#[test]
+fn test_windows_with_hop() {
+    let signal: Signal = (0..48_000).map(|i| i as f64).collect();
+
+    // 帧数 = (len - window_size) / hop + 1
+    for (window_size, hop) in [(1024, 512), (1024, 1024), (1000, 333), (48_000, 7)] {
+        let frames: Vec<Signal> = signal.windows_with_hop(window_size, hop).collect();
+        assert_eq!(frames.len(), (48_000 - window_size) / hop + 1);
+        for (k, frame) in frames.iter().enumerate() {
+            assert_eq!(frame.len(), window_size);
+            assert_eq!(frame[0], (k * hop) as f64);
+        }
+    }
+    assert_eq!(signal.windows_with_hop(1024, 512).count(), 92);
+    assert_eq!(signal.windows_with_hop(48_001, 1).count(), 0);
+
+    // hop = 1 与 windows 一致
+    let short = Signal::from_vec(vec![1.0, 4.0, 2.0, 8.0, 5.0]);
+    let hopped: Vec<Vec<f64>> = short.windows_with_hop(3, 1).map(|w| w.to_vec()).collect();
+    let plain: Vec<Vec<f64>> = short.windows(3).map(|w| w.to_vec()).collect();
+    assert_eq!(hopped, plain);
+}
+
+#[test]
+#[should_panic]
+fn test_windows_with_zero_hop_panics() {
+    let _ = Signal::ones(4).windows_with_hop(2, 0);
+}
+
+#[test]
+fn test_windows_view() {
+    let signal: Signal = (0..1000).map(|i| ((i * 37) % 101) as f64).collect();
+
+    // 视图与拷贝窗口的结果一致
+    let viewed: Vec<f64> = signal.windows_view(16).map(|w| w.sum()).collect();
+    let owned: Vec<f64> = signal.windows(16).map(|w| w.iter().sum()).collect();
+    assert_eq!(viewed, owned);
+    assert_eq!(viewed.len(), 1000 - 16 + 1);
+
+    // 每个视图直接指向原信号的缓冲区，没有为窗口分配新内存
+    let base = signal.as_slice().unwrap().as_ptr();
+    for (k, window) in signal.windows_view(16).enumerate() {
+        assert_eq!(window.as_ptr(), base.wrapping_add(k));
+    }
+
+    assert_eq!(signal.windows_view(1001).count(), 0);
+}
+
+#[test]
+fn test_interleave_and_deinterleave() {
+    use dsp4rust::errors::ShapeError;
+
+    let a = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let b = Signal::from_vec(vec![10.0, 20.0, 30.0]);
+    let stereo = a.interleave(&b).unwrap();
+    assert_eq!(stereo.to_vec(), vec![1.0, 10.0, 2.0, 20.0, 3.0, 30.0]);
+
+    // 双通道往返
+    let channels = stereo.deinterleave(2);
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[0].to_vec(), a.to_vec());
+    assert_eq!(channels[1].to_vec(), b.to_vec());
+
+    // 三通道往返
+    let c = Signal::from_vec(vec![100.0, 200.0, 300.0]);
+    let three = Signal::from_vec(vec![1.0, 10.0, 100.0, 2.0, 20.0, 200.0, 3.0, 30.0, 300.0]);
+    let channels = three.deinterleave(3);
+    assert_eq!(channels[0].to_vec(), a.to_vec());
+    assert_eq!(channels[1].to_vec(), b.to_vec());
+    assert_eq!(channels[2].to_vec(), c.to_vec());
+
+    // 长度不是通道数的整数倍时丢弃不完整的最后一帧
+    let ragged = Signal::from_vec(vec![1.0, 10.0, 100.0, 2.0, 20.0, 200.0, 3.0, 30.0]);
+    let channels = ragged.deinterleave(3);
+    assert!(channels.iter().all(|ch| ch.len() == 2));
+    assert_eq!(channels[1].to_vec(), vec![10.0, 20.0]);
+    assert_eq!(Signal::from_vec(vec![1.0]).deinterleave(2)[0].len(), 0);
+    assert_eq!(ragged.deinterleave(1)[0].to_vec(), ragged.to_vec());
+
+    assert_eq!(
+        a.interleave(&Signal::ones(2)).err(),
+        Some(ShapeError::LengthMismatch { left: 3, right: 2 })
+    );
+    let empty = Signal::from_vec(vec![]);
+    assert_eq!(empty.interleave(&empty).unwrap().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_deinterleave_zero_channels_panics() {
+    Signal::ones(4).deinterleave(0);
+}
+
+#[test]
+fn test_median_and_quantiles() {
+    use dsp4rust::errors::StatError;
+
+    let odd = Signal::from_vec(vec![5.0, 1.0, 4.0, 2.0, 3.0]);
+    let even = Signal::from_vec(vec![4.0, 1.0, 3.0, 2.0]);
+    assert_eq!(odd.median().unwrap(), 3.0);
+    assert_eq!(even.median().unwrap(), 2.5);
+    // 不修改原信号
+    assert_eq!(odd.to_vec(), vec![5.0, 1.0, 4.0, 2.0, 3.0]);
+
+    for (signal, max) in [(&odd, 5.0), (&even, 4.0)] {
+        assert_eq!(signal.quantile(0.0).unwrap(), 1.0);
+        assert_eq!(signal.quantile(0.5).unwrap(), signal.median().unwrap());
+        assert_eq!(signal.quantile(1.0).unwrap(), max);
+    }
+    // 相邻顺序统计量之间线性插值：位置 (n - 1) * q
+    assert_eq!(even.quantile(0.25).unwrap(), 1.75);
+    assert_relative_eq!(odd.quantile(0.1).unwrap(), 1.4);
+
+    // 重复值
+    let repeated = Signal::from_vec(vec![2.0, 2.0, 2.0, 7.0]);
+    assert_eq!(repeated.median().unwrap(), 2.0);
+    assert_eq!(repeated.quantile(0.5).unwrap(), 2.0);
+    assert_relative_eq!(repeated.quantile(0.9).unwrap(), 5.5);
+    assert_eq!(Signal::from_vec(vec![3.0]).quantile(0.7).unwrap(), 3.0);
+
+    // 无穷大样本：落在样本上时不因 inf - inf 得到 NaN
+    let infinite = Signal::from_vec(vec![1.0, f64::INFINITY, f64::INFINITY]);
+    assert_eq!(infinite.quantile(0.0).unwrap(), 1.0);
+    assert_eq!(infinite.median().unwrap(), f64::INFINITY);
+    assert_eq!(infinite.quantile(0.75).unwrap(), f64::INFINITY);
+
+    assert_eq!(
+        odd.percentiles(&[0.0, 25.0, 50.0, 100.0]).unwrap(),
+        vec![1.0, 2.0, 3.0, 5.0]
+    );
+    assert_eq!(odd.percentiles(&[]).unwrap(), Vec::<f64>::new());
+
+    // NaN 样本被忽略
+    let with_nan = Signal::from_vec(vec![f64::NAN, 3.0, 1.0]);
+    assert_eq!(with_nan.median().unwrap(), 2.0);
+
+    let empty = Signal::from_vec(vec![]);
+    assert_eq!(empty.median(), Err(StatError::EmptyInput));
+    assert_eq!(empty.quantile(0.5), Err(StatError::EmptyInput));
+    assert_eq!(empty.percentiles(&[50.0]), Err(StatError::EmptyInput));
+    assert_eq!(odd.quantile(1.5), Err(StatError::InvalidQuantile(1.5)));
+    assert_eq!(odd.quantile(-0.1), Err(StatError::InvalidQuantile(-0.1)));
+    assert!(odd.quantile(f64::NAN).is_err());
+    assert_eq!(
+        odd.percentiles(&[50.0, 101.0]),
+        Err(StatError::InvalidPercentile(101.0))
+    );
+}
+
+#[test]
+fn test_rms_and_sum_of_squares() {
+    use std::f64::consts::TAU;
+
+    // 整数个周期的单位幅值正弦，均方根为 1/√2
+    let sine: Signal = (0..1000).map(|i| (TAU * i as f64 / 100.0).sin()).collect();
+    assert_relative_eq!(sine.rms().unwrap(), 0.5f64.sqrt(), epsilon = 1e-12);
+
+    for c in [-3.0, 0.0, 2.5] {
+        assert_relative_eq!(Signal::from_vec(vec![c; 7]).rms().unwrap(), f64::abs(c));
+    }
+
+    let signal = Signal::from_vec(vec![3.0, -4.0]);
+    assert_eq!(signal.sum_of_squares(), 25.0);
+    assert_eq!(signal.energy(), signal.sum_of_squares());
+    assert_eq!(signal.rms(), Some(12.5f64.sqrt()));
+
+    let empty = Signal::from_vec(vec![]);
+    assert_eq!(empty.rms(), None);
+    assert_eq!(empty.sum_of_squares(), 0.0);
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_dot_pearson_and_cosine() {
+    use dsp4rust::errors::{ShapeError, SimilarityError};
+    use std::f64::consts::TAU;
+
+    let x: Signal = (0..200).map(|i| ((i * 7919) % 97) as f64 / 10.0).collect();
+
+    // 仿射变换后的 Pearson 相关系数为 ±1
+    let up = &(&x * &2.5) + &-4.0;
+    let down = &(&x * &-0.3) + &10.0;
+    assert_relative_eq!(x.pearson(&up).unwrap(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(x.pearson(&down).unwrap(), -1.0, epsilon = 1e-12);
+    assert_relative_eq!(x.pearson(&x).unwrap(), 1.0, epsilon = 1e-12);
+
+    // 整数个周期的正弦与余弦正交
+    let sin: Signal = (0..1000)
+        .map(|i| (TAU * 5.0 * i as f64 / 1000.0).sin())
+        .collect();
+    let cos: Signal = (0..1000)
+        .map(|i| (TAU * 5.0 * i as f64 / 1000.0).cos())
+        .collect();
+    assert!(sin.dot(&cos).unwrap().abs() < 1e-9);
+    assert!(sin.cosine_similarity(&cos).unwrap().abs() < 1e-12);
+    assert_relative_eq!(sin.cosine_similarity(&sin).unwrap(), 1.0, epsilon = 1e-12);
+    assert_relative_eq!(
+        sin.cosine_similarity(&(&sin * &-2.0)).unwrap(),
+        -1.0,
+        epsilon = 1e-12
+    );
+
+    let a = Signal::from_vec(vec![1.0, 2.0]);
+    let b = Signal::from_vec(vec![3.0, 4.0]);
+    assert_eq!(a.dot(&b).unwrap(), 11.0);
+    let empty = Signal::from_vec(vec![]);
+    assert_eq!(empty.dot(&empty).unwrap(), 0.0);
+
+    // 错误情况
+    let mismatch = ShapeError::LengthMismatch { left: 2, right: 3 };
+    assert_eq!(a.dot(&Signal::ones(3)), Err(mismatch.clone()));
+    assert_eq!(
+        a.pearson(&Signal::ones(3)),
+        Err(SimilarityError::Shape(mismatch.clone()))
+    );
+    assert_eq!(
+        a.cosine_similarity(&Signal::ones(3)),
+        Err(SimilarityError::Shape(mismatch))
+    );
+    assert_eq!(
+        a.pearson(&Signal::ones(2)),
+        Err(SimilarityError::ZeroScale("variance"))
+    );
+    assert_eq!(
+        a.cosine_similarity(&Signal::zeros(2)),
+        Err(SimilarityError::ZeroScale("norm"))
+    );
+    assert_eq!(empty.pearson(&empty), Err(SimilarityError::EmptyInput));
+    assert_eq!(
+        empty.cosine_similarity(&empty),
+        Err(SimilarityError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_gradient() {
+    use dsp4rust::errors::DiffError;
+
+    // 抛物线的中心差分在内部点与解析导数完全一致
+    let dx = 0.5;
+    let parabola: Signal = (0..41).map(|i| (i as f64 * dx - 7.0).powi(2)).collect();
+    let grad = parabola.gradient(dx).unwrap();
+    assert_eq!(grad.len(), parabola.len());
+    for i in 1..40 {
+        assert_eq!(grad[i], 2.0 * (i as f64 * dx - 7.0));
+    }
+    // 两端为单侧差分
+    assert_eq!(grad[0], (parabola[1] - parabola[0]) / dx);
+    assert_eq!(grad[-1], (parabola[-1] - parabola[-2]) / dx);
+
+    let two = Signal::from_vec(vec![1.0, 3.0]);
+    assert_eq!(two.gradient(2.0).unwrap().to_vec(), vec![1.0, 1.0]);
+    assert_eq!(
+        Signal::from_vec(vec![1.0]).gradient(1.0).err(),
+        Some(DiffError::ShortLength)
+    );
+}
+
+#[test]
+fn test_diff_n() {
+    use dsp4rust::errors::DiffError;
+
+    let signal: Signal = (0..30)
+        .map(|i| ((i * 37) % 11) as f64 - 0.5 * i as f64)
+        .collect();
+    let twice = signal.diff().unwrap().diff().unwrap();
+    assert_eq!(signal.diff_n(2, &[], &[]).unwrap().to_vec(), twice.to_vec());
+    assert_eq!(
+        signal.diff_n(1, &[], &[]).unwrap().to_vec(),
+        signal.diff().unwrap().to_vec()
+    );
+    assert_eq!(
+        signal.diff_n(0, &[], &[]).unwrap().to_vec(),
+        signal.to_vec()
+    );
+
+    // 补值使输出与输入等长
+    let x = Signal::from_vec(vec![2.0, 5.0, 4.0]);
+    assert_eq!(
+        x.diff_n(1, &[0.0], &[]).unwrap().to_vec(),
+        vec![2.0, 3.0, -1.0]
+    );
+    assert_eq!(
+        x.diff_n(1, &[], &[4.0]).unwrap().to_vec(),
+        vec![3.0, -1.0, 0.0]
+    );
+    assert_eq!(
+        x.diff_n(2, &[2.0], &[4.0]).unwrap().to_vec(),
+        vec![3.0, -4.0, 1.0]
+    );
+    assert_eq!(
+        x.diff_n(0, &[1.0], &[]).unwrap().to_vec(),
+        vec![1.0, 2.0, 5.0, 4.0]
+    );
+
+    assert_eq!(x.diff_n(3, &[], &[]).err(), Some(DiffError::ShortLength));
+    assert!(x.diff_n(3, &[0.0], &[]).is_ok());
+    let empty = Signal::from_vec(vec![]);
+    assert!(empty.diff_n(0, &[], &[]).unwrap().is_empty());
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_numerical_integration() {
+    use std::f64::consts::PI;
+
+    let sampled_sin = |n: usize| -> (Signal, f64) {
+        let dx = PI / (n - 1) as f64;
+        ((0..n).map(|i| (i as f64 * dx).sin()).collect(), dx)
+    };
+
+    // sin 在 [0, π] 上的积分为 2；梯形误差约为 π·h²/12，Simpson 误差为 O(h⁴)
+    let (odd, dx) = sampled_sin(101);
+    let trapz_err = (odd.trapz(dx) - 2.0).abs();
+    assert!(trapz_err < PI * dx * dx / 12.0 * 1.01, "{trapz_err}");
+    assert!(trapz_err > 1e-5);
+    assert!((odd.simpson(dx) - 2.0).abs() < 1e-7);
+
+    // 偶数个样本时最后一个区间单独处理，精度仍为四阶
+    let (even, dx) = sampled_sin(100);
+    assert!((even.simpson(dx) - 2.0).abs() < 1e-6);
+    assert!((even.simpson(dx) - 2.0).abs() < (even.trapz(dx) - 2.0).abs() / 100.0);
+
+    // 对三次多项式 Simpson 公式是精确的
+    let cubic: Signal = (0..7).map(|i| (i as f64 * 0.5).powi(3)).collect();
+    assert_relative_eq!(cubic.simpson(0.5), 81.0 / 4.0, epsilon = 1e-12);
+
+    // 常数的累积积分为斜坡
+    let ramp = (&Signal::ones(5) * &3.0).cumtrapz(0.25, -1.0);
+    assert_eq!(ramp.to_vec(), vec![-1.0, -0.25, 0.5, 1.25, 2.0]);
+    let (sin, dx) = sampled_sin(51);
+    assert_relative_eq!(sin.cumtrapz(dx, 0.0)[-1], sin.trapz(dx), epsilon = 1e-12);
+
+    // 空信号与单样本信号
+    let empty = Signal::from_vec(vec![]);
+    let single = Signal::from_vec(vec![4.0]);
+    assert_eq!(empty.trapz(1.0), 0.0);
+    assert_eq!(single.trapz(1.0), 0.0);
+    assert_eq!(empty.simpson(1.0), 0.0);
+    assert_eq!(single.simpson(1.0), 0.0);
+    assert_eq!(Signal::from_vec(vec![1.0, 3.0]).simpson(2.0), 4.0);
+    assert_eq!(empty.cumtrapz(1.0, 5.0).len(), 0);
+    assert_eq!(single.cumtrapz(1.0, 5.0).to_vec(), vec![5.0]);
+}
+
+#[test]
+fn test_crest_factor() {
+    use dsp4rust::errors::StatError;
+    use dsp4rust::generator::Generator;
+
+    let generator = Generator::new()
+        .sample_rate(1000.0)
+        .num_samples(1000)
+        .build()
+        .unwrap();
+    // 整数个周期的正弦峰值因数为 √2
+    let sine = generator.sin(10.0, 0.0, 3.0, 0.0);
+    assert_relative_eq!(sine.crest_factor().unwrap(), 2f64.sqrt(), epsilon = 1e-6);
+    assert_relative_eq!(sine.papr_db().unwrap(), 3.0103, epsilon = 1e-4);
+
+    let square: Signal = (0..100)
+        .map(|i| if i % 10 < 5 { 2.0 } else { -2.0 })
+        .collect();
+    assert_eq!(square.crest_factor().unwrap(), 1.0);
+    assert_eq!(square.papr_db().unwrap(), 0.0);
+
+    // 全零中的单个冲激峰值因数为 √N
+    let mut impulse = vec![0.0; 400];
+    impulse[123] = -5.0;
+    let impulse = Signal::from_vec(impulse);
+    assert_relative_eq!(impulse.crest_factor().unwrap(), 20.0, epsilon = 1e-12);
+
+    assert_eq!(
+        Signal::zeros(8).crest_factor(),
+        Err(StatError::ZeroDenominator("rms"))
+    );
+    assert_eq!(
+        Signal::zeros(8).papr_db(),
+        Err(StatError::ZeroDenominator("rms"))
+    );
+    assert_eq!(
+        Signal::from_vec(vec![]).crest_factor(),
+        Err(StatError::EmptyInput)
+    );
+}
+
+#[test]
+fn test_frame_energy_and_rms() {
+    // 帧数为 (len - frame_len) / hop + 1，短于一帧的信号没有帧
+    let signal = Signal::ones(100);
+    for (frame_len, hop, count) in [
+        (10, 5, 19),
+        (10, 10, 10),
+        (30, 7, 11),
+        (100, 3, 1),
+        (101, 1, 0),
+    ] {
+        assert_eq!(signal.frame_energy(frame_len, hop).len(), count);
+        assert_eq!(signal.frame_rms(frame_len, hop).len(), count);
+    }
+
+    // 静音中的一段能量只点亮与其重叠的帧
+    let mut samples = vec![0.0; 200];
+    samples[83..97].iter_mut().for_each(|x| *x = 0.5);
+    let burst = Signal::from_vec(samples);
+    let (frame_len, hop) = (20, 8);
+    let energy = burst.frame_energy(frame_len, hop);
+    for (k, e) in energy.iter().enumerate() {
+        let (start, end) = (k * hop, k * hop + frame_len);
+        let overlap = end.min(97).saturating_sub(start.max(83));
+        assert_eq!(*e, 0.25 * overlap as f64, "frame {k}");
+        assert_eq!(*e > 0.0, start < 97 && end > 83);
+    }
+    let rms = burst.frame_rms(frame_len, hop);
+    for (r, e) in rms.iter().zip(energy.iter()) {
+        assert_relative_eq!(*r, (e / frame_len as f64).sqrt());
+    }
+
+    // hop = frame_len 时各帧不重叠，能量之和等于总能量
+    let ramp: Signal = (0..60).map(|i| i as f64 / 10.0).collect();
+    let tiled = ramp.frame_energy(12, 12);
+    assert_eq!(tiled.len(), 5);
+    assert_relative_eq!(
+        tiled.iter().sum::<f64>(),
+        ramp.sum_of_squares(),
+        epsilon = 1e-9
+    );
+    let first: f64 = (0..12).map(|i| (i as f64 / 10.0).powi(2)).sum();
+    assert_relative_eq!(tiled[0], first, epsilon = 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_energy_zero_hop_panics() {
+    Signal::ones(10).frame_energy(4, 0);
+}
+
+#[test]
+fn test_allclose() {
+    let a = Signal::from_vec(vec![0.0, 1.0, -250.0, f64::INFINITY]);
+    let b = Signal::from_vec(vec![1e-9, 1.0 + 1e-7, -250.002, f64::INFINITY]);
+    assert!(a.allclose(&b, 1e-5, 1e-8));
+    assert!(!a.allclose(&b, 1e-9, 1e-8));
+    assert!(!a.allclose(&b, 1e-5, 0.0));
+    assert!(a.allclose(&a, 0.0, 0.0));
+
+    // 长度不同返回 false 而不是恐慌
+    assert!(!a.allclose(&Signal::ones(3), 1.0, 1.0));
+    assert!(Signal::from_vec(vec![]).allclose(&Signal::from_vec(vec![]), 0.0, 0.0));
+
+    // NaN 与任何值都不接近，包括自身
+    let with_nan = Signal::from_vec(vec![1.0, f64::NAN]);
+    assert!(!with_nan.allclose(&with_nan, 1.0, 1.0));
+    let opposite = Signal::from_vec(vec![f64::INFINITY]);
+    assert!(!opposite.allclose(&Signal::from_vec(vec![f64::NEG_INFINITY]), 1.0, 1.0));
+}
+
+#[test]
+fn test_max_abs_error() {
+    use dsp4rust::errors::ShapeError;
+
+    let a = Signal::from_vec(vec![1.0, -2.0, 3.0]);
+    let b = Signal::from_vec(vec![1.25, -2.5, 3.0]);
+    assert_eq!(a.max_abs_error(&b).unwrap(), 0.5);
+    assert_eq!(b.max_abs_error(&a).unwrap(), 0.5);
+    assert_eq!(a.max_abs_error(&a).unwrap(), 0.0);
+    let empty = Signal::from_vec(vec![]);
+    assert_eq!(empty.max_abs_error(&empty).unwrap(), 0.0);
+    assert_eq!(
+        a.max_abs_error(&Signal::ones(2)),
+        Err(ShapeError::LengthMismatch { left: 3, right: 2 })
+    );
+
+    // NaN 不会被较小的误差掩盖，无论出现在哪个位置
+    for position in 0..3 {
+        let mut values = a.to_vec();
+        values[position] = f64::NAN;
+        let with_nan = Signal::from_vec(values);
+        assert!(with_nan.max_abs_error(&b).unwrap().is_nan());
+        assert!(b.max_abs_error(&with_nan).unwrap().is_nan());
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_approx_integration() {
+    use approx::{abs_diff_eq, assert_abs_diff_eq, relative_eq};
+
+    let a = Signal::from_vec(vec![0.1 + 0.2, 1e6 / 3.0]);
+    let b = Signal::from_vec(vec![0.3, 333_333.333_333_333_3]);
+    assert_relative_eq!(a, b);
+    assert_abs_diff_eq!(a, b, epsilon = 1e-9);
+    assert!(!relative_eq!(a, &b * &1.001));
+
+    // 长度不同与含 NaN 的信号不相等
+    assert!(!abs_diff_eq!(a, Signal::ones(3), epsilon = 1e9));
+    let with_nan = Signal::from_vec(vec![f64::NAN, 1.0]);
+    assert!(!relative_eq!(with_nan, with_nan.clone()));
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_partial_eq() {
+    let a = Signal::from_vec(vec![1.0, -2.0, 3.5]);
+    let b: Signal = [1.0, -2.0, 3.5].into_iter().collect();
+    assert_eq!(a, b);
+    assert_eq!(a, a.clone());
+    assert_eq!(&a * &2.0, Signal::from_vec(vec![2.0, -4.0, 7.0]));
+    assert_eq!(Signal::from_vec(vec![]), Signal::zeros(0));
+    // 0.0 与 -0.0 按 IEEE 语义相等
+    assert_eq!(Signal::from_vec(vec![0.0]), Signal::from_vec(vec![-0.0]));
+
+    // 长度不同或某个元素不同时不相等
+    assert_ne!(a, Signal::from_vec(vec![1.0, -2.0]));
+    assert_ne!(a, Signal::from_vec(vec![1.0, -2.0, 3.5, 0.0]));
+    assert_ne!(a, Signal::from_vec(vec![1.0, -2.0, 3.5000001]));
+
+    // 含 NaN 的信号与自身也不相等
+    let with_nan = Signal::from_vec(vec![1.0, f64::NAN]);
+    assert_ne!(with_nan, with_nan.clone());
+    assert!(with_nan != with_nan);
+}
+
+#[test]
+fn test_ndarray_conversions() {
+    let array = ndarray::Array1::linspace(0.0, 1.0, 5);
+    let ptr = array.as_slice().unwrap().as_ptr();
+    // 进出 Array1 都只转移所有权，不复制数据
+    let signal = Signal::from_array1(array);
+    assert_eq!(signal.as_array().as_slice().unwrap().as_ptr(), ptr);
+    let array = signal.into_array1();
+    assert_eq!(array.as_slice().unwrap().as_ptr(), ptr);
+
+    let signal: Signal = array.into();
+    assert_eq!(signal.to_vec(), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    let mut signal = Signal::from(ndarray::Array1::from_vec(signal.to_vec()));
+    signal.as_array_mut()[1] = -1.0;
+    assert_eq!(signal[1], -1.0);
+    let array: ndarray::Array1<f64> = signal.into();
+    assert_eq!(array.to_vec(), vec![0.0, -1.0, 0.5, 0.75, 1.0]);
+
+    // 非标准布局的数组被复制为连续存储
+    let reversed = array.slice_move(ndarray::s![..;-1]);
+    let signal = Signal::from_array1(reversed.clone());
+    assert!(signal.as_array().is_standard_layout());
+    assert_eq!(signal.to_vec(), vec![1.0, 0.75, 0.5, -1.0, 0.0]);
+    let strided: Signal = reversed.slice_move(ndarray::s![..;2]).into();
+    assert!(strided.as_array().is_standard_layout());
+    assert_eq!(strided.to_vec(), vec![1.0, 0.5, 0.0]);
+}
+
+#[test]
+fn test_f32_interop() {
+    // 可用 f32 表示的值往返无损
+    let samples = [
+        0.0f32,
+        -0.0,
+        1.0,
+        -0.75,
+        1e-40,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::INFINITY,
+    ];
+    let signal = Signal::from_slice_f32(&samples);
+    assert_eq!(signal.len(), samples.len());
+    assert_eq!(signal[2], 1.0);
+    let back = signal.to_vec_f32();
+    assert_eq!(
+        back.iter().map(|x| x.to_bits()).collect::<Vec<_>>(),
+        samples.iter().map(|x| x.to_bits()).collect::<Vec<_>>()
+    );
+
+    // 不可表示的值舍入到最近的 f32
+    let signal = Signal::from_vec(vec![0.1, 1.0 + 2f64.powi(-24), 1e39, -1e-50, f64::NAN]);
+    let narrowed = signal.to_vec_f32();
+    assert_eq!(narrowed[0], 0.1f32);
+    assert!((narrowed[0] as f64 - 0.1).abs() < 1e-8);
+    // 恰在中间时取偶数
+    assert_eq!(narrowed[1], 1.0);
+    assert_eq!(narrowed[2], f32::INFINITY);
+    assert!(narrowed[3] == 0.0 && narrowed[3].is_sign_negative());
+    assert!(narrowed[4].is_nan());
+
+    let mut signal = Signal::from_vec(vec![1.0]);
+    signal.extend_from_f32(&[]);
+    signal.extend_from_f32(&[2.5, -3.0]);
+    assert_eq!(signal, Signal::from_vec(vec![1.0, 2.5, -3.0]));
+}
+
+#[test]
+fn test_push_and_extend() {
+    let n = 1_000_000;
+    let value = |i: usize| (i as f64 * 0.001).sin();
+    let expected: Signal = (0..n).map(value).collect();
+
+    let mut pushed = Signal::with_capacity(16);
+    for i in 0..n {
+        pushed.push(value(i));
+    }
+    assert_eq!(pushed, expected);
+
+    let mut pushed = Signal::default();
+    assert_eq!(pushed, Signal::zeros(0));
+    for i in 0..n {
+        pushed.push(value(i));
+    }
+    assert_eq!(pushed, expected);
+
+    let mut extended = Signal::default();
+    extended.extend((0..n / 2).map(value));
+    extended.extend((n / 2..n).map(value).collect::<Vec<_>>().iter());
+    assert_eq!(extended, expected);
+}
+
+#[test]
+// 有意按引用传入标量
+#[allow(clippy::op_ref)]
+fn test_extend_after_arithmetic() {
+    let a = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let b = Signal::from_vec(vec![0.5, 0.5, 0.5]);
+    let mut sum = &a + &b;
+    sum.extend([10.0, 20.0]);
+    assert_eq!(sum, Signal::from_vec(vec![1.5, 2.5, 3.5, 10.0, 20.0]));
+
+    let mut scaled = &a * &2.0;
+    scaled *= &b;
+    scaled.push(-1.0);
+    scaled.extend(&[7.0]);
+    assert_eq!(scaled, Signal::from_vec(vec![1.0, 2.0, 3.0, -1.0, 7.0]));
+
+    // 追加后仍可参与运算
+    let doubled = &scaled + &scaled;
+    assert_eq!(doubled[-1], 14.0);
+}
+
+#[test]
+fn test_usize_and_literal_indexing() {
+    let mut signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+    let i: usize = 2;
+    let j: isize = -2;
+    assert_eq!(signal[i], 3.0);
+    assert_eq!(signal[j], 3.0);
+    assert_eq!(signal[0], 1.0);
+    assert_eq!(signal[-1], 4.0);
+    // 字面量索引的结果可以直接参与运算
+    assert_eq!((signal[-1] - 4.5).abs(), 0.5);
+
+    signal[1usize] = 20.0;
+    signal[-1] = 40.0;
+    signal[2isize] *= 10.0;
+    assert_eq!(signal.to_vec(), vec![1.0, 20.0, 30.0, 40.0]);
+    assert_eq!(signal[3usize], signal[-1isize]);
+}
+
+#[test]
+fn test_range_indexing() {
+    let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(&signal[1..4], &[1.0, 2.0, 3.0]);
+    assert_eq!(&signal[..2], &[0.0, 1.0]);
+    assert_eq!(&signal[3..], &[3.0, 4.0]);
+    assert_eq!(&signal[..], signal.to_vec().as_slice());
+    assert_eq!(&signal[1..=2], &[1.0, 2.0]);
+    assert_eq!(&signal[..=0], &[0.0]);
+    assert!(signal[2..2].is_empty());
+    assert!(signal[5..].is_empty());
+    // 切片与负索引指向同一份数据
+    assert_eq!(signal[1..][signal.len() - 2], signal[-1]);
+
+    let sum = &signal + &signal;
+    assert_eq!(sum[2..].iter().sum::<f64>(), 18.0);
+}
+
+#[test]
+fn test_range_indexing_of_reversed_array() {
+    let reversed = ndarray::Array1::from(vec![0.0, 1.0, 2.0, 3.0]).slice_move(ndarray::s![..;-1]);
+    let signal = Signal::from_array1(reversed);
+    assert_eq!(&signal[..], &[3.0, 2.0, 1.0, 0.0]);
+    assert_eq!(&signal[1..3], &[2.0, 1.0]);
+    assert_eq!(&signal[..=1], &[3.0, 2.0]);
+    assert_eq!(signal[-1], 0.0);
+}
+
+#[test]
+#[should_panic(expected = "index 4 is out of bounds for a signal of length 4")]
+fn test_usize_index_out_of_bounds() {
+    let signal = Signal::ones(4);
+    let i: usize = 4;
+    let _ = signal[i];
+}
+
+#[test]
+#[should_panic(expected = "index -5 is out of bounds for a signal of length 4")]
+fn test_negative_index_out_of_bounds() {
+    let _ = Signal::ones(4)[-5];
+}
+
+#[test]
+#[should_panic(expected = "out of range for slice of length 4")]
+fn test_range_out_of_bounds() {
+    let _ = &Signal::ones(4)[2..6];
+}
+
+#[test]
+fn test_get_and_get_mut() {
+    let mut signal = Signal::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    let len = signal.len() as isize;
+    assert_eq!(signal.get(0), Some(1.0));
+    assert_eq!(signal.get(-len), Some(1.0));
+    assert_eq!(signal.get(-len - 1), None);
+    assert_eq!(signal.get(len), None);
+    assert_eq!(signal.get(isize::MIN), None);
+    assert_eq!(signal.get(usize::MAX), None);
+    assert_eq!(signal.get(4usize), Some(5.0));
+
+    *signal.get_mut(-len).unwrap() = 10.0;
+    *signal.get_mut(-1).unwrap() += 1.0;
+    assert!(signal.get_mut(-len - 1).is_none());
+    assert!(signal.get_mut(len).is_none());
+    assert_eq!(signal.to_vec(), vec![10.0, 2.0, 3.0, 4.0, 6.0]);
+    assert_eq!((signal.first(), signal.last()), (Some(10.0), Some(6.0)));
+    assert!(!signal.is_empty());
+
+    let mut empty = Signal::zeros(0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.get(0), None);
+    assert_eq!(empty.get(-1), None);
+    assert!(empty.get_mut(0).is_none());
+    assert_eq!((empty.first(), empty.last()), (None, None));
+}
+
+#[test]
+fn test_into_iterator() {
+    let values = vec![1.0, -2.0, 3.5, 0.0, 7.25];
+    let signal = Signal::from_vec(values.clone());
+
+    // 借用迭代逐个访问每个样本且会结束
+    let mut visited = Vec::new();
+    for x in &signal {
+        visited.push(*x);
+    }
+    assert_eq!(visited, values);
+    assert_eq!((&signal).into_iter().count(), values.len());
+
+    // 按值迭代消耗信号
+    let mut visited = Vec::new();
+    for x in signal.clone() {
+        visited.push(x);
+    }
+    assert_eq!(visited, values);
+
+    let collected: Vec<f64> = signal.clone().into_iter().collect();
+    assert_eq!(collected, values);
+    let round_trip: Signal = collected.into_iter().collect();
+    assert_eq!(round_trip, signal);
+
+    // 空信号立即结束
+    assert_eq!(Signal::zeros(0).into_iter().count(), 0);
+    assert_eq!((&Signal::zeros(0)).into_iter().count(), 0);
+}
+
+#[test]
+fn test_negative_index_translation() {
+    // 负索引曾被转换为 usize 再相加，在调试模式下溢出恐慌，在发布模式下依赖回绕
+    let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(signal.cut_from(-2).to_vec(), vec![3.0, 4.0]);
+    assert_eq!(signal.cut_from(-5).to_vec(), signal.to_vec());
+    assert_eq!(signal.cut_to(-2).to_vec(), vec![0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(signal.cut_to(-5).to_vec(), vec![0.0]);
+    assert_eq!(signal.cut_from_to(-4, -2).to_vec(), vec![1.0, 2.0, 3.0]);
+    assert_eq!(signal.cut_from_to(1, -1).to_vec(), vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(signal.cut_from_to(-1, 4).to_vec(), vec![4.0]);
+
+    let mut signal = signal;
+    for (index, expected) in [(-1, 4.0), (-2, 3.0), (-5, 0.0)] {
+        assert_eq!(signal[index], expected);
+        signal[index] += 10.0;
+        assert_eq!(signal[index as isize], expected + 10.0);
+    }
+    assert_eq!(signal.get(isize::MIN), None);
+}
+
+#[test]
+#[should_panic(expected = "Index -6 is out of bounds for a signal of length 5")]
+fn test_cut_from_negative_out_of_bounds() {
+    Signal::ones(5).cut_from(-6);
+}
+
+#[test]
+fn test_try_cut() {
+    use dsp4rust::errors::SliceError;
+    let signal = Signal::from_vec(vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    let out_of_bounds = |index| SliceError::IndexOutOfBounds { index, len: 5 };
+
+    assert_eq!(signal.try_cut_from(-5).unwrap(), signal);
+    assert_eq!(signal.try_cut_from(5).err(), Some(out_of_bounds(5)));
+    assert_eq!(signal.try_cut_from(-6).err(), Some(out_of_bounds(-6)));
+    assert_eq!(signal.try_cut_to(4).unwrap(), signal);
+    assert_eq!(signal.try_cut_to(-1).unwrap(), signal);
+    assert_eq!(signal.try_cut_to(5).err(), Some(out_of_bounds(5)));
+
+    // 闭区间：from 恰为 to 的下一个样本时为空，再往后则为反向区间
+    assert_eq!(
+        signal.try_cut_from_to(1, 3).unwrap().to_vec(),
+        vec![1.0, 2.0, 3.0]
+    );
+    assert_eq!(signal.try_cut_from_to(2, 2).unwrap().to_vec(), vec![2.0]);
+    assert!(signal.try_cut_from_to(3, 2).unwrap().is_empty());
+    assert!(signal.try_cut_from_to(-1, -2).unwrap().is_empty());
+    assert_eq!(
+        signal.try_cut_from_to(4, 2).err(),
+        Some(SliceError::InvertedRange { from: 4, to: 2 })
+    );
+    assert_eq!(signal.try_cut_from_to(0, 5).err(), Some(out_of_bounds(5)));
+    assert_eq!(signal.try_cut_from_to(-7, 1).err(), Some(out_of_bounds(-7)));
+
+    // 半开区间：两端可以取到 len 与 -len
+    assert_eq!(signal.try_cut_range(0, 5).unwrap(), signal);
+    assert_eq!(
+        signal.try_cut_range(-5, -2).unwrap().to_vec(),
+        vec![0.0, 1.0, 2.0]
+    );
+    assert!(signal.try_cut_range(3, 3).unwrap().is_empty());
+    assert!(signal.try_cut_range(5, 5).unwrap().is_empty());
+    assert!(signal.try_cut_range(-2, 3).unwrap().is_empty());
+    assert_eq!(
+        signal.try_cut_range(4, 2).err(),
+        Some(SliceError::InvertedRange { from: 4, to: 2 })
+    );
+    assert_eq!(signal.try_cut_range(0, 6).err(), Some(out_of_bounds(6)));
+    assert_eq!(signal.try_cut_range(-6, 2).err(), Some(out_of_bounds(-6)));
+
+    // 空信号只有空区间有效
+    let empty = Signal::zeros(0);
+    assert!(empty.try_cut_range(0, 0).unwrap().is_empty());
+    assert!(empty.try_cut_from(0).is_err());
+
+    // 恐慌版本与 try 版本一致
+    assert_eq!(
+        signal.cut_from_to(1, -2),
+        signal.try_cut_from_to(1, -2).unwrap()
+    );
+    assert_eq!(
+        signal.cut_range(1, -1),
+        signal.try_cut_range(1, -1).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Range start 4 lies after its end 2")]
+fn test_cut_from_to_inverted_panics() {
+    Signal::ones(5).cut_from_to(4, 2);
+}
+
+#[test]
+// 有意比较按值与按引用两种写法
+#[allow(clippy::op_ref)]
+fn test_owned_and_mixed_operators() {
+    let a = Signal::from_vec(vec![1.0, -2.0, 3.5, 8.0]);
+    let b = Signal::from_vec(vec![0.5, 4.0, -1.5, 2.0]);
+    type Op = fn(f64, f64) -> f64;
+    let ops: [(Op, Signal, Signal, Signal, Signal); 4] = [
+        (
+            |x, y| x + y,
+            &a + &b,
+            a.clone() + b.clone(),
+            a.clone() + &b,
+            &a + b.clone(),
+        ),
+        (
+            |x, y| x - y,
+            &a - &b,
+            a.clone() - b.clone(),
+            a.clone() - &b,
+            &a - b.clone(),
+        ),
+        (
+            |x, y| x * y,
+            &a * &b,
+            a.clone() * b.clone(),
+            a.clone() * &b,
+            &a * b.clone(),
+        ),
+        (
+            |x, y| x / y,
+            &a / &b,
+            a.clone() / b.clone(),
+            a.clone() / &b,
+            &a / b.clone(),
+        ),
+    ];
+    for (op, by_ref, owned, owned_lhs, owned_rhs) in ops {
+        let expected: Signal = a.iter().zip(b.iter()).map(|(&x, &y)| op(x, y)).collect();
+        assert_eq!(by_ref, expected);
+        assert_eq!(owned, expected);
+        assert_eq!(owned_lhs, expected);
+        assert_eq!(owned_rhs, expected);
+    }
+
+    // 按值的标量与按引用的标量结果相同
+    assert_eq!(&a * 2.0, &a * &2.0);
+    assert_eq!(&a + 3, &a + &3);
+    assert_eq!(a.clone() - 1.5, &a - &1.5);
+    assert_eq!(a.clone() / 4, &a / &4);
+    assert_eq!(a.clone() * &2u8, &a * &2u8);
+
+    // 不同长度时退回引用版本，保留长度为 1 的信号的广播语义
+    let one = Signal::from_vec(vec![10.0]);
+    assert_eq!(one.clone() + a.clone(), &one + &a);
+    assert_eq!(a.clone() - one.clone(), &a - &one);
+    assert_eq!(&one / a.clone(), &one / &a);
+}
+
+#[test]
+fn test_owned_operands_reuse_buffers() {
+    let ptr = |signal: &Signal| signal.as_array().as_ptr();
+    let a = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let b = Signal::from_vec(vec![4.0, 5.0, 6.0]);
+
+    let lhs = a.clone();
+    let before = ptr(&lhs);
+    let sum = lhs + &b;
+    assert_eq!(ptr(&sum), before);
+
+    let lhs = a.clone();
+    let before = ptr(&lhs);
+    let product = lhs * b.clone();
+    assert_eq!(ptr(&product), before);
+
+    // 左侧为引用时复用右侧的缓冲区
+    let rhs = b.clone();
+    let before = ptr(&rhs);
+    let difference = &a - rhs;
+    assert_eq!(ptr(&difference), before);
+    assert_eq!(difference.to_vec(), vec![-3.0, -3.0, -3.0]);
+
+    let lhs = a.clone();
+    let before = ptr(&lhs);
+    let scaled = lhs * 2.0;
+    assert_eq!(ptr(&scaled), before);
+    assert_eq!(scaled.to_vec(), vec![2.0, 4.0, 6.0]);
+
+    // 链式表达式不需要中间变量
+    let chained = &a * Signal::ones(3) + &b - 1.0;
+    assert_eq!(chained.to_vec(), vec![4.0, 6.0, 8.0]);
+}
+
+#[test]
+// 有意按引用传入左侧标量
+#[allow(clippy::op_ref)]
+fn test_scalar_on_left() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, 4.0]);
+
+    // 不满足交换律的运算
+    assert_eq!(1.0 - &signal, Signal::from_vec(vec![0.0, -1.0, -3.0]));
+    assert_eq!(10.0 / &signal, Signal::from_vec(vec![10.0, 5.0, 2.5]));
+    assert_eq!(2.0 * &signal, Signal::from_vec(vec![2.0, 4.0, 8.0]));
+    assert_eq!(0.5 + &signal, Signal::from_vec(vec![1.5, 2.5, 4.5]));
+
+    // 六种原生类型均可按值或按引用放在左侧
+    let expected = Signal::from_vec(vec![9.0, 8.0, 6.0]);
+    assert_eq!(10 - &signal, expected);
+    assert_eq!(10.0f32 - &signal, expected);
+    assert_eq!(10i64 - &signal, expected);
+    assert_eq!(10u32 - &signal, expected);
+    assert_eq!(10usize - signal.clone(), expected);
+    assert_eq!(&10.0f64 - &signal, expected);
+    assert_eq!(&10.0f32 - &signal, expected);
+    assert_eq!(&10i32 - &signal, expected);
+    assert_eq!(&10i64 - &signal, expected);
+    assert_eq!(&10u32 - &signal, expected);
+    assert_eq!(&10usize - signal.clone(), expected);
+    assert_eq!(2.0f32 * &signal, Signal::from_vec(vec![2.0, 4.0, 8.0]));
+    assert_eq!(4usize * &signal, Signal::from_vec(vec![4.0, 8.0, 16.0]));
+
+    // 按值传入的信号复用其缓冲区
+    let owned = signal.clone();
+    let before = owned.as_array().as_ptr();
+    let reciprocal: Signal = 1.0 / owned;
+    assert_eq!(reciprocal.as_array().as_ptr(), before);
+    assert_eq!(reciprocal.to_vec(), vec![1.0, 0.5, 0.25]);
+
+    assert_eq!(1.0 - &signal, -1.0 * (&signal - 1.0));
+}
+
+#[test]
+fn test_negation() {
+    let signal = Signal::from_vec(vec![1.5, -2.0, 0.0]);
+    assert_eq!((-&signal).to_vec(), vec![-1.5, 2.0, -0.0]);
+    assert_eq!(-(-&signal), signal);
+    assert_eq!(-&signal, &signal * -1.0);
+
+    // 零取负得到负零，与零比较相等
+    let negated = -Signal::zeros(3);
+    assert_eq!(negated, Signal::zeros(3));
+    assert!(negated.iter().all(|x| x.is_sign_negative()));
+
+    // 按值取负复用缓冲区
+    let owned = signal.clone();
+    let before = owned.as_array().as_ptr();
+    let negated = -owned;
+    assert_eq!(negated.as_array().as_ptr(), before);
+}
+
+#[test]
+fn test_recip() {
+    let signal = Signal::from_vec(vec![2.0, 4.0]);
+    assert_eq!(signal.recip().to_vec(), vec![0.5, 0.25]);
+    assert_eq!(signal.recip().recip(), signal);
+
+    // 零的倒数为同号的无穷
+    let zeros = Signal::from_vec(vec![0.0, -0.0, f64::INFINITY, f64::NAN]);
+    let recip = zeros.recip();
+    assert_eq!(recip[0], f64::INFINITY);
+    assert_eq!(recip[1], f64::NEG_INFINITY);
+    assert_eq!(recip[2], 0.0);
+    assert!(recip[3].is_nan());
+
+    let mut inplace = signal.clone();
+    inplace.recip_inplace();
+    assert_eq!(inplace, signal.recip());
+}
+
+#[test]
+fn test_powf_and_powi() {
+    let signal = Signal::from_vec(vec![-2.0, -1.0, 0.0, 3.0]);
+    // 奇数次幂保留符号，偶数次幂为正
+    assert_eq!(signal.powi(3).to_vec(), vec![-8.0, -1.0, 0.0, 27.0]);
+    assert_eq!(signal.powi(2).to_vec(), vec![4.0, 1.0, 0.0, 9.0]);
+    assert_eq!(signal.powi(0).to_vec(), vec![1.0; 4]);
+    assert_eq!(
+        Signal::from_vec(vec![2.0, 4.0]).powi(-1).to_vec(),
+        vec![0.5, 0.25]
+    );
+
+    let roots = signal.powf(0.5);
+    assert!(roots[0].is_nan() && roots[1].is_nan());
+    assert_eq!(roots[2], 0.0);
+    assert_eq!(signal.powf(2.0), signal.powi(2));
+}
+
+#[test]
+fn test_rem() {
+    use dsp4rust::errors::ShapeError;
+
+    let x = Signal::from_vec(vec![7.0, -7.0, 7.0, -7.0, 5.5, 1.0]);
+    let y = Signal::from_vec(vec![3.0, 3.0, -3.0, -3.0, 2.0, 0.0]);
+    let rem = x.rem(&y).unwrap();
+    // 余数与被除数同号，与 f64::rem 一致
+    for i in 0..5 {
+        assert_eq!(rem[i], x[i] % y[i]);
+    }
+    assert_eq!(rem.to_vec()[..5], [1.0, -1.0, 1.0, -1.0, 1.5]);
+    assert!(rem[5].is_nan());
+
+    assert_eq!(
+        x.rem(&Signal::ones(2)),
+        Err(ShapeError::LengthMismatch { left: 6, right: 2 })
+    );
+
+    assert_eq!(x.rem_scalar(-3.0).to_vec()[..4], [1.0, -1.0, 1.0, -1.0]);
+    assert!(x.rem_scalar(0.0).iter().all(|v| v.is_nan()));
+}
+
+#[test]
+fn test_comparison_masks() {
+    let signal = Signal::from_vec(vec![-1.0, 0.5, 1.0, f64::NAN]);
+    assert_eq!(signal.gt(0.5).to_vec(), vec![0.0, 0.0, 1.0, 0.0]);
+    assert_eq!(signal.ge(0.5).to_vec(), vec![0.0, 1.0, 1.0, 0.0]);
+    assert_eq!(signal.lt(0.5).to_vec(), vec![1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(signal.le(0.5).to_vec(), vec![1.0, 1.0, 0.0, 0.0]);
+
+    // 掩码相乘即逻辑与
+    let band = &signal.ge(0.0) * &signal.le(0.5);
+    assert_eq!(band.to_vec(), vec![0.0, 1.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_select() {
+    use dsp4rust::errors::ShapeError;
+
+    let signal = Signal::from_vec(vec![0.2, -1.5, 0.9, 2.0, -0.3]);
+    let limit = Signal::from_elem(1.0, 5);
+    // 用掩码实现削波：超过 1 的样本替换为 1
+    let clipped = signal.select(&signal.le(1.0), &limit).unwrap();
+    assert_eq!(clipped.to_vec(), vec![0.2, -1.5, 0.9, 1.0, -0.3]);
+
+    let mask = Signal::from_vec(vec![1.0, 0.0, -2.0, f64::NAN, -0.0]);
+    let other = Signal::from_vec(vec![10.0, 20.0, 30.0, 40.0, 50.0]);
+    let expected = vec![0.2, 20.0, 0.9, 2.0, 50.0];
+    assert_eq!(signal.select(&mask, &other).unwrap().to_vec(), expected);
+
+    assert_eq!(
+        signal.select(&Signal::ones(2), &other),
+        Err(ShapeError::LengthMismatch { left: 5, right: 2 })
+    );
+    assert_eq!(
+        signal.select(&mask, &Signal::ones(3)),
+        Err(ShapeError::LengthMismatch { left: 5, right: 3 })
+    );
+}
+
+#[test]
+fn test_elementary_functions() {
+    use dsp4rust::generator::Generator;
+    use std::f64::consts::PI;
+
+    // sin(linspace(0, π)) 与生成器给出的半个正弦周期一致
+    let n = 101;
+    let generator = Generator::new()
+        .sample_rate(200.0)
+        .start_time(0.0)
+        .num_samples(n)
+        .build()
+        .unwrap();
+    let half_sine = generator.sin_unit(1.0, 0.0);
+    let mapped = Signal::linspace(0.0, PI, n).sin();
+    assert!(mapped.allclose(&half_sine, 0.0, 1e-12));
+
+    let positive = Signal::from_vec(vec![1e-3, 0.5, 1.0, 2.0, 1e6]);
+    assert!(positive.ln().exp().allclose(&positive, 1e-12, 0.0));
+    assert!(positive.sqrt().powi(2).allclose(&positive, 1e-12, 0.0));
+    assert_eq!(positive.log10()[4], 6.0);
+
+    // 负数的对数与平方根为 NaN
+    let negative = Signal::from_vec(vec![-1.0]);
+    assert!(negative.ln()[0].is_nan());
+    assert!(negative.log10()[0].is_nan());
+    assert!(negative.sqrt()[0].is_nan());
+
+    let angles = Signal::linspace(-1.0, 1.0, 9);
+    let identity = &angles.sin().powi(2) + &angles.cos().powi(2);
+    assert!(identity.allclose(&Signal::ones(9), 0.0, 1e-12));
+    assert!((&angles.tan() - &(&angles.sin() / &angles.cos()))
+        .iter()
+        .all(|x| x.abs() < 1e-12));
+    assert!(angles.tanh().iter().all(|x| x.abs() < 1.0));
+
+    // 原地版本与返回副本的版本一致
+    let mut inplace = angles.clone();
+    inplace.sin_inplace();
+    inplace.exp_inplace();
+    inplace.tanh_inplace();
+    assert_eq!(inplace, angles.sin().exp().tanh());
+    let mut inplace = positive.clone();
+    inplace.sqrt_inplace();
+    inplace.ln_inplace();
+    assert_eq!(inplace, positive.sqrt().ln());
+    let mut inplace = positive.clone();
+    inplace.log10_inplace();
+    assert_eq!(inplace, positive.log10());
+    let mut inplace = angles.clone();
+    inplace.cos_inplace();
+    inplace.tan_inplace();
+    assert_eq!(inplace, angles.cos().tan());
+}
+
+#[test]
+fn test_atan2() {
+    use dsp4rust::errors::ShapeError;
+    use std::f64::consts::PI;
+
+    // 由 (sin, cos) 恢复 (-π, π) 内的相位斜坡
+    let phase = Signal::linspace(-3.0, 3.0, 61);
+    let recovered = phase.sin().atan2(&phase.cos()).unwrap();
+    assert!(recovered.allclose(&phase, 0.0, 1e-12));
+
+    let y = Signal::from_vec(vec![0.0, 1.0, 0.0, -1.0]);
+    let x = Signal::from_vec(vec![1.0, 0.0, -1.0, 0.0]);
+    assert_eq!(
+        y.atan2(&x).unwrap().to_vec(),
+        vec![0.0, PI / 2.0, PI, -PI / 2.0]
+    );
+
+    assert_eq!(
+        y.atan2(&Signal::ones(3)),
+        Err(ShapeError::LengthMismatch { left: 4, right: 3 })
+    );
+
+    let mut inplace = y.clone();
+    inplace.atan2_inplace(&x).unwrap();
+    assert_eq!(inplace, y.atan2(&x).unwrap());
+    assert_eq!(
+        inplace.atan2_inplace(&Signal::ones(2)),
+        Err(ShapeError::LengthMismatch { left: 4, right: 2 })
+    );
+    assert_eq!(inplace, y.atan2(&x).unwrap());
+}
+
+#[test]
+fn test_zip_map() {
+    use dsp4rust::errors::ShapeError;
+
+    let a = Signal::from_vec(vec![1.0, -4.0, 2.5, 0.0]);
+    let b = Signal::from_vec(vec![0.5, -3.0, 7.0, -0.0]);
+    let max = a.zip_map(&b, |x, y| if x >= y { x } else { y }).unwrap();
+    assert_eq!(max.to_vec(), vec![1.0, -3.0, 7.0, 0.0]);
+
+    // 按 mix 比例混合两路信号
+    let mix = a.zip_map(&b, |x, y| 0.25 * x + 0.75 * y).unwrap();
+    assert_eq!(mix.to_vec(), vec![0.625, -3.25, 5.875, 0.0]);
+
+    assert_eq!(
+        a.zip_map(&Signal::ones(2), f64::max),
+        Err(ShapeError::LengthMismatch { left: 4, right: 2 })
+    );
+    assert_eq!(
+        Signal::default().zip_map(&Signal::default(), f64::max),
+        Ok(Signal::default())
+    );
+}
+
+#[test]
+fn test_enumerate_map() {
+    // 线性淡出：增益从 1 线性降到 0
+    let signal = Signal::from_vec(vec![2.0, 2.0, -2.0, 2.0, 2.0]);
+    let last = (signal.len() - 1) as f64;
+    let fade_out = signal.enumerate_map(|i, x| x * (1.0 - i as f64 / last));
+    assert_eq!(fade_out.to_vec(), vec![2.0, 1.5, -1.0, 0.5, 0.0]);
+
+    let indices = Signal::zeros(3).enumerate_map(|i, x| x + i as f64);
+    assert_eq!(indices.to_vec(), vec![0.0, 1.0, 2.0]);
+    assert!(Signal::default().enumerate_map(|i, _| i as f64).is_empty());
+}
+
+#[test]
+fn test_compensated_summation() {
+    // 1e16 的 ulp 为 2，朴素地逐个加 1.0 每次都被舍去
+    let ones = 1000;
+    let mut values = vec![1e16];
+    values.extend(std::iter::repeat_n(1.0, ones));
+    let naive: f64 = values.iter().sum();
+    assert_eq!(naive, 1e16);
+
+    let signal = Signal::from_vec(values);
+    assert_eq!(signal.sum(), 1e16 + ones as f64);
+    assert_eq!(signal.view().sum(), signal.sum());
+    assert_eq!(
+        signal.mean(),
+        Some((1e16 + ones as f64) / (ones + 1) as f64)
+    );
+
+    // 大数相消后剩下的小数不丢失
+    let cancelling = Signal::from_vec(vec![1.0, 1e100, 1.0, -1e100]);
+    assert_eq!(cancelling.sum(), 2.0);
+
+    // 能量：1e8 的平方为 1e16，同样会吞掉后面的 1.0
+    let mut values = vec![1e8];
+    values.extend(std::iter::repeat_n(1.0, ones));
+    let signal = Signal::from_vec(values);
+    assert_eq!(signal.energy(), 1e16 + ones as f64);
+    assert_eq!(signal.view().energy(), signal.energy());
+
+    // 大直流偏置上的小交流信号：方差不受偏置影响
+    let ac = Signal::from_vec([1.0, -1.0].repeat(500));
+    let offset = &ac + 1e9;
+    assert_eq!(ac.var_pop(), 1.0);
+    assert_eq!(offset.var_pop(), 1.0);
+    assert_eq!(offset.std_sample(), (1000.0f64 / 999.0).sqrt());
+    assert_eq!(offset.mean(), Some(1e9));
+
+    // 无穷与 NaN 按朴素求和的语义传播
+    assert_eq!(
+        Signal::from_vec(vec![1.0, f64::INFINITY]).sum(),
+        f64::INFINITY
+    );
+    assert!(Signal::from_vec(vec![f64::INFINITY, f64::NEG_INFINITY])
+        .sum()
+        .is_nan());
+    assert!(Signal::from_vec(vec![1.0, f64::NAN])
+        .mean()
+        .unwrap()
+        .is_nan());
+    assert_eq!(Signal::default().sum(), 0.0);
+    assert_eq!(Signal::default().mean(), None);
+}
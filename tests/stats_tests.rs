@@ -0,0 +1,186 @@
+use dsp4rust::signal::Signal;
+use dsp4rust::stats::{Moments, RunningStats};
+
+// 相对误差不超过几个 ulp
+fn assert_ulp_close(actual: f64, expected: f64) {
+    let tolerance = 4.0 * f64::EPSILON * expected.abs();
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{actual} differs from {expected} by more than {tolerance}"
+    );
+}
+
+// 样本为 1/256 的整数倍，平方与部分和都能精确表示，因此朴素的 powf(2.) 求和就是精确的参照值
+fn test_signal(len: usize) -> Signal {
+    Signal::from_len_fn(len, |i| ((i * 7919) % 1000) as f64 / 256.0 - 2.0)
+}
+
+#[test]
+fn test_energy_matches_powf_reference() {
+    for len in [1, 2, 17, 1000, 100_000] {
+        let signal = test_signal(len);
+        let reference: f64 = signal.iter().map(|x| x.powf(2.)).sum();
+        assert_ulp_close(signal.energy(), reference);
+        assert_ulp_close(signal.sum_of_squares(), reference);
+        assert_ulp_close(signal.rms().unwrap(), (reference / len as f64).sqrt());
+    }
+    assert_eq!(Signal::default().energy(), 0.0);
+}
+
+#[test]
+fn test_variance_matches_reference() {
+    // 长度取 2 的幂，均值也能精确表示
+    let signal = test_signal(1024);
+    let n = signal.len() as f64;
+    let mean = signal.iter().sum::<f64>() / n;
+    let squares: f64 = signal.iter().map(|x| (x - mean).powf(2.)).sum();
+    assert_ulp_close(signal.var_pop(), squares / n);
+    assert_ulp_close(signal.var_sample(), squares / (n - 1.0));
+    assert_ulp_close(signal.std_pop(), (squares / n).sqrt());
+}
+
+#[test]
+fn test_moments() {
+    let signal = test_signal(1000);
+    let moments = signal.moments();
+    assert_eq!(moments.count, 1000);
+    // 与逐个统计量的结果逐位一致
+    assert_eq!(moments.sum, signal.sum());
+    assert_eq!(moments.sum_of_squares, signal.energy());
+    assert_eq!(moments.mean(), signal.mean());
+    assert_eq!(moments.rms(), signal.rms());
+    assert_eq!(signal.view().moments(), moments);
+
+    let tail = signal.view_range(500, 1000).unwrap();
+    assert_eq!(tail.moments(), signal.cut_range(500, 1000).moments());
+
+    assert_eq!(Signal::default().moments(), Moments::default());
+    assert_eq!(Moments::default().mean(), None);
+    assert_eq!(Moments::default().rms(), None);
+
+    // 补偿求和同样适用于单遍内核
+    let mut values = vec![1e8];
+    values.extend(std::iter::repeat_n(1.0, 1000));
+    let moments = Signal::from_vec(values).moments();
+    assert_eq!(moments.sum, 1e8 + 1000.0);
+    assert_eq!(moments.sum_of_squares, 1e16 + 1000.0);
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+        "{actual} differs from {expected}"
+    );
+}
+
+// 简单的线性同余发生器，产生可复现的随机块长
+fn chunk_lengths(total: usize, mut seed: u64) -> Vec<usize> {
+    let mut lengths = Vec::new();
+    let mut remaining = total;
+    while remaining > 0 {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let len = ((seed >> 33) as usize % 97 + 1).min(remaining);
+        lengths.push(len);
+        remaining -= len;
+    }
+    lengths
+}
+
+fn stats_of(signal: &Signal) -> RunningStats {
+    let mut stats = RunningStats::new();
+    stats.push_signal(signal);
+    stats
+}
+
+fn assert_matches_batch(stats: &RunningStats, signal: &Signal) {
+    assert_eq!(stats.count(), signal.len());
+    assert_close(stats.mean().unwrap(), signal.mean().unwrap());
+    assert_close(stats.var_pop(), signal.var_pop());
+    assert_close(stats.var_sample(), signal.var_sample());
+    assert_close(stats.std_pop(), signal.std_pop());
+    assert_close(stats.std_sample(), signal.std_sample());
+    assert_close(stats.rms().unwrap(), signal.rms().unwrap());
+    assert_eq!(stats.min(), Some(signal.min().unwrap()));
+    assert_eq!(stats.max(), Some(signal.max().unwrap()));
+}
+
+#[test]
+fn test_running_stats_matches_batch() {
+    // 带直流偏置的信号
+    let signal = Signal::from_len_fn(5000, |i| 1e3 + (i as f64 * 0.37).sin() * 2.0);
+
+    let mut sample_by_sample = RunningStats::new();
+    signal.iter().for_each(|&x| sample_by_sample.push(x));
+    assert_matches_batch(&sample_by_sample, &signal);
+
+    for seed in [1, 2, 3] {
+        let mut chunked = RunningStats::new();
+        let mut merged = RunningStats::new();
+        let mut start = 0;
+        for len in chunk_lengths(signal.len(), seed) {
+            let chunk = signal.cut_range(start as isize, (start + len) as isize);
+            chunked.push_signal(&chunk);
+            merged.merge(&stats_of(&chunk));
+            start += len;
+        }
+        assert_matches_batch(&chunked, &signal);
+        assert_matches_batch(&merged, &signal);
+    }
+}
+
+#[test]
+fn test_running_stats_merge_is_associative() {
+    let a = stats_of(&Signal::from_vec(vec![1.0, 5.0, -2.0]));
+    let b = stats_of(&Signal::from_len_fn(40, |i| (i as f64).sqrt()));
+    let c = stats_of(&Signal::from_vec(vec![1e3, 1e3 + 1.0]));
+
+    let mut left = a;
+    left.merge(&b);
+    left.merge(&c);
+    let mut bc = b;
+    bc.merge(&c);
+    let mut right = a;
+    right.merge(&bc);
+
+    assert_eq!(left.count(), right.count());
+    assert_close(left.mean().unwrap(), right.mean().unwrap());
+    assert_close(left.var_sample(), right.var_sample());
+    assert_close(left.rms().unwrap(), right.rms().unwrap());
+    assert_eq!(left.min(), right.min());
+    assert_eq!(left.max(), right.max());
+
+    // 空累加器是合并的单位元
+    let mut with_empty = a;
+    with_empty.merge(&RunningStats::new());
+    assert_eq!(with_empty, a);
+    let mut from_empty = RunningStats::new();
+    from_empty.merge(&a);
+    assert_eq!(from_empty, a);
+}
+
+#[test]
+fn test_running_stats_edge_cases() {
+    let empty = RunningStats::new();
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.rms(), None);
+    assert_eq!(empty.min(), None);
+    assert!(empty.var_pop().is_nan());
+    assert!(empty.var_sample().is_nan());
+
+    let mut single = RunningStats::new();
+    single.push(-3.0);
+    assert_eq!(single.mean(), Some(-3.0));
+    assert_eq!(single.var_pop(), 0.0);
+    assert!(single.var_sample().is_nan());
+    assert_eq!(single.rms(), Some(3.0));
+
+    // NaN 传播到均值，但极值跳过它
+    let mut with_nan = RunningStats::new();
+    with_nan.push_signal(&Signal::from_vec(vec![1.0, f64::NAN, 3.0]));
+    assert!(with_nan.mean().unwrap().is_nan());
+    assert_eq!(with_nan.min(), Some(1.0));
+    assert_eq!(with_nan.max(), Some(3.0));
+}
@@ -0,0 +1,97 @@
+use dsp4rust::errors::{ShapeError, SliceError};
+use dsp4rust::generator::Generator;
+use dsp4rust::signal::Signal;
+
+fn noise(len: usize, seed: u64) -> Signal {
+    Generator::new()
+        .sample_rate(1.0)
+        .num_samples(len)
+        .seed(seed)
+        .build()
+        .unwrap()
+        .gaussian_white_noise(0.5, 2.0)
+}
+
+// NaN 与 NaN 视为相同，其余要求逐位一致
+fn same(a: f64, b: f64) -> bool {
+    a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+}
+
+#[test]
+fn test_view_matches_copy() {
+    let signal = noise(2000, 3);
+    for (start, end) in [
+        (0, 2000),
+        (0, 1),
+        (17, 18),
+        (100, 612),
+        (-500, -1),
+        (-7, 2000),
+    ] {
+        let view = signal.view_range(start, end).unwrap();
+        let copy = signal.cut_range(start, end);
+        assert_eq!(view.len(), copy.len());
+        assert_eq!(view.to_signal(), copy);
+        assert!(same(view.sum(), copy.sum()));
+        assert_eq!(view.mean(), copy.mean());
+        assert!(same(view.std_pop(), copy.std_pop()));
+        assert!(same(view.std_sample(), copy.std_sample()));
+        assert_eq!(view.min().unwrap(), copy.min().unwrap());
+        assert_eq!(view.max().unwrap(), copy.max().unwrap());
+        assert!(same(view.energy(), copy.energy()));
+        assert_eq!(view.rms(), copy.rms());
+        assert!(same(view.dot(view).unwrap(), copy.dot(&copy).unwrap()));
+        assert!(view.iter().eq(copy.iter()));
+    }
+}
+
+#[test]
+fn test_view_windows_match_copies() {
+    let signal = noise(300, 5);
+    let views: Vec<_> = signal.view_windows(32).collect();
+    let copies: Vec<_> = signal.windows(32).collect();
+    assert_eq!(views.len(), copies.len());
+    for (view, copy) in views.iter().zip(&copies) {
+        assert_eq!(view.mean(), copy.mean());
+        assert_eq!(view.rms(), copy.rms());
+        assert_eq!(view.max().unwrap(), copy.max().unwrap());
+    }
+    // 窗口比信号长时没有窗口
+    assert_eq!(signal.view_windows(301).count(), 0);
+}
+
+#[test]
+fn test_view_edge_cases() {
+    let signal = Signal::from_vec(vec![1.0, 2.0, 3.0]);
+    let empty = signal.view_range(1, 1).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.rms(), None);
+    assert_eq!(empty.energy(), 0.0);
+    assert!(empty.min().is_err());
+    assert_eq!(empty.dot(empty).unwrap(), 0.0);
+
+    assert_eq!(signal.view().to_signal(), signal);
+    assert_eq!(
+        signal.view_range(0, 4).err(),
+        Some(SliceError::IndexOutOfBounds { index: 4, len: 3 })
+    );
+    assert_eq!(
+        signal.view_range(2, 1).err(),
+        Some(SliceError::InvertedRange { from: 2, to: 1 })
+    );
+    assert_eq!(
+        signal.view().dot(signal.view_range(0, 2).unwrap()).err(),
+        Some(ShapeError::LengthMismatch { left: 3, right: 2 })
+    );
+
+    // 视图与 ndarray 共享同一块内存
+    let view = signal.view_range(1, 3).unwrap();
+    assert_eq!(view.as_array().as_ptr(), &signal[1] as *const f64);
+}
+
+#[test]
+#[should_panic]
+fn test_view_windows_zero_len_panics() {
+    let _ = Signal::ones(4).view_windows(0);
+}
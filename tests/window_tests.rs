@@ -0,0 +1,209 @@
+use approx::assert_relative_eq;
+use dsp4rust::errors::WindowError;
+use dsp4rust::signal::Signal;
+use dsp4rust::window::{self, GainCorrection};
+use std::f64::consts::TAU;
+
+#[test]
+fn test_tukey_window() {
+    // alpha * (len - 1) / 2 = 25，两侧各有 25 个过渡样本
+    let len = 101;
+    let alpha = 0.5;
+    let w = window::tukey(len, alpha).unwrap();
+    let taper = (alpha * (len - 1) as f64 / 2.0).floor() as usize;
+    assert_eq!(w.len(), len);
+    assert_eq!(taper, 25);
+
+    let samples = w.to_vec();
+    assert!(samples[..taper].iter().all(|&x| x < 1.0));
+    assert!(samples[len - taper..].iter().all(|&x| x < 1.0));
+    assert!(samples[taper..len - taper].iter().all(|&x| x == 1.0));
+    for n in 0..len {
+        assert_eq!(samples[n], samples[len - 1 - n]);
+    }
+
+    // alpha * (len - 1) / 2 = 2.25 不是整数，过渡段取 floor 后为 m <= 2
+    let w = window::tukey(10, 0.5).unwrap();
+    for m in 0..=2 {
+        let expected = 0.5 * (1.0 - (TAU * m as f64 / 4.5).cos());
+        assert_relative_eq!(w[m as isize], expected, epsilon = 1e-12);
+        assert_relative_eq!(w[9 - m as isize], expected, epsilon = 1e-12);
+        assert!(w[m as isize] < 1.0);
+    }
+    assert!((3..=6).all(|n| w[n] == 1.0));
+
+    // alpha = 0 为矩形窗，alpha = 1 为 Hann 窗
+    let rect = window::tukey(16, 0.0).unwrap();
+    assert!(rect.iter().all(|&x| x == 1.0));
+    for len in [15, 16] {
+        let hann = window::tukey(len, 1.0).unwrap();
+        for n in 0..len {
+            let expected = 0.5 * (1.0 - (TAU * n as f64 / (len - 1) as f64).cos());
+            assert_relative_eq!(hann[n as isize], expected, epsilon = 1e-12);
+        }
+    }
+
+    // 边界长度
+    assert_eq!(window::tukey(0, 0.5).unwrap().len(), 0);
+    assert_eq!(window::tukey(1, 0.5).unwrap().to_vec(), vec![1.0]);
+
+    // 参数校验
+    assert_eq!(
+        window::tukey(8, 1.5).unwrap_err(),
+        WindowError::ParameterOutOfRange {
+            name: "alpha",
+            value: 1.5
+        }
+    );
+    assert!(window::tukey(8, -0.1).is_err());
+    assert!(window::tukey(8, f64::NAN).is_err());
+}
+
+// 在频点 bin 处计算加窗单频信号的 DFT 幅值
+fn windowed_tone_magnitude(w: &[f64], tone_bin: f64, bin: usize) -> f64 {
+    let n = w.len() as f64;
+    let x: Vec<f64> = w
+        .iter()
+        .enumerate()
+        .map(|(i, &wi)| wi * (TAU * tone_bin * i as f64 / n).cos())
+        .collect();
+    dft_magnitude(&x, bin)
+}
+
+// 单频信号在一个频点间隔内扫频时的最大扇贝损失（dB）
+fn worst_scalloping_db(w: &[f64]) -> f64 {
+    let bin = 100;
+    let reference = windowed_tone_magnitude(w, bin as f64, bin);
+    (0..=50)
+        .map(|step| {
+            let tone_bin = bin as f64 + step as f64 / 100.0;
+            let mag = windowed_tone_magnitude(w, tone_bin, bin);
+            -20.0 * (mag / reference).log10()
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn test_flattop_and_blackman_harris() {
+    let len = 1024;
+    let flattop = window::flattop(len);
+    let blackman_harris = window::blackman_harris(len);
+    for w in [&flattop, &blackman_harris] {
+        assert_eq!(w.len(), len);
+        for n in 0..len {
+            assert_relative_eq!(w[n as isize], w[(len - 1 - n) as isize], epsilon = 1e-12);
+        }
+    }
+    assert_relative_eq!(blackman_harris[0], 6e-5, epsilon = 1e-12);
+    assert!(flattop.iter().any(|&x| x < 0.0));
+    assert_eq!(window::flattop(1).to_vec(), vec![1.0]);
+    assert_eq!(window::blackman_harris(0).len(), 0);
+
+    let flattop_loss = worst_scalloping_db(&flattop.to_vec());
+    let hann_loss = worst_scalloping_db(&window::tukey(len, 1.0).unwrap().to_vec());
+    assert!(flattop_loss < 0.02, "flat-top scalloping {flattop_loss} dB");
+    assert!(
+        (hann_loss - 1.42).abs() < 0.05,
+        "hann scalloping {hann_loss} dB"
+    );
+}
+
+#[test]
+fn test_gaussian_and_exponential() {
+    // 高斯窗：峰值位于中点，按 σ 衰减
+    let sigma = 4.0;
+    let gauss = window::gaussian(33, sigma).unwrap();
+    assert_eq!(gauss.len(), 33);
+    assert_eq!(gauss[16], 1.0);
+    assert_eq!(gauss.argmax().unwrap(), 16);
+    assert_relative_eq!(gauss[20], (-0.5f64).exp(), epsilon = 1e-12);
+    assert_relative_eq!(gauss[24], (-2.0f64).exp(), epsilon = 1e-12);
+    for n in 0..33 {
+        assert_relative_eq!(gauss[n], gauss[32 - n], epsilon = 1e-12);
+    }
+
+    // 偶数长度时峰值落在两个中间样本之间
+    let even = window::gaussian(8, 2.0).unwrap();
+    assert_eq!(even[3], even[4]);
+    assert!(even[3] < 1.0);
+
+    // 指数窗：每 tau 个样本衰减为 1/e
+    let tau = 3.0;
+    let w = window::exponential(31, None, tau).unwrap();
+    assert_eq!(w[15], 1.0);
+    for k in 1..=15 {
+        assert_relative_eq!(w[15 + k], (-(k as f64) / tau).exp(), epsilon = 1e-12);
+        assert_relative_eq!(w[15 - k], w[15 + k], epsilon = 1e-12);
+    }
+    let shifted = window::exponential(31, Some(0.0), tau).unwrap();
+    assert_eq!(shifted[0], 1.0);
+    assert_relative_eq!(shifted[3], (-1.0f64).exp(), epsilon = 1e-12);
+
+    // 周期窗是长度加一的对称窗去掉最后一个样本
+    let periodic = window::periodic(32, |n| window::gaussian(n, sigma).unwrap());
+    assert_eq!(periodic.len(), 32);
+    assert_eq!(periodic[16], 1.0);
+    assert_eq!(periodic.to_vec(), gauss.to_vec()[..32].to_vec());
+
+    // 参数校验
+    assert!(window::gaussian(8, 0.0).is_err());
+    assert!(window::gaussian(8, f64::INFINITY).is_err());
+    assert!(window::exponential(8, None, -1.0).is_err());
+    assert!(window::exponential(8, Some(f64::NAN), 1.0).is_err());
+}
+
+#[test]
+fn test_apply_window_gain_correction() {
+    // 满量程正弦，恰好落在第 64 个频点上
+    let len = 1024;
+    let bin = 64;
+    let sine = Signal::from_len_fn(len, |n| (TAU * bin as f64 * n as f64 / len as f64).sin());
+    let hann = window::periodic(len, |n| window::tukey(n, 1.0).unwrap());
+
+    let peak_dbfs = |x: &Signal| {
+        let amplitude = 2.0 * dft_magnitude(&x.to_vec(), bin) / len as f64;
+        20.0 * amplitude.log10()
+    };
+    let raw = sine.apply_window(&hann, GainCorrection::None).unwrap();
+    let coherent = sine.apply_window(&hann, GainCorrection::Coherent).unwrap();
+    assert_relative_eq!(peak_dbfs(&raw), 20.0 * 0.5f64.log10(), epsilon = 1e-9);
+    assert_relative_eq!(peak_dbfs(&coherent), 0.0, epsilon = 1e-9);
+
+    // 噪声增益补偿后，加窗前后平均功率一致（对常数信号精确成立）
+    let ones = Signal::ones(len);
+    let noise = ones.apply_window(&hann, GainCorrection::Noise).unwrap();
+    assert_relative_eq!(noise.avg_power(), 1.0, epsilon = 1e-12);
+
+    // 错误处理
+    assert_eq!(
+        sine.apply_window(&Signal::ones(10), GainCorrection::None)
+            .unwrap_err(),
+        WindowError::LengthMismatch {
+            signal: len,
+            window: 10
+        }
+    );
+    assert_eq!(
+        ones.apply_window(&Signal::zeros(len), GainCorrection::Coherent)
+            .unwrap_err(),
+        WindowError::ZeroGain
+    );
+    let empty = Signal::zeros(0);
+    assert_eq!(
+        empty
+            .apply_window(&Signal::zeros(0), GainCorrection::Noise)
+            .unwrap()
+            .len(),
+        0
+    );
+}
+
+// 实信号在频点 bin 处的 DFT 幅值
+fn dft_magnitude(x: &[f64], bin: usize) -> f64 {
+    let n = x.len() as f64;
+    let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &xi)| {
+        let angle = TAU * bin as f64 * i as f64 / n;
+        (re + xi * angle.cos(), im - xi * angle.sin())
+    });
+    (re * re + im * im).sqrt()
+}